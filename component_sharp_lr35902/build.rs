@@ -0,0 +1,778 @@
+// Generates, from the instructions metadata (`extra/data/instructions.json`):
+//
+// - the CPU decoder, which dispatches to the (hand-written) `execute_*` methods;
+// - the disassembler decoder;
+// - the cycles and flag effects tables.
+//
+// The `execute_*` methods are parsed, and checked against the flags, size and cycles metadata, so
+// that a mismatch fails the build; a mismatch in the operands is caught by the compiler, since the
+// decoder calls are generated.
+
+use serde_json::{Map, Value};
+use syn::visit::{self, Visit};
+use syn::{Block, Expr, ExprIf, ExprLit, ExprMethodCall, ExprPath, File, ImplItem, Item, Lit};
+
+use std::collections::{BTreeSet, HashMap};
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const INSTRUCTIONS_FILE: &str = "extra/data/instructions.json";
+const CPU_FILE: &str = "src/cpu.rs";
+
+const DECODING_FILE: &str = "instructions_decoding.rs";
+const DISASSEMBLY_FILE: &str = "instructions_disassembly.rs";
+const TABLES_FILE: &str = "instructions_tables.rs";
+
+const DECODING_HEADER: &str = "
+impl<B: Bus> Cpu<B> {
+    fn decode_and_execute(
+        &mut self,
+        instruction_bytes: &[u8; MAX_INSTRUCTION_SIZE],
+    ) -> Option<u8> {
+        let cycles = match instruction_bytes {
+";
+
+const DECODING_FOOTER: &str = "
+            _ => return None,
+        };
+
+        Some(cycles)
+    }
+}
+";
+
+const DISASSEMBLY_HEADER: &str = "
+fn decode(
+    instruction_bytes: &[u8; MAX_INSTRUCTION_SIZE],
+    address: u16,
+) -> Option<Instruction> {
+    let instruction = match instruction_bytes {
+";
+
+const DISASSEMBLY_FOOTER: &str = "
+        _ => return None,
+    };
+
+    Some(instruction)
+}
+";
+
+// Operand types; see `extra/shared.lib/operand_types.rb`.
+//
+const IMMEDIATE_OPERAND_8: &str = "n";
+const IMMEDIATE_OPERAND_16: &str = "nn";
+const REGISTER_OPERAND_8: &str = "r";
+const REGISTER_OPERAND_16: &str = "rr";
+const FLAG_OPERAND: &str = "cc";
+const BIT_OPERAND: &str = "b";
+const VECTOR_OPERAND: &str = "v";
+
+// Operands resolved in the disassembled mnemonic; the immediates are left as placeholders.
+//
+const RESOLVED_OPERAND_TYPES: [&str; 5] = [
+    REGISTER_OPERAND_8,
+    REGISTER_OPERAND_16,
+    FLAG_OPERAND,
+    BIT_OPERAND,
+    VECTOR_OPERAND,
+];
+
+// Instructions whose 8-bit immediate is signed (besides the relative jumps).
+//
+const SIGNED_IMMEDIATE_INSTRUCTIONS: [&str; 2] = ["ADD SP, n", "LDHL SP, n"];
+
+// Instructions whose immediate/vector operand is a jump/call target.
+//
+const JUMP_MNEMONICS: [&str; 4] = ["JP", "JR", "CALL", "RST"];
+
+// In `Flag` order.
+//
+const FLAGS: [&str; 4] = ["Z", "N", "H", "C"];
+
+const PREFIX_OPCODE: u8 = 0xCB;
+
+// Instructions whose bytes are not all fetched: the STOP second byte is skipped.
+//
+const FETCHED_BYTES_EXCEPTIONS: [(&str, u64); 1] = [("STOP", 1)];
+
+struct Opcode<'a> {
+    instruction: &'a str,
+    instruction_data: &'a Map<String, Value>,
+    opcode: u8,
+    operand_names: Vec<&'a str>,
+}
+
+impl<'a> Opcode<'a> {
+    fn prefix(&self) -> Option<&'a str> {
+        self.instruction_data
+            .get("prefix")
+            .map(|prefix| prefix.as_str().unwrap())
+    }
+
+    fn operand_types(&self) -> Vec<&'a str> {
+        string_array(&self.instruction_data["operand_types"])
+    }
+
+    fn operands(&self) -> Vec<(&'a str, &'a str)> {
+        self.operand_names
+            .iter()
+            .cloned()
+            .zip(self.operand_types())
+            .collect()
+    }
+
+    fn has_operand_type(&self, operand_type: &str) -> bool {
+        self.operand_types().contains(&operand_type)
+    }
+
+    fn instruction_size(&self) -> u64 {
+        self.instruction_data["instruction_size"].as_u64().unwrap()
+    }
+
+    fn fetched_bytes(&self) -> u64 {
+        fetched_bytes(self.instruction, self.instruction_size())
+    }
+
+    fn cycles(&self) -> u64 {
+        self.instruction_data["cycles"].as_u64().unwrap()
+    }
+
+    fn cycles_not_taken(&self) -> Option<u64> {
+        self.instruction_data
+            .get("cycles_not_taken")
+            .map(|cycles| cycles.as_u64().unwrap())
+    }
+
+    // Example: `[0xCB, 0x36, ..`; the closing bracket is left to the caller.
+    //
+    fn matcher_prefix(&self) -> String {
+        match self.prefix() {
+            Some(prefix) => format!("[0x{}, 0x{:02X}", prefix, self.opcode),
+            None => format!("[0x{:02X}", self.opcode),
+        }
+    }
+}
+
+fn string_array(value: &Value) -> Vec<&str> {
+    value
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| entry.as_str().unwrap())
+        .collect()
+}
+
+// Each byte fetched takes an M-cycle.
+//
+fn fetched_bytes(instruction: &str, instruction_size: u64) -> u64 {
+    FETCHED_BYTES_EXCEPTIONS
+        .iter()
+        .find(|(exception, _)| *exception == instruction)
+        .map_or(instruction_size, |(_, fetched_bytes)| *fetched_bytes)
+}
+
+// Example: `LD r1, (rr2)` -> `LD_r1_Irr2`.
+//
+fn encode_instruction(instruction: &str) -> String {
+    instruction
+        .replace('(', "I")
+        .replace(')', "")
+        .replace(", ", "_")
+        .replace(' ', "_")
+}
+
+fn collect_opcodes(instructions_data: &Map<String, Value>) -> Vec<Opcode<'_>> {
+    let mut opcodes = vec![];
+
+    for (instruction, instruction_data) in instructions_data {
+        let instruction_data = instruction_data.as_object().unwrap();
+
+        for (opcode_hex, opcode_data) in instruction_data["opcodes"].as_object().unwrap() {
+            opcodes.push(Opcode {
+                instruction,
+                instruction_data,
+                opcode: u8::from_str_radix(opcode_hex, 16).unwrap(),
+                operand_names: string_array(&opcode_data["operands"]),
+            });
+        }
+    }
+
+    opcodes
+}
+
+// Decoder arms; the rest pattern matches the fetched bytes in excess. Example:
+//
+//     [0x36, immediate, ..] => {
+//         self.execute_LD_IHL_n(immediate);
+//         12
+//     }
+//
+fn generate_decoding(opcodes: &[Opcode]) -> String {
+    let mut buffer = String::new();
+
+    buffer.push_str(DECODING_HEADER);
+
+    for opcode in opcodes {
+        let immediate_bindings = if opcode.has_operand_type(IMMEDIATE_OPERAND_8) {
+            ", immediate"
+        } else if opcode.has_operand_type(IMMEDIATE_OPERAND_16) {
+            ", immediate_low, immediate_high"
+        } else {
+            ""
+        };
+
+        writeln!(
+            buffer,
+            "{}{}, ..] => {{",
+            opcode.matcher_prefix(),
+            immediate_bindings
+        )
+        .unwrap();
+
+        let mut params = vec![];
+
+        for (operand_name, operand_type) in opcode.operands() {
+            match operand_type {
+                REGISTER_OPERAND_8 => params.push(format!("Reg8::{}", operand_name)),
+                REGISTER_OPERAND_16 => params.push(format!("Reg16::{}", operand_name)),
+                IMMEDIATE_OPERAND_8 => params.push("immediate".to_string()),
+                IMMEDIATE_OPERAND_16 => {
+                    // The reference is unnecessary, but we pass it for consistency with the 8-bit
+                    // immediates.
+                    //
+                    writeln!(
+                        buffer,
+                        "let immediate = &u16::from_le_bytes([*immediate_low, *immediate_high]);"
+                    )
+                    .unwrap();
+                    params.push("immediate".to_string());
+                }
+                FLAG_OPERAND => {
+                    let flag = operand_name.chars().last().unwrap().to_ascii_lowercase();
+                    let flag_condition = !operand_name.starts_with('N');
+
+                    writeln!(buffer, "let flag_condition = {};", flag_condition).unwrap();
+                    params.push(format!("Flag::{}", flag));
+                    params.push("flag_condition".to_string());
+                }
+                BIT_OPERAND => params.push(operand_name.to_string()),
+                VECTOR_OPERAND => params.push(format!("0x{}", operand_name.trim_end_matches('H'))),
+                _ => panic!("Unexpected operand type: {}", operand_type),
+            }
+        }
+
+        let execution_method_call = format!(
+            "self.execute_{}({})",
+            encode_instruction(opcode.instruction),
+            params.join(", ")
+        );
+
+        // Conditional instructions return whether the condition matched, which determines the
+        // cycles.
+        //
+        match opcode.cycles_not_taken() {
+            Some(cycles_not_taken) => writeln!(
+                buffer,
+                "if {} {{ {} }} else {{ {} }}",
+                execution_method_call,
+                opcode.cycles(),
+                cycles_not_taken
+            )
+            .unwrap(),
+            None => writeln!(buffer, "{};\n{}", execution_method_call, opcode.cycles()).unwrap(),
+        }
+
+        writeln!(buffer, "}}").unwrap();
+    }
+
+    buffer.push_str(DECODING_FOOTER);
+
+    // Bytes fetched, by (unprefixed) opcode; the prefix is fetched with the following byte, and the
+    // illegal opcodes are fetched on their own.
+    //
+    let mut fetched_bytes_entries = vec!["1".to_string(); 256];
+    fetched_bytes_entries[PREFIX_OPCODE as usize] = "2".to_string();
+
+    for opcode in opcodes.iter().filter(|opcode| opcode.prefix().is_none()) {
+        fetched_bytes_entries[opcode.opcode as usize] = opcode.fetched_bytes().to_string();
+    }
+
+    writeln!(
+        buffer,
+        "const FETCHED_BYTES: [u8; 256] = [{}];",
+        fetched_bytes_entries.join(", ")
+    )
+    .unwrap();
+
+    buffer
+}
+
+// Replaces the operand types in the instruction with the opcode operands, e.g. `LD r1, (rr2)` with
+// `B`, `HL` becomes `LD B, (HL)`. Type names may have a numeric suffix.
+//
+fn resolve_mnemonic(opcode: &Opcode) -> String {
+    let mut tokens = opcode
+        .instruction
+        .split(|char: char| !char.is_ascii_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .collect::<Vec<&str>>();
+    let mut mnemonic = opcode.instruction.to_string();
+
+    for (operand_name, operand_type) in opcode.operands() {
+        if !RESOLVED_OPERAND_TYPES.contains(&operand_type) {
+            continue;
+        }
+
+        let token_index = tokens
+            .iter()
+            .position(|token| token.trim_end_matches(char::is_numeric) == operand_type)
+            .unwrap_or_else(|| {
+                panic!(
+                    "Operand {} not found in {}",
+                    operand_type, opcode.instruction
+                )
+            });
+        let token = tokens.remove(token_index);
+
+        let token_start = find_token(&mnemonic, token);
+        mnemonic.replace_range(token_start..token_start + token.len(), operand_name);
+    }
+
+    mnemonic
+}
+
+// Finds a whole-word token (e.g. `r` in `LD r, (HL)`, but not in `rr`).
+//
+fn find_token(text: &str, token: &str) -> usize {
+    let is_word_char =
+        |char: Option<char>| matches!(char, Some(char) if char.is_ascii_alphanumeric());
+
+    text.match_indices(token)
+        .map(|(index, _)| index)
+        .find(|&index| {
+            !is_word_char(text[..index].chars().last())
+                && !is_word_char(text[index + token.len()..].chars().next())
+        })
+        .unwrap()
+}
+
+fn generate_disassembly(opcodes: &[Opcode]) -> String {
+    let mut buffer = String::new();
+
+    buffer.push_str(DISASSEMBLY_HEADER);
+
+    for opcode in opcodes {
+        let jump_instruction =
+            JUMP_MNEMONICS.contains(&opcode.instruction.split(' ').next().unwrap());
+        let instruction_size = opcode.instruction_size();
+
+        let (immediate_bindings, immediate_assignment, immediate, target) =
+            if opcode.has_operand_type(IMMEDIATE_OPERAND_16) {
+                (
+                    ", immediate_low, immediate_high",
+                    "let immediate = u16::from_le_bytes([*immediate_low, *immediate_high]);",
+                    "Some(Immediate::Word(immediate))".to_string(),
+                    if jump_instruction {
+                        "Some(immediate)".to_string()
+                    } else {
+                        "None".to_string()
+                    },
+                )
+            } else if opcode.has_operand_type(IMMEDIATE_OPERAND_8) {
+                // Relative jump; the offset is relative to the following instruction.
+                //
+                let target = if jump_instruction {
+                    format!(
+                        "Some(address.wrapping_add({}).wrapping_add(*immediate as i8 as u16))",
+                        instruction_size
+                    )
+                } else {
+                    "None".to_string()
+                };
+
+                let immediate = if SIGNED_IMMEDIATE_INSTRUCTIONS.contains(&opcode.instruction) {
+                    "Some(Immediate::SignedByte(*immediate as i8))"
+                } else {
+                    "Some(Immediate::Byte(*immediate))"
+                };
+
+                (", immediate", "", immediate.to_string(), target)
+            } else if opcode.has_operand_type(VECTOR_OPERAND) {
+                let (vector, _) = opcode
+                    .operands()
+                    .into_iter()
+                    .find(|(_, operand_type)| *operand_type == VECTOR_OPERAND)
+                    .unwrap();
+
+                (
+                    "",
+                    "",
+                    "None".to_string(),
+                    format!("Some(0x{})", vector.trim_end_matches('H')),
+                )
+            } else {
+                ("", "", "None".to_string(), "None".to_string())
+            };
+
+        writeln!(
+            buffer,
+            "{}{}, ..] => {{",
+            opcode.matcher_prefix(),
+            immediate_bindings
+        )
+        .unwrap();
+        writeln!(buffer, "{}", immediate_assignment).unwrap();
+        writeln!(
+            buffer,
+            "Instruction {{ address, mnemonic: \"{}\", immediate: {}, size: {}, target: {} }}",
+            resolve_mnemonic(opcode),
+            immediate,
+            instruction_size,
+            target
+        )
+        .unwrap();
+        writeln!(buffer, "}}").unwrap();
+    }
+
+    buffer.push_str(DISASSEMBLY_FOOTER);
+
+    buffer
+}
+
+// Metadata states: `false`/`true` for reset/set; a number (carry position) or `*` for computed.
+//
+fn flag_effect(instruction_data: &Map<String, Value>, flag: &str) -> &'static str {
+    match instruction_data["flags_set"].get(flag) {
+        None => "FlagEffect::Unaffected",
+        Some(Value::Bool(false)) => "FlagEffect::Reset",
+        Some(Value::Bool(true)) => "FlagEffect::Set",
+        Some(_) => "FlagEffect::Computed",
+    }
+}
+
+fn generate_tables(opcodes: &[Opcode]) -> String {
+    let mut buffer = String::new();
+
+    for &(table_prefix, prefixed) in [("UNPREFIXED", false), ("PREFIXED", true)].iter() {
+        let mut cycles_entries = vec!["None".to_string(); 256];
+        let mut flag_effects_entries = vec!["None".to_string(); 256];
+
+        for opcode in opcodes
+            .iter()
+            .filter(|opcode| opcode.prefix().is_some() == prefixed)
+        {
+            let cycles_not_taken = match opcode.cycles_not_taken() {
+                Some(cycles_not_taken) => format!("Some({})", cycles_not_taken),
+                None => "None".to_string(),
+            };
+
+            cycles_entries[opcode.opcode as usize] = format!(
+                "Some(InstructionCycles {{ cycles: {}, cycles_not_taken: {} }})",
+                opcode.cycles(),
+                cycles_not_taken
+            );
+
+            let flag_effects = FLAGS
+                .iter()
+                .map(|flag| flag_effect(opcode.instruction_data, flag))
+                .collect::<Vec<&str>>();
+
+            flag_effects_entries[opcode.opcode as usize] =
+                format!("Some([{}])", flag_effects.join(", "));
+        }
+
+        writeln!(
+            buffer,
+            "pub static {}_CYCLES: [Option<InstructionCycles>; 256] = [{}];",
+            table_prefix,
+            cycles_entries.join(", ")
+        )
+        .unwrap();
+        writeln!(
+            buffer,
+            "pub static {}_FLAG_EFFECTS: [Option<[FlagEffect; 4]>; 256] = [{}];",
+            table_prefix,
+            flag_effects_entries.join(", ")
+        )
+        .unwrap();
+    }
+
+    buffer
+}
+
+// Facts about an `execute_*` method, collected from its syntax tree.
+//
+#[derive(Default)]
+struct ExecuteMethodFacts {
+    flags_set: BTreeSet<String>,
+    writes_flags_register: bool,
+    pc_offsets: Vec<u64>,
+}
+
+impl<'ast> Visit<'ast> for ExecuteMethodFacts {
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        let first_arg = call.args.first();
+
+        match call.method.to_string().as_str() {
+            "set_flag" => {
+                if let Some(flag) = first_arg.and_then(path_last_segment) {
+                    self.flags_set.insert(flag.to_ascii_uppercase());
+                }
+            }
+            "wrapping_add" | "overflowing_add" if is_pc_read(&call.receiver) => {
+                self.pc_offsets.extend(first_arg.and_then(int_literal));
+            }
+            _ => {}
+        }
+
+        visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_expr_path(&mut self, path: &'ast ExprPath) {
+        if path_last_segment(&Expr::Path(path.clone())).as_deref() == Some("AF") {
+            self.writes_flags_register = true;
+        }
+    }
+}
+
+// An execution path through an `execute_*` method. The condition is set once the method branches on
+// `condition_matching` (conditional instructions).
+//
+#[derive(Clone, Copy)]
+struct ExecutionPath {
+    condition_matching: Option<bool>,
+    mcycles: u64,
+}
+
+// Counts the M-cycles ticked on each execution path, after the instruction fetch: each memory access
+// or internal M-cycle.
+//
+struct MCyclesCounter {
+    paths: Vec<ExecutionPath>,
+}
+
+impl<'ast> Visit<'ast> for MCyclesCounter {
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        visit::visit_expr_method_call(self, call);
+
+        let mcycles = match call.method.to_string().as_str() {
+            "read8" | "write8" | "internal_mcycle" => 1,
+            _ => 0,
+        };
+
+        for path in self.paths.iter_mut() {
+            path.mcycles += mcycles;
+        }
+    }
+
+    fn visit_expr_if(&mut self, expr_if: &'ast ExprIf) {
+        self.visit_expr(&expr_if.cond);
+
+        let branches_on_condition =
+            path_last_segment(&expr_if.cond).as_deref() == Some("condition_matching");
+
+        let mut then_counter = MCyclesCounter {
+            paths: self.paths.clone(),
+        };
+        then_counter.visit_block(&expr_if.then_branch);
+
+        let mut else_counter = MCyclesCounter {
+            paths: self.paths.clone(),
+        };
+        if let Some((_, else_branch)) = &expr_if.else_branch {
+            else_counter.visit_expr(else_branch);
+        }
+
+        if branches_on_condition {
+            for path in then_counter.paths.iter_mut() {
+                path.condition_matching = Some(true);
+            }
+            for path in else_counter.paths.iter_mut() {
+                path.condition_matching = Some(false);
+            }
+        }
+
+        self.paths = then_counter.paths;
+        self.paths.extend(else_counter.paths);
+    }
+}
+
+fn path_last_segment(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Path(path) => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string()),
+        _ => None,
+    }
+}
+
+fn int_literal(expr: &Expr) -> Option<u64> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(literal),
+            ..
+        }) => literal.base10_parse().ok(),
+        _ => None,
+    }
+}
+
+// Matches `self.reg16(Reg16::PC)`.
+//
+fn is_pc_read(expr: &Expr) -> bool {
+    match expr {
+        Expr::MethodCall(call) => {
+            call.method == "reg16"
+                && call.args.first().and_then(path_last_segment).as_deref() == Some("PC")
+        }
+        _ => false,
+    }
+}
+
+fn collect_execute_methods(cpu_file: &File) -> HashMap<String, &Block> {
+    let mut methods = HashMap::new();
+
+    for item in &cpu_file.items {
+        if let Item::Impl(item_impl) = item {
+            for impl_item in &item_impl.items {
+                if let ImplItem::Fn(method) = impl_item {
+                    let name = method.sig.ident.to_string();
+
+                    if let Some(encoded_instruction) = name.strip_prefix("execute_") {
+                        methods.insert(encoded_instruction.to_string(), &method.block);
+                    }
+                }
+            }
+        }
+    }
+
+    methods
+}
+
+// Verifies the `execute_*` methods against the metadata:
+//
+// - they set exactly the flags affected; instructions that write F as a whole (`POP AF`) are not
+//   required to set the flags individually;
+// - the PC advances/return addresses match the instruction size;
+// - the M-cycles ticked on each path, including the fetch, don't exceed the cycles (for conditional instructions, the
+//   path where the condition is not matching is checked against the cycles not taken); the
+//   remaining ones are the internal M-cycles at the end of the instruction, ticked by the step.
+//
+fn check_execute_methods(instructions_data: &Map<String, Value>, cpu_source: &str) -> Vec<String> {
+    let cpu_file = syn::parse_file(cpu_source).unwrap();
+    let execute_methods = collect_execute_methods(&cpu_file);
+
+    let mut errors = vec![];
+
+    for (instruction, instruction_data) in instructions_data {
+        let method_body = match execute_methods.get(&encode_instruction(instruction)) {
+            Some(method_body) => *method_body,
+            None => {
+                errors.push(format!("Missing method for instruction {}", instruction));
+                continue;
+            }
+        };
+
+        let mut facts = ExecuteMethodFacts::default();
+        facts.visit_block(method_body);
+
+        for flag in FLAGS.iter() {
+            let flag_set_in_code = facts.flags_set.contains(*flag);
+            let flag_affected = instruction_data["flags_set"].get(*flag).is_some();
+
+            if flag_set_in_code && !flag_affected {
+                errors.push(format!(
+                    "Instruction {} sets the unaffected flag {}",
+                    instruction, flag
+                ));
+            } else if !flag_set_in_code && flag_affected && !facts.writes_flags_register {
+                errors.push(format!(
+                    "Instruction {} doesn't set the affected flag {}",
+                    instruction, flag
+                ));
+            }
+        }
+
+        let instruction_size = instruction_data["instruction_size"].as_u64().unwrap();
+
+        for pc_offset in facts.pc_offsets {
+            if pc_offset != instruction_size {
+                errors.push(format!(
+                    "Instruction {} offsets PC by {}, but its size is {}",
+                    instruction, pc_offset, instruction_size
+                ));
+            }
+        }
+
+        let cycles = instruction_data["cycles"].as_u64().unwrap();
+        let cycles_not_taken = instruction_data
+            .get("cycles_not_taken")
+            .map(|value| value.as_u64().unwrap());
+
+        let mut counter = MCyclesCounter {
+            paths: vec![ExecutionPath {
+                condition_matching: None,
+                mcycles: fetched_bytes(instruction, instruction_size),
+            }],
+        };
+        counter.visit_block(method_body);
+
+        for path in counter.paths {
+            let path_cycles = match (cycles_not_taken, path.condition_matching) {
+                (Some(cycles_not_taken), Some(false)) => cycles_not_taken,
+                (Some(_), None) => {
+                    errors.push(format!(
+                        "Instruction {} doesn't branch on `condition_matching`",
+                        instruction
+                    ));
+                    continue;
+                }
+                _ => cycles,
+            };
+
+            if path.mcycles * 4 > path_cycles {
+                errors.push(format!(
+                    "Instruction {} ticks {} M-cycles, but takes {} cycles",
+                    instruction, path.mcycles, path_cycles
+                ));
+            }
+        }
+    }
+
+    errors
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", INSTRUCTIONS_FILE);
+    println!("cargo:rerun-if-changed={}", CPU_FILE);
+
+    let instructions_content = fs::read_to_string(INSTRUCTIONS_FILE).unwrap();
+    let instructions_data: Map<String, Value> =
+        serde_json::from_str(&instructions_content).unwrap();
+
+    let cpu_source = fs::read_to_string(CPU_FILE).unwrap();
+    let errors = check_execute_methods(&instructions_data, &cpu_source);
+
+    if !errors.is_empty() {
+        panic!(
+            "Instructions metadata/code mismatch:\n{}",
+            errors.join("\n")
+        );
+    }
+
+    let opcodes = collect_opcodes(&instructions_data);
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let out_dir = Path::new(&out_dir);
+
+    fs::write(out_dir.join(DECODING_FILE), generate_decoding(&opcodes)).unwrap();
+    fs::write(
+        out_dir.join(DISASSEMBLY_FILE),
+        generate_disassembly(&opcodes),
+    )
+    .unwrap();
+    fs::write(out_dir.join(TABLES_FILE), generate_tables(&opcodes)).unwrap();
+}
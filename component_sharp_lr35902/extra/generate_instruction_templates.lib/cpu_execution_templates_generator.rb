@@ -12,7 +12,7 @@ class CpuExecutionTemplatesGenerator
     generate_register_operations!(instruction_data, instruction_code)
     generate_flag_operations!(instruction_encoded, instruction_data, instruction_code)
 
-    generate_closure!
+    generate_closure!(instruction_data)
   end
 
   def code
@@ -38,23 +38,32 @@ class CpuExecutionTemplatesGenerator
         @buffer.print ", immediate: &u16"
       when FLAG_OPERAND
         @buffer.print ", flag: Flag, flag_condition: bool"
+      when BIT_OPERAND
+        @buffer.print ", bit: u8"
+      when VECTOR_OPERAND
+        @buffer.print ", vector: u16"
       else
         raise "Unexpected operand type: #{operand_type.type}"
       end
     end
 
-    @buffer.puts ") {"
+    # Conditional instructions return whether the condition matched (see `generate_closure!`).
+    #
+    return_type = " -> bool" if instruction_data.key?("cycles_not_taken")
+
+    @buffer.puts ")#{return_type} {"
   end
 
+  # The instruction bytes are fetched (and their M-cycles ticked) by the CPU before the execution.
+  #
   def generate_register_operations!(instruction_data, instruction_code)
     operation_code = instruction_code.fetch(:operation_code)
+    instruction_size = instruction_data.fetch("instruction_size")
 
     if operation_code
-      if !operation_code.include?("self[Reg16::PC] = ")
-        instruction_size = instruction_data.fetch("instruction_size")
-
+      if !operation_code.include?("self.set_reg16(Reg16::PC, ")
         @buffer.puts <<-RUST
-        self[Reg16::PC] += #{instruction_size};
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(#{instruction_size}));
 
         RUST
       end
@@ -105,7 +114,7 @@ class CpuExecutionTemplatesGenerator
       RUST
     when 4, 8, 12, 16
       <<-RUST
-        let flag_#{flag.downcase}_value = Cpu::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, #{state});
+        let flag_#{flag.downcase}_value = Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, #{state});
         self.set_flag(Flag::#{flag.downcase}, flag_#{flag.downcase}_value);
       RUST
     end
@@ -133,9 +142,16 @@ class CpuExecutionTemplatesGenerator
     end
   end
 
-  # Closing brace.
+  # Closing brace; conditional instructions are required to define `condition_matching`, which is
+  # returned.
   #
-  def generate_closure!
+  def generate_closure!(instruction_data)
+    if instruction_data.key?("cycles_not_taken")
+      @buffer.puts <<-RUST
+        condition_matching
+      RUST
+    end
+
     @buffer.puts <<-RUST
     }
 
@@ -1,17 +1,13 @@
 require 'json'
-require 'open-uri'
 
-require_relative "cpu_decoding_template_generator"
 require_relative "cpu_execution_templates_generator"
 require_relative "templates_generator"
 require_relative "instructions_code"
 require_relative "test_templates_generator"
 
+# The decoder, the disassembler and the metadata tables are generated at build time (see `build.rs`).
+#
 class CpuTemplatesGenerator
-  OPCODES_ADDRESS = 'https://gbdev.io/gb-opcodes/Opcodes.json'
-
-  DECODING_REPLACEMENT_START_PATTERN = '// __OPCODES_DECODING_REPLACEMENT_START__'
-  DECODING_REPLACEMENT_END_PATTERN = '// __OPCODES_DECODING_REPLACEMENT_END__'
   EXECUTION_REPLACEMENT_START_PATTERN = '// __OPCODES_EXECUTION_REPLACEMENT_START__'
   EXECUTION_REPLACEMENT_END_PATTERN = '// __OPCODES_EXECUTION_REPLACEMENT_END__'
   TESTS_REPLACEMENT_START_PATTERN = '// __TESTS_REPLACEMENT_START__'
@@ -25,22 +21,11 @@ class CpuTemplatesGenerator
 
   def execute(only_opcodes: [])
     instructions_data = JSON.parse(IO.read(@instructions_file))
-    cpu_decoding_code, cpu_execution_code, tests_code = generate_templates(instructions_data, only_opcodes: only_opcodes)
-    insert_content_in_source_files(cpu_decoding_code, cpu_execution_code, tests_code)
-  end
-
-  def download_json_page_content
-    page_content = open(OPCODES_ADDRESS).read
-    prettified_data = JSON.pretty_generate(JSON.parse(page_content))
-    IO.write(@instructions_file, prettified_data)
-  end
-
-  def find_and_read_json_page_content
-    IO.read(@instructions_file)
+    cpu_execution_code, tests_code = generate_templates(instructions_data, only_opcodes: only_opcodes)
+    insert_content_in_source_files(cpu_execution_code, tests_code)
   end
 
   def generate_templates(instructions_data, only_opcodes:)
-    decoding_generator = CpuDecodingTemplateGenerator.new
     execution_generator = CpuExecutionTemplatesGenerator.new
     tests_generator = TestTemplatesGenerator.new
 
@@ -70,7 +55,6 @@ class CpuTemplatesGenerator
       end
 
       opcodes_data.each do |opcode, opcode_data|
-        decoding_generator.add_code!(opcode, instruction_encoded, opcode_data, instruction_data)
         tests_generator.add_code!(opcode, instruction, instruction_encoded, opcode_data, instruction_data, instruction_code)
       end
 
@@ -81,14 +65,13 @@ class CpuTemplatesGenerator
 
     # Remove the trailing empty line, if any.
     #
-    [decoding_generator, execution_generator, tests_generator].map { |generator| generator.code.sub(/^\n\Z/, '') }
+    [execution_generator, tests_generator].map { |generator| generator.code.sub(/^\n\Z/, '') }
   end
 
-  def insert_content_in_source_files(cpu_decoding_code, cpu_execution_code, tests_code)
+  def insert_content_in_source_files(cpu_execution_code, tests_code)
     cpu_file_content = IO.read(@cpu_file)
 
     new_cpu_file_content = cpu_file_content
-      .sub(/^( *#{DECODING_REPLACEMENT_START_PATTERN}\n).*(^ *#{DECODING_REPLACEMENT_END_PATTERN}\n)/m, "\\1#{cpu_decoding_code}\\2")
       .sub(/^( *#{EXECUTION_REPLACEMENT_START_PATTERN}\n).*(^ *#{EXECUTION_REPLACEMENT_END_PATTERN}\n)/m, "\\1#{cpu_execution_code}\\2")
 
     IO.write(@cpu_file, new_cpu_file_content)
@@ -80,8 +80,9 @@ class TestTemplatesGenerator
       title = "with jump condition #{jump_condition}, jump #{"not " if !condition_matching}performed: "
 
       test_input_params = [flag, flag_value, condition_matching]
+      cycles_key = condition_matching ? "cycles" : "cycles_not_taken"
 
-      generate_test_body!(opcode, opcode_data, instruction_data, instruction_code, title, test_input_params)
+      generate_test_body!(opcode, opcode_data, instruction_data, instruction_code, title, test_input_params, cycles_key: cycles_key)
     end
   end
 
@@ -131,7 +132,7 @@ class TestTemplatesGenerator
     end
   end
 
-  def generate_test_body!(opcode, opcode_data, instruction_data, instruction_code, title, test_input_params, test_key_prefix: //, flags_preset: [], flag_expectations: [])
+  def generate_test_body!(opcode, opcode_data, instruction_data, instruction_code, title, test_input_params, test_key_prefix: //, flags_preset: [], flag_expectations: [], cycles_key: "cycles")
     testing_block = instruction_code.fetch(:testing)
 
     tests_data =
@@ -165,8 +166,8 @@ class TestTemplatesGenerator
 
       presets ||= ""
 
-      if !presets.include?("cpu[Reg16::PC] = ")
-        presets = "cpu[Reg16::PC] = 0x21;\n#{presets}"
+      if !presets.include?("cpu.set_reg16(Reg16::PC, ")
+        presets = "cpu.set_reg16(Reg16::PC, 0x21);\n#{presets}"
       end
 
       presets.each_line.map(&:strip).each do |preset_statement|
@@ -225,7 +226,7 @@ class TestTemplatesGenerator
         @buffer.puts "                        #{expectation}"
       end
 
-      cycles = instruction_data.fetch("cycles")
+      cycles = instruction_data.fetch(cycles_key)
 
       @buffer.puts <<-RUST
                         cycles: #{cycles}
@@ -613,6 +613,12 @@ module InstructionsIntegrationData
       opcodes: [
         0x10,
       ],
+      # The opcode is followed by a byte (conventionally 0x00), which is skipped; the source data
+      # defines the instruction as 1 byte long.
+      #
+      transform_data: ->(data) do
+        data['bytes'] = 2
+      end,
     },
     "DI" => {
       opcodes: [
@@ -628,41 +634,21 @@ module InstructionsIntegrationData
       opcodes: [
         0x07,
       ],
-      # Bug fix: See https://git.io/JTWwx.
-      #
-      transform_data: ->(data) do
-        data['flags']['Z'] = 'Z'
-      end,
     },
     "RLA" => {
       opcodes: [
         0x17,
       ],
-      # Bug fix: See https://git.io/JTWwx.
-      #
-      transform_data: ->(data) do
-        data['flags']['Z'] = 'Z'
-      end,
     },
     "RRCA" => {
       opcodes: [
         0x0F,
       ],
-      # Bug fix: See https://git.io/JTWwx.
-      #
-      transform_data: ->(data) do
-        data['flags']['Z'] = 'Z'
-      end,
     },
     "RRA" => {
       opcodes: [
         0x1F,
       ],
-      # Bug fix: See https://git.io/JTWwx.
-      #
-      transform_data: ->(data) do
-        data['flags']['Z'] = 'Z'
-      end,
     },
     "RLC r" => {
       prefix: 0xCB,
@@ -790,7 +776,7 @@ module InstructionsIntegrationData
         0x3E,
       ],
     },
-    "BIT n, r" => {
+    "BIT b, r" => {
       prefix: 0xCB,
       opcodes: [
         0x47,
@@ -800,15 +786,71 @@ module InstructionsIntegrationData
         0x43,
         0x44,
         0x45,
+        0x4F,
+        0x48,
+        0x49,
+        0x4A,
+        0x4B,
+        0x4C,
+        0x4D,
+        0x57,
+        0x50,
+        0x51,
+        0x52,
+        0x53,
+        0x54,
+        0x55,
+        0x5F,
+        0x58,
+        0x59,
+        0x5A,
+        0x5B,
+        0x5C,
+        0x5D,
+        0x67,
+        0x60,
+        0x61,
+        0x62,
+        0x63,
+        0x64,
+        0x65,
+        0x6F,
+        0x68,
+        0x69,
+        0x6A,
+        0x6B,
+        0x6C,
+        0x6D,
+        0x77,
+        0x70,
+        0x71,
+        0x72,
+        0x73,
+        0x74,
+        0x75,
+        0x7F,
+        0x78,
+        0x79,
+        0x7A,
+        0x7B,
+        0x7C,
+        0x7D,
       ],
     },
-    "BIT n, (HL)" => {
+    "BIT b, (HL)" => {
       prefix: 0xCB,
       opcodes: [
         0x46,
+        0x4E,
+        0x56,
+        0x5E,
+        0x66,
+        0x6E,
+        0x76,
+        0x7E,
       ],
     },
-    "SET n, r" => {
+    "SET b, r" => {
       prefix: 0xCB,
       opcodes: [
         0xC7,
@@ -818,15 +860,71 @@ module InstructionsIntegrationData
         0xC3,
         0xC4,
         0xC5,
+        0xCF,
+        0xC8,
+        0xC9,
+        0xCA,
+        0xCB,
+        0xCC,
+        0xCD,
+        0xD7,
+        0xD0,
+        0xD1,
+        0xD2,
+        0xD3,
+        0xD4,
+        0xD5,
+        0xDF,
+        0xD8,
+        0xD9,
+        0xDA,
+        0xDB,
+        0xDC,
+        0xDD,
+        0xE7,
+        0xE0,
+        0xE1,
+        0xE2,
+        0xE3,
+        0xE4,
+        0xE5,
+        0xEF,
+        0xE8,
+        0xE9,
+        0xEA,
+        0xEB,
+        0xEC,
+        0xED,
+        0xF7,
+        0xF0,
+        0xF1,
+        0xF2,
+        0xF3,
+        0xF4,
+        0xF5,
+        0xFF,
+        0xF8,
+        0xF9,
+        0xFA,
+        0xFB,
+        0xFC,
+        0xFD,
       ],
     },
-    "SET n, (HL)" => {
+    "SET b, (HL)" => {
       prefix: 0xCB,
       opcodes: [
         0xC6,
+        0xCE,
+        0xD6,
+        0xDE,
+        0xE6,
+        0xEE,
+        0xF6,
+        0xFE,
       ],
     },
-    "RES n, r" => {
+    "RES b, r" => {
       prefix: 0xCB,
       opcodes: [
         0x87,
@@ -836,12 +934,68 @@ module InstructionsIntegrationData
         0x83,
         0x84,
         0x85,
+        0x8F,
+        0x88,
+        0x89,
+        0x8A,
+        0x8B,
+        0x8C,
+        0x8D,
+        0x97,
+        0x90,
+        0x91,
+        0x92,
+        0x93,
+        0x94,
+        0x95,
+        0x9F,
+        0x98,
+        0x99,
+        0x9A,
+        0x9B,
+        0x9C,
+        0x9D,
+        0xA7,
+        0xA0,
+        0xA1,
+        0xA2,
+        0xA3,
+        0xA4,
+        0xA5,
+        0xAF,
+        0xA8,
+        0xA9,
+        0xAA,
+        0xAB,
+        0xAC,
+        0xAD,
+        0xB7,
+        0xB0,
+        0xB1,
+        0xB2,
+        0xB3,
+        0xB4,
+        0xB5,
+        0xBF,
+        0xB8,
+        0xB9,
+        0xBA,
+        0xBB,
+        0xBC,
+        0xBD,
       ],
     },
-    "RES n, (HL)" => {
+    "RES b, (HL)" => {
       prefix: 0xCB,
       opcodes: [
         0x86,
+        0x8E,
+        0x96,
+        0x9E,
+        0xA6,
+        0xAE,
+        0xB6,
+        0xBE,
       ],
     },
     "JP nn" => {
@@ -888,9 +1042,7 @@ module InstructionsIntegrationData
         0xDC,
       ],
     },
-    # No `n`, since the operand is implicit.
-    #
-    "RST" => {
+    "RST v" => {
       opcodes: [
         0xC7,
         0xCF,
@@ -901,11 +1053,6 @@ module InstructionsIntegrationData
         0xF7,
         0xFF,
       ],
-      transform_data: ->(data) do
-        # The operand is implicit.
-        #
-        data['operands'] = []
-      end,
     },
     "RET" => {
       opcodes: [
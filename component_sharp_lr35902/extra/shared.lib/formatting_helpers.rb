@@ -2,4 +2,10 @@ module FormattingHelpers
   def hex(value)
     "0x%02X" % value
   end
+
+  # Byte, formatted in nibbles; e.g. `0b0000_1000`.
+  #
+  def binary(value)
+    "0b%04b_%04b" % [value >> 4, value & 0b1111]
+  end
 end
@@ -4,4 +4,6 @@ module OperandTypes
   REGISTER_OPERAND_8 = "r"
   REGISTER_OPERAND_16 = "rr"
   FLAG_OPERAND = "cc"
+  BIT_OPERAND = "b"
+  VECTOR_OPERAND = "v"
 end
@@ -0,0 +1,56 @@
+use super::power_on::PowerOn;
+use rand::RngCore;
+
+/// Memory bus, as seen by the CPU; the system memory map (cartridge, I/O registers, mirrors...) is
+/// implemented by the system on top of this.
+///
+pub trait Bus {
+    fn read8(&mut self, address: u16) -> u8;
+    fn write8(&mut self, address: u16, value: u8);
+
+    /// Invoked with the clock ticks spent, for the components that need to be kept in sync with the
+    /// CPU; it's invoked after each step, or, with the `mcycle-timing` feature, on each M-cycle.
+    ///
+    fn tick(&mut self, _cycles: u8) {}
+
+    /// Invoked when STOP is executed; returns true if the system handled it (e.g. the CGB speed
+    /// switch), in which case the CPU doesn't enter the stopped state.
+    ///
+    fn stop(&mut self) -> bool {
+        false
+    }
+}
+
+/// 64 KiB of plain RAM, with no mapping; used for testing.
+///
+pub struct FlatRam {
+    pub memory: [u8; 0x10_000],
+}
+
+impl FlatRam {
+    // The RAM is filled with random values, like the real hardware does on power up.
+    //
+    pub fn new() -> Self {
+        let mut memory = [0; 0x10_000];
+        rand::thread_rng().fill_bytes(&mut memory);
+
+        FlatRam { memory }
+    }
+
+    pub fn with_power_on(power_on: PowerOn) -> Self {
+        let mut memory = [0; 0x10_000];
+        power_on.fill_memory(&mut memory);
+
+        FlatRam { memory }
+    }
+}
+
+impl Bus for FlatRam {
+    fn read8(&mut self, address: u16) -> u8 {
+        self.memory[address as usize]
+    }
+
+    fn write8(&mut self, address: u16, value: u8) {
+        self.memory[address as usize] = value;
+    }
+}
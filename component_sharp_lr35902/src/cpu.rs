@@ -1,22 +1,37 @@
 #![allow(non_camel_case_types)]
+// The generated carry computations cast the operands regardless of their type.
+#![allow(clippy::unnecessary_cast)]
 
-use super::utils;
-use rand::RngCore;
-use std::convert::TryInto;
+use super::bus::Bus;
+use super::disassembler::disassemble;
+use super::power_on::PowerOn;
 use std::ops::{Index, IndexMut};
 use strum_macros::EnumIter;
 
-#[derive(Copy, Clone)]
-pub struct Registers8Pair {
-    pub l: u8,
-    pub h: u8,
-}
+const MAX_INSTRUCTION_SIZE: usize = 3;
 
-#[repr(C)]
-pub union Register16 {
-    pub r16: u16,
-    pub r8: Registers8Pair,
-}
+// Interrupt registers; they're accessed through the bus, so that the system components can request
+// interrupts by setting the IF bits. Bits 0 to 4 are, in order of priority: V-Blank, LCD STAT,
+// Timer, Serial and Joypad.
+//
+pub const INTERRUPT_ENABLE_ADDRESS: u16 = 0xFFFF;
+pub const INTERRUPT_FLAG_ADDRESS: u16 = 0xFF0F;
+pub const JOYPAD_INTERRUPT: u8 = 0b0001_0000;
+const INTERRUPTS_MASK: u8 = 0b0001_1111;
+
+const INTERRUPT_VECTORS_BASE: u16 = 0x40;
+const INTERRUPT_DISPATCH_CYCLES: u8 = 20;
+const HALT_EXIT_CYCLES: u8 = 4;
+const IDLE_CYCLES: u8 = 4; // Clock ticks spent per step, while halted/stopped/locked
+
+// Opcodes not assigned to any instruction; on the hardware, executing them locks up the CPU.
+//
+const ILLEGAL_OPCODES: [u8; 11] = [
+    0xD3, 0xDB, 0xDD, 0xE3, 0xE4, 0xEB, 0xEC, 0xED, 0xF4, 0xFC, 0xFD,
+];
+
+#[cfg(feature = "mcycle-timing")]
+const MCYCLE_TICKS: u8 = 4;
 
 // Naming of the register enums is tricky. Using `Register<width>` is accurate, however it clashes
 // with the struct. Since such enums are extensively used, using an abbreviation works the problem
@@ -25,7 +40,7 @@ pub union Register16 {
 // attention.
 //
 #[derive(Copy, Clone, Debug, EnumIter)]
-pub(crate) enum Reg8 {
+pub enum Reg8 {
     A,
     F,
     B,
@@ -40,7 +55,7 @@ pub(crate) enum Reg8 {
 // they're treated as such is `PUSH/POP AF`.
 //
 #[derive(Copy, Clone, Debug, EnumIter)]
-pub(crate) enum Reg16 {
+pub enum Reg16 {
     AF,
     BC,
     DE,
@@ -62,96 +77,105 @@ pub enum Flag {
 // Preliminary, supersimplified implementation.
 // Based on a cursory look at the manuals, there is one operation that mass-sets the flags, so it
 // makes sense to store them individually.
-// The execution is temporary, designed with in mind the testability only.
 //
-pub struct Cpu {
-    // WATCH OUT! For consistency, registers/flags must be accessed via Index[Mut] trait, with the
-    // exception of flag setting, to perform via `flag_set()`.
+pub struct Cpu<B: Bus> {
+    // WATCH OUT! For consistency, registers/flags must be accessed via Index[Mut] trait (8 bit),
+    // `[set_]reg16()` (16 bit) and `[set_]flag()`.
 
-    // Registers; flags are part of the `F` register.
+    // 8-bit registers, in `Reg8` order, so that each pair is stored high byte first; flags are part
+    // of the `F` register. Pairs are composed/split explicitly in big endian order, which keeps the
+    // layout independent from the host endianness.
     //
-    AF: Register16,
-    BC: Register16,
-    DE: Register16,
-    HL: Register16,
+    registers: [u8; 8],
     SP: u16,
     PC: u16,
 
-    // Internal RAM. Until there is properly memory management, this is kept trivial.
+    // Interrupt Master Enable flag; `EI` sets it after the following instruction, so it's scheduled.
+    //
+    pub IME: bool,
+    pub(crate) ime_scheduled: bool,
+
+    // Low power states; `halt_bug` is set when HALT is executed with IME disabled and an interrupt
+    // pending, in which case, the next opcode is read twice.
+    //
+    pub(crate) halted: bool,
+    pub(crate) halt_bug: bool,
+    pub(crate) stopped: bool,
+
+    // Set when an illegal opcode is executed; there's no way out (interrupts are not serviced), other
+    // than resetting the system.
+    //
+    locked: bool,
+
+    // Clock ticks of the current step, already ticked on the bus; always 0, unless in M-cycle
+    // timing mode.
     //
-    pub internal_ram: [u8; 0x10_000],
+    ticks_done: u8,
+
+    pub bus: B,
 }
 
-impl Index<Reg8> for Cpu {
+impl<B: Bus> Index<Reg8> for Cpu<B> {
     type Output = u8;
 
     fn index(&self, register: Reg8) -> &Self::Output {
-        match register {
-            Reg8::A => unsafe { &self.AF.r8.h },
-            Reg8::F => unsafe { &self.AF.r8.l },
-            Reg8::B => unsafe { &self.BC.r8.h },
-            Reg8::C => unsafe { &self.BC.r8.l },
-            Reg8::D => unsafe { &self.DE.r8.h },
-            Reg8::E => unsafe { &self.DE.r8.l },
-            Reg8::H => unsafe { &self.HL.r8.h },
-            Reg8::L => unsafe { &self.HL.r8.l },
-        }
+        &self.registers[register as usize]
     }
 }
 
-impl IndexMut<Reg8> for Cpu {
+impl<B: Bus> IndexMut<Reg8> for Cpu<B> {
     fn index_mut(&mut self, register: Reg8) -> &mut Self::Output {
-        match register {
-            Reg8::A => unsafe { &mut self.AF.r8.h },
-            Reg8::F => unsafe { &mut self.AF.r8.l },
-            Reg8::B => unsafe { &mut self.BC.r8.h },
-            Reg8::C => unsafe { &mut self.BC.r8.l },
-            Reg8::D => unsafe { &mut self.DE.r8.h },
-            Reg8::E => unsafe { &mut self.DE.r8.l },
-            Reg8::H => unsafe { &mut self.HL.r8.h },
-            Reg8::L => unsafe { &mut self.HL.r8.l },
-        }
+        &mut self.registers[register as usize]
     }
 }
 
-impl Index<Reg16> for Cpu {
-    type Output = u16;
-
-    fn index(&self, register: Reg16) -> &Self::Output {
+// 16-bit registers can't be accessed via `Index[Mut]`, since the pairs are not stored as `u16`.
+//
+impl<B: Bus> Cpu<B> {
+    pub fn reg16(&self, register: Reg16) -> u16 {
         match register {
-            Reg16::AF => unsafe { &self.AF.r16 },
-            Reg16::BC => unsafe { &self.BC.r16 },
-            Reg16::DE => unsafe { &self.DE.r16 },
-            Reg16::HL => unsafe { &self.HL.r16 },
-            Reg16::SP => &self.SP,
-            Reg16::PC => &self.PC,
+            Reg16::AF => self.register_pair(0),
+            Reg16::BC => self.register_pair(2),
+            Reg16::DE => self.register_pair(4),
+            Reg16::HL => self.register_pair(6),
+            Reg16::SP => self.SP,
+            Reg16::PC => self.PC,
         }
     }
-}
 
-impl IndexMut<Reg16> for Cpu {
-    fn index_mut(&mut self, register: Reg16) -> &mut Self::Output {
+    pub fn set_reg16(&mut self, register: Reg16, value: u16) {
         match register {
-            Reg16::AF => unsafe { &mut self.AF.r16 },
-            Reg16::BC => unsafe { &mut self.BC.r16 },
-            Reg16::DE => unsafe { &mut self.DE.r16 },
-            Reg16::HL => unsafe { &mut self.HL.r16 },
-            Reg16::SP => &mut self.SP,
-            Reg16::PC => &mut self.PC,
+            Reg16::AF => self.set_register_pair(0, value),
+            Reg16::BC => self.set_register_pair(2, value),
+            Reg16::DE => self.set_register_pair(4, value),
+            Reg16::HL => self.set_register_pair(6, value),
+            Reg16::SP => self.SP = value,
+            Reg16::PC => self.PC = value,
         }
     }
+
+    fn register_pair(&self, high_index: usize) -> u16 {
+        u16::from_be_bytes([self.registers[high_index], self.registers[high_index + 1]])
+    }
+
+    fn set_register_pair(&mut self, high_index: usize, value: u16) {
+        let [high, low] = value.to_be_bytes();
+
+        self.registers[high_index] = high;
+        self.registers[high_index + 1] = low;
+    }
 }
 
 // Flags and `Index[Mut]` don't play very well together, for a variety of reasons, basically, the
 // mismatch between the return value (int or bool), and the underlying representation (sub-byte).
 //
-impl Cpu {
+impl<B: Bus> Cpu<B> {
     pub fn get_flag(&self, flag: Flag) -> bool {
         match flag {
-            Flag::z => (unsafe { self.AF.r8.l } & 0b1000_0000 > 0),
-            Flag::n => (unsafe { self.AF.r8.l } & 0b0100_0000 > 0),
-            Flag::h => (unsafe { self.AF.r8.l } & 0b0010_0000 > 0),
-            Flag::c => (unsafe { self.AF.r8.l } & 0b0001_0000 > 0),
+            Flag::z => self[Reg8::F] & 0b1000_0000 > 0,
+            Flag::n => self[Reg8::F] & 0b0100_0000 > 0,
+            Flag::h => self[Reg8::F] & 0b0010_0000 > 0,
+            Flag::c => self[Reg8::F] & 0b0001_0000 > 0,
         }
     }
 
@@ -171,60 +195,1607 @@ impl Cpu {
     }
 }
 
-impl Cpu {
-    pub fn new() -> Self {
-        let mut internal_ram = [0; 0x10_000];
-        rand::thread_rng().fill_bytes(&mut internal_ram);
+impl<B: Bus> Cpu<B> {
+    pub fn new(bus: B) -> Self {
+        Self::with_power_on(bus, PowerOn::Zeroed)
+    }
+
+    /// Initializes the registers according to the power-on policy; the bus memory is owned by the
+    /// system, so it's not affected.
+    ///
+    pub fn with_power_on(bus: B, power_on: PowerOn) -> Self {
+        let (registers, SP, PC) = power_on.registers();
 
         Cpu {
-            AF: Register16 { r16: 0 },
-            BC: Register16 { r16: 0 },
-            DE: Register16 { r16: 0 },
-            HL: Register16 { r16: 0 },
-            SP: 0,
-            PC: 0,
-            internal_ram,
+            registers,
+            SP,
+            PC,
+            IME: false,
+            ime_scheduled: false,
+            halted: false,
+            halt_bug: false,
+            stopped: false,
+            locked: false,
+            ticks_done: 0,
+            bus,
+        }
+    }
+
+    /// Runs instructions until (at least) the given number of clock ticks is spent.
+    ///
+    /// # Arguments/return value:
+    ///
+    /// * `cycles` - clock ticks to run
+    /// * returns the number of clock ticks spent; it can exceed `cycles`, as instructions are not
+    ///   interrupted
+    ///
+    pub fn run_until(&mut self, cycles: u32) -> u32 {
+        let mut cycles_spent = 0;
+
+        while cycles_spent < cycles {
+            cycles_spent += self.step() as u32;
         }
+
+        cycles_spent
     }
 
+    /// Test helper: writes the instruction at PC, then executes it.
+    ///
     /// # Arguments/return value:
     ///
     /// * `instruction_bytes` - instruction, in bytes
     /// * returns the number of clock ticks spent
     ///
-    pub fn execute(&mut self, instruction_bytes: &[u8]) -> u8 {
-        // Workaround until proper execution from memory is implemented.
-        //
-        let pc = self[Reg16::PC] as usize;
-        self.internal_ram[pc..pc + instruction_bytes.len()].copy_from_slice(&instruction_bytes);
-
-        match instruction_bytes {
-            // __OPCODES_DECODING_REPLACEMENT_START__
-            // __OPCODES_DECODING_REPLACEMENT_END__
-            _ => {
-                let formatted_instruction = utils::format_hex(instruction_bytes);
-                panic!("Unsupported instruction!: {}", formatted_instruction)
-            }
+    #[cfg(test)]
+    pub(crate) fn execute(&mut self, instruction_bytes: &[u8]) -> u8 {
+        for (i, instruction_byte) in instruction_bytes.iter().enumerate() {
+            let address = self.reg16(Reg16::PC).wrapping_add(i as u16);
+            self.bus.write8(address, *instruction_byte);
         }
+
+        self.step()
     }
 
-    // __OPCODES_EXECUTION_REPLACEMENT_START__
-    // __OPCODES_EXECUTION_REPLACEMENT_END__
+    /// Services a pending interrupt, if any, otherwise, fetches the instruction at PC, and executes
+    /// it; PC is advanced by the instruction.
+    ///
+    /// While halted or stopped, the CPU idles; HALT is exited when an interrupt is pending (even if
+    /// IME is disabled), and STOP when the joypad interrupt is requested. STOP is not entered if the
+    /// bus handles it (see `Bus::stop()`).
+    ///
+    /// After executing an illegal opcode, the CPU is locked (see `locked()`), and idles forever.
+    ///
+    /// # Arguments/return value:
+    ///
+    /// * returns the number of clock ticks spent
+    ///
+    pub fn step(&mut self) -> u8 {
+        if self.locked {
+            self.bus.tick(IDLE_CYCLES);
+            return IDLE_CYCLES;
+        }
 
-    ////////////////////////////////////////////////////////////////////////////////////////////////
-    // HELPERS
-    ////////////////////////////////////////////////////////////////////////////////////////////////
+        if self.stopped {
+            let interrupt_flag = self.bus.read8(INTERRUPT_FLAG_ADDRESS);
 
-    /// carry_position
-    ///
-    /// WATCH OUT #1!: 0-based;
-    /// WATCH OUT #2!: if the result is larger (e.g. 16 bits) than the operands (e.g. 8 bits), don't
-    ///   forget to size it accordingly. An example of mistake is to use an `overflowing_add()` and
-    ///   discard the carry; in such case, it's best to use the resulting carry, rather than this API.
+            if interrupt_flag & JOYPAD_INTERRUPT != 0 {
+                self.stopped = false;
+            } else {
+                self.bus.tick(IDLE_CYCLES);
+                return IDLE_CYCLES;
+            }
+        }
+
+        // The HALT exit cycles precede the interrupt dispatch, or, if IME is disabled, the execution
+        // of the next instruction.
+        //
+        let halt_exit_cycles = self.exit_halt();
+
+        let cycles = halt_exit_cycles
+            + if let Some(cycles) = self.service_interrupts() {
+                cycles
+            } else if self.halted {
+                IDLE_CYCLES
+            } else {
+                self.execute_instruction()
+            };
+
+        // In M-cycle timing mode, these are the internal cycles at the end of the step (if any).
+        //
+        self.bus.tick(cycles - self.ticks_done);
+        self.ticks_done = 0;
+
+        cycles
+    }
+
+    /// Whether the CPU has been locked up by an illegal opcode; PC points to the opcode.
     ///
-    /// The formula is based on XOR-based addition; see https://stackoverflow.com/q/62006764/210029.
+    pub fn locked(&self) -> bool {
+        self.locked
+    }
+
+    /// Interrupts pending (requested and enabled), as IF/IE bitmask.
     ///
-    fn compute_carry_flag(operand1: u16, operand2: u16, result: u16, carry_position: u8) -> bool {
-        (operand1 ^ operand2 ^ result) & (1 << carry_position) > 0
+    pub(crate) fn pending_interrupts(&mut self) -> u8 {
+        let interrupt_enable = self.bus.read8(INTERRUPT_ENABLE_ADDRESS);
+        let interrupt_flag = self.bus.read8(INTERRUPT_FLAG_ADDRESS);
+
+        interrupt_enable & interrupt_flag & INTERRUPTS_MASK
     }
-}
+
+    // Exits HALT if an interrupt is pending, regardless of IME; returns the clock ticks spent.
+    //
+    fn exit_halt(&mut self) -> u8 {
+        if !self.halted || self.pending_interrupts() == 0 {
+            return 0;
+        }
+
+        self.halted = false;
+        self.internal_mcycle();
+
+        HALT_EXIT_CYCLES
+    }
+
+    // Dispatches the highest priority pending interrupt (lowest bit) to its vector: IME and the IF
+    // bit are cleared, and PC is pushed on the stack.
+    //
+    // Returns the clock ticks spent, if an interrupt has been serviced.
+    //
+    fn service_interrupts(&mut self) -> Option<u8> {
+        if !self.IME {
+            return None;
+        }
+
+        let pending_interrupts = self.pending_interrupts();
+
+        if pending_interrupts == 0 {
+            return None;
+        }
+
+        let interrupt_index = pending_interrupts.trailing_zeros() as u16;
+        let interrupt_flag = self.bus.read8(INTERRUPT_FLAG_ADDRESS);
+        self.bus.write8(
+            INTERRUPT_FLAG_ADDRESS,
+            interrupt_flag & !(1 << interrupt_index),
+        );
+
+        self.IME = false;
+        self.ime_scheduled = false;
+
+        self.internal_mcycle();
+        self.internal_mcycle();
+
+        let (new_sp, _) = self.reg16(Reg16::SP).overflowing_sub(2);
+        self.set_reg16(Reg16::SP, new_sp);
+
+        let pushed_bytes = self.reg16(Reg16::PC).to_le_bytes();
+        self.write8(new_sp.wrapping_add(1), pushed_bytes[1]);
+        self.write8(new_sp, pushed_bytes[0]);
+
+        self.set_reg16(Reg16::PC, INTERRUPT_VECTORS_BASE + 8 * interrupt_index);
+
+        Some(INTERRUPT_DISPATCH_CYCLES)
+    }
+
+    // Memory accesses performed by the instructions.
+    //
+    // In M-cycle timing mode (`mcycle-timing` feature), the bus is ticked on each M-cycle, so that
+    // the components observe the accesses at the same time they happen on the hardware; each access
+    // is performed at the end of its M-cycle. Otherwise, the bus is ticked once per step, which is
+    // considerably cheaper.
+
+    fn read8(&mut self, address: u16) -> u8 {
+        self.tick_mcycles(1);
+        self.bus.read8(address)
+    }
+
+    fn write8(&mut self, address: u16, value: u8) {
+        self.tick_mcycles(1);
+        self.bus.write8(address, value);
+    }
+
+    // M-cycle without memory access, which precedes the following accesses.
+    //
+    fn internal_mcycle(&mut self) {
+        self.tick_mcycles(1);
+    }
+
+    #[cfg(feature = "mcycle-timing")]
+    fn tick_mcycles(&mut self, count: u8) {
+        for _ in 0..count {
+            self.bus.tick(MCYCLE_TICKS);
+            self.ticks_done += MCYCLE_TICKS;
+        }
+    }
+
+    #[cfg(not(feature = "mcycle-timing"))]
+    fn tick_mcycles(&mut self, _count: u8) {}
+
+    fn execute_instruction(&mut self) -> u8 {
+        // The instruction bytes are fetched before decoding, one M-cycle each; the decoder ignores
+        // the bytes in excess.
+        //
+        let mut instruction_bytes = [0; MAX_INSTRUCTION_SIZE];
+
+        let opcode_address = self.reg16(Reg16::PC);
+        instruction_bytes[0] = self.read8(opcode_address);
+
+        // With the HALT bug, PC is not incremented after the opcode fetch, so the opcode is read
+        // again as first operand (or executed twice, for single-byte instructions). This is
+        // equivalent to executing from the previous address, with the opcode duplicated.
+        //
+        let operands_address = if self.halt_bug {
+            self.halt_bug = false;
+            self.set_reg16(Reg16::PC, opcode_address.wrapping_sub(1));
+
+            opcode_address
+        } else {
+            opcode_address.wrapping_add(1)
+        };
+
+        if ILLEGAL_OPCODES.contains(&instruction_bytes[0]) {
+            self.locked = true;
+            return IDLE_CYCLES;
+        }
+
+        let fetched_bytes = FETCHED_BYTES[instruction_bytes[0] as usize] as usize;
+
+        for (i, instruction_byte) in instruction_bytes
+            .iter_mut()
+            .enumerate()
+            .take(fetched_bytes)
+            .skip(1)
+        {
+            *instruction_byte = self.read8(operands_address.wrapping_add(i as u16 - 1));
+        }
+
+        let enable_ime = self.ime_scheduled;
+
+        // All the other opcodes are decoded, so this can fail only if the instructions data is
+        // inconsistent.
+        //
+        let cycles = self
+            .decode_and_execute(&instruction_bytes)
+            .unwrap_or_else(|| {
+                let address = self.reg16(Reg16::PC);
+                let formatted_instruction = match disassemble(&instruction_bytes, address) {
+                    Some(instruction) => instruction.to_string(),
+                    None => format!("DB ${:02X}", instruction_bytes[0]),
+                };
+
+                panic!(
+                    "Unsupported instruction!: {} (at ${:04X})",
+                    formatted_instruction, address
+                )
+            });
+
+        // The instruction may have been a `DI`, which cancels the scheduling.
+        //
+        if enable_ime && self.ime_scheduled {
+            self.IME = true;
+            self.ime_scheduled = false;
+        }
+
+        cycles
+    }
+
+    // __OPCODES_EXECUTION_REPLACEMENT_START__
+    fn execute_LD_r_n(&mut self, dst_register: Reg8, immediate: &u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        self[dst_register] = *immediate;
+    }
+
+    fn execute_LD_r1_r2(&mut self, dst_register: Reg8, src_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        self[dst_register] = self[src_register];
+    }
+
+    fn execute_LD_r1_Irr2(&mut self, dst_register: Reg8, src_register: Reg16) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        self[dst_register] = self.read8(self.reg16(src_register));
+    }
+
+    fn execute_LD_Irr1_r2(&mut self, dst_register: Reg16, src_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        self.write8(self.reg16(dst_register), self[src_register]);
+    }
+
+    fn execute_LD_IHL_n(&mut self, immediate: &u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        self.write8(self.reg16(Reg16::HL), *immediate);
+    }
+
+    fn execute_LD_A_Inn(&mut self, immediate: &u16) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(3));
+
+        self[Reg8::A] = self.read8(*immediate);
+    }
+
+    fn execute_LD_Inn_A(&mut self, immediate: &u16) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(3));
+
+        self.write8(*immediate, self[Reg8::A]);
+    }
+
+    fn execute_LD_A_IC(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let address = 0xFF00 + self[Reg8::C] as u16;
+        self[Reg8::A] = self.read8(address);
+    }
+
+    fn execute_LD_IC_A(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let address = 0xFF00 + self[Reg8::C] as u16;
+        self.write8(address, self[Reg8::A]);
+    }
+
+    fn execute_LDD_A_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        self[Reg8::A] = self.read8(self.reg16(Reg16::HL));
+
+        let (new_value, _) = self.reg16(Reg16::HL).overflowing_sub(1);
+        self.set_reg16(Reg16::HL, new_value);
+    }
+
+    fn execute_LDD_IHL_A(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        self.write8(self.reg16(Reg16::HL), self[Reg8::A]);
+
+        let (new_value, _) = self.reg16(Reg16::HL).overflowing_sub(1);
+        self.set_reg16(Reg16::HL, new_value);
+    }
+
+    fn execute_LDI_A_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        self[Reg8::A] = self.read8(self.reg16(Reg16::HL));
+
+        let (new_value, _) = self.reg16(Reg16::HL).overflowing_add(1);
+        self.set_reg16(Reg16::HL, new_value);
+    }
+
+    fn execute_LDI_IHL_A(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        self.write8(self.reg16(Reg16::HL), self[Reg8::A]);
+
+        let (new_value, _) = self.reg16(Reg16::HL).overflowing_add(1);
+        self.set_reg16(Reg16::HL, new_value);
+    }
+
+    fn execute_LDH_In_A(&mut self, immediate: &u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let address = 0xFF00 + *immediate as u16;
+        self.write8(address, self[Reg8::A]);
+    }
+
+    fn execute_LDH_A_In(&mut self, immediate: &u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let address = 0xFF00 + *immediate as u16;
+        self[Reg8::A] = self.read8(address);
+    }
+
+    fn execute_LD_rr_nn(&mut self, dst_register: Reg16, immediate: &u16) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(3));
+
+        self.set_reg16(dst_register, *immediate);
+    }
+
+    fn execute_LD_SP_HL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        self.set_reg16(Reg16::SP, self.reg16(Reg16::HL));
+    }
+
+    fn execute_LDHL_SP_n(&mut self, immediate: &u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let operand1 = self.reg16(Reg16::SP);
+        // Ugly, but required, conversions.
+        let operand2 = *immediate as i8 as i16 as u16;
+
+        let (result, _) = operand1.overflowing_add(operand2);
+        self.set_reg16(Reg16::HL, result);
+
+        self.set_flag(Flag::z, false);
+        self.set_flag(Flag::n, false);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+        let flag_c_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 8);
+        self.set_flag(Flag::c, flag_c_value);
+    }
+
+    fn execute_LD_Inn_SP(&mut self, immediate: &u16) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(3));
+
+        let (high_byte_address, _) = immediate.overflowing_add(1);
+
+        self.write8(*immediate, self.reg16(Reg16::SP) as u8);
+        self.write8(high_byte_address, (self.reg16(Reg16::SP) >> 8) as u8);
+    }
+
+    fn execute_PUSH_rr(&mut self, dst_register: Reg16) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let (new_sp, _) = self.reg16(Reg16::SP).overflowing_sub(2);
+        self.set_reg16(Reg16::SP, new_sp);
+
+        let pushed_bytes = self.reg16(dst_register).to_le_bytes();
+        self.internal_mcycle();
+        self.write8(new_sp.wrapping_add(1), pushed_bytes[1]);
+        self.write8(new_sp, pushed_bytes[0]);
+    }
+
+    fn execute_POP_rr(&mut self, dst_register: Reg16) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let source_bytes = [
+            self.read8(self.reg16(Reg16::SP)),
+            self.read8(self.reg16(Reg16::SP).wrapping_add(1)),
+        ];
+        self.set_reg16(dst_register, u16::from_le_bytes(source_bytes));
+
+        let (result, _) = self.reg16(Reg16::SP).overflowing_add(2);
+        self.set_reg16(Reg16::SP, result);
+    }
+
+    fn execute_POP_AF(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let source_bytes = [
+            self.read8(self.reg16(Reg16::SP)),
+            self.read8(self.reg16(Reg16::SP).wrapping_add(1)),
+        ];
+        self.set_reg16(
+            Reg16::AF,
+            u16::from_le_bytes(source_bytes) & 0b1111_1111_1111_0000,
+        );
+
+        let (result, _) = self.reg16(Reg16::SP).overflowing_add(2);
+        self.set_reg16(Reg16::SP, result);
+
+        // self.set_flag(Flag::h, phony);
+        // self.set_flag(Flag::z, phony);
+        // self.set_flag(Flag::c, phony);
+        // self.set_flag(Flag::n, phony);
+    }
+
+    fn execute_ADD_A_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self[Reg8::A];
+        let operand2 = self[dst_register];
+
+        let (result, carry) = operand1.overflowing_add(operand2);
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::c, carry);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_ADD_A_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self[Reg8::A];
+        let operand2 = self.read8(self.reg16(Reg16::HL));
+
+        let (result, carry) = operand1.overflowing_add(operand2);
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::c, carry);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_ADD_A_n(&mut self, immediate: &u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let operand1 = self[Reg8::A];
+        let operand2 = *immediate;
+
+        let (result, carry) = operand1.overflowing_add(operand2);
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::c, carry);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_ADC_A_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self[Reg8::A];
+        let operand2 = self[dst_register];
+        let carry_in = self.get_flag(Flag::c) as u8;
+
+        let (partial_result, carry_1) = operand1.overflowing_add(operand2);
+        let (result, carry_2) = partial_result.overflowing_add(carry_in);
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::c, carry_1 || carry_2);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_ADC_A_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self[Reg8::A];
+        let operand2 = self.read8(self.reg16(Reg16::HL));
+        let carry_in = self.get_flag(Flag::c) as u8;
+
+        let (partial_result, carry_1) = operand1.overflowing_add(operand2);
+        let (result, carry_2) = partial_result.overflowing_add(carry_in);
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::c, carry_1 || carry_2);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_ADC_A_n(&mut self, immediate: &u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let operand1 = self[Reg8::A];
+        let operand2 = *immediate;
+        let carry_in = self.get_flag(Flag::c) as u8;
+
+        let (partial_result, carry_1) = operand1.overflowing_add(operand2);
+        let (result, carry_2) = partial_result.overflowing_add(carry_in);
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::c, carry_1 || carry_2);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_SUB_A_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self[Reg8::A];
+        let operand2 = self[dst_register];
+
+        let (result, carry) = operand1.overflowing_sub(operand2);
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::c, carry);
+        self.set_flag(Flag::n, true);
+
+        self.set_flag(Flag::z, result == 0);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_SUB_A_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self[Reg8::A];
+        let operand2 = self.read8(self.reg16(Reg16::HL));
+
+        let (result, carry) = operand1.overflowing_sub(operand2);
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::c, carry);
+        self.set_flag(Flag::n, true);
+
+        self.set_flag(Flag::z, result == 0);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_SUB_A_n(&mut self, immediate: &u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let operand1 = self[Reg8::A];
+        let operand2 = *immediate;
+
+        let (result, carry) = operand1.overflowing_sub(operand2);
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::c, carry);
+        self.set_flag(Flag::n, true);
+
+        self.set_flag(Flag::z, result == 0);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_SBC_A_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self[Reg8::A];
+        let operand2 = self[dst_register];
+        let carry_in = self.get_flag(Flag::c) as u8;
+
+        let (partial_result, carry_1) = operand1.overflowing_sub(operand2);
+        let (result, carry_2) = partial_result.overflowing_sub(carry_in);
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::c, carry_1 || carry_2);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, true);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_SBC_A_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self[Reg8::A];
+        let operand2 = self.read8(self.reg16(Reg16::HL));
+        let carry_in = self.get_flag(Flag::c) as u8;
+
+        let (partial_result, carry_1) = operand1.overflowing_sub(operand2);
+        let (result, carry_2) = partial_result.overflowing_sub(carry_in);
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::c, carry_1 || carry_2);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, true);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_SBC_A_n(&mut self, immediate: &u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let operand1 = self[Reg8::A];
+        let operand2 = *immediate;
+        let carry_in = self.get_flag(Flag::c) as u8;
+
+        let (partial_result, carry_1) = operand1.overflowing_sub(operand2);
+        let (result, carry_2) = partial_result.overflowing_sub(carry_in);
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::c, carry_1 || carry_2);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, true);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_AND_A_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let result = self[Reg8::A] & self[dst_register];
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, true);
+        self.set_flag(Flag::c, false);
+    }
+
+    fn execute_AND_A_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let result = self[Reg8::A] & self.read8(self.reg16(Reg16::HL));
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, true);
+        self.set_flag(Flag::c, false);
+    }
+
+    fn execute_AND_A_n(&mut self, immediate: &u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let result = self[Reg8::A] & *immediate;
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, true);
+        self.set_flag(Flag::c, false);
+    }
+
+    fn execute_OR_A_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let result = self[Reg8::A] | self[dst_register];
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+        self.set_flag(Flag::c, false);
+    }
+
+    fn execute_OR_A_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let result = self[Reg8::A] | self.read8(self.reg16(Reg16::HL));
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+        self.set_flag(Flag::c, false);
+    }
+
+    fn execute_OR_A_n(&mut self, immediate: &u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let result = self[Reg8::A] | *immediate;
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+        self.set_flag(Flag::c, false);
+    }
+
+    fn execute_XOR_A_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let result = self[Reg8::A] ^ self[dst_register];
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+        self.set_flag(Flag::c, false);
+    }
+
+    fn execute_XOR_A_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let result = self[Reg8::A] ^ self.read8(self.reg16(Reg16::HL));
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+        self.set_flag(Flag::c, false);
+    }
+
+    fn execute_XOR_A_n(&mut self, immediate: &u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let result = self[Reg8::A] ^ *immediate;
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+        self.set_flag(Flag::c, false);
+    }
+
+    fn execute_CP_A_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self[Reg8::A];
+        let operand2 = self[dst_register];
+
+        let (result, carry) = operand1.overflowing_sub(operand2);
+
+        self.set_flag(Flag::c, carry);
+        self.set_flag(Flag::n, true);
+
+        self.set_flag(Flag::z, result == 0);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_CP_A_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self[Reg8::A];
+        let operand2 = self.read8(self.reg16(Reg16::HL));
+
+        let (result, carry) = operand1.overflowing_sub(operand2);
+
+        self.set_flag(Flag::c, carry);
+        self.set_flag(Flag::n, true);
+
+        self.set_flag(Flag::z, result == 0);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_CP_A_n(&mut self, immediate: &u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let operand1 = self[Reg8::A];
+        let operand2 = *immediate;
+
+        let (result, carry) = operand1.overflowing_sub(operand2);
+
+        self.set_flag(Flag::c, carry);
+        self.set_flag(Flag::n, true);
+
+        self.set_flag(Flag::z, result == 0);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_INC_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self[dst_register];
+        let operand2 = 1;
+
+        let (result, _) = operand1.overflowing_add(operand2);
+        self[dst_register] = result;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_INC_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self.read8(self.reg16(Reg16::HL));
+        let operand2 = 1;
+        let (result, _) = operand1.overflowing_add(operand2);
+        self.write8(self.reg16(Reg16::HL), result);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_DEC_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self[dst_register];
+        let operand2 = 1;
+
+        let (result, _) = operand1.overflowing_sub(operand2);
+        self[dst_register] = result;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, true);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_DEC_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self.read8(self.reg16(Reg16::HL));
+        let operand2 = 1;
+        let (result, _) = operand1.overflowing_sub(operand2);
+        self.write8(self.reg16(Reg16::HL), result);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, true);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_ADD_HL_rr(&mut self, dst_register: Reg16) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self.reg16(Reg16::HL);
+        let operand2 = self.reg16(dst_register);
+
+        let (result, carry) = operand1.overflowing_add(operand2);
+        self.set_reg16(Reg16::HL, result);
+
+        self.set_flag(Flag::c, carry);
+
+        self.set_flag(Flag::n, false);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 12);
+        self.set_flag(Flag::h, flag_h_value);
+    }
+
+    fn execute_ADD_SP_n(&mut self, immediate: &u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let operand1 = self.reg16(Reg16::SP);
+        // Ugly, but required, conversions.
+        let operand2 = *immediate as i8 as i16 as u16;
+
+        let (result, _) = operand1.overflowing_add(operand2);
+        self.set_reg16(Reg16::SP, result);
+
+        self.set_flag(Flag::z, false);
+        self.set_flag(Flag::n, false);
+        let flag_h_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 4);
+        self.set_flag(Flag::h, flag_h_value);
+        let flag_c_value =
+            Self::compute_carry_flag(operand1 as u16, operand2 as u16, result as u16, 8);
+        self.set_flag(Flag::c, flag_c_value);
+    }
+
+    fn execute_INC_rr(&mut self, dst_register: Reg16) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self.reg16(dst_register);
+        let operand2 = 1;
+
+        let (result, _) = operand1.overflowing_add(operand2);
+        self.set_reg16(dst_register, result);
+    }
+
+    fn execute_DEC_rr(&mut self, dst_register: Reg16) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand1 = self.reg16(dst_register);
+        let operand2 = 1;
+
+        let (result, _) = operand1.overflowing_sub(operand2);
+        self.set_reg16(dst_register, result);
+    }
+
+    fn execute_SWAP_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let result = self[dst_register] >> 4 | ((self[dst_register] & 0b0000_1111) << 4);
+        self[dst_register] = result;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+        self.set_flag(Flag::c, false);
+    }
+
+    fn execute_SWAP_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let value = self.read8(self.reg16(Reg16::HL));
+        let result = value >> 4 | ((value & 0b0000_1111) << 4);
+        self.write8(self.reg16(Reg16::HL), result);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+        self.set_flag(Flag::c, false);
+    }
+
+    fn execute_DAA(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let operand = self[Reg8::A];
+        let mut adjustment = 0;
+        let mut carry = self.get_flag(Flag::c);
+
+        if self.get_flag(Flag::h) || (!self.get_flag(Flag::n) && (operand & 0x0F) > 0x09) {
+            adjustment |= 0x06;
+        }
+
+        if carry || (!self.get_flag(Flag::n) && operand > 0x99) {
+            adjustment |= 0x60;
+            carry = true;
+        }
+
+        let result = if self.get_flag(Flag::n) {
+            operand.wrapping_sub(adjustment)
+        } else {
+            operand.wrapping_add(adjustment)
+        };
+
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::c, carry);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_CPL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        self[Reg8::A] = !self[Reg8::A];
+
+        self.set_flag(Flag::n, true);
+        self.set_flag(Flag::h, true);
+    }
+
+    fn execute_CCF(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let cf_value = self.get_flag(Flag::c);
+        self.set_flag(Flag::c, !cf_value);
+
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_SCF(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        self.set_flag(Flag::c, true);
+
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_NOP(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+    }
+
+    fn execute_HALT(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        if !self.IME && self.pending_interrupts() != 0 {
+            self.halt_bug = true;
+        } else {
+            self.halted = true;
+        }
+    }
+
+    // The second byte is skipped, but it doesn't take an additional M-cycle.
+    //
+    fn execute_STOP(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        if !self.bus.stop() {
+            self.stopped = true;
+        }
+    }
+
+    fn execute_DI(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        self.IME = false;
+        self.ime_scheduled = false;
+    }
+
+    fn execute_EI(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        self.ime_scheduled = true;
+    }
+
+    fn execute_RLCA(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        self.set_flag(Flag::c, (self[Reg8::A] & 0b1000_0000) != 0);
+        let result = self[Reg8::A].rotate_left(1);
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::z, false);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_RLA(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let new_carry = (self[Reg8::A] & 0b1000_0000) != 0;
+
+        let result = self[Reg8::A].wrapping_shl(1) | self.get_flag(Flag::c) as u8;
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::c, new_carry);
+
+        self.set_flag(Flag::z, false);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_RRCA(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        self.set_flag(Flag::c, (self[Reg8::A] & 0b0000_0001) != 0);
+        let result = self[Reg8::A].rotate_right(1);
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::z, false);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_RRA(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+
+        let new_carry = (self[Reg8::A] & 0b0000_0001) != 0;
+
+        let mut result = self[Reg8::A].wrapping_shr(1);
+        if self.get_flag(Flag::c) {
+            result |= 0b1000_0000;
+        }
+        self[Reg8::A] = result;
+
+        self.set_flag(Flag::c, new_carry);
+
+        self.set_flag(Flag::z, false);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_RLC_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        self.set_flag(Flag::c, (self[dst_register] & 0b1000_0000) != 0);
+        let result = self[dst_register].rotate_left(1);
+        self[dst_register] = result;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_RLC_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let address = self.reg16(Reg16::HL);
+        let value = self.read8(address);
+
+        self.set_flag(Flag::c, (value & 0b1000_0000) != 0);
+        let result = value.rotate_left(1);
+
+        self.write8(address, result);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_RL_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let new_carry = (self[dst_register] & 0b1000_0000) != 0;
+
+        let result = self[dst_register].wrapping_shl(1) | self.get_flag(Flag::c) as u8;
+        self[dst_register] = result;
+
+        self.set_flag(Flag::c, new_carry);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_RL_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let address = self.reg16(Reg16::HL);
+        let value = self.read8(address);
+        let new_carry = (value & 0b1000_0000) != 0;
+
+        let result = value.wrapping_shl(1) | self.get_flag(Flag::c) as u8;
+        self.write8(address, result);
+
+        self.set_flag(Flag::c, new_carry);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_RRC_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        self.set_flag(Flag::c, (self[dst_register] & 0b0000_0001) != 0);
+        let result = self[dst_register].rotate_right(1);
+        self[dst_register] = result;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_RRC_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let address = self.reg16(Reg16::HL);
+        let value = self.read8(address);
+
+        self.set_flag(Flag::c, (value & 0b0000_0001) != 0);
+        let result = value.rotate_right(1);
+
+        self.write8(address, result);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_RR_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let new_carry = (self[dst_register] & 0b0000_0001) != 0;
+
+        let mut result = self[dst_register].wrapping_shr(1);
+        if self.get_flag(Flag::c) {
+            result |= 0b1000_0000;
+        }
+        self[dst_register] = result;
+
+        self.set_flag(Flag::c, new_carry);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_RR_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let address = self.reg16(Reg16::HL);
+        let value = self.read8(address);
+        let new_carry = (value & 0b0000_0001) != 0;
+
+        let mut result = value.wrapping_shr(1);
+        if self.get_flag(Flag::c) {
+            result |= 0b1000_0000;
+        }
+        self.write8(address, result);
+
+        self.set_flag(Flag::c, new_carry);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_SLA_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let new_carry = (self[dst_register] & 0b1000_0000) != 0;
+
+        let result = self[dst_register].wrapping_shl(1);
+        self[dst_register] = result;
+
+        self.set_flag(Flag::c, new_carry);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_SLA_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let address = self.reg16(Reg16::HL);
+        let value = self.read8(address);
+        let new_carry = (value & 0b1000_0000) != 0;
+
+        let result = value.wrapping_shl(1);
+        self.write8(address, result);
+
+        self.set_flag(Flag::c, new_carry);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_SRA_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let new_carry = (self[dst_register] & 0b0000_0001) != 0;
+        let old_msb = self[dst_register] & 0b1000_0000;
+
+        let result = self[dst_register].wrapping_shr(1) | old_msb;
+        self[dst_register] = result;
+
+        self.set_flag(Flag::c, new_carry);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_SRA_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let address = self.reg16(Reg16::HL);
+        let value = self.read8(address);
+
+        let new_carry = (value & 0b0000_0001) != 0;
+        let old_msb = value & 0b1000_0000;
+
+        let result = value.wrapping_shr(1) | old_msb;
+        self.write8(address, result);
+
+        self.set_flag(Flag::c, new_carry);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_SRL_r(&mut self, dst_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let new_carry = (self[dst_register] & 0b0000_0001) != 0;
+
+        let result = self[dst_register].wrapping_shr(1);
+        self[dst_register] = result;
+
+        self.set_flag(Flag::c, new_carry);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_SRL_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let address = self.reg16(Reg16::HL);
+        let value = self.read8(address);
+        let new_carry = (value & 0b0000_0001) != 0;
+
+        let result = value.wrapping_shr(1);
+        self.write8(address, result);
+
+        self.set_flag(Flag::c, new_carry);
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, false);
+    }
+
+    fn execute_BIT_b_r(&mut self, bit: u8, src_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let bitmask = 1 << bit;
+
+        let result = self[src_register] & bitmask;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, true);
+    }
+
+    fn execute_BIT_b_IHL(&mut self, bit: u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let address = self.reg16(Reg16::HL);
+        let bitmask = 1 << bit;
+
+        let result = self.read8(address) & bitmask;
+
+        self.set_flag(Flag::z, result == 0);
+        self.set_flag(Flag::n, false);
+        self.set_flag(Flag::h, true);
+    }
+
+    fn execute_SET_b_r(&mut self, bit: u8, src_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let bitmask = 1 << bit;
+
+        self[src_register] |= bitmask;
+    }
+
+    fn execute_SET_b_IHL(&mut self, bit: u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let address = self.reg16(Reg16::HL);
+        let bitmask = 1 << bit;
+
+        let value = self.read8(address);
+        self.write8(address, value | bitmask);
+    }
+
+    fn execute_RES_b_r(&mut self, bit: u8, src_register: Reg8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let bitmask = !(1 << bit);
+
+        self[src_register] &= bitmask;
+    }
+
+    fn execute_RES_b_IHL(&mut self, bit: u8) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+
+        let address = self.reg16(Reg16::HL);
+        let bitmask = !(1 << bit);
+
+        let value = self.read8(address);
+        self.write8(address, value & bitmask);
+    }
+
+    fn execute_JP_nn(&mut self, immediate: &u16) {
+        self.set_reg16(Reg16::PC, *immediate);
+    }
+
+    fn execute_JP_cc_nn(&mut self, flag: Flag, flag_condition: bool, immediate: &u16) -> bool {
+        let condition_matching = self.get_flag(flag) == flag_condition;
+
+        if condition_matching {
+            self.set_reg16(Reg16::PC, *immediate);
+        } else {
+            self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(3));
+        }
+
+        condition_matching
+    }
+
+    fn execute_JP_IHL(&mut self) {
+        self.set_reg16(Reg16::PC, self.reg16(Reg16::HL));
+    }
+
+    fn execute_JR_n(&mut self, immediate: &u8) {
+        let (operand1, _) = self.reg16(Reg16::PC).overflowing_add(2);
+        let operand2 = *immediate as i8 as i16 as u16;
+
+        let (result, _) = operand1.overflowing_add(operand2);
+        self.set_reg16(Reg16::PC, result);
+    }
+
+    fn execute_JR_cc_n(&mut self, flag: Flag, flag_condition: bool, immediate: &u8) -> bool {
+        let condition_matching = self.get_flag(flag) == flag_condition;
+
+        if condition_matching {
+            let (operand1, _) = self.reg16(Reg16::PC).overflowing_add(2);
+            let operand2 = *immediate as i8 as i16 as u16;
+
+            let (result, _) = operand1.overflowing_add(operand2);
+            self.set_reg16(Reg16::PC, result);
+        } else {
+            self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(2));
+        }
+
+        condition_matching
+    }
+
+    fn execute_CALL_nn(&mut self, immediate: &u16) {
+        let (new_sp, _) = self.reg16(Reg16::SP).overflowing_sub(2);
+        self.set_reg16(Reg16::SP, new_sp);
+
+        let (stored_address, _) = self.reg16(Reg16::PC).overflowing_add(3);
+        let pushed_bytes = stored_address.to_le_bytes();
+        self.internal_mcycle();
+        self.write8(new_sp.wrapping_add(1), pushed_bytes[1]);
+        self.write8(new_sp, pushed_bytes[0]);
+
+        self.set_reg16(Reg16::PC, *immediate);
+    }
+
+    fn execute_CALL_cc_nn(&mut self, flag: Flag, flag_condition: bool, immediate: &u16) -> bool {
+        let condition_matching = self.get_flag(flag) == flag_condition;
+
+        if condition_matching {
+            let (new_sp, _) = self.reg16(Reg16::SP).overflowing_sub(2);
+            self.set_reg16(Reg16::SP, new_sp);
+
+            let (stored_address, _) = self.reg16(Reg16::PC).overflowing_add(3);
+            let pushed_bytes = stored_address.to_le_bytes();
+            self.internal_mcycle();
+            self.write8(new_sp.wrapping_add(1), pushed_bytes[1]);
+            self.write8(new_sp, pushed_bytes[0]);
+
+            self.set_reg16(Reg16::PC, *immediate);
+        } else {
+            self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(3));
+        }
+
+        condition_matching
+    }
+
+    fn execute_RST_v(&mut self, vector: u16) {
+        let (new_sp, _) = self.reg16(Reg16::SP).overflowing_sub(2);
+        self.set_reg16(Reg16::SP, new_sp);
+
+        let (stored_address, _) = self.reg16(Reg16::PC).overflowing_add(1);
+        let pushed_bytes = stored_address.to_le_bytes();
+        self.internal_mcycle();
+        self.write8(new_sp.wrapping_add(1), pushed_bytes[1]);
+        self.write8(new_sp, pushed_bytes[0]);
+
+        self.set_reg16(Reg16::PC, vector);
+    }
+
+    fn execute_RET(&mut self) {
+        let source_bytes = [
+            self.read8(self.reg16(Reg16::SP)),
+            self.read8(self.reg16(Reg16::SP).wrapping_add(1)),
+        ];
+        self.set_reg16(Reg16::PC, u16::from_le_bytes(source_bytes));
+
+        let (new_sp, _) = self.reg16(Reg16::SP).overflowing_add(2);
+        self.set_reg16(Reg16::SP, new_sp);
+    }
+
+    fn execute_RET_cc(&mut self, flag: Flag, flag_condition: bool) -> bool {
+        let condition_matching = self.get_flag(flag) == flag_condition;
+        self.internal_mcycle();
+
+        if condition_matching {
+            let source_bytes = [
+                self.read8(self.reg16(Reg16::SP)),
+                self.read8(self.reg16(Reg16::SP).wrapping_add(1)),
+            ];
+            self.set_reg16(Reg16::PC, u16::from_le_bytes(source_bytes));
+
+            let (new_sp, _) = self.reg16(Reg16::SP).overflowing_add(2);
+            self.set_reg16(Reg16::SP, new_sp);
+        } else {
+            self.set_reg16(Reg16::PC, self.reg16(Reg16::PC).wrapping_add(1));
+        }
+
+        condition_matching
+    }
+
+    fn execute_RETI(&mut self) {
+        let source_bytes = [
+            self.read8(self.reg16(Reg16::SP)),
+            self.read8(self.reg16(Reg16::SP).wrapping_add(1)),
+        ];
+        self.set_reg16(Reg16::PC, u16::from_le_bytes(source_bytes));
+
+        let (new_sp, _) = self.reg16(Reg16::SP).overflowing_add(2);
+        self.set_reg16(Reg16::SP, new_sp);
+
+        self.IME = true;
+    }
+    // __OPCODES_EXECUTION_REPLACEMENT_END__
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////
+    // HELPERS
+    ////////////////////////////////////////////////////////////////////////////////////////////////
+
+    /// carry_position
+    ///
+    /// WATCH OUT #1!: 0-based;
+    /// WATCH OUT #2!: if the result is larger (e.g. 16 bits) than the operands (e.g. 8 bits), don't
+    ///   forget to size it accordingly. An example of mistake is to use an `overflowing_add()` and
+    ///   discard the carry; in such case, it's best to use the resulting carry, rather than this API.
+    ///
+    /// The formula is based on XOR-based addition; see https://stackoverflow.com/q/62006764/210029.
+    ///
+    fn compute_carry_flag(operand1: u16, operand2: u16, result: u16, carry_position: u8) -> bool {
+        (operand1 ^ operand2 ^ result) & (1 << carry_position) > 0
+    }
+}
+
+// Instructions decoder (`decode_and_execute()`), generated by the build script.
+//
+include!(concat!(env!("OUT_DIR"), "/instructions_decoding.rs"));
@@ -1,11 +1,55 @@
 #![allow(unused_macros)]
 
-use super::cpu::{Cpu, Flag, Reg16, Reg8};
+use super::bus::{Bus, FlatRam};
+use super::cpu::{
+    Cpu, Flag, Reg16, Reg8, INTERRUPT_ENABLE_ADDRESS, INTERRUPT_FLAG_ADDRESS, JOYPAD_INTERRUPT,
+};
+use super::instructions_metadata::{
+    FlagEffect, InstructionCycles, PREFIXED_CYCLES, PREFIXED_FLAG_EFFECTS, UNPREFIXED_CYCLES,
+    UNPREFIXED_FLAG_EFFECTS,
+};
+use super::power_on::{Model, PowerOn};
 use demonstrate::demonstrate;
 use strum::IntoEnumIterator;
 
+const TEST_SEED: u64 = 0xCAFE_BABE;
+
+const ILLEGAL_OPCODES: [u8; 11] = [
+    0xD3, 0xDB, 0xDD, 0xE3, 0xE4, 0xEB, 0xEC, 0xED, 0xF4, 0xFC, 0xFD,
+];
+
+// Handles STOP, like a CGB system does when a speed switch is prepared.
+//
+struct StopHandlingBus(FlatRam);
+
+impl Bus for StopHandlingBus {
+    fn read8(&mut self, address: u16) -> u8 {
+        self.0.read8(address)
+    }
+
+    fn write8(&mut self, address: u16, value: u8) {
+        self.0.write8(address, value)
+    }
+
+    fn stop(&mut self) -> bool {
+        true
+    }
+}
+
+// Registers are in `Reg8` order.
+//
+fn assert_cpu_state(cpu: &Cpu<FlatRam>, registers: [u8; 8], SP: u16, PC: u16) {
+    for (register, value) in Reg8::iter().zip(registers.iter()) {
+        assert_eq!(cpu[register], *value, "Unexpected `{:?}`", register);
+    }
+
+    assert_eq!(cpu.reg16(Reg16::SP), SP);
+    assert_eq!(cpu.reg16(Reg16::PC), PC);
+}
+
+#[allow(clippy::too_many_arguments)]
 fn assert_cpu_execute(
-    cpu: &mut Cpu,
+    cpu: &mut Cpu<FlatRam>,
     instruction_bytes: &[u8],
     A: Option<u8>,
     F: Option<u8>,
@@ -28,7 +72,7 @@ fn assert_cpu_execute(
     mem: Option<(u16, &[u8])>,
     cycles_spent: u8,
 ) {
-    let actual_cycles_spent = cpu.execute(&instruction_bytes);
+    let actual_cycles_spent = cpu.execute(instruction_bytes);
 
     for (register, value) in Reg8::iter().zip([A, F, B, C, D, E, H, L].iter()) {
         if let Some(value) = value {
@@ -43,9 +87,12 @@ fn assert_cpu_execute(
     for (register, value) in Reg16::iter().zip([AF, BC, DE, HL, SP, PC].iter()) {
         if let Some(value) = value {
             assert_eq!(
-                cpu[register], *value,
+                cpu.reg16(register),
+                *value,
                 "Unexpected `{:?}`: actual=0x{:02X}, expected=0x{:02X}",
-                register, cpu[register], *value
+                register,
+                cpu.reg16(register),
+                *value
             );
         }
     }
@@ -64,13 +111,12 @@ fn assert_cpu_execute(
     }
 
     if let Some((start_address, expected_values)) = mem {
-        for i in 0..(expected_values.len()) {
+        for (i, expected_value) in expected_values.iter().enumerate() {
             let address = start_address as usize + i;
-            let actual_value = cpu.internal_ram[address];
-            let expected_value = expected_values[i];
+            let actual_value = cpu.bus.memory[address];
 
             assert_eq!(
-                actual_value, expected_value,
+                actual_value, *expected_value,
                 "Unexpected mem[0x{:04X}]: actual=0x{:02X}, expected=0x{:02X}",
                 address, actual_value, expected_value,
             );
@@ -170,24 +216,26 @@ demonstrate! {
           // (Current) issue with declarative testing frameworks; see https://git.io/JUlar.
           //
           #[allow(unused_mut)]
-          let mut cpu = Cpu::new();
+          let mut cpu = Cpu::new(FlatRam::with_power_on(PowerOn::SeededRandom(TEST_SEED)));
+
+          // Interrupts are requested only by the tests that need them.
+          //
+          cpu.bus.memory[INTERRUPT_FLAG_ADDRESS as usize] = 0;
         }
 
-        // Can't really test random, but it's good practice to just make sure it's not been initialized
-        // with zeros.
-        // This test will fail for near-impossibly unlucky runs (or lucky, depending on the perspective).
-        //
         it "initializes" {
-            let internal_ram_sum: u32 = cpu.internal_ram.to_vec().iter().map(|&x| x as u32).sum();
+            let mut expected_memory = FlatRam::with_power_on(PowerOn::SeededRandom(TEST_SEED)).memory;
+            expected_memory[INTERRUPT_FLAG_ADDRESS as usize] = 0;
 
-            assert_ne!(internal_ram_sum, 0);
+            assert!(cpu.bus.memory[..] == expected_memory[..]);
+            assert!(cpu.bus.memory.iter().any(|&byte| byte != 0));
 
             assert_eq!(cpu[Reg8::A], 0);
-            assert_eq!(cpu[Reg16::BC], 0);
-            assert_eq!(cpu[Reg16::DE], 0);
-            assert_eq!(cpu[Reg16::HL], 0);
-            assert_eq!(cpu[Reg16::SP], 0);
-            assert_eq!(cpu[Reg16::PC], 0);
+            assert_eq!(cpu.reg16(Reg16::BC), 0);
+            assert_eq!(cpu.reg16(Reg16::DE), 0);
+            assert_eq!(cpu.reg16(Reg16::HL), 0);
+            assert_eq!(cpu.reg16(Reg16::SP), 0);
+            assert_eq!(cpu.reg16(Reg16::PC), 0);
 
             assert_eq!(cpu.get_flag(Flag::z), false);
             assert_eq!(cpu.get_flag(Flag::n), false);
@@ -195,9 +243,18468 @@ demonstrate! {
             assert_eq!(cpu.get_flag(Flag::c), false);
         }
 
+        context "register file" {
+            it "composes the pairs from the 8-bit registers" {
+                cpu[Reg8::A] = 0x12;
+                cpu[Reg8::F] = 0x30;
+                cpu[Reg8::B] = 0x56;
+                cpu[Reg8::C] = 0x78;
+                cpu[Reg8::D] = 0x9A;
+                cpu[Reg8::E] = 0xBC;
+                cpu[Reg8::H] = 0xDE;
+                cpu[Reg8::L] = 0xF0;
+
+                assert_eq!(cpu.reg16(Reg16::AF), 0x1230);
+                assert_eq!(cpu.reg16(Reg16::BC), 0x5678);
+                assert_eq!(cpu.reg16(Reg16::DE), 0x9ABC);
+                assert_eq!(cpu.reg16(Reg16::HL), 0xDEF0);
+            }
+
+            // Simulates both host byte orders, by producing the values from bytes in native order
+            // for each of them; the registers layout must not be affected.
+            //
+            it "splits the pairs independently from the host byte order" {
+                let host_bytes = [0xCA, 0xFE];
+                let simulated_values = [u16::from_le_bytes(host_bytes), u16::from_be_bytes(host_bytes)];
+
+                for &value in simulated_values.iter() {
+                    for &(register, high, low) in [
+                        (Reg16::AF, Reg8::A, Reg8::F),
+                        (Reg16::BC, Reg8::B, Reg8::C),
+                        (Reg16::DE, Reg8::D, Reg8::E),
+                        (Reg16::HL, Reg8::H, Reg8::L),
+                    ]
+                    .iter()
+                    {
+                        cpu.set_reg16(register, value);
+
+                        assert_eq!(cpu[high], (value >> 8) as u8, "{:?}", register);
+                        assert_eq!(cpu[low], value as u8, "{:?}", register);
+                        assert_eq!(cpu.reg16(register), value, "{:?}", register);
+                    }
+                }
+            }
+
+            it "reflects the 8-bit accesses in the pairs, and vice versa" {
+                cpu.set_reg16(Reg16::BC, 0x1234);
+                cpu[Reg8::C] = 0xFF;
+
+                assert_eq!(cpu.reg16(Reg16::BC), 0x12FF);
+
+                cpu[Reg8::B] = cpu[Reg8::B].wrapping_add(1);
+
+                assert_eq!(cpu.reg16(Reg16::BC), 0x13FF);
+
+                cpu.set_reg16(Reg16::DE, 0xCAFE);
+                cpu[Reg8::H] = 0xBE;
+                cpu[Reg8::L] = 0xEF;
+
+                assert_eq!((cpu[Reg8::D], cpu[Reg8::E]), (0xCA, 0xFE));
+                assert_eq!(cpu.reg16(Reg16::HL), 0xBEEF);
+
+                // The neighbour pairs are not affected.
+                //
+                assert_eq!(cpu.reg16(Reg16::BC), 0x13FF);
+                assert_eq!(cpu.reg16(Reg16::DE), 0xCAFE);
+            }
+
+            it "mixes 8-bit and 16-bit instructions on the same pair" {
+                // LD BC, 0x12FF; INC C; INC BC; DEC B; LD A, C; INC BC
+                //
+                cpu.bus.memory[0x00..0x08]
+                    .copy_from_slice(&[0x01, 0xFF, 0x12, 0x0C, 0x03, 0x05, 0x79, 0x03]);
+
+                cpu.step();
+                cpu.step();
+
+                // INC C doesn't carry into B.
+                //
+                assert_eq!(cpu.reg16(Reg16::BC), 0x1200);
+
+                cpu.step();
+                cpu.step();
+
+                assert_eq!(cpu.reg16(Reg16::BC), 0x1101);
+
+                cpu.step();
+                cpu.step();
+
+                assert_eq!(cpu[Reg8::A], 0x01);
+                assert_eq!(cpu.reg16(Reg16::BC), 0x1102);
+            }
+
+            it "keeps SP and PC separate from the pairs" {
+                cpu.set_reg16(Reg16::HL, 0x1234);
+                cpu.set_reg16(Reg16::SP, 0xCAFE);
+                cpu.set_reg16(Reg16::PC, 0xBEEF);
+
+                assert_eq!(cpu.reg16(Reg16::HL), 0x1234);
+                assert_eq!(cpu.reg16(Reg16::SP), 0xCAFE);
+                assert_eq!(cpu.reg16(Reg16::PC), 0xBEEF);
+            }
+        }
+
+        // The last instruction overshoots the requested cycles.
+        //
+        it "runs from memory until the cycles are spent" {
+            // LD A, 0x21; INC A; LD (0xCAFE), A
+            //
+            cpu.bus.memory[0x00..0x06].copy_from_slice(&[0x3E, 0x21, 0x3C, 0xEA, 0xFE, 0xCA]);
+
+            let cycles_spent = cpu.run_until(13);
+
+            assert_eq!(cycles_spent, 28);
+            assert_eq!(cpu.reg16(Reg16::PC), 0x06);
+            assert_eq!(cpu.bus.memory[0xCAFE], 0x22);
+        }
+
+        context "interrupts" {
+            before {
+                cpu.set_reg16(Reg16::PC, 0x0200);
+                cpu.set_reg16(Reg16::SP, 0xCAFE);
+                cpu.bus.memory[INTERRUPT_ENABLE_ADDRESS as usize] = 0b0001_1111;
+            }
+
+            it "services the highest priority interrupt first" {
+                cpu.IME = true;
+                cpu.bus.memory[INTERRUPT_FLAG_ADDRESS as usize] = 0b0001_0110;
+
+                let cycles_spent = cpu.step();
+
+                assert_eq!(cycles_spent, 20);
+                assert_eq!(cpu.reg16(Reg16::PC), 0x48);
+                assert_eq!(cpu.reg16(Reg16::SP), 0xCAFC);
+                assert_eq!(cpu.bus.memory[0xCAFC..=0xCAFD], [0x00, 0x02]);
+                assert_eq!(cpu.bus.memory[INTERRUPT_FLAG_ADDRESS as usize], 0b0001_0100);
+                assert_eq!(cpu.IME, false);
+
+                // RETI
+                //
+                cpu.bus.memory[0x48] = 0xD9;
+                cpu.step();
+                cpu.step();
+
+                assert_eq!(cpu.reg16(Reg16::PC), 0x50);
+                assert_eq!(cpu.bus.memory[INTERRUPT_FLAG_ADDRESS as usize], 0b0001_0000);
+            }
+
+            it "ignores the disabled interrupts" {
+                cpu.IME = true;
+                cpu.bus.memory[INTERRUPT_ENABLE_ADDRESS as usize] = 0b0000_0100;
+                cpu.bus.memory[INTERRUPT_FLAG_ADDRESS as usize] = 0b0000_0011;
+                cpu.bus.memory[0x0200] = 0x00; // NOP
+
+                let cycles_spent = cpu.step();
+
+                assert_eq!(cycles_spent, 4);
+                assert_eq!(cpu.reg16(Reg16::PC), 0x0201);
+            }
+
+            it "enables IME after the instruction following EI" {
+                cpu.bus.memory[INTERRUPT_FLAG_ADDRESS as usize] = 0b0000_0001;
+                cpu.bus.memory[0x0200..0x0202].copy_from_slice(&[0xFB, 0x00]); // EI; NOP
+
+                cpu.step();
+                cpu.step();
+
+                assert_eq!(cpu.reg16(Reg16::PC), 0x0202);
+
+                cpu.step();
+
+                assert_eq!(cpu.reg16(Reg16::PC), 0x40);
+            }
+
+            it "cancels a scheduled EI with DI" {
+                cpu.bus.memory[INTERRUPT_FLAG_ADDRESS as usize] = 0b0000_0001;
+                cpu.bus.memory[0x0200..0x0203].copy_from_slice(&[0xFB, 0xF3, 0x00]); // EI; DI; NOP
+
+                cpu.run_until(12);
+
+                assert_eq!(cpu.reg16(Reg16::PC), 0x0203);
+                assert_eq!(cpu.IME, false);
+            }
+
+            it "exits HALT on interrupt, servicing it" {
+                cpu.IME = true;
+                cpu.bus.memory[0x0200] = 0x76; // HALT
+
+                cpu.step();
+
+                assert_eq!(cpu.step(), 4);
+                assert_eq!(cpu.reg16(Reg16::PC), 0x0201);
+
+                cpu.bus.memory[INTERRUPT_FLAG_ADDRESS as usize] = 0b0000_1000;
+
+                assert_eq!(cpu.step(), 24);
+                assert_eq!(cpu.reg16(Reg16::PC), 0x58);
+                assert_eq!(cpu.bus.memory[0xCAFC..=0xCAFD], [0x01, 0x02]);
+            }
+
+            it "exits HALT on interrupt, without servicing it, if IME is disabled" {
+                cpu.bus.memory[0x0200..0x0202].copy_from_slice(&[0x76, 0x3C]); // HALT; INC A
+                cpu[Reg8::A] = 0x21;
+
+                cpu.step();
+                cpu.step();
+
+                cpu.bus.memory[INTERRUPT_FLAG_ADDRESS as usize] = 0b0000_1000;
+
+                // HALT exit + INC A.
+                //
+                assert_eq!(cpu.step(), 8);
+                assert_eq!(cpu.reg16(Reg16::PC), 0x0202);
+                assert_eq!(cpu[Reg8::A], 0x22);
+            }
+
+            it "reads the opcode after HALT twice, if an interrupt is pending and IME is disabled" {
+                cpu.bus.memory[INTERRUPT_FLAG_ADDRESS as usize] = 0b0000_0001;
+                cpu.bus.memory[0x0200..0x0202].copy_from_slice(&[0x76, 0x3C]); // HALT; INC A
+                cpu[Reg8::A] = 0x21;
+
+                cpu.run_until(12);
+
+                assert_eq!(cpu.reg16(Reg16::PC), 0x0202);
+                assert_eq!(cpu[Reg8::A], 0x23);
+            }
+
+            it "exits STOP on joypad interrupt request" {
+                cpu.bus.memory[INTERRUPT_ENABLE_ADDRESS as usize] = 0b0000_0000;
+                cpu.bus.memory[0x0200..0x0203].copy_from_slice(&[0x10, 0x00, 0x00]); // STOP; NOP
+
+                cpu.step();
+                cpu.step();
+
+                assert_eq!(cpu.reg16(Reg16::PC), 0x0202);
+
+                cpu.bus.memory[INTERRUPT_FLAG_ADDRESS as usize] = JOYPAD_INTERRUPT;
+                cpu.step();
+
+                assert_eq!(cpu.reg16(Reg16::PC), 0x0203);
+            }
+        }
+
         context "executes" {
             // __TESTS_REPLACEMENT_START__
-            // __TESTS_REPLACEMENT_END__
-          }
+            context "LD r, n [0x06: B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x06, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x21,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD r, n [0x0E: C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x0E, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x21,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD r, n [0x16: D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x16, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x21,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD r, n [0x1E: E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x1E, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x21,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD r, n [0x26: H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x26, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x21,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD r, n [0x2E: L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x2E, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x21,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD r, n [0x3E: A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x3E, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x78: A, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x78];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x79: A, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x79];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x7A: A, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x7A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x7B: A, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x7B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x7C: A, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x7C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x7D: A, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x7D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x41: B, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x41];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x42: B, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x42];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x43: B, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x43];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x44: B, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x44];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x45: B, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x45];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x48: C, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x48];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x4A: C, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x4A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x4B: C, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x4B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x4C: C, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x4C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x4D: C, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x4D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x50: D, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x50];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x51: D, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x51];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x53: D, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x53];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x54: D, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x54];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x55: D, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x55];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x58: E, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x58];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x59: E, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x59];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x5A: E, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x5A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x5C: E, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x5C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x5D: E, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x5D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x60: H, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x60];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x61: H, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x61];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x62: H, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x62];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x63: H, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x63];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x65: H, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x65];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x68: L, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x68];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x69: L, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x69];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x6A: L, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x6A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x6B: L, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x6B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x6C: L, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x6C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x47: B, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x47];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x4F: C, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x4F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x57: D, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x57];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x5F: E, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x5F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x67: H, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x67];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x6F: L, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x6F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x7F: A, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x7F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x40: B, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x40];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x49: C, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x49];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x52: D, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x52];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x5B: E, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x5B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x64: H, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x64];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, r2 [0x6D: L, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x6D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x21,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "LD r1, (rr2) [0x46: B, HL]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x46];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x21,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD r1, (rr2) [0x4E: C, HL]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x4E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x21,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD r1, (rr2) [0x56: D, HL]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x56];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x21,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD r1, (rr2) [0x5E: E, HL]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x5E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x21,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD r1, (rr2) [0x7E: A, HL]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x7E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD r1, (rr2) [0x0A: A, BC]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x0A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0x21;
+                    cpu.set_reg16(Reg16::BC, 0x0CAF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD r1, (rr2) [0x1A: A, DE]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x1A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0x21;
+                    cpu.set_reg16(Reg16::DE, 0x0CAF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD r1, (rr2) [0x66: H, HL]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x66];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x21,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD r1, (rr2) [0x6E: L, HL]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x6E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x21,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD (rr1), r2 [0x70: HL, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x70];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    let expected_value = cpu[Reg8::B];
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        mem[0x0CAF] => [expected_value],
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD (rr1), r2 [0x71: HL, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x71];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    let expected_value = cpu[Reg8::C];
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        mem[0x0CAF] => [expected_value],
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD (rr1), r2 [0x72: HL, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x72];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    let expected_value = cpu[Reg8::D];
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        mem[0x0CAF] => [expected_value],
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD (rr1), r2 [0x73: HL, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x73];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    let expected_value = cpu[Reg8::E];
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        mem[0x0CAF] => [expected_value],
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD (rr1), r2 [0x74: HL, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x74];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    let expected_value = cpu[Reg8::H];
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        mem[0x0CAF] => [expected_value],
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD (rr1), r2 [0x75: HL, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x75];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    let expected_value = cpu[Reg8::L];
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        mem[0x0CAF] => [expected_value],
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD (rr1), r2 [0x02: BC, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x02];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu.set_reg16(Reg16::BC, 0x0CAF);
+
+                    let expected_value = cpu[Reg8::A];
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        mem[0x0CAF] => [expected_value],
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD (rr1), r2 [0x12: DE, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x12];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu.set_reg16(Reg16::DE, 0x0CAF);
+
+                    let expected_value = cpu[Reg8::A];
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        mem[0x0CAF] => [expected_value],
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD (rr1), r2 [0x77: HL, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x77];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    let expected_value = cpu[Reg8::A];
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        mem[0x0CAF] => [expected_value],
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD (HL), n [0x36]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x36, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0x0CAF] => [0x21],
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "LD A, (nn) [0xFA]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xFA, 0xAF, 0x0C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x24,
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "LD (nn), A [0xEA]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xEA, 0xAF, 0x0C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x24,
+                        mem[0x0CAF] => [0x21],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "LD A, (C) [0xF2]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xF2];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x13;
+                    cpu.bus.memory[0xFF13] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LD (C), A [0xE2]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xE2];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::C] = 0x13;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        mem[0xFF13] => [0x21],
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LDD A, (HL) [0x3A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x3A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0x0000);
+                    cpu.bus.memory[0x0000] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        HL => 0xFFFF,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LDD (HL), A [0x32]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x32];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0000);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0xFFFF,
+                        PC => 0x22,
+                        mem[0x0000] => [0x21],
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LDI A, (HL) [0x2A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x2A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xFFFF);
+                    cpu.bus.memory[0xFFFF] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        HL => 0x0000,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LDI (HL), A [0x22]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x22];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0xFFFF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x0000,
+                        PC => 0x22,
+                        mem[0xFFFF] => [0x21],
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LDH (n), A [0xE0]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xE0, 0x13];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xFF13] => [0x21],
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "LDH A, (n) [0xF0]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xF0, 0x13];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0xFF13] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x23,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "LD rr, nn [0x01: BC]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x01, 0xFE, 0xCA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        BC => 0xCAFE,
+                        PC => 0x24,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "LD rr, nn [0x11: DE]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x11, 0xFE, 0xCA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        DE => 0xCAFE,
+                        PC => 0x24,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "LD rr, nn [0x21: HL]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x21, 0xFE, 0xCA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0xCAFE,
+                        PC => 0x24,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "LD rr, nn [0x31: SP]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x31, 0xFE, 0xCA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFE,
+                        PC => 0x24,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "LD SP, HL [0xF9]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xF9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFE,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "LDHL SP, n [0xF8]" {
+                it "without conditional flag modifications: positive immediate" {
+                    let instruction_bytes = [0xF8, 0x01];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0x2100);
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x2101,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        cycles: 12
+                    );
+                }
+                it "without conditional flag modifications: negative immediate" {
+                    let instruction_bytes = [0xF8, 0xFF];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0x2100);
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x20FF,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        cycles: 12
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0xF8, 0x01];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAEF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0xCAF0,
+                        PC => 0x23,
+                        hf => true,
+                        cycles: 12
+                    );
+                }
+                it "with flag H modified: negative immediate" {
+                    let instruction_bytes = [0xF8, 0xE1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCA0F);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0xC9F0,
+                        PC => 0x23,
+                        hf => true,
+                        cycles: 12
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xF8, 0x10];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0xCB0F,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 12
+                    );
+                }
+                it "with flag C modified: negative immediate" {
+                    let instruction_bytes = [0xF8, 0xE0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCA2F);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0xCA0F,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "LD (nn), SP [0x08]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x08, 0xFE, 0xCA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xBEEF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x24,
+                        mem[0xCAFE] => [0xEF, 0xBE],
+                        cycles: 20
+                    );
+                }
+            }
+
+            context "PUSH rr [0xF5: AF]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xF5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::AF, 0xBEEF);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x22,
+                        mem[0xCAFC] => [0xEF, 0xBE],
+                        cycles: 16
+                    );
+                }
+                it "without conditional flag modifications: wraparound" {
+                    let instruction_bytes = [0xF5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::AF, 0xBEEF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xFFFE,
+                        PC => 0x22,
+                        mem[0xFFFE] => [0xEF, 0xBE],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "PUSH rr [0xC5: BC]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xC5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::BC, 0xBEEF);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x22,
+                        mem[0xCAFC] => [0xEF, 0xBE],
+                        cycles: 16
+                    );
+                }
+                it "without conditional flag modifications: wraparound" {
+                    let instruction_bytes = [0xC5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::BC, 0xBEEF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xFFFE,
+                        PC => 0x22,
+                        mem[0xFFFE] => [0xEF, 0xBE],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "PUSH rr [0xD5: DE]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xD5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::DE, 0xBEEF);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x22,
+                        mem[0xCAFC] => [0xEF, 0xBE],
+                        cycles: 16
+                    );
+                }
+                it "without conditional flag modifications: wraparound" {
+                    let instruction_bytes = [0xD5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::DE, 0xBEEF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xFFFE,
+                        PC => 0x22,
+                        mem[0xFFFE] => [0xEF, 0xBE],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "PUSH rr [0xE5: HL]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xE5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xBEEF);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x22,
+                        mem[0xCAFC] => [0xEF, 0xBE],
+                        cycles: 16
+                    );
+                }
+                it "without conditional flag modifications: wraparound" {
+                    let instruction_bytes = [0xE5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xBEEF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xFFFE,
+                        PC => 0x22,
+                        mem[0xFFFE] => [0xEF, 0xBE],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "POP rr [0xC1: BC]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xC1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    let address = cpu.reg16(Reg16::SP) as usize;
+                    cpu.bus.memory[address..address + 2].copy_from_slice(&[0xEF, 0xBE]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        BC => 0xBEEF,
+                        SP => 0xCB00,
+                        PC => 0x22,
+                        cycles: 12
+                    );
+                }
+                it "without conditional flag modifications: wraparound" {
+                    let instruction_bytes = [0xC1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xFFFE);
+
+                    let address = cpu.reg16(Reg16::SP) as usize;
+                    cpu.bus.memory[address..address + 2].copy_from_slice(&[0xEF, 0xBE]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        BC => 0xBEEF,
+                        SP => 0x0000,
+                        PC => 0x22,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "POP rr [0xD1: DE]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xD1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    let address = cpu.reg16(Reg16::SP) as usize;
+                    cpu.bus.memory[address..address + 2].copy_from_slice(&[0xEF, 0xBE]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        DE => 0xBEEF,
+                        SP => 0xCB00,
+                        PC => 0x22,
+                        cycles: 12
+                    );
+                }
+                it "without conditional flag modifications: wraparound" {
+                    let instruction_bytes = [0xD1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xFFFE);
+
+                    let address = cpu.reg16(Reg16::SP) as usize;
+                    cpu.bus.memory[address..address + 2].copy_from_slice(&[0xEF, 0xBE]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        DE => 0xBEEF,
+                        SP => 0x0000,
+                        PC => 0x22,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "POP rr [0xE1: HL]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xE1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    let address = cpu.reg16(Reg16::SP) as usize;
+                    cpu.bus.memory[address..address + 2].copy_from_slice(&[0xEF, 0xBE]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0xBEEF,
+                        SP => 0xCB00,
+                        PC => 0x22,
+                        cycles: 12
+                    );
+                }
+                it "without conditional flag modifications: wraparound" {
+                    let instruction_bytes = [0xE1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xFFFE);
+
+                    let address = cpu.reg16(Reg16::SP) as usize;
+                    cpu.bus.memory[address..address + 2].copy_from_slice(&[0xEF, 0xBE]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0xBEEF,
+                        SP => 0x0000,
+                        PC => 0x22,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "POP AF [0xF1]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xF1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    let address = cpu.reg16(Reg16::SP) as usize;
+                    cpu.bus.memory[address..address + 2].copy_from_slice(&[0xFF, 0xBE]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        AF => 0xBEF0,
+                        SP => 0xCB00,
+                        PC => 0x22,
+                        cycles: 12
+                    );
+                }
+
+
+
+
+            }
+
+            context "ADD A, r [0x87: A]" {
+                it "without conditional flag modifications: A" {
+                    let instruction_bytes = [0x87];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x42,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x87];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x18;
+                    cpu[Reg8::A] = 0x18;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x87];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x90;
+                    cpu[Reg8::A] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "ADD A, r [0x80: B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x80];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::B] = 0x30;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x51,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x80];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x80];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x18;
+                    cpu[Reg8::B] = 0x18;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x80];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x90;
+                    cpu[Reg8::B] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "ADD A, r [0x81: C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x81];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::C] = 0x30;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x51,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x81];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x81];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x18;
+                    cpu[Reg8::C] = 0x18;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x81];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x90;
+                    cpu[Reg8::C] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "ADD A, r [0x82: D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x82];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::D] = 0x30;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x51,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x82];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x82];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x18;
+                    cpu[Reg8::D] = 0x18;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x82];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x90;
+                    cpu[Reg8::D] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "ADD A, r [0x83: E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x83];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::E] = 0x30;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x51,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x83];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x83];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x18;
+                    cpu[Reg8::E] = 0x18;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x83];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x90;
+                    cpu[Reg8::E] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "ADD A, r [0x84: H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x84];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::H] = 0x30;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x51,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x84];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x84];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x18;
+                    cpu[Reg8::H] = 0x18;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x84];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x90;
+                    cpu[Reg8::H] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "ADD A, r [0x85: L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x85];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::L] = 0x30;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x51,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x85];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x85];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x18;
+                    cpu[Reg8::L] = 0x18;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x85];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x90;
+                    cpu[Reg8::L] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "ADD A, (HL) [0x86]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x86];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x42,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x86];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x86];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x0F;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x31,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x86];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0xF0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x10,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "ADD A, n [0xC6]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xC6, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x42,
+                        PC => 0x23,
+                        nf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xC6, 0x00];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0xC6, 0x0F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x31,
+                        PC => 0x23,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xC6, 0xF0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x10,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "ADC A, r [0x8F: A]" {
+                it "without conditional flag modifications: A" {
+                    let instruction_bytes = [0x8F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x42,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0x8F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+                    cpu[Reg8::A] = 0xFF;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xFF,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x8F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x8F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x18;
+                    cpu[Reg8::A] = 0x18;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x8F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x90;
+                    cpu[Reg8::A] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "ADC A, r [0x88: B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x88];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::B] = 0x30;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x51,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0x88];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+                    cpu[Reg8::B] = 0xFF;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xFF,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x88];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag Z modified: carry in" {
+                    let instruction_bytes = [0x88];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+                    cpu[Reg8::B] = 0x00;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x88];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x18;
+                    cpu[Reg8::B] = 0x18;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag H modified: carry in" {
+                    let instruction_bytes = [0x88];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x00;
+                    cpu[Reg8::B] = 0x0F;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x10,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x88];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x90;
+                    cpu[Reg8::B] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "ADC A, r [0x89: C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x89];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::C] = 0x30;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x51,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0x89];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+                    cpu[Reg8::C] = 0xFF;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xFF,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x89];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag Z modified: carry in" {
+                    let instruction_bytes = [0x89];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+                    cpu[Reg8::C] = 0x00;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x89];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x18;
+                    cpu[Reg8::C] = 0x18;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag H modified: carry in" {
+                    let instruction_bytes = [0x89];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x00;
+                    cpu[Reg8::C] = 0x0F;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x10,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x89];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x90;
+                    cpu[Reg8::C] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "ADC A, r [0x8A: D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x8A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::D] = 0x30;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x51,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0x8A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+                    cpu[Reg8::D] = 0xFF;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xFF,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x8A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag Z modified: carry in" {
+                    let instruction_bytes = [0x8A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+                    cpu[Reg8::D] = 0x00;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x8A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x18;
+                    cpu[Reg8::D] = 0x18;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag H modified: carry in" {
+                    let instruction_bytes = [0x8A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x00;
+                    cpu[Reg8::D] = 0x0F;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x10,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x8A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x90;
+                    cpu[Reg8::D] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "ADC A, r [0x8B: E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x8B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::E] = 0x30;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x51,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0x8B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+                    cpu[Reg8::E] = 0xFF;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xFF,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x8B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag Z modified: carry in" {
+                    let instruction_bytes = [0x8B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+                    cpu[Reg8::E] = 0x00;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x8B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x18;
+                    cpu[Reg8::E] = 0x18;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag H modified: carry in" {
+                    let instruction_bytes = [0x8B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x00;
+                    cpu[Reg8::E] = 0x0F;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x10,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x8B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x90;
+                    cpu[Reg8::E] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "ADC A, r [0x8C: H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x8C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::H] = 0x30;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x51,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0x8C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+                    cpu[Reg8::H] = 0xFF;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xFF,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x8C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag Z modified: carry in" {
+                    let instruction_bytes = [0x8C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+                    cpu[Reg8::H] = 0x00;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x8C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x18;
+                    cpu[Reg8::H] = 0x18;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag H modified: carry in" {
+                    let instruction_bytes = [0x8C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x00;
+                    cpu[Reg8::H] = 0x0F;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x10,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x8C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x90;
+                    cpu[Reg8::H] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "ADC A, r [0x8D: L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x8D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::L] = 0x30;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x51,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0x8D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+                    cpu[Reg8::L] = 0xFF;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xFF,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x8D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag Z modified: carry in" {
+                    let instruction_bytes = [0x8D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+                    cpu[Reg8::L] = 0x00;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x8D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x18;
+                    cpu[Reg8::L] = 0x18;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag H modified: carry in" {
+                    let instruction_bytes = [0x8D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x00;
+                    cpu[Reg8::L] = 0x0F;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x10,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x8D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x90;
+                    cpu[Reg8::L] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "ADC A, (HL) [0x8E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x8E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x42,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0x8E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0xFF;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xFF,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x8E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x8E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x0F;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x31,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x8E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0xF0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x10,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "ADC A, n [0xCE]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCE, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x42,
+                        PC => 0x23,
+                        nf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0xCE, 0xFF];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xFF,
+                        PC => 0x23,
+                        nf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCE, 0x00];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0xCE, 0x0F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x31,
+                        PC => 0x23,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCE, 0xF0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x10,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SUB A, r [0x97: A]" {
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x97];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+
+            }
+
+            context "SUB A, r [0x90: B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x90];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+                    cpu[Reg8::B] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x01,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x90];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x90];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::B] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x22,
+                        nf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x90];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x70;
+                    cpu[Reg8::B] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xE0,
+                        PC => 0x22,
+                        nf => true,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "SUB A, r [0x91: C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x91];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+                    cpu[Reg8::C] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x01,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x91];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x91];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::C] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x22,
+                        nf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x91];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x70;
+                    cpu[Reg8::C] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xE0,
+                        PC => 0x22,
+                        nf => true,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "SUB A, r [0x92: D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x92];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+                    cpu[Reg8::D] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x01,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x92];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x92];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::D] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x22,
+                        nf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x92];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x70;
+                    cpu[Reg8::D] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xE0,
+                        PC => 0x22,
+                        nf => true,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "SUB A, r [0x93: E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x93];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+                    cpu[Reg8::E] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x01,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x93];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x93];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::E] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x22,
+                        nf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x93];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x70;
+                    cpu[Reg8::E] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xE0,
+                        PC => 0x22,
+                        nf => true,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "SUB A, r [0x94: H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x94];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+                    cpu[Reg8::H] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x01,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x94];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x94];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::H] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x22,
+                        nf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x94];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x70;
+                    cpu[Reg8::H] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xE0,
+                        PC => 0x22,
+                        nf => true,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "SUB A, r [0x95: L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x95];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+                    cpu[Reg8::L] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x01,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x95];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x95];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::L] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x22,
+                        nf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x95];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x70;
+                    cpu[Reg8::L] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xE0,
+                        PC => 0x22,
+                        nf => true,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "SUB A, (HL) [0x96]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x96];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x42;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x96];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x96];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x22,
+                        nf => true,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x96];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x70;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xE0,
+                        PC => 0x22,
+                        nf => true,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SUB A, n [0xD6]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xD6, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x42;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x23,
+                        nf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xD6, 0x00];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x23,
+                        zf => true,
+                        nf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0xD6, 0x0F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x12,
+                        PC => 0x23,
+                        nf => true,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xD6, 0xF0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x10;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x23,
+                        nf => true,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SBC A, r [0x9F: A]" {
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x9F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+
+            }
+
+            context "SBC A, r [0x98: B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x98];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu[Reg8::B] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0F,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0x98];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu[Reg8::B] = 0x21;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0E,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x98];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag Z modified: borrow out" {
+                    let instruction_bytes = [0x98];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x00;
+                    cpu[Reg8::B] = 0xFF;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x98];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::B] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag H modified: carry in" {
+                    let instruction_bytes = [0x98];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x10;
+                    cpu[Reg8::B] = 0x0F;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x98];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::B] = 0x30;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xF1,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "SBC A, r [0x99: C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x99];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu[Reg8::C] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0F,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0x99];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu[Reg8::C] = 0x21;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0E,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x99];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag Z modified: borrow out" {
+                    let instruction_bytes = [0x99];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x00;
+                    cpu[Reg8::C] = 0xFF;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x99];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::C] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag H modified: carry in" {
+                    let instruction_bytes = [0x99];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x10;
+                    cpu[Reg8::C] = 0x0F;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x99];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::C] = 0x30;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xF1,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "SBC A, r [0x9A: D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x9A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu[Reg8::D] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0F,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0x9A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu[Reg8::D] = 0x21;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0E,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x9A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag Z modified: borrow out" {
+                    let instruction_bytes = [0x9A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x00;
+                    cpu[Reg8::D] = 0xFF;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x9A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::D] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag H modified: carry in" {
+                    let instruction_bytes = [0x9A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x10;
+                    cpu[Reg8::D] = 0x0F;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x9A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::D] = 0x30;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xF1,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "SBC A, r [0x9B: E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x9B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu[Reg8::E] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0F,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0x9B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu[Reg8::E] = 0x21;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0E,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x9B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag Z modified: borrow out" {
+                    let instruction_bytes = [0x9B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x00;
+                    cpu[Reg8::E] = 0xFF;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x9B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::E] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag H modified: carry in" {
+                    let instruction_bytes = [0x9B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x10;
+                    cpu[Reg8::E] = 0x0F;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x9B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::E] = 0x30;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xF1,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "SBC A, r [0x9C: H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x9C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu[Reg8::H] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0F,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0x9C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu[Reg8::H] = 0x21;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0E,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x9C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag Z modified: borrow out" {
+                    let instruction_bytes = [0x9C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x00;
+                    cpu[Reg8::H] = 0xFF;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x9C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::H] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag H modified: carry in" {
+                    let instruction_bytes = [0x9C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x10;
+                    cpu[Reg8::H] = 0x0F;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x9C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::H] = 0x30;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xF1,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "SBC A, r [0x9D: L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x9D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu[Reg8::L] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0F,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0x9D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu[Reg8::L] = 0x21;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0E,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x9D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag Z modified: borrow out" {
+                    let instruction_bytes = [0x9D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x00;
+                    cpu[Reg8::L] = 0xFF;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x9D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::L] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag H modified: carry in" {
+                    let instruction_bytes = [0x9D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x10;
+                    cpu[Reg8::L] = 0x0F;
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x9D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu[Reg8::L] = 0x30;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0xF1,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "SBC A, (HL) [0x9E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x9E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0F,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0x9E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x21;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0E,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x9E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x9E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x9E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0xF0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SBC A, n [0xDE]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xDE, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0F,
+                        PC => 0x23,
+                        nf => true,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry set" {
+                    let instruction_bytes = [0xDE, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x30;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0E,
+                        PC => 0x23,
+                        nf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xDE, 0x00];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0xDE, 0x01];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x23,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xDE, 0xF0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x30,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "AND A, r [0xA7: A]" {
+                it "without conditional flag modifications: A" {
+                    let instruction_bytes = [0xA7];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1010_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => true,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xA7];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "AND A, r [0xA0: B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xA0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::B] = 0b0101_1111;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => true,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xA0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "AND A, r [0xA1: C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xA1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::C] = 0b0101_1111;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => true,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xA1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "AND A, r [0xA2: D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xA2];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::D] = 0b0101_1111;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => true,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xA2];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "AND A, r [0xA3: E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xA3];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::E] = 0b0101_1111;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => true,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xA3];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "AND A, r [0xA4: H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xA4];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::H] = 0b0101_1111;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => true,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xA4];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "AND A, r [0xA5: L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xA5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::L] = 0b0101_1111;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => true,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xA5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "AND A, (HL) [0xA6]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xA6];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0101_1111;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => true,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xA6];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0101_0110;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "AND A, n [0xE6]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xE6, 0x5F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_1001,
+                        PC => 0x23,
+                        nf => false,
+                        hf => true,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xE6, 0x56];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "OR A, r [0xB7: A]" {
+                it "without conditional flag modifications: A" {
+                    let instruction_bytes = [0xB7];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1010_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xB7];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "OR A, r [0xB0: B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xB0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::B] = 0b0101_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xB0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "OR A, r [0xB1: C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xB1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::C] = 0b0101_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xB1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "OR A, r [0xB2: D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xB2];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::D] = 0b0101_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xB2];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "OR A, r [0xB3: E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xB3];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::E] = 0b0101_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xB3];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "OR A, r [0xB4: H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xB4];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::H] = 0b0101_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xB4];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "OR A, r [0xB5: L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xB5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::L] = 0b0101_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xB5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "OR A, (HL) [0xB6]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xB6];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0101_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_1001,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xB6];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "OR A, n [0xF6]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xF6, 0x59];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_1001,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xF6, 0x00];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "XOR A, r [0xAF: A]" {
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xAF];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::A] = 0b1010_1001;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "XOR A, r [0xA8: B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xA8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::B] = 0b0101_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0000,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xA8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::B] = 0b1010_1001;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "XOR A, r [0xA9: C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xA9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::C] = 0b0101_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0000,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xA9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::C] = 0b1010_1001;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "XOR A, r [0xAA: D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xAA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::D] = 0b0101_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0000,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xAA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::D] = 0b1010_1001;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "XOR A, r [0xAB: E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xAB];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::E] = 0b0101_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0000,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xAB];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::E] = 0b1010_1001;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "XOR A, r [0xAC: H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xAC];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::H] = 0b0101_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0000,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xAC];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::H] = 0b1010_1001;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "XOR A, r [0xAD: L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xAD];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::L] = 0b0101_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0000,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xAD];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu[Reg8::L] = 0b1010_1001;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "XOR A, (HL) [0xAE]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xAE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0101_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0000,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xAE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1010_1001;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "XOR A, n [0xEE]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xEE, 0x59];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xEE, 0xA9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1010_1001;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "CP A, r [0xBF: A]" {
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xBF];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+
+            }
+
+            context "CP A, r [0xB8: B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xB8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+                    cpu[Reg8::B] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x22,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xB8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0xB8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::B] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        nf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xB8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x70;
+                    cpu[Reg8::B] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x70,
+                        PC => 0x22,
+                        nf => true,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "CP A, r [0xB9: C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xB9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+                    cpu[Reg8::C] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x22,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xB9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0xB9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::C] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        nf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xB9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x70;
+                    cpu[Reg8::C] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x70,
+                        PC => 0x22,
+                        nf => true,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "CP A, r [0xBA: D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xBA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+                    cpu[Reg8::D] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x22,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xBA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0xBA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::D] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        nf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xBA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x70;
+                    cpu[Reg8::D] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x70,
+                        PC => 0x22,
+                        nf => true,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "CP A, r [0xBB: E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xBB];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+                    cpu[Reg8::E] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x22,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xBB];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0xBB];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::E] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        nf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xBB];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x70;
+                    cpu[Reg8::E] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x70,
+                        PC => 0x22,
+                        nf => true,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "CP A, r [0xBC: H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xBC];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+                    cpu[Reg8::H] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x22,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xBC];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0xBC];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::H] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        nf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xBC];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x70;
+                    cpu[Reg8::H] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x70,
+                        PC => 0x22,
+                        nf => true,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "CP A, r [0xBD: L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xBD];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+                    cpu[Reg8::L] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x22,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xBD];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x0;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0xBD];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu[Reg8::L] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        nf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xBD];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x70;
+                    cpu[Reg8::L] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x70,
+                        PC => 0x22,
+                        nf => true,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "CP A, (HL) [0xBE]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xBE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x42;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x21;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x42,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xBE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        nf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0xBE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        nf => true,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xBE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x70;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0x90;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x70,
+                        PC => 0x22,
+                        nf => true,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "CP A, n [0xFE]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xFE, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x42;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x42,
+                        PC => 0x23,
+                        nf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xFE, 0x00];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x23,
+                        zf => true,
+                        nf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0xFE, 0x0F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x23,
+                        nf => true,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xFE, 0xF0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x10;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x10,
+                        PC => 0x23,
+                        nf => true,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "INC r [0x3C: A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x3C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x22,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x3C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xFF;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x3C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x1F;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x20,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "INC r [0x04: B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x04];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x22,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x04];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0xFF;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x04];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x1F;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x20,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "INC r [0x0C: C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x0C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x22,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x0C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0xFF;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x0C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x1F;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x20,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "INC r [0x14: D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x14];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x22,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x14];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0xFF;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x14];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x1F;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x20,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "INC r [0x1C: E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x1C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x22,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x1C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0xFF;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x1C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x1F;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x20,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "INC r [0x24: H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x24];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x22,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x24];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0xFF;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x24];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x1F;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x20,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "INC r [0x2C: L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x2C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x22,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x2C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0xFF;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x2C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x1F;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x20,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "INC (HL) [0x34]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x34];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        nf => false,
+                        mem[0x0CAF] => [0x22],
+                        cycles: 12
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x34];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0xFF;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        zf => true,
+                        hf => true,
+                        mem[0x0CAF] => [0x0],
+                        cycles: 12
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x34];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0x1F;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        hf => true,
+                        mem[0x0CAF] => [0x20],
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "DEC r [0x3D: A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x3D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x22;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x3D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x3D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x20;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x1F,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "DEC r [0x05: B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x05];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x22;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x21,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x05];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x05];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x20;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x1F,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "DEC r [0x0D: C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x0D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x22;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x21,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x0D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x0D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x20;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x1F,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "DEC r [0x15: D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x15];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x22;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x21,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x15];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x15];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x20;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x1F,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "DEC r [0x1D: E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x1D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x22;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x21,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x1D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x1D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x20;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x1F,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "DEC r [0x25: H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x25];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x22;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x21,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x25];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x25];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x20;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x1F,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "DEC r [0x2D: L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x2D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x22;
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x21,
+                        PC => 0x22,
+                        nf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x2D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x01;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x2D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x20;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x1F,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "DEC (HL) [0x35]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x35];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0x22;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+                    cpu.set_flag(Flag::n, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        nf => true,
+                        mem[0x0CAF] => [0x21],
+                        cycles: 12
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x35];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0x01;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        zf => true,
+                        mem[0x0CAF] => [0x00],
+                        cycles: 12
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x35];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0x0CAF] = 0x20;
+                    cpu.set_reg16(Reg16::HL, 0x0CAF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        hf => true,
+                        mem[0x0CAF] => [0x1F],
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "ADD HL, rr [0x09: BC]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x09];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0x2121);
+                    cpu.set_reg16(Reg16::BC, 0x2121);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x4242,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x09];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0x1800);
+                    cpu.set_reg16(Reg16::BC, 0x1800);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x3000,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x09];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0x9000);
+                    cpu.set_reg16(Reg16::BC, 0x9000);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x2000,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "ADD HL, rr [0x19: DE]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x19];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0x2121);
+                    cpu.set_reg16(Reg16::DE, 0x2121);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x4242,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x19];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0x1800);
+                    cpu.set_reg16(Reg16::DE, 0x1800);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x3000,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x19];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0x9000);
+                    cpu.set_reg16(Reg16::DE, 0x9000);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x2000,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "ADD HL, rr [0x29: HL]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x29];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0x2121);
+                    cpu.set_reg16(Reg16::HL, 0x2121);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x4242,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x29];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0x1800);
+                    cpu.set_reg16(Reg16::HL, 0x1800);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x3000,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x29];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0x9000);
+                    cpu.set_reg16(Reg16::HL, 0x9000);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x2000,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "ADD HL, rr [0x39: SP]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x39];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0x2121);
+                    cpu.set_reg16(Reg16::SP, 0x2121);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x4242,
+                        PC => 0x22,
+                        nf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0x39];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0x1800);
+                    cpu.set_reg16(Reg16::SP, 0x1800);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x3000,
+                        PC => 0x22,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x39];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0x9000);
+                    cpu.set_reg16(Reg16::SP, 0x9000);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x2000,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "ADD SP, n [0xE8]" {
+                it "without conditional flag modifications: positive immediate" {
+                    let instruction_bytes = [0xE8, 0x01];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0x2100);
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0x2101,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        cycles: 16
+                    );
+                }
+                it "without conditional flag modifications: negative immediate" {
+                    let instruction_bytes = [0xE8, 0xFF];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0x2100);
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_flag(Flag::n, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0x20FF,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        cycles: 16
+                    );
+                }
+
+                it "with flag H modified" {
+                    let instruction_bytes = [0xE8, 0x01];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAEF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAF0,
+                        PC => 0x23,
+                        hf => true,
+                        cycles: 16
+                    );
+                }
+                it "with flag H modified: negative immediate" {
+                    let instruction_bytes = [0xE8, 0xE1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCA0F);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xC9F0,
+                        PC => 0x23,
+                        hf => true,
+                        cycles: 16
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xE8, 0x10];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCB0F,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 16
+                    );
+                }
+                it "with flag C modified: negative immediate" {
+                    let instruction_bytes = [0xE8, 0xE0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCA2F);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCA0F,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "INC rr [0x03: BC]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x03];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::BC, 0xFFFF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        BC => 0x0000,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "INC rr [0x13: DE]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x13];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::DE, 0xFFFF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        DE => 0x0000,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "INC rr [0x23: HL]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x23];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xFFFF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0x0000,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "INC rr [0x33: SP]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x33];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xFFFF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0x0000,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "DEC rr [0x0B: BC]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x0B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::BC, 0x0000);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        BC => 0xFFFF,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "DEC rr [0x1B: DE]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x1B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::DE, 0x0000);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        DE => 0xFFFF,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "DEC rr [0x2B: HL]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x2B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0x0000);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        HL => 0xFFFF,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "DEC rr [0x3B: SP]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x3B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0x0000);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xFFFF,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SWAP r [0xCB 0x37: A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x37];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x12,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x37];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SWAP r [0xCB 0x30: B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x30];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x12,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x30];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0x00,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SWAP r [0xCB 0x31: C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x31];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x12,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x31];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0x00,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SWAP r [0xCB 0x32: D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x32];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x12,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x32];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0x00,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SWAP r [0xCB 0x33: E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x33];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x12,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x33];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0x00,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SWAP r [0xCB 0x34: H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x34];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x12,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x34];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0x00,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SWAP r [0xCB 0x35: L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x35];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x21;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x12,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x35];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0x00;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0x00,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SWAP (HL) [0xCB 0x36]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x36];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0xCAFE] = 0x21;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        mem[0xCAFE] => [0x12],
+                        cycles: 16
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x36];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.bus.memory[0xCAFE] = 0x00;
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        mem[0xCAFE] => [0x00],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "DAA [0x27]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x27];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x1B;
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x21,
+                        PC => 0x22,
+                        hf => false,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: subtraction" {
+                    let instruction_bytes = [0x27];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x0F;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x09,
+                        PC => 0x22,
+                        hf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0x27];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0x9A;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x00,
+                        PC => 0x22,
+                        zf => true,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x27];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xA1;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x01,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag C modified: subtraction, preserved" {
+                    let instruction_bytes = [0x27];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xA0;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x40,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "CPL [0x2F]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x2F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0xF0;
+                    cpu.set_flag(Flag::n, false);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0x0F,
+                        PC => 0x22,
+                        nf => true,
+                        hf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "CCF [0x3F]" {
+
+                it "with flag C modified: -> true" {
+                    let instruction_bytes = [0x3F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+                it "with flag C modified: -> false" {
+                    let instruction_bytes = [0x3F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "SCF [0x37]" {
+                it "without conditional flag modifications: C -> true" {
+                    let instruction_bytes = [0x37];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, false);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: C -> false" {
+                    let instruction_bytes = [0x37];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+                    cpu.set_flag(Flag::c, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        nf => false,
+                        hf => false,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "NOP [0x00]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x00];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "HALT [0x76]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x76];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "STOP [0x10]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x10, 0x00];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "DI [0xF3]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xF3];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.IME = true;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "EI [0xFB]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xFB];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "RLCA [0x07]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x07];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0111_1000;
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0000,
+                        PC => 0x22,
+                        zf => false,
+                        nf => false,
+                        hf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x07];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1110_0001,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "RLA [0x17]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0x17];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0111_1000;
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0000,
+                        PC => 0x22,
+                        zf => false,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0x17];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0001,
+                        PC => 0x22,
+                        zf => false,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x17];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1110_0000,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "RRCA [0x0F]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0x0F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0001_1110;
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_1111,
+                        PC => 0x22,
+                        zf => false,
+                        nf => false,
+                        hf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x0F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1000_0111,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "RRA [0x1F]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0x1F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0001_1110;
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_1111,
+                        PC => 0x22,
+                        zf => false,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0x1F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1000_1111,
+                        PC => 0x22,
+                        zf => false,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 4
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0x1F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0111,
+                        PC => 0x22,
+                        cf => true,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "RLC r [0xCB 0x07: A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x07];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x07];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x07];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1110_0001,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RLC r [0xCB 0x00: B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x00];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x00];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x00];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1110_0001,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RLC r [0xCB 0x01: C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x01];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x01];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x01];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1110_0001,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RLC r [0xCB 0x02: D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x02];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x02];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x02];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1110_0001,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RLC r [0xCB 0x03: E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x03];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x03];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x03];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1110_0001,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RLC r [0xCB 0x04: H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x04];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x04];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x04];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1110_0001,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RLC r [0xCB 0x05: L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x05];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x05];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x05];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1110_0001,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RLC (HL) [0xCB 0x06]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x06];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        mem[0xCAFE] => [0b1111_0000],
+                        cycles: 16
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x06];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        mem[0xCAFE] => [0b0000_0000],
+                        cycles: 16
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x06];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cf => true,
+                        mem[0xCAFE] => [0b1110_0001],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RL r [0xCB 0x17: A]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x17];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x17];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0001,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x17];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x17];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1110_0000,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RL r [0xCB 0x10: B]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x10];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x10];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1111_0001,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x10];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x10];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1110_0000,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RL r [0xCB 0x11: C]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x11];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x11];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1111_0001,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x11];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x11];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1110_0000,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RL r [0xCB 0x12: D]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x12];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x12];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1111_0001,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x12];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x12];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1110_0000,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RL r [0xCB 0x13: E]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x13];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x13];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1111_0001,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x13];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x13];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1110_0000,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RL r [0xCB 0x14: H]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x14];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x14];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1111_0001,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x14];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x14];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1110_0000,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RL r [0xCB 0x15: L]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x15];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x15];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1111_0001,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x15];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x15];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1110_0000,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RL (HL) [0xCB 0x16]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x16];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0111_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        mem[0xCAFE] => [0b1111_0000],
+                        cycles: 16
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x16];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        mem[0xCAFE] => [0b1111_0001],
+                        cycles: 16
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x16];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        mem[0xCAFE] => [0b0000_0000],
+                        cycles: 16
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x16];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cf => true,
+                        mem[0xCAFE] => [0b1110_0000],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RRC r [0xCB 0x0F: A]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x0F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x0F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x0F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x0F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RRC r [0xCB 0x08: B]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x08];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x08];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x08];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x08];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RRC r [0xCB 0x09: C]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x09];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x09];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x09];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x09];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RRC r [0xCB 0x0A: D]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x0A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x0A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x0A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x0A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RRC r [0xCB 0x0B: E]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x0B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x0B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x0B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x0B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RRC r [0xCB 0x0C: H]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x0C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x0C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x0C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x0C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RRC r [0xCB 0x0D: L]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x0D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x0D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x0D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x0D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RRC (HL) [0xCB 0x0E]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x0E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        mem[0xCAFE] => [0b0000_1111],
+                        cycles: 16
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x0E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        mem[0xCAFE] => [0b0000_1111],
+                        cycles: 16
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x0E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        mem[0xCAFE] => [0b0000_0000],
+                        cycles: 16
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x0E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cf => true,
+                        mem[0xCAFE] => [0b1000_0111],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RR r [0xCB 0x1F: A]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x1F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x1F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x1F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x1F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RR r [0xCB 0x18: B]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x18];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x18];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x18];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x18];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RR r [0xCB 0x19: C]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x19];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x19];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x19];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x19];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RR r [0xCB 0x1A: D]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x1A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x1A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x1A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x1A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RR r [0xCB 0x1B: E]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x1B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x1B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x1B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x1B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RR r [0xCB 0x1C: H]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x1C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x1C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x1C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x1C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RR r [0xCB 0x1D: L]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x1D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x1D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x1D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x1D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RR (HL) [0xCB 0x1E]" {
+                it "without conditional flag modifications: carry was not set" {
+                    let instruction_bytes = [0xCB, 0x1E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        mem[0xCAFE] => [0b0000_1111],
+                        cycles: 16
+                    );
+                }
+                it "without conditional flag modifications: carry was set" {
+                    let instruction_bytes = [0xCB, 0x1E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0001_1110;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        mem[0xCAFE] => [0b1000_1111],
+                        cycles: 16
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x1E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        mem[0xCAFE] => [0b0000_0000],
+                        cycles: 16
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x1E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cf => true,
+                        mem[0xCAFE] => [0b0000_0111],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "SLA r [0xCB 0x27: A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x27];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x27];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x27];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1110_0000,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SLA r [0xCB 0x20: B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x20];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x20];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x20];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1110_0000,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SLA r [0xCB 0x21: C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x21];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1110_0000,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SLA r [0xCB 0x22: D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x22];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x22];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x22];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1110_0000,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SLA r [0xCB 0x23: E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x23];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x23];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x23];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1110_0000,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SLA r [0xCB 0x24: H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x24];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x24];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x24];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1110_0000,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SLA r [0xCB 0x25: L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x25];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1111_0000,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x25];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x25];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1110_0000,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SLA (HL) [0xCB 0x26]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x26];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0111_1000;
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        mem[0xCAFE] => [0b1111_0000],
+                        cycles: 16
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x26];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        mem[0xCAFE] => [0b0000_0000],
+                        cycles: 16
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x26];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1111_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cf => true,
+                        mem[0xCAFE] => [0b1110_0000],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "SRA r [0xCB 0x2F: A]" {
+                it "without conditional flag modifications: MSB=0" {
+                    let instruction_bytes = [0xCB, 0x2F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: MSB=1" {
+                    let instruction_bytes = [0xCB, 0x2F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1100_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x2F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x2F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SRA r [0xCB 0x28: B]" {
+                it "without conditional flag modifications: MSB=0" {
+                    let instruction_bytes = [0xCB, 0x28];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: MSB=1" {
+                    let instruction_bytes = [0xCB, 0x28];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1100_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x28];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x28];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SRA r [0xCB 0x29: C]" {
+                it "without conditional flag modifications: MSB=0" {
+                    let instruction_bytes = [0xCB, 0x29];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: MSB=1" {
+                    let instruction_bytes = [0xCB, 0x29];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1100_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x29];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x29];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SRA r [0xCB 0x2A: D]" {
+                it "without conditional flag modifications: MSB=0" {
+                    let instruction_bytes = [0xCB, 0x2A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: MSB=1" {
+                    let instruction_bytes = [0xCB, 0x2A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1100_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x2A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x2A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SRA r [0xCB 0x2B: E]" {
+                it "without conditional flag modifications: MSB=0" {
+                    let instruction_bytes = [0xCB, 0x2B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: MSB=1" {
+                    let instruction_bytes = [0xCB, 0x2B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1100_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x2B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x2B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SRA r [0xCB 0x2C: H]" {
+                it "without conditional flag modifications: MSB=0" {
+                    let instruction_bytes = [0xCB, 0x2C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: MSB=1" {
+                    let instruction_bytes = [0xCB, 0x2C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1100_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x2C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x2C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SRA r [0xCB 0x2D: L]" {
+                it "without conditional flag modifications: MSB=0" {
+                    let instruction_bytes = [0xCB, 0x2D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+                it "without conditional flag modifications: MSB=1" {
+                    let instruction_bytes = [0xCB, 0x2D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1100_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x2D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x2D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SRA (HL) [0xCB 0x2E]" {
+                it "without conditional flag modifications: MSB=0" {
+                    let instruction_bytes = [0xCB, 0x2E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        mem[0xCAFE] => [0b0000_1111],
+                        cycles: 16
+                    );
+                }
+                it "without conditional flag modifications: MSB=1" {
+                    let instruction_bytes = [0xCB, 0x2E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        mem[0xCAFE] => [0b1100_1111],
+                        cycles: 16
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x2E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        mem[0xCAFE] => [0b0000_0000],
+                        cycles: 16
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x2E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cf => true,
+                        mem[0xCAFE] => [0b0000_0111],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "SRL r [0xCB 0x3F: A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x3F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0100_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x3F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x3F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SRL r [0xCB 0x38: B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x38];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0100_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x38];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x38];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SRL r [0xCB 0x39: C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x39];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0100_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x39];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x39];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SRL r [0xCB 0x3A: D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x3A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0100_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x3A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x3A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SRL r [0xCB 0x3B: E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x3B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0100_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x3B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x3B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SRL r [0xCB 0x3C: H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x3C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0100_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x3C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x3C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SRL r [0xCB 0x3D: L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x3D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0100_1111,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x3D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_0000,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x3D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_0111,
+                        PC => 0x23,
+                        cf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SRL (HL) [0xCB 0x3E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x3E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1001_1110;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        nf => false,
+                        hf => false,
+                        cf => false,
+                        mem[0xCAFE] => [0b0100_1111],
+                        cycles: 16
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x3E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        mem[0xCAFE] => [0b0000_0000],
+                        cycles: 16
+                    );
+                }
+
+                it "with flag C modified" {
+                    let instruction_bytes = [0xCB, 0x3E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cf => true,
+                        mem[0xCAFE] => [0b0000_0111],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x47: 0, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x47];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x47];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_1110;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x40: 0, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x40];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x40];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1111_1110;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x41: 0, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x41];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x41];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1111_1110;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x42: 0, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x42];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x42];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1111_1110;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x43: 0, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x43];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x43];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1111_1110;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x44: 0, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x44];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x44];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1111_1110;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x45: 0, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x45];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x45];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1111_1110;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x4F: 1, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x4F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0010;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x4F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_1101;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x48: 1, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x48];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0010;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x48];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1111_1101;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x49: 1, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x49];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0010;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x49];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1111_1101;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x4A: 1, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x4A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0010;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x4A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1111_1101;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x4B: 1, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x4B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0010;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x4B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1111_1101;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x4C: 1, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x4C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0010;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x4C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1111_1101;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x4D: 1, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x4D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0010;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x4D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1111_1101;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x57: 2, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x57];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0100;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x57];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_1011;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x50: 2, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x50];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0100;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x50];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1111_1011;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x51: 2, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x51];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0100;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x51];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1111_1011;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x52: 2, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x52];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0100;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x52];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1111_1011;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x53: 2, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x53];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0100;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x53];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1111_1011;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x54: 2, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x54];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0100;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x54];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1111_1011;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x55: 2, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x55];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0100;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x55];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1111_1011;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x5F: 3, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x5F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x5F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_0111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x58: 3, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x58];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x58];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1111_0111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x59: 3, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x59];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x59];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1111_0111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x5A: 3, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x5A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x5A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1111_0111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x5B: 3, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x5B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x5B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1111_0111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x5C: 3, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x5C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x5C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1111_0111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x5D: 3, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x5D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x5D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1111_0111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x67: 4, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x67];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0001_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x67];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1110_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x60: 4, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x60];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0001_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x60];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1110_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x61: 4, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x61];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0001_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x61];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1110_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x62: 4, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x62];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0001_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x62];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1110_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x63: 4, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x63];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0001_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x63];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1110_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x64: 4, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x64];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0001_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x64];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1110_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x65: 4, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x65];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0001_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x65];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1110_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x6F: 5, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x6F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0010_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x6F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1101_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x68: 5, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x68];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0010_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x68];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1101_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x69: 5, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x69];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0010_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x69];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1101_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x6A: 5, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x6A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0010_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x6A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1101_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x6B: 5, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x6B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0010_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x6B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1101_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x6C: 5, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x6C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0010_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x6C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1101_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x6D: 5, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x6D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0010_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x6D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1101_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x77: 6, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x77];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0100_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x77];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1011_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x70: 6, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x70];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0100_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x70];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1011_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x71: 6, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x71];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0100_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x71];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1011_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x72: 6, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x72];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0100_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x72];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1011_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x73: 6, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x73];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0100_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x73];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1011_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x74: 6, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x74];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0100_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x74];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1011_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x75: 6, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x75];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0100_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x75];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1011_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x7F: 7, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x7F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1000_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x7F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x78: 7, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x78];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1000_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x78];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x79: 7, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x79];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1000_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x79];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x7A: 7, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x7A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1000_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x7A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x7B: 7, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x7B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1000_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x7B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x7C: 7, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x7C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1000_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x7C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, r [0xCB 0x7D: 7, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x7D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1000_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 8
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x7D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "BIT b, (HL) [0xCB 0x46: 0]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x46];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0001;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 12
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x46];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1111_1110;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "BIT b, (HL) [0xCB 0x4E: 1]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x4E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0010;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 12
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x4E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1111_1101;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "BIT b, (HL) [0xCB 0x56: 2]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x56];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0100;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 12
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x56];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1111_1011;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "BIT b, (HL) [0xCB 0x5E: 3]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x5E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_1000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 12
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x5E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1111_0111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "BIT b, (HL) [0xCB 0x66: 4]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x66];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0001_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 12
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x66];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1110_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "BIT b, (HL) [0xCB 0x6E: 5]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x6E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0010_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 12
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x6E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1101_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "BIT b, (HL) [0xCB 0x76: 6]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x76];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0100_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 12
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x76];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1011_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "BIT b, (HL) [0xCB 0x7E: 7]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x7E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1000_0000;
+                    cpu.set_flag(Flag::n, true);
+                    cpu.set_flag(Flag::h, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => false,
+                        nf => false,
+                        hf => true,
+                        cycles: 12
+                    );
+                }
+
+                it "with flag Z modified" {
+                    let instruction_bytes = [0xCB, 0x7E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        zf => true,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xC7: 0, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xC7];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0001,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xC0: 0, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xC0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_0001,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xC1: 0, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xC1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_0001,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xC2: 0, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xC2];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_0001,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xC3: 0, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xC3];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_0001,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xC4: 0, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xC4];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_0001,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xC5: 0, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xC5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_0001,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xCF: 1, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xCF];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0010,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xC8: 1, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xC8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_0010,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xC9: 1, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xC9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_0010,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xCA: 1, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xCA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_0010,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xCB: 1, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xCB];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_0010,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xCC: 1, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xCC];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_0010,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xCD: 1, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xCD];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_0010,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xD7: 2, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xD7];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_0100,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xD0: 2, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xD0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_0100,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xD1: 2, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xD1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_0100,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xD2: 2, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xD2];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_0100,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xD3: 2, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xD3];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_0100,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xD4: 2, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xD4];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_0100,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xD5: 2, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xD5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_0100,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xDF: 3, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xDF];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0000_1000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xD8: 3, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xD8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0000_1000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xD9: 3, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xD9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0000_1000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xDA: 3, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xDA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0000_1000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xDB: 3, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xDB];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0000_1000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xDC: 3, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xDC];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0000_1000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xDD: 3, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xDD];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0000_1000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xE7: 4, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xE7];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0001_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xE0: 4, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xE0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0001_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xE1: 4, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xE1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0001_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xE2: 4, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xE2];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0001_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xE3: 4, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xE3];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0001_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xE4: 4, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xE4];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0001_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xE5: 4, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xE5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0001_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xEF: 5, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xEF];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0010_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xE8: 5, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xE8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0010_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xE9: 5, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xE9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0010_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xEA: 5, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xEA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0010_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xEB: 5, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xEB];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0010_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xEC: 5, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xEC];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0010_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xED: 5, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xED];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0010_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xF7: 6, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xF7];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0100_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xF0: 6, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xF0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0100_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xF1: 6, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xF1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0100_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xF2: 6, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xF2];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0100_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xF3: 6, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xF3];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0100_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xF4: 6, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xF4];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0100_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xF5: 6, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xF5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0100_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xFF: 7, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xFF];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1000_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xF8: 7, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xF8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1000_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xF9: 7, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xF9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1000_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xFA: 7, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xFA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1000_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xFB: 7, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xFB];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1000_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xFC: 7, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xFC];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1000_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, r [0xCB 0xFD: 7, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xFD];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1000_0000,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "SET b, (HL) [0xCB 0xC6: 0]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xC6];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b0000_0001],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "SET b, (HL) [0xCB 0xCE: 1]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xCE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b0000_0010],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "SET b, (HL) [0xCB 0xD6: 2]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xD6];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b0000_0100],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "SET b, (HL) [0xCB 0xDE: 3]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xDE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b0000_1000],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "SET b, (HL) [0xCB 0xE6: 4]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xE6];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b0001_0000],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "SET b, (HL) [0xCB 0xEE: 5]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xEE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b0010_0000],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "SET b, (HL) [0xCB 0xF6: 6]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xF6];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b0100_0000],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "SET b, (HL) [0xCB 0xFE: 7]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xFE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b0000_0000;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b1000_0000],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x87: 0, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x87];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_1110,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x80: 0, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x80];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1111_1110,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x81: 0, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x81];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1111_1110,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x82: 0, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x82];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1111_1110,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x83: 0, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x83];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1111_1110,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x84: 0, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x84];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1111_1110,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x85: 0, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x85];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1111_1110,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x8F: 1, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x8F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_1101,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x88: 1, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x88];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1111_1101,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x89: 1, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x89];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1111_1101,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x8A: 1, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x8A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1111_1101,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x8B: 1, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x8B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1111_1101,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x8C: 1, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x8C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1111_1101,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x8D: 1, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x8D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1111_1101,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x97: 2, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x97];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_1011,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x90: 2, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x90];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1111_1011,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x91: 2, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x91];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1111_1011,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x92: 2, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x92];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1111_1011,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x93: 2, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x93];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1111_1011,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x94: 2, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x94];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1111_1011,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x95: 2, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x95];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1111_1011,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x9F: 3, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x9F];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1111_0111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x98: 3, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x98];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1111_0111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x99: 3, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x99];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1111_0111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x9A: 3, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x9A];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1111_0111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x9B: 3, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x9B];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1111_0111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x9C: 3, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x9C];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1111_0111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0x9D: 3, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x9D];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1111_0111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xA7: 4, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xA7];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1110_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xA0: 4, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xA0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1110_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xA1: 4, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xA1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1110_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xA2: 4, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xA2];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1110_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xA3: 4, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xA3];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1110_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xA4: 4, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xA4];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1110_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xA5: 4, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xA5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1110_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xAF: 5, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xAF];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1101_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xA8: 5, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xA8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1101_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xA9: 5, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xA9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1101_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xAA: 5, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xAA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1101_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xAB: 5, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xAB];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1101_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xAC: 5, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xAC];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1101_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xAD: 5, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xAD];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1101_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xB7: 6, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xB7];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b1011_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xB0: 6, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xB0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b1011_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xB1: 6, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xB1];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b1011_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xB2: 6, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xB2];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b1011_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xB3: 6, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xB3];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b1011_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xB4: 6, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xB4];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b1011_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xB5: 6, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xB5];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b1011_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xBF: 7, A]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xBF];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::A] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        A => 0b0111_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xB8: 7, B]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xB8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::B] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        B => 0b0111_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xB9: 7, C]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xB9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::C] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        C => 0b0111_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xBA: 7, D]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xBA];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::D] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        D => 0b0111_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xBB: 7, E]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xBB];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::E] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        E => 0b0111_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xBC: 7, H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xBC];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::H] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        H => 0b0111_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, r [0xCB 0xBD: 7, L]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xBD];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu[Reg8::L] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        L => 0b0111_1111,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RES b, (HL) [0xCB 0x86: 0]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x86];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b1111_1110],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RES b, (HL) [0xCB 0x8E: 1]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x8E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b1111_1101],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RES b, (HL) [0xCB 0x96: 2]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x96];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b1111_1011],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RES b, (HL) [0xCB 0x9E: 3]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0x9E];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b1111_0111],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RES b, (HL) [0xCB 0xA6: 4]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xA6];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b1110_1111],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RES b, (HL) [0xCB 0xAE: 5]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xAE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b1101_1111],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RES b, (HL) [0xCB 0xB6: 6]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xB6];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b1011_1111],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RES b, (HL) [0xCB 0xBE: 7]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCB, 0xBE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+                    cpu.bus.memory[0xCAFE] = 0b1111_1111;
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        mem[0xCAFE] => [0b0111_1111],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "JP nn [0xC3]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xC3, 0xEF, 0xBE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0xBEEF,
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "JP cc, nn [0xC2: NZ]" {
+
+                it "with jump condition NZ, jump performed: absolute jump" {
+                    let instruction_bytes = [0xC2, 0xEF, 0xBE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0xBEEF,
+                        cycles: 16
+                    );
+                }
+                it "with jump condition NZ, jump not performed: absolute jump" {
+                    let instruction_bytes = [0xC2, 0xEF, 0xBE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x24,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "JP cc, nn [0xCA: Z]" {
+
+                it "with jump condition Z, jump not performed: absolute jump" {
+                    let instruction_bytes = [0xCA, 0xEF, 0xBE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x24,
+                        cycles: 12
+                    );
+                }
+                it "with jump condition Z, jump performed: absolute jump" {
+                    let instruction_bytes = [0xCA, 0xEF, 0xBE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0xBEEF,
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "JP cc, nn [0xD2: NC]" {
+
+                it "with jump condition NC, jump performed: absolute jump" {
+                    let instruction_bytes = [0xD2, 0xEF, 0xBE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0xBEEF,
+                        cycles: 16
+                    );
+                }
+                it "with jump condition NC, jump not performed: absolute jump" {
+                    let instruction_bytes = [0xD2, 0xEF, 0xBE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x24,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "JP cc, nn [0xDA: C]" {
+
+                it "with jump condition C, jump not performed: absolute jump" {
+                    let instruction_bytes = [0xDA, 0xEF, 0xBE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x24,
+                        cycles: 12
+                    );
+                }
+                it "with jump condition C, jump performed: absolute jump" {
+                    let instruction_bytes = [0xDA, 0xEF, 0xBE];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0xBEEF,
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "JP (HL) [0xE9]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xE9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::HL, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0xCAFE,
+                        cycles: 4
+                    );
+                }
+            }
+
+            context "JR n [0x18]" {
+                it "without conditional flag modifications: positive" {
+                    let instruction_bytes = [0x18, 0x10];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x0033,
+                        cycles: 12
+                    );
+                }
+                it "without conditional flag modifications: negative" {
+                    let instruction_bytes = [0x18, 0xF0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x0013,
+                        cycles: 12
+                    );
+                }
+                it "without conditional flag modifications: overflow (positive)" {
+                    let instruction_bytes = [0x18, 0x20];
+
+                    cpu.set_reg16(Reg16::PC, 0xFFEF);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x0011,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "JR cc, n [0x20: NZ]" {
+
+                it "with jump condition NZ, jump performed: positive jump" {
+                    let instruction_bytes = [0x20, 0x10];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x0033,
+                        cycles: 12
+                    );
+                }
+                it "with jump condition NZ, jump performed: negative jump" {
+                    let instruction_bytes = [0x20, 0xF0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x0013,
+                        cycles: 12
+                    );
+                }
+                it "with jump condition NZ, jump performed: positive jump, with overflow" {
+                    let instruction_bytes = [0x20, 0x1F];
+
+                    cpu.set_flag(Flag::z, false);
+                    cpu.set_reg16(Reg16::PC, 0xFFF0);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x0011,
+                        cycles: 12
+                    );
+                }
+                it "with jump condition NZ, jump not performed: positive jump" {
+                    let instruction_bytes = [0x20, 0x10];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+                it "with jump condition NZ, jump not performed: negative jump" {
+                    let instruction_bytes = [0x20, 0xF0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+                it "with jump condition NZ, jump not performed: positive jump, with overflow" {
+                    let instruction_bytes = [0x20, 0x1F];
+
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_reg16(Reg16::PC, 0xFFF0);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0xFFF2,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "JR cc, n [0x28: Z]" {
+
+                it "with jump condition Z, jump not performed: positive jump" {
+                    let instruction_bytes = [0x28, 0x10];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+                it "with jump condition Z, jump not performed: negative jump" {
+                    let instruction_bytes = [0x28, 0xF0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+                it "with jump condition Z, jump not performed: positive jump, with overflow" {
+                    let instruction_bytes = [0x28, 0x1F];
+
+                    cpu.set_flag(Flag::z, false);
+                    cpu.set_reg16(Reg16::PC, 0xFFF0);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0xFFF2,
+                        cycles: 8
+                    );
+                }
+                it "with jump condition Z, jump performed: positive jump" {
+                    let instruction_bytes = [0x28, 0x10];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x0033,
+                        cycles: 12
+                    );
+                }
+                it "with jump condition Z, jump performed: negative jump" {
+                    let instruction_bytes = [0x28, 0xF0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x0013,
+                        cycles: 12
+                    );
+                }
+                it "with jump condition Z, jump performed: positive jump, with overflow" {
+                    let instruction_bytes = [0x28, 0x1F];
+
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_reg16(Reg16::PC, 0xFFF0);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x0011,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "JR cc, n [0x30: NC]" {
+
+                it "with jump condition NC, jump performed: positive jump" {
+                    let instruction_bytes = [0x30, 0x10];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x0033,
+                        cycles: 12
+                    );
+                }
+                it "with jump condition NC, jump performed: negative jump" {
+                    let instruction_bytes = [0x30, 0xF0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x0013,
+                        cycles: 12
+                    );
+                }
+                it "with jump condition NC, jump performed: positive jump, with overflow" {
+                    let instruction_bytes = [0x30, 0x1F];
+
+                    cpu.set_flag(Flag::c, false);
+                    cpu.set_reg16(Reg16::PC, 0xFFF0);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x0011,
+                        cycles: 12
+                    );
+                }
+                it "with jump condition NC, jump not performed: positive jump" {
+                    let instruction_bytes = [0x30, 0x10];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+                it "with jump condition NC, jump not performed: negative jump" {
+                    let instruction_bytes = [0x30, 0xF0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+                it "with jump condition NC, jump not performed: positive jump, with overflow" {
+                    let instruction_bytes = [0x30, 0x1F];
+
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_reg16(Reg16::PC, 0xFFF0);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0xFFF2,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "JR cc, n [0x38: C]" {
+
+                it "with jump condition C, jump not performed: positive jump" {
+                    let instruction_bytes = [0x38, 0x10];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+                it "with jump condition C, jump not performed: negative jump" {
+                    let instruction_bytes = [0x38, 0xF0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, false);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x23,
+                        cycles: 8
+                    );
+                }
+                it "with jump condition C, jump not performed: positive jump, with overflow" {
+                    let instruction_bytes = [0x38, 0x1F];
+
+                    cpu.set_flag(Flag::c, false);
+                    cpu.set_reg16(Reg16::PC, 0xFFF0);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0xFFF2,
+                        cycles: 8
+                    );
+                }
+                it "with jump condition C, jump performed: positive jump" {
+                    let instruction_bytes = [0x38, 0x10];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x0033,
+                        cycles: 12
+                    );
+                }
+                it "with jump condition C, jump performed: negative jump" {
+                    let instruction_bytes = [0x38, 0xF0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, true);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x0013,
+                        cycles: 12
+                    );
+                }
+                it "with jump condition C, jump performed: positive jump, with overflow" {
+                    let instruction_bytes = [0x38, 0x1F];
+
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_reg16(Reg16::PC, 0xFFF0);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x0011,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "CALL nn [0xCD]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCD, 0x21, 0x30];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x3021,
+                        mem[0xCAFC] => [0x24, 0x00],
+                        cycles: 24
+                    );
+                }
+            }
+
+            context "CALL cc, nn [0xC4: NZ]" {
+
+                it "with jump condition NZ, jump performed: no wraparounds" {
+                    let instruction_bytes = [0xC4, 0x21, 0x30];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, false);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x3021,
+                        mem[0xCAFC] => [0x24, 0x00],
+                        cycles: 24
+                    );
+                }
+                it "with jump condition NZ, jump not performed: no wraparounds" {
+                    let instruction_bytes = [0xC4, 0x21, 0x30];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x24,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "CALL cc, nn [0xCC: Z]" {
+
+                it "with jump condition Z, jump not performed: no wraparounds" {
+                    let instruction_bytes = [0xCC, 0x21, 0x30];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, false);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x24,
+                        cycles: 12
+                    );
+                }
+                it "with jump condition Z, jump performed: no wraparounds" {
+                    let instruction_bytes = [0xCC, 0x21, 0x30];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x3021,
+                        mem[0xCAFC] => [0x24, 0x00],
+                        cycles: 24
+                    );
+                }
+            }
+
+            context "CALL cc, nn [0xD4: NC]" {
+
+                it "with jump condition NC, jump performed: no wraparounds" {
+                    let instruction_bytes = [0xD4, 0x21, 0x30];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, false);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x3021,
+                        mem[0xCAFC] => [0x24, 0x00],
+                        cycles: 24
+                    );
+                }
+                it "with jump condition NC, jump not performed: no wraparounds" {
+                    let instruction_bytes = [0xD4, 0x21, 0x30];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x24,
+                        cycles: 12
+                    );
+                }
+            }
+
+            context "CALL cc, nn [0xDC: C]" {
+
+                it "with jump condition C, jump not performed: no wraparounds" {
+                    let instruction_bytes = [0xDC, 0x21, 0x30];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, false);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x24,
+                        cycles: 12
+                    );
+                }
+                it "with jump condition C, jump performed: no wraparounds" {
+                    let instruction_bytes = [0xDC, 0x21, 0x30];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x3021,
+                        mem[0xCAFC] => [0x24, 0x00],
+                        cycles: 24
+                    );
+                }
+            }
+
+            context "RST v [0xC7: 00H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xC7];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x00,
+                        mem[0xCAFC] => [0x22, 0x00],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RST v [0xCF: 08H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xCF];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x08,
+                        mem[0xCAFC] => [0x22, 0x00],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RST v [0xD7: 10H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xD7];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x10,
+                        mem[0xCAFC] => [0x22, 0x00],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RST v [0xDF: 18H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xDF];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x18,
+                        mem[0xCAFC] => [0x22, 0x00],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RST v [0xE7: 20H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xE7];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x20,
+                        mem[0xCAFC] => [0x22, 0x00],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RST v [0xEF: 28H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xEF];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x28,
+                        mem[0xCAFC] => [0x22, 0x00],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RST v [0xF7: 30H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xF7];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x30,
+                        mem[0xCAFC] => [0x22, 0x00],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RST v [0xFF: 38H]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xFF];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCAFC,
+                        PC => 0x38,
+                        mem[0xCAFC] => [0x22, 0x00],
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RET [0xC9]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xC9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+                    cpu.bus.memory[0xCAFE..=0xCAFF].copy_from_slice(&[0x30, 0x21]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCB00,
+                        PC => 0x2130,
+                        cycles: 16
+                    );
+                }
+            }
+
+            context "RET cc [0xC0: NZ]" {
+
+                it "with jump condition NZ, jump performed: no wraparounds" {
+                    let instruction_bytes = [0xC0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, false);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+                    cpu.bus.memory[0xCAFE..=0xCAFF].copy_from_slice(&[0x30, 0x21]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCB00,
+                        PC => 0x2130,
+                        cycles: 20
+                    );
+                }
+                it "with jump condition NZ, jump not performed: no wraparounds" {
+                    let instruction_bytes = [0xC0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+                    cpu.bus.memory[0xCAFE..=0xCAFF].copy_from_slice(&[0x30, 0x21]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RET cc [0xC8: Z]" {
+
+                it "with jump condition Z, jump not performed: no wraparounds" {
+                    let instruction_bytes = [0xC8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, false);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+                    cpu.bus.memory[0xCAFE..=0xCAFF].copy_from_slice(&[0x30, 0x21]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+                it "with jump condition Z, jump performed: no wraparounds" {
+                    let instruction_bytes = [0xC8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::z, true);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+                    cpu.bus.memory[0xCAFE..=0xCAFF].copy_from_slice(&[0x30, 0x21]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCB00,
+                        PC => 0x2130,
+                        cycles: 20
+                    );
+                }
+            }
+
+            context "RET cc [0xD0: NC]" {
+
+                it "with jump condition NC, jump performed: no wraparounds" {
+                    let instruction_bytes = [0xD0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, false);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+                    cpu.bus.memory[0xCAFE..=0xCAFF].copy_from_slice(&[0x30, 0x21]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCB00,
+                        PC => 0x2130,
+                        cycles: 20
+                    );
+                }
+                it "with jump condition NC, jump not performed: no wraparounds" {
+                    let instruction_bytes = [0xD0];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+                    cpu.bus.memory[0xCAFE..=0xCAFF].copy_from_slice(&[0x30, 0x21]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+            }
+
+            context "RET cc [0xD8: C]" {
+
+                it "with jump condition C, jump not performed: no wraparounds" {
+                    let instruction_bytes = [0xD8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, false);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+                    cpu.bus.memory[0xCAFE..=0xCAFF].copy_from_slice(&[0x30, 0x21]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        PC => 0x22,
+                        cycles: 8
+                    );
+                }
+                it "with jump condition C, jump performed: no wraparounds" {
+                    let instruction_bytes = [0xD8];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_flag(Flag::c, true);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+                    cpu.bus.memory[0xCAFE..=0xCAFF].copy_from_slice(&[0x30, 0x21]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCB00,
+                        PC => 0x2130,
+                        cycles: 20
+                    );
+                }
+            }
+
+            context "RETI [0xD9]" {
+                it "without conditional flag modifications" {
+                    let instruction_bytes = [0xD9];
+
+                    cpu.set_reg16(Reg16::PC, 0x21);
+                    cpu.set_reg16(Reg16::SP, 0xCAFE);
+                    cpu.bus.memory[0xCAFE..=0xCAFF].copy_from_slice(&[0x30, 0x21]);
+
+                    assert_cpu_execute!(
+                        cpu,
+                        instruction_bytes,
+                        SP => 0xCB00,
+                        PC => 0x2130,
+                        cycles: 16
+                    );
+                }
+            }
+            // __TESTS_REPLACEMENT_END__
+          }
+    }
+
+    describe "CPU program counter" {
+        use super::*;
+
+        before {
+            let mut cpu = Cpu::new(FlatRam::with_power_on(PowerOn::Zeroed));
+        }
+
+        it "wraps around at the end of the address space" {
+            cpu.bus.memory[0xFFFE..=0xFFFF].copy_from_slice(&[0x3E, 0x42]); // LD A, 0x42
+            cpu.set_reg16(Reg16::PC, 0xFFFE);
+
+            cpu.step();
+
+            assert_eq!(cpu[Reg8::A], 0x42);
+            assert_eq!(cpu.reg16(Reg16::PC), 0x0000);
+        }
+
+        it "fetches the operands across the end of the address space" {
+            cpu.bus.memory[0xFFFF] = 0x01; // LD BC, 0xCAFE
+            cpu.bus.memory[0x0000..=0x0001].copy_from_slice(&[0xFE, 0xCA]);
+            cpu.set_reg16(Reg16::PC, 0xFFFF);
+
+            cpu.step();
+
+            assert_eq!(cpu.reg16(Reg16::BC), 0xCAFE);
+            assert_eq!(cpu.reg16(Reg16::PC), 0x0002);
+        }
+    }
+
+    describe "CPU bus hooks" {
+        use super::*;
+
+        it "skips STOP, if handled by the bus" {
+            let mut cpu = Cpu::new(StopHandlingBus(FlatRam::with_power_on(PowerOn::Zeroed)));
+            cpu.bus.0.memory[0x0000..0x0003].copy_from_slice(&[0x10, 0x00, 0x00]); // STOP; NOP
+
+            cpu.step();
+            cpu.step();
+
+            assert_eq!(cpu.reg16(Reg16::PC), 0x0003);
+        }
+    }
+
+    describe "CPU illegal opcodes" {
+        use super::*;
+
+        it "lock up the CPU" {
+            for opcode in ILLEGAL_OPCODES {
+                let mut cpu = Cpu::new(FlatRam::with_power_on(PowerOn::Zeroed));
+                cpu.bus.memory[0x0000] = opcode;
+
+                assert_eq!(cpu.step(), 4);
+
+                assert!(cpu.locked(), "Opcode ${:02X} didn't lock the CPU", opcode);
+                assert_eq!(cpu.reg16(Reg16::PC), 0x0000);
+            }
+        }
+
+        it "leave the CPU idling, without servicing the interrupts" {
+            let mut cpu = Cpu::new(FlatRam::with_power_on(PowerOn::Zeroed));
+
+            cpu.bus.memory[0x0000] = 0xD3;
+            cpu.bus.memory[INTERRUPT_ENABLE_ADDRESS as usize] = JOYPAD_INTERRUPT;
+            cpu.step();
+
+            cpu.IME = true;
+            cpu.bus.memory[INTERRUPT_FLAG_ADDRESS as usize] = JOYPAD_INTERRUPT;
+
+            for _ in 0..4 {
+                assert_eq!(cpu.step(), 4);
+            }
+
+            assert!(cpu.locked());
+            assert_eq!(cpu.reg16(Reg16::PC), 0x0000);
+            assert_eq!(cpu.reg16(Reg16::SP), 0x0000);
+            assert_eq!(cpu.bus.memory[INTERRUPT_FLAG_ADDRESS as usize], JOYPAD_INTERRUPT);
+        }
+
+        it "do not lock up the CPU when used as operands" {
+            let mut cpu = Cpu::new(FlatRam::with_power_on(PowerOn::Zeroed));
+
+            cpu.bus.memory[0x0000..=0x0001].copy_from_slice(&[0x3E, 0xD3]); // LD A, 0xD3
+
+            cpu.step();
+
+            assert!(!cpu.locked());
+            assert_eq!(cpu[Reg8::A], 0xD3);
+        }
+    }
+
+    describe "CPU power on" {
+        use super::*;
+
+        it "zeroes registers and memory" {
+            let cpu = Cpu::with_power_on(FlatRam::with_power_on(PowerOn::Zeroed), PowerOn::Zeroed);
+
+            assert_cpu_state(&cpu, [0; 8], 0, 0);
+            assert!(cpu.bus.memory.iter().all(|&byte| byte == 0));
+        }
+
+        it "randomizes registers and memory reproducibly" {
+            let power_on = PowerOn::SeededRandom(TEST_SEED);
+
+            let cpu1 = Cpu::with_power_on(FlatRam::with_power_on(power_on), power_on);
+            let cpu2 = Cpu::with_power_on(FlatRam::with_power_on(power_on), power_on);
+
+            for register in Reg8::iter() {
+                assert_eq!(cpu1[register], cpu2[register], "{:?}", register);
+            }
+
+            assert_eq!(cpu1.reg16(Reg16::SP), cpu2.reg16(Reg16::SP));
+            assert_eq!(cpu1.reg16(Reg16::PC), 0);
+            assert_eq!(cpu1[Reg8::F] & 0x0F, 0);
+            assert!(cpu1.bus.memory[..] == cpu2.bus.memory[..]);
+
+            let other_seed_ram = FlatRam::with_power_on(PowerOn::SeededRandom(TEST_SEED + 1));
+
+            assert!(cpu1.bus.memory[..] != other_seed_ram.memory[..]);
+        }
+
+        it "sets the post boot ROM registers" {
+            let expectations = [
+                (Model::Dmg, [0x01, 0xB0, 0x00, 0x13, 0x00, 0xD8, 0x01, 0x4D]),
+                (Model::Mgb, [0xFF, 0xB0, 0x00, 0x13, 0x00, 0xD8, 0x01, 0x4D]),
+                (Model::Cgb, [0x11, 0x80, 0x00, 0x00, 0xFF, 0x56, 0x00, 0x0D]),
+            ];
+
+            for &(model, registers) in expectations.iter() {
+                let power_on = PowerOn::PostBootRom(model);
+                let cpu = Cpu::with_power_on(FlatRam::with_power_on(power_on), power_on);
+
+                assert_cpu_state(&cpu, registers, 0xFFFE, 0x0100);
+                assert!(cpu.bus.memory.iter().all(|&byte| byte == 0));
+            }
+        }
+    }
+
+    describe "instructions metadata" {
+        use super::*;
+
+        it "has the cycles table" {
+            assert_eq!(
+                UNPREFIXED_CYCLES[0x06],
+                Some(InstructionCycles { cycles: 8, cycles_not_taken: None })
+            );
+            assert_eq!(
+                UNPREFIXED_CYCLES[0x20],
+                Some(InstructionCycles { cycles: 12, cycles_not_taken: Some(8) })
+            );
+            assert_eq!(
+                PREFIXED_CYCLES[0x46],
+                Some(InstructionCycles { cycles: 12, cycles_not_taken: None })
+            );
+            assert_eq!(UNPREFIXED_CYCLES[0xD3], None);
+        }
+
+        it "has the flag effects table" {
+            assert_eq!(
+                UNPREFIXED_FLAG_EFFECTS[0x80],
+                Some([
+                    FlagEffect::Computed,
+                    FlagEffect::Reset,
+                    FlagEffect::Computed,
+                    FlagEffect::Computed,
+                ])
+            );
+            assert_eq!(
+                UNPREFIXED_FLAG_EFFECTS[0x37],
+                Some([
+                    FlagEffect::Unaffected,
+                    FlagEffect::Reset,
+                    FlagEffect::Reset,
+                    FlagEffect::Set,
+                ])
+            );
+            assert_eq!(
+                PREFIXED_FLAG_EFFECTS[0x7E],
+                Some([
+                    FlagEffect::Computed,
+                    FlagEffect::Reset,
+                    FlagEffect::Set,
+                    FlagEffect::Unaffected,
+                ])
+            );
+            assert_eq!(UNPREFIXED_FLAG_EFFECTS[0xD3], None);
+        }
+
+        it "matches the cycles spent by the CPU" {
+            let mut cpu = Cpu::new(FlatRam::with_power_on(PowerOn::Zeroed));
+
+            for &opcode in [0x00, 0x06, 0x36, 0xC5].iter() {
+                cpu.set_reg16(Reg16::PC, 0x0100);
+                cpu.set_reg16(Reg16::SP, 0xCAFE);
+
+                let cycles_spent = cpu.execute(&[opcode, 0x21, 0x00]);
+
+                assert_eq!(UNPREFIXED_CYCLES[opcode as usize].unwrap().cycles, cycles_spent);
+            }
+        }
     }
 }
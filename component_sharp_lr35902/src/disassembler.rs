@@ -0,0 +1,88 @@
+use std::fmt;
+
+const MAX_INSTRUCTION_SIZE: usize = 3;
+
+const WORD_PLACEHOLDER: &str = "nn";
+const BYTE_PLACEHOLDER: &str = "n";
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Immediate {
+    Byte(u8),
+    SignedByte(i8), // Stack pointer offset
+    Word(u16),
+}
+
+/// Disassembled instruction.
+///
+/// The mnemonic has the register/condition/bit/vector operands resolved, while the immediate operand,
+/// if any, is represented by the `n` (8 bit) or `nn` (16 bit) placeholder, e.g. `LD (HL), n`; the
+/// formatted instruction (via `Display`) replaces it with its value.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Instruction {
+    pub address: u16,
+    pub mnemonic: &'static str,
+    pub immediate: Option<Immediate>,
+    pub size: u8,
+    // Jump/call target; relative jumps are resolved to the absolute address.
+    pub target: Option<u16>,
+}
+
+impl Instruction {
+    /// Formats the instruction, representing the target, if any, with the given label.
+    ///
+    pub fn format_with_label(&self, target_label: Option<&str>) -> String {
+        let formatted_operand = match (self.target, target_label, self.immediate) {
+            (Some(_), Some(label), _) => label.to_string(),
+            (Some(target), None, _) => format!("${:04X}", target),
+            (None, _, Some(Immediate::Byte(value))) => format!("${:02X}", value),
+            (None, _, Some(Immediate::SignedByte(value))) => {
+                let sign = if value < 0 { "-" } else { "" };
+                format!("{}${:02X}", sign, value.unsigned_abs())
+            }
+            (None, _, Some(Immediate::Word(value))) => format!("${:04X}", value),
+            (None, _, None) => return self.mnemonic.to_string(),
+        };
+
+        // RST has a target, but no placeholder.
+        //
+        if self.mnemonic.contains(WORD_PLACEHOLDER) {
+            self.mnemonic
+                .replacen(WORD_PLACEHOLDER, &formatted_operand, 1)
+        } else if self.mnemonic.contains(BYTE_PLACEHOLDER) {
+            self.mnemonic
+                .replacen(BYTE_PLACEHOLDER, &formatted_operand, 1)
+        } else {
+            self.mnemonic.to_string()
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}", self.format_with_label(None))
+    }
+}
+
+/// Disassembles the instruction at the beginning of `bytes`, located at `address`.
+///
+/// Returns None if the bytes don't start with a valid instruction, or if they're not enough to hold
+/// it.
+///
+pub fn disassemble(bytes: &[u8], address: u16) -> Option<Instruction> {
+    let mut instruction_bytes = [0; MAX_INSTRUCTION_SIZE];
+    let available_size = bytes.len().min(MAX_INSTRUCTION_SIZE);
+    instruction_bytes[..available_size].copy_from_slice(&bytes[..available_size]);
+
+    let instruction = decode(&instruction_bytes, address)?;
+
+    if instruction.size as usize <= bytes.len() {
+        Some(instruction)
+    } else {
+        None
+    }
+}
+
+// Decoder (`decode()`), generated by the build script.
+//
+include!(concat!(env!("OUT_DIR"), "/instructions_disassembly.rs"));
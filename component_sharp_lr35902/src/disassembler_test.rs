@@ -0,0 +1,111 @@
+use super::disassembler::{disassemble, Immediate, Instruction};
+use demonstrate::demonstrate;
+
+demonstrate! {
+    describe "disassembler" {
+        use super::*;
+
+        it "disassembles an instruction without operands" {
+            let instruction = disassemble(&[0x00, 0xFF, 0xFF], 0x0100).unwrap();
+
+            assert_eq!(
+                instruction,
+                Instruction {
+                    address: 0x0100,
+                    mnemonic: "NOP",
+                    immediate: None,
+                    size: 1,
+                    target: None,
+                }
+            );
+            assert_eq!(instruction.to_string(), "NOP");
+        }
+
+        it "resolves the register operands" {
+            let instruction = disassemble(&[0x70], 0x0000).unwrap();
+
+            assert_eq!(instruction.to_string(), "LD (HL), B");
+        }
+
+        it "disassembles the prefixed instructions" {
+            let instruction = disassemble(&[0xCB, 0x7E], 0x0000).unwrap();
+
+            assert_eq!(instruction.size, 2);
+            assert_eq!(instruction.to_string(), "BIT 7, (HL)");
+        }
+
+        it "formats the immediates" {
+            let instruction = disassemble(&[0xE0, 0x44], 0x0000).unwrap();
+
+            assert_eq!(instruction.immediate, Some(Immediate::Byte(0x44)));
+            assert_eq!(instruction.to_string(), "LDH ($44), A");
+
+            let instruction = disassemble(&[0xFA, 0xFE, 0xCA], 0x0000).unwrap();
+
+            assert_eq!(instruction.immediate, Some(Immediate::Word(0xCAFE)));
+            assert_eq!(instruction.to_string(), "LD A, ($CAFE)");
+        }
+
+        it "formats the stack pointer offsets as signed" {
+            let instruction = disassemble(&[0xE8, 0xFE], 0x0000).unwrap();
+
+            assert_eq!(instruction.immediate, Some(Immediate::SignedByte(-2)));
+            assert_eq!(instruction.to_string(), "ADD SP, -$02");
+
+            let instruction = disassemble(&[0xF8, 0x05], 0x0000).unwrap();
+
+            assert_eq!(instruction.to_string(), "LDHL SP, $05");
+
+            let instruction = disassemble(&[0xF8, 0x80], 0x0000).unwrap();
+
+            assert_eq!(instruction.to_string(), "LDHL SP, -$80");
+        }
+
+        context "jump targets" {
+            it "resolves the absolute targets" {
+                let instruction = disassemble(&[0xC4, 0x50, 0x01], 0x0200).unwrap();
+
+                assert_eq!(instruction.target, Some(0x0150));
+                assert_eq!(instruction.to_string(), "CALL NZ, $0150");
+            }
+
+            it "resolves the relative targets" {
+                let instruction = disassemble(&[0x18, 0xFC], 0x0200).unwrap();
+
+                assert_eq!(instruction.target, Some(0x01FE));
+                assert_eq!(instruction.to_string(), "JR $01FE");
+            }
+
+            it "resolves the vectors" {
+                let instruction = disassemble(&[0xFF], 0x0200).unwrap();
+
+                assert_eq!(instruction.target, Some(0x38));
+                assert_eq!(instruction.to_string(), "RST 38H");
+            }
+
+            it "formats the target with a label" {
+                let instruction = disassemble(&[0xC3, 0x50, 0x01], 0x0100).unwrap();
+
+                assert_eq!(instruction.format_with_label(Some("main")), "JP main");
+            }
+        }
+
+        it "includes the byte following STOP" {
+            let instruction = disassemble(&[0x10, 0x00], 0x0000).unwrap();
+
+            assert_eq!(instruction.size, 2);
+            assert_eq!(instruction.to_string(), "STOP");
+
+            assert_eq!(disassemble(&[0x10], 0x0000), None);
+        }
+
+        it "doesnt disassemble invalid opcodes" {
+            assert_eq!(disassemble(&[0xD3], 0x0000), None);
+        }
+
+        it "doesnt disassemble truncated instructions" {
+            assert_eq!(disassemble(&[0xC3, 0x50], 0x0000), None);
+            assert_eq!(disassemble(&[], 0x0000), None);
+        }
+    }
+}
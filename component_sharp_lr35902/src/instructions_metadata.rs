@@ -0,0 +1,26 @@
+/// Effect of an instruction on a flag.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FlagEffect {
+    Unaffected,
+    Reset,
+    Set,
+    Computed,
+}
+
+/// Clock ticks spent by an instruction; conditional instructions spend less when the condition
+/// doesn't match.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct InstructionCycles {
+    pub cycles: u8,
+    pub cycles_not_taken: Option<u8>,
+}
+
+// Tables, indexed by opcode (the prefixed ones, by the byte following the prefix), generated by the
+// build script; unsupported opcodes are None. The flag effects are in `Flag` order:
+//
+// - `UNPREFIXED_CYCLES`, `PREFIXED_CYCLES`: `[Option<InstructionCycles>; 256]`
+// - `UNPREFIXED_FLAG_EFFECTS`, `PREFIXED_FLAG_EFFECTS`: `[Option<[FlagEffect; 4]>; 256]`
+//
+include!(concat!(env!("OUT_DIR"), "/instructions_tables.rs"));
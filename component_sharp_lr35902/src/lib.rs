@@ -1,11 +1,17 @@
 #![allow(non_snake_case)]
 #![allow(clippy::new_without_default)]
 
-mod utils;
-
+pub mod bus;
 pub mod cpu;
+pub mod disassembler;
+pub mod instructions_metadata;
+pub mod power_on;
 
 pub use cpu::Cpu as SharpLr35902;
 
 #[cfg(test)]
 mod cpu_test;
+#[cfg(test)]
+mod disassembler_test;
+#[cfg(all(test, feature = "mcycle-timing"))]
+mod mcycle_timing_test;
@@ -0,0 +1,179 @@
+use super::bus::{Bus, FlatRam};
+use super::cpu::{Cpu, Reg16, Reg8, INTERRUPT_ENABLE_ADDRESS, INTERRUPT_FLAG_ADDRESS};
+use super::power_on::PowerOn;
+use demonstrate::demonstrate;
+
+#[derive(Debug, PartialEq)]
+enum Access {
+    Tick(u8),
+    Read(u16),
+    Write(u16, u8),
+}
+
+use Access::{Read, Tick, Write};
+
+// Records the bus accesses, in order; the ticks of 0 cycles (e.g. when there are no internal cycles
+// at the end of a step) are omitted.
+//
+struct RecordingBus {
+    ram: FlatRam,
+    accesses: Vec<Access>,
+}
+
+impl Bus for RecordingBus {
+    fn read8(&mut self, address: u16) -> u8 {
+        self.accesses.push(Access::Read(address));
+        self.ram.read8(address)
+    }
+
+    fn write8(&mut self, address: u16, value: u8) {
+        self.accesses.push(Access::Write(address, value));
+        self.ram.write8(address, value)
+    }
+
+    fn tick(&mut self, cycles: u8) {
+        if cycles > 0 {
+            self.accesses.push(Access::Tick(cycles));
+        }
+    }
+}
+
+impl RecordingBus {
+    // The accesses preceding the first M-cycle (the pending interrupts check, and the IF
+    // acknowledgement) are not timed, so they're not part of the sequence.
+    //
+    fn timed_accesses(&self) -> &[Access] {
+        let first_tick = self
+            .accesses
+            .iter()
+            .position(|access| matches!(access, Access::Tick(_)))
+            .unwrap_or(self.accesses.len());
+
+        &self.accesses[first_tick..]
+    }
+}
+
+demonstrate! {
+    describe "M-cycle timing" {
+        use super::*;
+
+        before {
+            let mut cpu = Cpu::new(RecordingBus {
+                ram: FlatRam::with_power_on(PowerOn::Zeroed),
+                accesses: vec![],
+            });
+
+            cpu.set_reg16(Reg16::PC, 0x0100);
+            cpu.set_reg16(Reg16::SP, 0xD000);
+        }
+
+        it "writes LD (HL), n on the third M-cycle" {
+            cpu.bus.ram.memory[0x0100..0x0102].copy_from_slice(&[0x36, 0x42]); // LD (HL), 0x42
+            cpu.set_reg16(Reg16::HL, 0xC000);
+
+            assert_eq!(cpu.step(), 12);
+
+            assert_eq!(
+                cpu.bus.timed_accesses(),
+                [
+                    Tick(4),
+                    Read(0x0100),
+                    Tick(4),
+                    Read(0x0101),
+                    Tick(4),
+                    Write(0xC000, 0x42),
+                ]
+            );
+        }
+
+        it "pushes PUSH rr after an internal M-cycle, high byte first" {
+            cpu.bus.ram.memory[0x0100] = 0xC5; // PUSH BC
+            cpu.set_reg16(Reg16::BC, 0x1234);
+
+            assert_eq!(cpu.step(), 16);
+
+            assert_eq!(
+                cpu.bus.timed_accesses(),
+                [
+                    Tick(4),
+                    Read(0x0100),
+                    Tick(4),
+                    Tick(4),
+                    Write(0xCFFF, 0x12),
+                    Tick(4),
+                    Write(0xCFFE, 0x34),
+                ]
+            );
+        }
+
+        it "pushes the return address of CALL nn after the operands fetch and an internal M-cycle" {
+            cpu.bus.ram.memory[0x0100..0x0103].copy_from_slice(&[0xCD, 0x00, 0x20]); // CALL 0x2000
+
+            assert_eq!(cpu.step(), 24);
+
+            assert_eq!(
+                cpu.bus.timed_accesses(),
+                [
+                    Tick(4),
+                    Read(0x0100),
+                    Tick(4),
+                    Read(0x0101),
+                    Tick(4),
+                    Read(0x0102),
+                    Tick(4),
+                    Tick(4),
+                    Write(0xCFFF, 0x01),
+                    Tick(4),
+                    Write(0xCFFE, 0x03),
+                ]
+            );
+            assert_eq!(cpu.reg16(Reg16::PC), 0x2000);
+        }
+
+        it "fetches only the opcode of STOP" {
+            cpu.bus.ram.memory[0x0100..0x0102].copy_from_slice(&[0x10, 0x00]); // STOP
+
+            assert_eq!(cpu.step(), 4);
+
+            assert_eq!(cpu.bus.timed_accesses(), [Tick(4), Read(0x0100)]);
+            assert_eq!(cpu.reg16(Reg16::PC), 0x0102);
+        }
+
+        it "fetches the opcode twice with the HALT bug" {
+            cpu.bus.ram.memory[0x0100..0x0102].copy_from_slice(&[0x06, 0x42]); // LD B, 0x42
+            cpu.halt_bug = true;
+
+            assert_eq!(cpu.step(), 8);
+
+            assert_eq!(
+                cpu.bus.timed_accesses(),
+                [Tick(4), Read(0x0100), Tick(4), Read(0x0100)]
+            );
+            assert_eq!(cpu[Reg8::B], 0x06);
+            assert_eq!(cpu.reg16(Reg16::PC), 0x0101);
+        }
+
+        it "dispatches an interrupt with two internal M-cycles, the push, and the jump" {
+            cpu.bus.ram.memory[INTERRUPT_ENABLE_ADDRESS as usize] = 0b0000_0100;
+            cpu.bus.ram.memory[INTERRUPT_FLAG_ADDRESS as usize] = 0b0000_0100;
+            cpu.IME = true;
+
+            assert_eq!(cpu.step(), 20);
+
+            assert_eq!(
+                cpu.bus.timed_accesses(),
+                [
+                    Tick(4),
+                    Tick(4),
+                    Tick(4),
+                    Write(0xCFFF, 0x01),
+                    Tick(4),
+                    Write(0xCFFE, 0x00),
+                    Tick(4),
+                ]
+            );
+            assert_eq!(cpu.reg16(Reg16::PC), 0x0050);
+            assert_eq!(cpu.bus.ram.memory[INTERRUPT_FLAG_ADDRESS as usize], 0);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+/// Hardware models; they differ in the state left by the boot ROM.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Model {
+    Dmg,
+    Mgb,
+    Cgb,
+}
+
+/// Power-on policy, determining the initial state of the registers and of the memory.
+///
+/// - `Zeroed`: everything is zeroed;
+/// - `SeededRandom`: registers and memory are filled with random values, reproducible from the seed,
+///   like the real hardware does on power up; PC is zeroed, since execution starts from the boot ROM;
+/// - `PostBootRom`: the registers have the values left by the boot ROM of the given model, with
+///   execution starting from the cartridge entry point; the memory is zeroed.
+///
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PowerOn {
+    Zeroed,
+    SeededRandom(u64),
+    PostBootRom(Model),
+}
+
+const CARTRIDGE_ENTRY_POINT: u16 = 0x0100;
+const POST_BOOT_ROM_SP: u16 = 0xFFFE;
+
+impl PowerOn {
+    /// Returns the registers values, in the order A, F, B, C, D, E, H, L, SP, PC.
+    ///
+    pub(crate) fn registers(&self) -> ([u8; 8], u16, u16) {
+        match self {
+            PowerOn::Zeroed => ([0; 8], 0, 0),
+            PowerOn::SeededRandom(seed) => {
+                let mut rng = StdRng::seed_from_u64(*seed);
+
+                let mut registers = [0; 8];
+                rng.fill_bytes(&mut registers);
+
+                // The lower nibble of F is hardwired to 0.
+                //
+                registers[1] &= 0xF0;
+
+                (registers, rng.next_u32() as u16, 0)
+            }
+            PowerOn::PostBootRom(model) => {
+                let registers = match model {
+                    Model::Dmg => [0x01, 0xB0, 0x00, 0x13, 0x00, 0xD8, 0x01, 0x4D],
+                    Model::Mgb => [0xFF, 0xB0, 0x00, 0x13, 0x00, 0xD8, 0x01, 0x4D],
+                    Model::Cgb => [0x11, 0x80, 0x00, 0x00, 0xFF, 0x56, 0x00, 0x0D],
+                };
+
+                (registers, POST_BOOT_ROM_SP, CARTRIDGE_ENTRY_POINT)
+            }
+        }
+    }
+
+    pub(crate) fn fill_memory(&self, memory: &mut [u8]) {
+        match self {
+            PowerOn::Zeroed | PowerOn::PostBootRom(_) => {
+                memory.iter_mut().for_each(|byte| *byte = 0)
+            }
+            PowerOn::SeededRandom(seed) => StdRng::seed_from_u64(*seed).fill_bytes(memory),
+        }
+    }
+}
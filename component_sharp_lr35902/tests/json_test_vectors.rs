@@ -0,0 +1,350 @@
+// Harness for the community single-step JSON test vectors (one file per opcode, e.g. `cb 37.json`);
+// they're not bundled, so the test is ignored, and the directory is passed via
+// `LR35902_JSON_TESTS_DIR`:
+//
+//     LR35902_JSON_TESTS_DIR=/path/to/vectors cargo test --test json_test_vectors -- --ignored
+//
+// Each vector is run by setting up the initial state, executing one step, and comparing registers,
+// flags, memory, cycles and memory writes; with the `mcycle-timing` feature, the bus activity of
+// each M-cycle is compared instead of the writes. All the mismatches are reported at the end.
+
+use component_sharp_lr35902::bus::Bus;
+use component_sharp_lr35902::cpu::{Cpu, Flag, Reg16, Reg8, INTERRUPT_ENABLE_ADDRESS};
+use serde::Deserialize;
+use serde_json::Value;
+use strum::IntoEnumIterator;
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const TESTS_DIRECTORY_VARIABLE: &str = "LR35902_JSON_TESTS_DIR";
+const MAX_REPORTED_FAILURES: usize = 50;
+const CYCLES_PER_MCYCLE: usize = 4;
+
+// Bus activity of an M-cycle; the accesses happen at the end of the M-cycle.
+//
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BusCycle {
+    Idle,
+    Read(u16, u8),
+    Write(u16, u8),
+}
+
+#[derive(Deserialize)]
+struct TestVector {
+    name: String,
+    initial: CpuState,
+    #[serde(rename = "final")]
+    final_state: CpuState,
+    // One entry per M-cycle: `[address, value, pins]` (e.g. `r-m` for a memory read), or null for
+    // idle cycles, in some versions.
+    cycles: Vec<Value>,
+}
+
+#[derive(Deserialize)]
+struct CpuState {
+    a: u8,
+    f: u8,
+    b: u8,
+    c: u8,
+    d: u8,
+    e: u8,
+    h: u8,
+    l: u8,
+    sp: u16,
+    pc: u16,
+    ime: Option<u8>,
+    ie: Option<u8>,
+    ram: Vec<(u16, u8)>,
+}
+
+impl CpuState {
+    fn registers(&self) -> [(Reg8, u8); 8] {
+        [
+            (Reg8::A, self.a),
+            (Reg8::F, self.f),
+            (Reg8::B, self.b),
+            (Reg8::C, self.c),
+            (Reg8::D, self.d),
+            (Reg8::E, self.e),
+            (Reg8::H, self.h),
+            (Reg8::L, self.l),
+        ]
+    }
+}
+
+// Unlisted locations are zero; the writes, and the activity of each M-cycle (with the
+// `mcycle-timing` feature), are recorded, in order to compare them with the vector ones.
+//
+// The accesses preceding the first M-cycle (e.g. the pending interrupts check) are not timed, so
+// they're not recorded as bus cycles.
+//
+struct TestBus {
+    memory: Vec<u8>,
+    writes: Vec<(u16, u8)>,
+    bus_cycles: Vec<BusCycle>,
+}
+
+impl TestBus {
+    fn record_access(&mut self, access: BusCycle) {
+        if let Some(bus_cycle) = self.bus_cycles.last_mut() {
+            *bus_cycle = access;
+        }
+    }
+}
+
+impl Bus for TestBus {
+    fn read8(&mut self, address: u16) -> u8 {
+        let value = self.memory[address as usize];
+        self.record_access(BusCycle::Read(address, value));
+
+        value
+    }
+
+    fn write8(&mut self, address: u16, value: u8) {
+        self.memory[address as usize] = value;
+        self.writes.push((address, value));
+        self.record_access(BusCycle::Write(address, value));
+    }
+
+    fn tick(&mut self, cycles: u8) {
+        for _ in 0..cycles as usize / CYCLES_PER_MCYCLE {
+            self.bus_cycles.push(BusCycle::Idle);
+        }
+    }
+}
+
+fn prepare_cpu(state: &CpuState) -> Cpu<TestBus> {
+    let bus = TestBus {
+        memory: vec![0; 0x10_000],
+        writes: vec![],
+        bus_cycles: vec![],
+    };
+
+    let mut cpu = Cpu::new(bus);
+
+    for &(register, value) in state.registers().iter() {
+        cpu[register] = value;
+    }
+
+    cpu.set_reg16(Reg16::SP, state.sp);
+    cpu.set_reg16(Reg16::PC, state.pc);
+    cpu.IME = state.ime == Some(1);
+
+    if let Some(ie) = state.ie {
+        cpu.bus.memory[INTERRUPT_ENABLE_ADDRESS as usize] = ie;
+    }
+
+    for &(address, value) in &state.ram {
+        cpu.bus.memory[address as usize] = value;
+    }
+
+    cpu
+}
+
+// The pins are `r`/`w` (read/write), in the first/second position; any other entry is idle.
+//
+fn expected_bus_cycles(cycles: &[Value]) -> Vec<BusCycle> {
+    cycles
+        .iter()
+        .map(|cycle| match cycle.as_array().map(Vec::as_slice) {
+            Some([address, value, Value::String(pins)]) => {
+                let address = address.as_u64().unwrap() as u16;
+                let value = value.as_u64().unwrap() as u8;
+
+                if pins.starts_with('r') {
+                    BusCycle::Read(address, value)
+                } else if pins[1..].starts_with('w') {
+                    BusCycle::Write(address, value)
+                } else {
+                    BusCycle::Idle
+                }
+            }
+            _ => BusCycle::Idle,
+        })
+        .collect()
+}
+
+// Returns the mismatches, formatted like `assert_cpu_execute`.
+//
+fn run_vector(vector: &TestVector) -> Vec<String> {
+    let mut cpu = prepare_cpu(&vector.initial);
+    let expected = &vector.final_state;
+    let mut mismatches = vec![];
+
+    let cycles_spent = cpu.step() as usize;
+
+    for &(register, value) in expected.registers().iter() {
+        if cpu[register] != value {
+            mismatches.push(format!(
+                "Unexpected `{:?}`: actual=0x{:02X}, expected=0x{:02X}",
+                register, cpu[register], value
+            ));
+        }
+    }
+
+    for &(register, value) in [(Reg16::SP, expected.sp), (Reg16::PC, expected.pc)].iter() {
+        if cpu.reg16(register) != value {
+            mismatches.push(format!(
+                "Unexpected `{:?}`: actual=0x{:04X}, expected=0x{:04X}",
+                register,
+                cpu.reg16(register),
+                value
+            ));
+        }
+    }
+
+    // The flags are stored in the upper nibble of F, in `Flag` order.
+    //
+    for flag in Flag::iter() {
+        let expected_flag = expected.f & (0b1000_0000 >> flag as u8) != 0;
+
+        if cpu.get_flag(flag) != expected_flag {
+            mismatches.push(format!(
+                "Unexpected `{:?}f`: actual={}, expected={}",
+                flag,
+                cpu.get_flag(flag),
+                expected_flag
+            ));
+        }
+    }
+
+    if let Some(ime) = expected.ime {
+        if cpu.IME != (ime == 1) {
+            mismatches.push(format!(
+                "Unexpected `IME`: actual={}, expected={}",
+                cpu.IME,
+                ime == 1
+            ));
+        }
+    }
+
+    for &(address, value) in &expected.ram {
+        let actual_value = cpu.bus.memory[address as usize];
+
+        if actual_value != value {
+            mismatches.push(format!(
+                "Unexpected `mem[0x{:04X}]`: actual=0x{:02X}, expected=0x{:02X}",
+                address, actual_value, value
+            ));
+        }
+    }
+
+    let expected_cycles = vector.cycles.len() * CYCLES_PER_MCYCLE;
+
+    if cycles_spent != expected_cycles {
+        mismatches.push(format!(
+            "Unexpected cycles: actual={}, expected={}",
+            cycles_spent, expected_cycles
+        ));
+    }
+
+    let expected_bus_cycles = expected_bus_cycles(&vector.cycles);
+
+    if cfg!(feature = "mcycle-timing") {
+        if cpu.bus.bus_cycles != expected_bus_cycles {
+            mismatches.push(format!(
+                "Unexpected bus cycles: actual={:X?}, expected={:X?}",
+                cpu.bus.bus_cycles, expected_bus_cycles
+            ));
+        }
+    } else {
+        let expected_writes = expected_bus_cycles
+            .iter()
+            .filter_map(|bus_cycle| match *bus_cycle {
+                BusCycle::Write(address, value) => Some((address, value)),
+                _ => None,
+            })
+            .collect::<Vec<(u16, u8)>>();
+
+        if cpu.bus.writes != expected_writes {
+            mismatches.push(format!(
+                "Unexpected writes: actual={:X?}, expected={:X?}",
+                cpu.bus.writes, expected_writes
+            ));
+        }
+    }
+
+    mismatches
+}
+
+fn find_test_files(directory: &Path) -> Vec<PathBuf> {
+    let mut test_files = fs::read_dir(directory)
+        .unwrap_or_else(|error| panic!("Error reading {:?}: {}", directory, error))
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().and_then(|extension| extension.to_str()) == Some("json"))
+        .collect::<Vec<PathBuf>>();
+
+    test_files.sort();
+
+    test_files
+}
+
+// `LD (HL), A`, with a deliberately wrong final B.
+//
+const MISMATCHING_VECTOR: &str = r#"{
+    "name": "77 0000",
+    "initial": {
+        "a": 66, "f": 0, "b": 0, "c": 0, "d": 0, "e": 0, "h": 192, "l": 0,
+        "sp": 65534, "pc": 256, "ime": 0,
+        "ram": [[256, 119]]
+    },
+    "final": {
+        "a": 66, "f": 0, "b": 1, "c": 0, "d": 0, "e": 0, "h": 192, "l": 0,
+        "sp": 65534, "pc": 257, "ime": 0,
+        "ram": [[256, 119], [49152, 66]]
+    },
+    "cycles": [[256, 119, "r-m"], [49152, 66, "-wm"]]
+}"#;
+
+#[test]
+fn run_vector_reports_the_mismatches() {
+    let vector: TestVector = serde_json::from_str(MISMATCHING_VECTOR).unwrap();
+
+    assert_eq!(
+        run_vector(&vector),
+        ["Unexpected `B`: actual=0x00, expected=0x01"]
+    );
+}
+
+#[test]
+#[ignore]
+fn json_test_vectors() {
+    let directory = match std::env::var_os(TESTS_DIRECTORY_VARIABLE) {
+        Some(directory) => PathBuf::from(directory),
+        None => panic!("`{}` must be set", TESTS_DIRECTORY_VARIABLE),
+    };
+
+    let mut failures = vec![];
+    let mut vectors_count = 0;
+
+    for test_file in find_test_files(&directory) {
+        let file_content = fs::read_to_string(&test_file).unwrap();
+        let vectors: Vec<TestVector> = serde_json::from_str(&file_content)
+            .unwrap_or_else(|error| panic!("Error parsing {:?}: {}", test_file, error));
+
+        for vector in &vectors {
+            let mismatches = run_vector(vector);
+
+            if !mismatches.is_empty() {
+                failures.push(format!("{}:\n  {}", vector.name, mismatches.join("\n  ")));
+            }
+        }
+
+        vectors_count += vectors.len();
+    }
+
+    if !failures.is_empty() {
+        panic!(
+            "{}/{} vectors failed; first failures:\n{}",
+            failures.len(),
+            vectors_count,
+            failures
+                .iter()
+                .take(MAX_REPORTED_FAILURES)
+                .cloned()
+                .collect::<Vec<String>>()
+                .join("\n")
+        );
+    }
+}
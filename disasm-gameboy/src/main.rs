@@ -0,0 +1,165 @@
+use clap::{self, App, Arg};
+
+use component_sharp_lr35902::disassembler::{disassemble, Instruction};
+
+use std::collections::BTreeSet;
+use std::fs;
+
+const BANK_SIZE: usize = 0x4000;
+
+// Bank 0 is always mapped at 0x0000; the other banks are switched in at 0x4000.
+//
+const SWITCHABLE_BANK_ADDRESS: u16 = 0x4000;
+
+// Each entry is either a valid instruction, or a data byte that can't be disassembled.
+//
+type Line = (u16, Result<Instruction, u8>);
+
+fn bank_base_address(bank: usize) -> u16 {
+    if bank == 0 {
+        0
+    } else {
+        SWITCHABLE_BANK_ADDRESS
+    }
+}
+
+// Linear sweep; data areas (e.g. the cartridge header) are disassembled as well.
+//
+fn disassemble_bank(bank_data: &[u8], base_address: u16) -> Vec<Line> {
+    let mut lines = vec![];
+    let mut offset = 0;
+
+    while offset < bank_data.len() {
+        let address = base_address + offset as u16;
+
+        match disassemble(&bank_data[offset..], address) {
+            Some(instruction) => {
+                offset += instruction.size as usize;
+                lines.push((address, Ok(instruction)));
+            }
+            None => {
+                lines.push((address, Err(bank_data[offset])));
+                offset += 1;
+            }
+        }
+    }
+
+    lines
+}
+
+// Only the targets inside the bank address range are labeled, since the bank mapped in the other
+// range is not known statically.
+//
+fn collect_labels(lines: &[Line], base_address: u16) -> BTreeSet<u16> {
+    let address_range = base_address as u32..(base_address as u32 + BANK_SIZE as u32);
+
+    lines
+        .iter()
+        .filter_map(|(_, line)| {
+            line.as_ref()
+                .ok()
+                .and_then(|instruction| instruction.target)
+        })
+        .filter(|target| address_range.contains(&(*target as u32)))
+        .collect()
+}
+
+fn label_name(address: u16) -> String {
+    format!("L{:04X}", address)
+}
+
+fn print_bank(bank: usize, bank_data: &[u8]) {
+    let base_address = bank_base_address(bank);
+    let lines = disassemble_bank(bank_data, base_address);
+    let labels = collect_labels(&lines, base_address);
+
+    println!("; Bank {:02X}", bank);
+
+    for (address, line) in lines {
+        if labels.contains(&address) {
+            println!("{}:", label_name(address));
+        }
+
+        let bank_offset = (address - base_address) as usize;
+
+        let (size, formatted_line) = match line {
+            Ok(instruction) => {
+                let target_label = instruction
+                    .target
+                    .filter(|target| labels.contains(target))
+                    .map(label_name);
+
+                (
+                    instruction.size as usize,
+                    instruction.format_with_label(target_label.as_deref()),
+                )
+            }
+            Err(byte) => (1, format!("DB ${:02X}", byte)),
+        };
+
+        let formatted_bytes = bank_data[bank_offset..bank_offset + size]
+            .iter()
+            .map(|byte| format!("{:02X}", byte))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        println!(
+            "    {:04X}  {:<8}  {}",
+            address, formatted_bytes, formatted_line
+        );
+    }
+
+    println!();
+}
+
+fn decode_commandline_arguments() -> (String, Option<usize>) {
+    let commandline_args = std::env::args().collect::<Vec<String>>();
+
+    let matches = App::new("disasm-gameboy")
+        .arg(Arg::with_name("ROM").required(true).index(1))
+        .arg(
+            Arg::with_name("BANK")
+                .short("b")
+                .long("bank")
+                .takes_value(true)
+                .help("Dump only the given ROM bank (default: all)"),
+        )
+        .get_matches_from(commandline_args);
+
+    let rom_filename = matches.value_of("ROM").unwrap().to_string();
+
+    let bank = matches.value_of("BANK").map(|bank| {
+        bank.parse::<usize>()
+            .unwrap_or_else(|_| panic!("Invalid bank: {}", bank))
+    });
+
+    (rom_filename, bank)
+}
+
+fn main() {
+    let (rom_filename, bank) = decode_commandline_arguments();
+
+    let rom_data = fs::read(&rom_filename)
+        .unwrap_or_else(|error| panic!("Error reading the ROM {}: {}", rom_filename, error));
+
+    let banks_data = rom_data.chunks(BANK_SIZE).collect::<Vec<&[u8]>>();
+
+    match bank {
+        Some(bank) => {
+            let bank_data = banks_data.get(bank).unwrap_or_else(|| {
+                panic!(
+                    "Bank {} not found; the ROM has {} banks",
+                    bank,
+                    banks_data.len()
+                )
+            });
+
+            print_bank(bank, bank_data);
+        }
+        None => {
+            for (bank, bank_data) in banks_data.iter().enumerate() {
+                print_bank(bank, bank_data);
+            }
+        }
+    }
+}
@@ -3,13 +3,100 @@ extern crate maplit;
 
 use clap::{self, App, Arg};
 
-use system_chip_8::Chip8;
-use interfaces_frontend::{events::EventCode, logging::Logger, logging::StdoutLogger};
 use frontend_sdl::FrontendSdl;
+use interfaces_frontend::{
+    events::EventCode, logging::Logger, logging::StdoutLogger, video::Pixel,
+};
+use system_chip_8::{Chip8, Palette, PostProcessing, RplFlagsStorage, Variant};
+use system_cosmac_vip::CosmacVip;
 
 use std::fs;
+use std::io;
+use std::path::PathBuf;
 
-fn decode_commandline_arguments() -> (String, bool, bool) {
+// Relative to the home directory.
+//
+const RPL_FLAGS_DIRECTORY: &str = ".libemuls/chip_8-rpl_flags";
+
+/// Stores the RPL flags of a ROM in a file, named after the ROM hash.
+///
+struct RplFlagsFile {
+    path: PathBuf,
+}
+
+impl RplFlagsFile {
+    fn new(game_rom_data: &[u8]) -> RplFlagsFile {
+        let home_directory = std::env::var("HOME").unwrap_or_else(|_| String::from("."));
+
+        let path = PathBuf::from(home_directory)
+            .join(RPL_FLAGS_DIRECTORY)
+            .join(format!("{:016x}.rpl", fnv1a_hash(game_rom_data)));
+
+        RplFlagsFile { path }
+    }
+}
+
+impl RplFlagsStorage for RplFlagsFile {
+    fn load(&mut self) -> Option<Vec<u8>> {
+        fs::read(&self.path).ok()
+    }
+
+    // The path is added to the error, since the system logs it without context.
+    //
+    fn save(&mut self, flags: &[u8]) -> io::Result<()> {
+        fs::create_dir_all(self.path.parent().unwrap())
+            .and_then(|_| fs::write(&self.path, flags))
+            .map_err(|error| io::Error::new(error.kind(), format!("{:?}: {}", self.path, error)))
+    }
+}
+
+// FNV-1a (64 bits); the hash must be stable across builds, which is not guaranteed by the standard
+// library hashers.
+//
+fn fnv1a_hash(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+// Format: comma-separated `RRGGBB` colors; two (background, foreground) or four (XO-CHIP).
+//
+fn decode_palette(colors_value: &str) -> Palette {
+    let colors = colors_value
+        .split(',')
+        .map(|color| {
+            let rgb = u32::from_str_radix(color, 16)
+                .unwrap_or_else(|_| panic!("Invalid color: {}", color));
+
+            Pixel((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8)
+        })
+        .collect::<Vec<Pixel>>();
+
+    match colors.as_slice() {
+        [background, foreground] => Palette::new(background.clone(), foreground.clone()),
+        [color0, color1, color2, color3] => Palette::xo_chip([
+            color0.clone(),
+            color1.clone(),
+            color2.clone(),
+            color3.clone(),
+        ]),
+        _ => panic!("Two or four colors must be specified: {}", colors_value),
+    }
+}
+
+// Monitor ROM and interpreter filenames, when running in COSMAC VIP mode.
+//
+type VipFilenames = Option<(String, String)>;
+
+fn decode_commandline_arguments() -> (
+    String,
+    bool,
+    bool,
+    Variant,
+    Palette,
+    PostProcessing,
+    VipFilenames,
+) {
     let commandline_args = std::env::args().collect::<Vec<String>>();
 
     let matches = App::new("chip8")
@@ -26,21 +113,111 @@ fn decode_commandline_arguments() -> (String, bool, bool) {
                 .long("max-speed")
                 .help("Set the maximum emulation speed (1000x)"),
         )
+        .arg(
+            Arg::with_name("VARIANT")
+                .long("variant")
+                .takes_value(true)
+                .possible_values(&["chip8", "chip8x", "megachip"])
+                .default_value("chip8")
+                .help("CHIP-8 variant the program is written for"),
+        )
+        .arg(
+            Arg::with_name("COLORS")
+                .short("c")
+                .long("colors")
+                .takes_value(true)
+                .help("Palette, as comma-separated RRGGBB colors: background,foreground[,plane2,both_planes]"),
+        )
+        .arg(
+            Arg::with_name("PHOSPHOR_DECAY")
+                .long("phosphor-decay")
+                .takes_value(true)
+                .conflicts_with("FRAME_BLEND")
+                .help("Keep cleared pixels lit, fading by the given factor (0.0-1.0) per frame"),
+        )
+        .arg(
+            Arg::with_name("FRAME_BLEND")
+                .long("frame-blend")
+                .help("Blend each frame with the previous one"),
+        )
+        .arg(
+            Arg::with_name("VIP_MONITOR")
+                .long("vip-monitor")
+                .takes_value(true)
+                .requires("VIP_INTERPRETER")
+                .help("Emulate the COSMAC VIP hardware, using the given monitor ROM"),
+        )
+        .arg(
+            Arg::with_name("VIP_INTERPRETER")
+                .long("vip-interpreter")
+                .takes_value(true)
+                .requires("VIP_MONITOR")
+                .help("CHIP-8 interpreter image, for the COSMAC VIP hardware emulation"),
+        )
         .get_matches_from(commandline_args);
 
     let game_rom_filename = matches.value_of("GAME_ROM").unwrap().to_string();
     let debug_mode = matches.is_present("DEBUG");
     let max_speed = matches.is_present("MAX_SPEED");
 
-    (game_rom_filename, debug_mode, max_speed)
+    let variant = match matches.value_of("VARIANT").unwrap() {
+        "chip8x" => Variant::Chip8X,
+        "megachip" => Variant::MegaChip,
+        _ => Variant::Chip8,
+    };
+
+    let palette = matches
+        .value_of("COLORS")
+        .map_or_else(Palette::standard, decode_palette);
+
+    let post_processing = if let Some(decay) = matches.value_of("PHOSPHOR_DECAY") {
+        let decay = decay
+            .parse::<f32>()
+            .ok()
+            .filter(|decay| (0.0..=1.0).contains(decay))
+            .unwrap_or_else(|| panic!("Invalid phosphor decay (0.0-1.0 allowed): {}", decay));
+
+        PostProcessing::PhosphorDecay { decay }
+    } else if matches.is_present("FRAME_BLEND") {
+        PostProcessing::FrameBlend
+    } else {
+        PostProcessing::None
+    };
+
+    let vip_filenames = matches.value_of("VIP_MONITOR").map(|monitor_filename| {
+        let interpreter_filename = matches.value_of("VIP_INTERPRETER").unwrap();
+
+        (
+            monitor_filename.to_string(),
+            interpreter_filename.to_string(),
+        )
+    });
+
+    (
+        game_rom_filename,
+        debug_mode,
+        max_speed,
+        variant,
+        palette,
+        post_processing,
+        vip_filenames,
+    )
 }
 
 fn main() {
-    let (game_rom_filename, debug_mode, max_speed) = decode_commandline_arguments();
+    let (
+        game_rom_filename,
+        debug_mode,
+        max_speed,
+        variant,
+        palette,
+        post_processing,
+        vip_filenames,
+    ) = decode_commandline_arguments();
 
     let game_rom_data = fs::read(game_rom_filename).unwrap();
 
-    let custom_keys_mapping = hashmap! {
+    let mut custom_keys_mapping = hashmap! {
          EventCode::KeyNum4 => EventCode::KeyC,
          EventCode::KeyQ => EventCode::KeyNum4,
          EventCode::KeyW => EventCode::KeyNum5,
@@ -56,6 +233,29 @@ fn main() {
          EventCode::KeyV => EventCode::KeyF,
     };
 
+    // The CHIP-8X second keypad is mapped to the right side of the keyboard.
+    //
+    if variant == Variant::Chip8X {
+        custom_keys_mapping.extend(hashmap! {
+            EventCode::KeyNum7 => EventCode::KeyKp1,
+            EventCode::KeyNum8 => EventCode::KeyKp2,
+            EventCode::KeyNum9 => EventCode::KeyKp3,
+            EventCode::KeyNum0 => EventCode::KeyKpC,
+            EventCode::KeyU => EventCode::KeyKp4,
+            EventCode::KeyI => EventCode::KeyKp5,
+            EventCode::KeyO => EventCode::KeyKp6,
+            EventCode::KeyP => EventCode::KeyKpD,
+            EventCode::KeyJ => EventCode::KeyKp7,
+            EventCode::KeyK => EventCode::KeyKp8,
+            EventCode::KeyL => EventCode::KeyKp9,
+            EventCode::KeySemicolon => EventCode::KeyKpE,
+            EventCode::KeyM => EventCode::KeyKpA,
+            EventCode::KeyComma => EventCode::KeyKp0,
+            EventCode::KeyPeriod => EventCode::KeyKpB,
+            EventCode::KeySlash => EventCode::KeyKpF,
+        });
+    }
+
     let mut sdl_frontend = FrontendSdl::new("CHIP-8!", custom_keys_mapping, Some(60));
 
     let mut logger: Option<Box<dyn Logger>> = if debug_mode {
@@ -64,7 +264,26 @@ fn main() {
         None
     };
 
-    let mut chip8 = Chip8::new(&mut sdl_frontend, &game_rom_data, &mut logger);
+    if let Some((monitor_filename, interpreter_filename)) = vip_filenames {
+        let monitor_rom_data = fs::read(monitor_filename).unwrap();
+        let interpreter_data = fs::read(interpreter_filename).unwrap();
+
+        let mut cosmac_vip = CosmacVip::new(
+            &mut sdl_frontend,
+            &monitor_rom_data,
+            &interpreter_data,
+            &game_rom_data,
+            &mut logger,
+        );
+
+        cosmac_vip.run(max_speed);
+    } else {
+        let mut chip8 = Chip8::new(&mut sdl_frontend, &game_rom_data, variant, &mut logger);
+
+        chip8.set_palette(palette);
+        chip8.set_post_processing(post_processing);
+        chip8.set_rpl_flags_storage(Box::new(RplFlagsFile::new(&game_rom_data)));
 
-    chip8.run(max_speed);
+        chip8.run(max_speed);
+    }
 }
@@ -0,0 +1,49 @@
+use interfaces_frontend::{audio::AudioStream, audio::AUDIO_DEVICE_FREQUENCY};
+use sdl2::{
+    audio::{AudioQueue, AudioSpecDesired},
+    AudioSubsystem,
+};
+
+use std::mem::size_of;
+
+pub(crate) struct AudioStreamSdl {
+    audio_queue: AudioQueue<i16>,
+    channels: u8,
+}
+
+impl AudioStreamSdl {
+    pub fn new(audio_subsystem: &AudioSubsystem, channels: u8) -> AudioStreamSdl {
+        let audio_spec = AudioSpecDesired {
+            freq: Some(AUDIO_DEVICE_FREQUENCY as i32),
+            channels: Some(channels),
+            samples: None,
+        };
+
+        let audio_queue = audio_subsystem.open_queue(None, &audio_spec).unwrap();
+
+        // When the queue is empty, the device plays silence, so it can be always kept running.
+        //
+        audio_queue.resume();
+
+        AudioStreamSdl {
+            audio_queue,
+            channels,
+        }
+    }
+}
+
+impl AudioStream for AudioStreamSdl {
+    fn queue(&mut self, samples: &[i16]) {
+        self.audio_queue.queue(samples);
+    }
+
+    // The SDL queue size is in bytes.
+    //
+    fn queued_frames(&self) -> usize {
+        self.audio_queue.size() as usize / (size_of::<i16>() * self.channels as usize)
+    }
+
+    fn clear(&mut self) {
+        self.audio_queue.clear();
+    }
+}
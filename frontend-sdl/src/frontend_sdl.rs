@@ -1,7 +1,9 @@
 use interfaces_frontend::audio::AudioDevice as FrontendAudioDevice;
+use interfaces_frontend::audio::AudioStream as FrontendAudioStream;
 use interfaces_frontend::{events::EventCode, video::Pixel, IoFrontend};
 
 use super::audio_device_sdl::AudioDeviceSdl;
+use super::audio_stream_sdl::AudioStreamSdl;
 
 use sdl2::event::Event;
 use sdl2::{
@@ -362,6 +364,12 @@ impl IoFrontend for FrontendSdl {
         Box::new(audio_device)
     }
 
+    fn audio_stream(&mut self, channels: u8) -> Box<dyn FrontendAudioStream> {
+        let audio_stream = AudioStreamSdl::new(&self.audio_subsystem, channels);
+
+        Box::new(audio_stream)
+    }
+
     fn read_event(&mut self, blocking: bool) -> Option<(EventCode, bool)> {
         loop {
             let event = if blocking {
@@ -1,4 +1,5 @@
 mod audio_device_sdl;
+mod audio_stream_sdl;
 mod frontend_sdl;
 
 pub use frontend_sdl::FrontendSdl;
@@ -6,3 +6,12 @@ pub trait AudioDevice {
     fn play(&mut self);
     fn pause(&mut self);
 }
+
+// Silent; returned by the default `IoFrontend::audio_device()`.
+//
+pub(crate) struct NullAudioDevice {}
+
+impl AudioDevice for NullAudioDevice {
+    fn play(&mut self) {}
+    fn pause(&mut self) {}
+}
@@ -0,0 +1,36 @@
+/// Sampled (PCM) audio stream; returned by the IoFrontend implementor, and used by the platform
+/// library.
+///
+/// Differently from AudioDevice, the samples are pushed by the platform library (e.g. once per
+/// frame), at AUDIO_DEVICE_FREQUENCY, so any resampling is up to the platform library. The number
+/// of channels is chosen when the stream is requested; with more than one, the samples are
+/// interleaved (e.g. left, right).
+///
+pub trait AudioStream {
+    /// Appends the samples to the playback queue; playback starts as soon as samples are queued.
+    ///
+    fn queue(&mut self, samples: &[i16]);
+
+    /// Number of sample frames (one sample per channel) queued and not played yet; platform
+    /// libraries running faster than real time can use it to keep the latency bounded.
+    ///
+    fn queued_frames(&self) -> usize;
+
+    /// Discards the samples not played yet.
+    ///
+    fn clear(&mut self);
+}
+
+// Discards the samples; returned by the default `IoFrontend::audio_stream()`.
+//
+pub(crate) struct NullAudioStream {}
+
+impl AudioStream for NullAudioStream {
+    fn queue(&mut self, _samples: &[i16]) {}
+
+    fn queued_frames(&self) -> usize {
+        0
+    }
+
+    fn clear(&mut self) {}
+}
@@ -1,4 +1,9 @@
 mod audio_device;
+mod audio_stream;
 
 pub use audio_device::AudioDevice;
 pub use audio_device::AUDIO_DEVICE_FREQUENCY;
+pub use audio_stream::AudioStream;
+
+pub(crate) use audio_device::NullAudioDevice;
+pub(crate) use audio_stream::NullAudioStream;
@@ -1,4 +1,4 @@
-use super::audio::AudioDevice;
+use super::audio::{AudioDevice, AudioStream, NullAudioDevice, NullAudioStream};
 use super::events::EventCode;
 use super::video::Pixel;
 
@@ -22,12 +22,28 @@ pub trait IoFrontend {
 
     /// Retrieve an AudioDevice used for playing a sound wave.
     ///
+    /// The default implementation returns a silent device, for frontends without audio (e.g.
+    /// testing ones).
+    ///
     /// # Arguments/Return value
     ///
     /// * `generator` - wave generator function; sample_i represent the index of the sample over
     ///   time; it increments monotonically with a step of 1; returns the amplitude.
     ///
-    fn audio_device(&mut self, generator: fn(sample_i: u32) -> i16) -> Box<dyn AudioDevice>;
+    fn audio_device(&mut self, _generator: fn(sample_i: u32) -> i16) -> Box<dyn AudioDevice> {
+        Box::new(NullAudioDevice {})
+    }
+
+    /// Retrieve an AudioStream, used for playing sampled audio (e.g. sound effects, or emulated
+    /// sound chips), with the given number of channels.
+    ///
+    /// Platform libraries should request it only if they need it, since implementors may allocate
+    /// a dedicated audio channel for it. The default implementation returns a stream that discards
+    /// the samples, for frontends without audio.
+    ///
+    fn audio_stream(&mut self, _channels: u8) -> Box<dyn AudioStream> {
+        Box::new(NullAudioStream {})
+    }
 
     /// Read an event.
     ///
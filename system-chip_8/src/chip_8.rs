@@ -1,9 +1,15 @@
 // For clarity, any register reference is upper case.
 #![allow(non_snake_case)]
 
+use super::chip_8x::{self, ColorMap, SoundBoard};
+use super::megachip::{self, MegaChip};
+use super::palette::Palette;
+use super::post_processing::{PostProcessing, Renderer};
+use super::rpl_flags_storage::RplFlagsStorage;
+use super::variant::Variant;
 use interfaces_frontend::{
     audio::AudioDevice, audio::AUDIO_DEVICE_FREQUENCY, events::EventCode, logging::Logger,
-    video::Pixel, IoFrontend,
+    IoFrontend,
 };
 
 use std::f64::consts::PI;
@@ -16,11 +22,8 @@ type Word = u16;
 // Simplification: the below are words, however, since they're used in indexing, the required
 // casting makes usage very ugly, therefore, they're defined as usize.
 //
-const RAM_SIZE: usize = 4096;
 const FONTS_LOCATION: usize = 0; // There's no reference location, but this is common practice
-const PROGRAMS_LOCATION: usize = 0x200;
 
-const CLOCK_SPEED: u32 = 500; // Herz
 const TIMERS_SPEED: u32 = 60; // Herz
 
 const TONE_FREQUENCY: f64 = 750.0; // Herz; typical beep frequency!
@@ -31,6 +34,10 @@ const STANDARD_SCREEN_HEIGHT: usize = 32;
 const HIRES_SCREEN_WIDTH: usize = 128;
 const HIRES_SCREEN_HEIGHT: usize = 64;
 
+// Framebuffer plane bitmasks; see `Palette`.
+//
+const FIRST_PLANE: Byte = 0b01;
+
 const FONTSET: [Byte; 80] = [
     0xF0, 0x90, 0x90, 0x90, 0xF0, // 0
     0x20, 0x60, 0x20, 0x20, 0x70, // 1
@@ -51,11 +58,11 @@ const FONTSET: [Byte; 80] = [
 ];
 
 pub struct Chip8<'a, T: IoFrontend> {
-    ram: [Byte; RAM_SIZE],
-    screen: Vec<Pixel>,
+    ram: Vec<Byte>,     // Size depends on the variant
+    screen: Vec<Byte>,  // Planes bitmask of each pixel; converted to pixels by the renderer.
     stack: [usize; 16], // Simplification (exactly: word); see location constants comment.
 
-    V: [Byte; 16],
+    pub(crate) V: [Byte; 16],
 
     // Simplification of the address registers (exactly: word); see location constants comment.
     //
@@ -66,14 +73,25 @@ pub struct Chip8<'a, T: IoFrontend> {
     delay_timer: Byte,
     sound_timer: Byte,
 
+    rpl_flags: Vec<Byte>, // Size depends on the variant
+    rpl_flags_storage: Option<Box<dyn RplFlagsStorage>>,
+
     // True/false for key pressed/released.
     //
     keys_status: [bool; 16],
+    second_keys_status: [bool; 16], // CHIP-8X
 
     io_frontend: &'a mut T,
-    audio_device: Box<dyn AudioDevice>,
+    audio_device: Option<Box<dyn AudioDevice>>, // CHIP-8X uses the sound board instead
     logger: &'a mut Option<Box<dyn Logger>>,
 
+    renderer: Renderer,
+    color_map: Option<ColorMap>,                // CHIP-8X
+    pub(crate) sound_board: Option<SoundBoard>, // CHIP-8X
+    megachip: Option<MegaChip>,
+
+    variant: Variant,
+
     screen_width: usize,
     screen_height: usize,
 }
@@ -85,13 +103,17 @@ impl<'a, T: IoFrontend> Chip8<'a, T> {
     pub fn new(
         io_frontend: &'a mut T,
         game_rom: &[Byte],
+        variant: Variant,
         logger: &'a mut Option<Box<dyn Logger>>,
     ) -> Chip8<'a, T> {
-        if game_rom.len() > RAM_SIZE - PROGRAMS_LOCATION {
+        let ram_size = variant.ram_size();
+        let programs_location = variant.programs_location();
+
+        if game_rom.len() > ram_size - programs_location {
             panic!(
                 "Rom too big!: {} bytes ({} allowed)",
                 game_rom.len(),
-                RAM_SIZE - PROGRAMS_LOCATION
+                ram_size - programs_location
             );
         }
 
@@ -103,42 +125,86 @@ impl<'a, T: IoFrontend> Chip8<'a, T> {
             (AMPLITUDE as f64 * scale_factor) as i16
         }
 
-        let audio_device = io_frontend.audio_device(wave_generator);
+        let (audio_device, color_map, sound_board) = if variant == Variant::Chip8X {
+            let sound_board = SoundBoard::new(io_frontend.audio_stream(1));
+
+            (None, Some(ColorMap::new()), Some(sound_board))
+        } else {
+            (Some(io_frontend.audio_device(wave_generator)), None, None)
+        };
+
+        let megachip = if variant == Variant::MegaChip {
+            Some(MegaChip::new(io_frontend.audio_stream(1)))
+        } else {
+            None
+        };
 
         let mut chip8 = Chip8 {
-            ram: [0; RAM_SIZE],
+            ram: vec![0; ram_size],
             screen: vec![],
             stack: [0; 16],
 
             V: [0; 16],
             I: 0,
-            PC: PROGRAMS_LOCATION,
+            PC: programs_location,
             SP: 0,
 
             delay_timer: 0,
             sound_timer: 0,
 
+            rpl_flags: vec![0; variant.rpl_flags_count()],
+            rpl_flags_storage: None,
+
             keys_status: [false; 16],
+            second_keys_status: [false; 16],
 
             io_frontend,
             audio_device,
             logger,
 
+            renderer: Renderer::new(Palette::standard(), PostProcessing::None),
+            color_map,
+            sound_board,
+            megachip,
+
+            variant,
+
             screen_width: STANDARD_SCREEN_WIDTH,
             screen_height: STANDARD_SCREEN_HEIGHT,
         };
 
         chip8.ram[FONTS_LOCATION..FONTS_LOCATION + FONTSET.len()].copy_from_slice(&FONTSET);
 
-        chip8.ram[PROGRAMS_LOCATION..PROGRAMS_LOCATION + game_rom.len()].copy_from_slice(game_rom);
+        chip8.ram[programs_location..programs_location + game_rom.len()].copy_from_slice(game_rom);
 
         chip8.setup_graphics();
 
         chip8
     }
 
+    pub fn set_palette(&mut self, palette: Palette) {
+        self.renderer.set_palette(palette);
+    }
+
+    pub fn set_post_processing(&mut self, post_processing: PostProcessing) {
+        self.renderer.set_post_processing(post_processing);
+    }
+
+    /// Sets the storage of the RPL flags, and loads them from it; the stored flags exceeding the
+    /// variant flags number are ignored.
+    ///
+    pub fn set_rpl_flags_storage(&mut self, mut rpl_flags_storage: Box<dyn RplFlagsStorage>) {
+        if let Some(stored_flags) = rpl_flags_storage.load() {
+            for (flag, stored_flag) in self.rpl_flags.iter_mut().zip(stored_flags.iter()) {
+                *flag = *stored_flag;
+            }
+        }
+
+        self.rpl_flags_storage = Some(rpl_flags_storage);
+    }
+
     pub fn run(&mut self, max_speed: bool) {
-        let cycle_time_slice = Duration::new(0, 1_000_000_000 / CLOCK_SPEED);
+        let cycle_time_slice = Duration::new(0, 1_000_000_000 / self.variant.clock_speed());
         let timers_time_slice = Duration::new(0, 1_000_000_000 / TIMERS_SPEED);
 
         let mut last_cycle_time = Instant::now();
@@ -152,8 +218,10 @@ impl<'a, T: IoFrontend> Chip8<'a, T> {
 
             self.emulate_cycle(&mut emulation_running, &mut screen_drawn);
 
-            if !screen_drawn {
-                self.io_frontend.update_screen(&self.screen, false);
+            // In MegaChip mode, the screen changes only when the buffers are flipped.
+            //
+            if !screen_drawn && !self.megachip_enabled() {
+                self.update_screen(false);
             }
 
             self.set_keys(&mut emulation_running);
@@ -169,8 +237,24 @@ impl<'a, T: IoFrontend> Chip8<'a, T> {
             // This check doesn't need to account delays, because it uses the next cycle time, which,
             // at this step, is not recalculated.
             //
+            // The timers run at the display refresh rate, so the (video) post-processing is advanced
+            // here as well.
+            //
             if last_cycle_time <= next_timers_time && next_timers_time < next_cycle_time {
+                if let Some(sound_board) = &mut self.sound_board {
+                    if self.sound_timer > 0 {
+                        sound_board.advance_sound((AUDIO_DEVICE_FREQUENCY / TIMERS_SPEED) as usize);
+                    }
+                }
+
                 self.update_timers();
+                self.renderer
+                    .advance_frame(&self.screen, self.color_map.as_ref());
+
+                if let Some(megachip) = &mut self.megachip {
+                    megachip.advance_sound((AUDIO_DEVICE_FREQUENCY / TIMERS_SPEED) as usize);
+                }
+
                 next_timers_time += timers_time_slice;
             }
 
@@ -195,12 +279,31 @@ impl<'a, T: IoFrontend> Chip8<'a, T> {
     }
 
     fn setup_graphics(&mut self) {
-        self.screen = vec![Pixel::OFF; self.screen_width * self.screen_height];
+        self.screen = vec![0; self.screen_width * self.screen_height];
+        self.renderer.resize(self.screen.len());
         self.io_frontend
             .init(self.screen_width as u32, self.screen_height as u32);
     }
 
-    fn emulate_cycle(&mut self, emulation_running: &mut bool, screen_drawn: &mut bool) {
+    pub(crate) fn update_screen(&mut self, force_update: bool) {
+        match &self.megachip {
+            Some(megachip) if megachip.enabled() => {
+                self.io_frontend
+                    .update_screen(&megachip.frame(), force_update);
+            }
+            _ => {
+                let pixels = self.renderer.render(&self.screen, self.color_map.as_ref());
+
+                self.io_frontend.update_screen(pixels, force_update);
+            }
+        }
+    }
+
+    fn megachip_enabled(&self) -> bool {
+        matches!(&self.megachip, Some(megachip) if megachip.enabled())
+    }
+
+    pub(crate) fn emulate_cycle(&mut self, emulation_running: &mut bool, screen_drawn: &mut bool) {
         // The decode/execute stages are conventionally split. In this system there is not real need
         // for this, so, for simplicity, they're merged. A separate-stages design would likely have
         // a function pointer and the operands as intermediate values.
@@ -212,8 +315,13 @@ impl<'a, T: IoFrontend> Chip8<'a, T> {
 
     // Return true if a quit event has been received.
     //
-    fn set_keys(&mut self, emulation_running: &mut bool) {
+    pub(crate) fn set_keys(&mut self, emulation_running: &mut bool) {
         while let Some((keycode, key_pressed)) = self.io_frontend.read_event(false) {
+            if let Some(key_index) = chip_8x::second_keypad_key_index(&keycode) {
+                self.second_keys_status[key_index] = key_pressed;
+                continue;
+            }
+
             let key_index = match keycode {
                 EventCode::KeyNum0 => 0,
                 EventCode::KeyNum1 => 1,
@@ -253,14 +361,19 @@ impl<'a, T: IoFrontend> Chip8<'a, T> {
     }
 
     fn handle_sound_playback(&mut self, previous_sound_timer: Byte) {
+        let audio_device = match &mut self.audio_device {
+            Some(audio_device) => audio_device,
+            None => return,
+        };
+
         #[allow(clippy::collapsible_if)]
         if self.sound_timer == 0 {
             if previous_sound_timer > 0 {
-                self.audio_device.pause();
+                audio_device.pause();
             }
         } else {
             if previous_sound_timer == 0 {
-                self.audio_device.play();
+                audio_device.play();
             }
         }
     }
@@ -287,6 +400,18 @@ impl<'a, T: IoFrontend> Chip8<'a, T> {
         let Vy = ((instruction & 0x00F0) >> 4) as usize;
         let nibble3 = (instruction & 0x000F) as usize;
 
+        // Variant instructions take priority, since they may redefine base ones.
+        //
+        let variant_instruction_executed = match self.variant {
+            Variant::Chip8 => false,
+            Variant::Chip8X => self.cycle_decode_execute_chip_8x(nibble0, Vx, Vy, nibble3),
+            Variant::MegaChip => self.cycle_decode_execute_megachip(instruction, screen_drawn),
+        };
+
+        if variant_instruction_executed {
+            return;
+        }
+
         match (nibble0, Vx, Vy, nibble3) {
             // Some instructions are in the 0x0NNN range (machine code routine call), and need to be
             // placed before it, therefore, out of order.
@@ -305,7 +430,7 @@ impl<'a, T: IoFrontend> Chip8<'a, T> {
                 self.execute_set_hires_mode();
             }
             (0, _, _, _) => panic!(
-                "Call machine code routine instruction or extension not implemented: {:04X}",
+                "Call machine code routine instruction (requires the COSMAC VIP system) or extension not implemented: {:04X}",
                 instruction
             ),
             (1, _, _, _) => {
@@ -432,16 +557,124 @@ impl<'a, T: IoFrontend> Chip8<'a, T> {
             (0xF, _, 6, 5) => {
                 self.execute_load_registers_from_memory(Vx);
             }
+            (0xF, _, 7, 5) => {
+                self.execute_dump_registers_to_rpl_flags(Vx);
+            }
+            (0xF, _, 8, 5) => {
+                self.execute_load_registers_from_rpl_flags(Vx);
+            }
             _ => panic!("Invalid/unsupported instruction: {:04X}", instruction),
         }
     }
 
+    // Returns whether the instruction belongs to the variant (and has therefore been executed).
+    //
+    fn cycle_decode_execute_chip_8x(
+        &mut self,
+        nibble0: usize,
+        Vx: usize,
+        Vy: usize,
+        nibble3: usize,
+    ) -> bool {
+        match (nibble0, Vx, Vy, nibble3) {
+            (0, 0, 0xF, 0xF) => {
+                panic!("Unsupported instruction: 00FF (the VP-590 color map requires the standard display)");
+            }
+            (0, 2, 0xA, 0) => {
+                self.execute_step_background_color();
+            }
+            (5, _, _, 1) => {
+                self.execute_add_Vy_to_Vx_nibbles(Vx, Vy);
+            }
+            (0xB, _, _, 0) => {
+                self.execute_set_coarse_zones_color(Vx, Vy);
+            }
+            (0xB, _, _, _) => {
+                self.execute_set_zones_color(Vx, Vy, nibble3);
+            }
+            (0xE, _, 0xF, 2) => {
+                self.execute_skip_next_instruction_if_Vx_second_keypad_key_pressed(Vx);
+            }
+            (0xE, _, 0xF, 5) => {
+                self.execute_skip_next_instruction_if_Vx_second_keypad_key_not_pressed(Vx);
+            }
+            (0xF, _, 0xF, 8) => {
+                self.execute_output_Vx_to_port(Vx);
+            }
+            (0xF, _, 0xF, 0xB) => {
+                self.execute_input_Vx_from_port(Vx);
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
+    // Returns whether the instruction belongs to the variant (and has therefore been executed).
+    //
+    fn cycle_decode_execute_megachip(
+        &mut self,
+        instruction: Word,
+        screen_drawn: &mut bool,
+    ) -> bool {
+        let nibble0 = (instruction >> 12) as usize;
+        let nibble1 = ((instruction & 0x0F00) >> 8) as usize;
+        let Vx = nibble1;
+        let Vy = ((instruction & 0x00F0) >> 4) as usize;
+        let nn = (instruction & 0x00FF) as Byte;
+        let nibble3 = (instruction & 0x000F) as Byte;
+
+        let megachip_enabled = self.megachip_enabled();
+
+        match (nibble0, nibble1, instruction & 0x00F0) {
+            (0, 0, 0x10) if nn == 0x10 || nn == 0x11 => {
+                self.execute_set_megachip_mode(nn == 0x11);
+            }
+            (0, 0, 0xE0) if megachip_enabled && nn == 0xE0 => {
+                self.execute_flip_screen(screen_drawn);
+            }
+            (0, 1, _) => {
+                self.execute_set_I_long(nn);
+            }
+            (0, 2, _) => {
+                self.execute_load_megachip_palette(nn);
+            }
+            (0, 3, _) => {
+                self.execute_set_megachip_sprite_width(nn);
+            }
+            (0, 4, _) => {
+                self.execute_set_megachip_sprite_height(nn);
+            }
+            (0, 5, _) => {
+                self.execute_set_megachip_screen_alpha(nn);
+            }
+            (0, 6, 0) => {
+                self.execute_play_megachip_sound(nibble3);
+            }
+            (0, 7, 0) if nibble3 == 0 => {
+                self.execute_stop_megachip_sound();
+            }
+            (0, 8, 0) => {
+                self.execute_set_megachip_blend_mode(nibble3);
+            }
+            (0, 9, _) => {
+                self.execute_set_megachip_collision_index(nn);
+            }
+            (0xD, _, _) if megachip_enabled => {
+                self.execute_draw_megachip_sprite(Vx, Vy);
+            }
+            _ => return false,
+        }
+
+        true
+    }
+
     // OPCODE EXECUTION ////////////////////////////////////////////////////////////////////////////
 
     fn execute_clear_screen(&mut self) {
         self.log(format!("[{:X}] CLS", self.PC));
 
-        self.screen = vec![Pixel::OFF; self.screen_width * self.screen_height];
+        self.screen = vec![0; self.screen_width * self.screen_height];
         self.PC += 2;
     }
 
@@ -652,13 +885,11 @@ impl<'a, T: IoFrontend> Chip8<'a, T> {
                     if pixel_value != 0 {
                         let pixel_screen_index = self.screen_width * pixel_y + pixel_x;
 
-                        // The two `screen` assignments constitute one XOR operation.
-                        //
-                        if self.screen[pixel_screen_index] == Pixel::ON {
+                        if self.screen[pixel_screen_index] & FIRST_PLANE != 0 {
                             sprite_collided = 1;
                         }
 
-                        self.screen[pixel_screen_index] ^= Pixel::ON;
+                        self.screen[pixel_screen_index] ^= FIRST_PLANE;
                     }
                 }
             }
@@ -710,11 +941,16 @@ impl<'a, T: IoFrontend> Chip8<'a, T> {
     ) {
         self.log(format!("[{:X}] LD V{}, K", self.PC, Vx));
 
-        self.io_frontend.update_screen(&self.screen, true);
+        self.update_screen(true);
         *screen_drawn = true;
 
         loop {
             if let Some((key_code, key_pressed)) = self.io_frontend.read_event(true) {
+                if let Some(key_index) = chip_8x::second_keypad_key_index(&key_code) {
+                    self.second_keys_status[key_index] = key_pressed;
+                    continue;
+                }
+
                 let key_index = match key_code {
                     EventCode::KeyNum0 => 0,
                     EventCode::KeyNum1 => 1,
@@ -818,8 +1054,294 @@ impl<'a, T: IoFrontend> Chip8<'a, T> {
         self.PC += 2;
     }
 
+    // The flags are saved on each dump, since there is no program termination event. A failure is
+    // not fatal: it's logged, and the flags are saved again on the next dump.
+    //
+    fn execute_dump_registers_to_rpl_flags(&mut self, Vx: usize) {
+        self.log(format!("[{:X}] LD R, V{}", self.PC, Vx));
+
+        self.check_rpl_flags_count(Vx);
+
+        self.rpl_flags[..=Vx].copy_from_slice(&self.V[..=Vx]);
+
+        if let Some(rpl_flags_storage) = &mut self.rpl_flags_storage {
+            if let Err(error) = rpl_flags_storage.save(&self.rpl_flags) {
+                self.log(format!("Error saving the RPL flags: {}", error));
+            }
+        }
+
+        self.PC += 2;
+    }
+
+    fn execute_load_registers_from_rpl_flags(&mut self, Vx: usize) {
+        self.log(format!("[{:X}] LD V{}, R", self.PC, Vx));
+
+        self.check_rpl_flags_count(Vx);
+
+        self.V[..=Vx].copy_from_slice(&self.rpl_flags[..=Vx]);
+        self.PC += 2;
+    }
+
+    fn check_rpl_flags_count(&self, Vx: usize) {
+        if Vx >= self.rpl_flags.len() {
+            panic!(
+                "Unsupported RPL flags range: V0-V{:X} ({} flags supported)",
+                Vx,
+                self.rpl_flags.len()
+            );
+        }
+    }
+
+    // OPCODE EXECUTION (CHIP-8X) ///////////////////////////////////////////////////////////////////
+
+    fn execute_step_background_color(&mut self) {
+        self.log(format!("[{:X}] BGSTEP", self.PC));
+
+        self.color_map.as_mut().unwrap().step_background();
+        self.PC += 2;
+    }
+
+    // Each nibble is added separately, and wraps at 8 (the color values range).
+    //
+    fn execute_add_Vy_to_Vx_nibbles(&mut self, Vx: usize, Vy: usize) {
+        self.log(format!("[{:X}] ADD V{}, V{} (nibbles)", self.PC, Vx, Vy));
+
+        let high_nibble = ((self.V[Vx] >> 4) + (self.V[Vy] >> 4)) & 0b111;
+        let low_nibble = ((self.V[Vx] & 0x0F) + (self.V[Vy] & 0x0F)) & 0b111;
+
+        self.V[Vx] = (high_nibble << 4) | low_nibble;
+        self.PC += 2;
+    }
+
+    // Vx (x) and Vx+1 (y): the low nibble is the top-left zone, and the high nibble is the
+    // number of additional zones; the zones are 8x4 pixels.
+    //
+    fn execute_set_coarse_zones_color(&mut self, Vx: usize, Vy: usize) {
+        self.log(format!("[{:X}] COL V{}, V{}", self.PC, Vx, Vy));
+
+        let x_value = self.V[Vx] as usize;
+        let y_value = self.V[(Vx + 1) % 16] as usize;
+
+        let zone_x = x_value & 0x0F;
+        let zone_width = (x_value >> 4) + 1;
+        let zone_y = (y_value & 0x0F) * chip_8x::COARSE_ZONE_HEIGHT;
+        let zone_height = ((y_value >> 4) + 1) * chip_8x::COARSE_ZONE_HEIGHT;
+
+        self.color_map.as_mut().unwrap().set_zones_color(
+            (zone_x, zone_width),
+            (zone_y, zone_height),
+            self.V[Vy],
+        );
+        self.PC += 2;
+    }
+
+    // Vx is the x coordinate, in pixels, and Vx+1 the y; the zones are 8x1 pixels.
+    //
+    fn execute_set_zones_color(&mut self, Vx: usize, Vy: usize, lines: usize) {
+        self.log(format!("[{:X}] COL V{}, V{}, {}", self.PC, Vx, Vy, lines));
+
+        let zone_x = (self.V[Vx] >> 3) as usize;
+        let zone_y = self.V[(Vx + 1) % 16] as usize;
+
+        self.color_map
+            .as_mut()
+            .unwrap()
+            .set_zones_color((zone_x, 1), (zone_y, lines), self.V[Vy]);
+        self.PC += 2;
+    }
+
+    fn execute_skip_next_instruction_if_Vx_second_keypad_key_pressed(&mut self, Vx: usize) {
+        self.log(format!("[{:X}] SKP2 V{}", self.PC, Vx));
+
+        let keyIndex = self.V[Vx] as usize & 0x0F;
+
+        if self.second_keys_status[keyIndex] {
+            self.PC += 4;
+        } else {
+            self.PC += 2;
+        }
+    }
+
+    fn execute_skip_next_instruction_if_Vx_second_keypad_key_not_pressed(&mut self, Vx: usize) {
+        self.log(format!("[{:X}] SKNP2 V{}", self.PC, Vx));
+
+        let keyIndex = self.V[Vx] as usize & 0x0F;
+
+        if !self.second_keys_status[keyIndex] {
+            self.PC += 4;
+        } else {
+            self.PC += 2;
+        }
+    }
+
+    // The only output device is the VP-595 sound board, which sets the tone frequency.
+    //
+    fn execute_output_Vx_to_port(&mut self, Vx: usize) {
+        self.log(format!("[{:X}] OUT V{}", self.PC, Vx));
+
+        self.sound_board
+            .as_mut()
+            .unwrap()
+            .set_frequency_latch(self.V[Vx]);
+        self.PC += 2;
+    }
+
+    // Simplification: no input device is emulated; on the real hardware, the instruction waits for
+    // the device to be ready, which would hang forever.
+    //
+    fn execute_input_Vx_from_port(&mut self, Vx: usize) {
+        self.log(format!("[{:X}] INP V{}", self.PC, Vx));
+
+        self.V[Vx] = 0;
+        self.PC += 2;
+    }
+
+    // OPCODE EXECUTION (MegaChip) //////////////////////////////////////////////////////////////////
+
+    fn execute_set_megachip_mode(&mut self, enabled: bool) {
+        self.log(format!(
+            "[{:X}] {}",
+            self.PC,
+            if enabled { "MEGAON" } else { "MEGAOFF" }
+        ));
+
+        self.megachip.as_mut().unwrap().set_enabled(enabled);
+
+        if enabled {
+            self.screen_width = megachip::SCREEN_WIDTH;
+            self.screen_height = megachip::SCREEN_HEIGHT;
+        } else {
+            self.screen_width = STANDARD_SCREEN_WIDTH;
+            self.screen_height = STANDARD_SCREEN_HEIGHT;
+        }
+
+        self.setup_graphics();
+        self.PC += 2;
+    }
+
+    fn execute_flip_screen(&mut self, screen_drawn: &mut bool) {
+        self.log(format!("[{:X}] CLS (flip)", self.PC));
+
+        self.megachip.as_mut().unwrap().flip();
+
+        self.update_screen(false);
+        *screen_drawn = true;
+
+        self.PC += 2;
+    }
+
+    // 01NN NNNN: the address is 24 bits wide, so the instruction is 4 bytes long. The bytes past the
+    // end of the RAM read as 0.
+    //
+    fn execute_set_I_long(&mut self, address_high: Byte) {
+        let address_byte = |offset| self.ram.get(self.PC + offset).copied().unwrap_or(0) as usize;
+        let address_low = (address_byte(2) << 8) | address_byte(3);
+        let address = ((address_high as usize) << 16) | address_low;
+
+        self.log(format!("[{:X}] LDHI I, 0x{:X}", self.PC, address));
+
+        self.I = address;
+        self.PC += 4;
+    }
+
+    fn execute_load_megachip_palette(&mut self, colors_count: Byte) {
+        self.log(format!("[{:X}] LDPAL {}", self.PC, colors_count));
+
+        let colors_data = Self::ram_data(&self.ram, self.I, 4 * colors_count as usize);
+
+        self.megachip.as_mut().unwrap().load_palette(colors_data);
+        self.PC += 2;
+    }
+
+    fn execute_set_megachip_sprite_width(&mut self, value: Byte) {
+        self.log(format!("[{:X}] SPRW {}", self.PC, value));
+
+        self.megachip.as_mut().unwrap().set_sprite_width(value);
+        self.PC += 2;
+    }
+
+    fn execute_set_megachip_sprite_height(&mut self, value: Byte) {
+        self.log(format!("[{:X}] SPRH {}", self.PC, value));
+
+        self.megachip.as_mut().unwrap().set_sprite_height(value);
+        self.PC += 2;
+    }
+
+    fn execute_set_megachip_screen_alpha(&mut self, value: Byte) {
+        self.log(format!("[{:X}] ALPHA {}", self.PC, value));
+
+        self.megachip.as_mut().unwrap().set_screen_alpha(value);
+        self.PC += 2;
+    }
+
+    // N = 0: loop; otherwise, play once.
+    //
+    fn execute_play_megachip_sound(&mut self, n: Byte) {
+        self.log(format!("[{:X}] DIGISND {}", self.PC, n));
+
+        let sound_data = Self::ram_data(&self.ram, self.I, self.ram.len());
+
+        self.megachip
+            .as_mut()
+            .unwrap()
+            .play_sound(sound_data, n == 0);
+        self.PC += 2;
+    }
+
+    fn execute_stop_megachip_sound(&mut self) {
+        self.log(format!("[{:X}] STOPSND", self.PC));
+
+        self.megachip.as_mut().unwrap().stop_sound();
+        self.PC += 2;
+    }
+
+    fn execute_set_megachip_blend_mode(&mut self, value: Byte) {
+        self.log(format!("[{:X}] BMODE {}", self.PC, value));
+
+        if !self.megachip.as_mut().unwrap().set_blend_mode(value) {
+            self.log(format!("Invalid MegaChip blend mode: {} (ignored)", value));
+        }
+
+        self.PC += 2;
+    }
+
+    fn execute_set_megachip_collision_index(&mut self, value: Byte) {
+        self.log(format!("[{:X}] CCOL {}", self.PC, value));
+
+        self.megachip.as_mut().unwrap().set_collision_index(value);
+        self.PC += 2;
+    }
+
+    // The sprite size is set by the dedicated registers, so N is ignored.
+    //
+    fn execute_draw_megachip_sprite(&mut self, Vx: usize, Vy: usize) {
+        self.log(format!(
+            "[{:X}] DRW V{}, V{}; I={:X}, x={}, y={}",
+            self.PC, Vx, Vy, self.I, self.V[Vx], self.V[Vy],
+        ));
+
+        let megachip = self.megachip.as_mut().unwrap();
+        let sprite_data = Self::ram_data(&self.ram, self.I, megachip.sprite_size());
+
+        let sprite_collided =
+            megachip.draw_sprite(sprite_data, self.V[Vx] as usize, self.V[Vy] as usize);
+
+        self.V[15] = sprite_collided as Byte;
+        self.PC += 2;
+    }
+
     // HELPERS /////////////////////////////////////////////////////////////////////////////////////
 
+    // MegaChip data addressed by I (and the 24-bit addresses) can extend past the end of the RAM;
+    // the data is truncated, rather than wrapped, since the programs are not expected to rely on it.
+    //
+    fn ram_data(ram: &[Byte], address: usize, length: usize) -> &[Byte] {
+        let start = address.min(ram.len());
+        let end = address.saturating_add(length).min(ram.len());
+
+        &ram[start..end]
+    }
+
     fn log(&mut self, message: String) {
         if let Some(logger) = self.logger {
             logger.log(message);
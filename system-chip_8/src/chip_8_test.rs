@@ -0,0 +1,407 @@
+// For clarity, any register reference is upper case.
+#![allow(non_snake_case)]
+
+use super::chip_8::Chip8;
+use super::rpl_flags_storage::RplFlagsStorage;
+use super::test_helpers::{rgb, CapturingAudioStream};
+use super::variant::Variant;
+use interfaces_frontend::{
+    audio::AudioStream, events::EventCode, logging::Logger, video::Pixel, IoFrontend,
+};
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::io;
+use std::rc::Rc;
+
+type Byte = u8;
+
+const SCREEN_WIDTH: usize = 64;
+
+// Stores the last frame and the streamed audio; the events are read from a queue.
+//
+struct TestFrontend {
+    frame: Vec<Pixel>,
+    samples: Rc<RefCell<Vec<i16>>>,
+    events: VecDeque<(EventCode, bool)>,
+}
+
+impl TestFrontend {
+    fn new() -> TestFrontend {
+        TestFrontend {
+            frame: vec![],
+            samples: Rc::new(RefCell::new(vec![])),
+            events: VecDeque::new(),
+        }
+    }
+}
+
+impl IoFrontend for TestFrontend {
+    fn init(&mut self, _screen_width: u32, _screen_height: u32) {}
+
+    fn update_screen(&mut self, pixels: &[Pixel], _force_update: bool) {
+        self.frame = pixels.to_vec();
+    }
+
+    fn audio_stream(&mut self, _channels: u8) -> Box<dyn AudioStream> {
+        Box::new(CapturingAudioStream(self.samples.clone()))
+    }
+
+    fn read_event(&mut self, _blocking: bool) -> Option<(EventCode, bool)> {
+        self.events.pop_front()
+    }
+}
+
+struct CapturingLogger(Rc<RefCell<Vec<String>>>);
+
+impl Logger for CapturingLogger {
+    fn log(&mut self, message: String) {
+        self.0.borrow_mut().push(message);
+    }
+}
+
+// Returns the stored flags, if set, and captures all the saved ones; if `failing` is set, the saves
+// fail (but are still captured).
+//
+struct CapturingRplFlagsStorage {
+    stored_flags: Option<Vec<Byte>>,
+    saved_flags: Rc<RefCell<Vec<Vec<Byte>>>>,
+    failing: bool,
+}
+
+impl CapturingRplFlagsStorage {
+    fn new(stored_flags: Option<Vec<Byte>>) -> CapturingRplFlagsStorage {
+        CapturingRplFlagsStorage {
+            stored_flags,
+            saved_flags: Rc::new(RefCell::new(vec![])),
+            failing: false,
+        }
+    }
+}
+
+impl RplFlagsStorage for CapturingRplFlagsStorage {
+    fn load(&mut self) -> Option<Vec<Byte>> {
+        self.stored_flags.clone()
+    }
+
+    fn save(&mut self, flags: &[Byte]) -> io::Result<()> {
+        self.saved_flags.borrow_mut().push(flags.to_vec());
+
+        if self.failing {
+            Err(io::Error::other("storage full"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn chip8<'a>(
+    frontend: &'a mut TestFrontend,
+    logger: &'a mut Option<Box<dyn Logger>>,
+    variant: Variant,
+    instructions: &[&[Byte]],
+) -> Chip8<'a, TestFrontend> {
+    Chip8::new(frontend, &instructions.concat(), variant, logger)
+}
+
+fn emulate_instructions(chip8: &mut Chip8<TestFrontend>, count: usize) {
+    for _ in 0..count {
+        chip8.emulate_cycle(&mut true, &mut false);
+    }
+}
+
+// Runs all the given instructions (one per cycle) on a CHIP-8; returns the V registers and the
+// logged messages.
+//
+fn run(
+    instructions: &[&[Byte]],
+    rpl_flags_storage: Option<CapturingRplFlagsStorage>,
+) -> ([Byte; 16], Vec<String>) {
+    let messages = Rc::new(RefCell::new(vec![]));
+
+    let mut frontend = TestFrontend::new();
+    let mut logger: Option<Box<dyn Logger>> = Some(Box::new(CapturingLogger(messages.clone())));
+
+    let mut chip8 = chip8(&mut frontend, &mut logger, Variant::Chip8, instructions);
+
+    if let Some(rpl_flags_storage) = rpl_flags_storage {
+        chip8.set_rpl_flags_storage(Box::new(rpl_flags_storage));
+    }
+
+    emulate_instructions(&mut chip8, instructions.len());
+
+    let messages = messages.borrow().clone();
+
+    (chip8.V, messages)
+}
+
+// Runs all the given instructions on a CHIP-8X, then draws the font glyph "0" (a 4x5 rectangle
+// outline) at (x, y) with V3/V4, and returns the rendered frame.
+//
+fn run_chip8x_and_draw(instructions: &[&[Byte]], x: Byte, y: Byte) -> Vec<Pixel> {
+    let draw_instructions: &[&[Byte]] = &[
+        &[0x63, x],    // LD V3, x
+        &[0x64, y],    // LD V4, y
+        &[0xA0, 0x00], // LD I, 0 (font glyph "0")
+        &[0xD3, 0x45], // DRW V3, V4, 5
+    ];
+
+    let instructions = [instructions, draw_instructions].concat();
+
+    let mut frontend = TestFrontend::new();
+    let mut logger = None;
+
+    let mut chip8 = chip8(&mut frontend, &mut logger, Variant::Chip8X, &instructions);
+
+    emulate_instructions(&mut chip8, instructions.len());
+
+    chip8.update_screen(true);
+
+    frontend.frame
+}
+
+fn frame_rgb(frame: &[Pixel], x: usize, y: usize) -> (u8, u8, u8) {
+    rgb(&frame[y * SCREEN_WIDTH + x])
+}
+
+#[test]
+fn dump_to_rpl_flags_saves_the_flags() {
+    let storage = CapturingRplFlagsStorage::new(None);
+    let saved_flags = storage.saved_flags.clone();
+
+    run(
+        &[
+            &[0x60, 0x12], // LD V0, 0x12
+            &[0x61, 0x34], // LD V1, 0x34
+            &[0xF1, 0x75], // LD R, V1
+            &[0x60, 0x56], // LD V0, 0x56
+            &[0xF0, 0x75], // LD R, V0
+        ],
+        Some(storage),
+    );
+
+    assert_eq!(
+        *saved_flags.borrow(),
+        vec![
+            vec![0x12, 0x34, 0, 0, 0, 0, 0, 0],
+            vec![0x56, 0x34, 0, 0, 0, 0, 0, 0],
+        ]
+    );
+}
+
+#[test]
+fn rpl_flags_are_loaded_when_the_storage_is_set() {
+    // The flags exceeding the variant number (8) are ignored.
+    //
+    let stored_flags = (1..=9).collect();
+
+    let (V, _) = run(
+        &[&[0xF7, 0x85]], // LD V7, R
+        Some(CapturingRplFlagsStorage::new(Some(stored_flags))),
+    );
+
+    assert_eq!(V[..9], [1, 2, 3, 4, 5, 6, 7, 8, 0]);
+}
+
+#[test]
+fn missing_stored_rpl_flags_are_zero() {
+    let (V, _) = run(
+        &[
+            &[0x61, 0xFF], // LD V1, 0xFF
+            &[0xF1, 0x85], // LD V1, R
+        ],
+        Some(CapturingRplFlagsStorage::new(Some(vec![0xAA]))),
+    );
+
+    assert_eq!(V[..2], [0xAA, 0]);
+}
+
+#[test]
+fn rpl_flags_are_kept_without_storage() {
+    let (V, _) = run(
+        &[
+            &[0x62, 0x78], // LD V2, 0x78
+            &[0xF2, 0x75], // LD R, V2
+            &[0x62, 0x00], // LD V2, 0
+            &[0xF2, 0x85], // LD V2, R
+        ],
+        None,
+    );
+
+    assert_eq!(V[2], 0x78);
+}
+
+#[test]
+fn rpl_flags_save_failure_is_logged() {
+    let mut storage = CapturingRplFlagsStorage::new(None);
+    storage.failing = true;
+
+    let (_, messages) = run(&[&[0xF0, 0x75]], Some(storage)); // LD R, V0
+
+    assert!(messages
+        .iter()
+        .any(|message| message == "Error saving the RPL flags: storage full"));
+}
+
+#[test]
+#[should_panic(expected = "Unsupported RPL flags range: V0-V8 (8 flags supported)")]
+fn dump_to_rpl_flags_rejects_the_registers_exceeding_the_flags() {
+    run(&[&[0xF8, 0x75]], None); // LD R, V8
+}
+
+#[test]
+#[should_panic(expected = "Unsupported RPL flags range: V0-VF (8 flags supported)")]
+fn load_from_rpl_flags_rejects_the_registers_exceeding_the_flags() {
+    run(&[&[0xFF, 0x85]], None); // LD VF, R
+}
+
+#[test]
+fn chip8x_adds_the_nibbles_separately() {
+    let mut frontend = TestFrontend::new();
+    let mut logger = None;
+
+    let instructions: &[&[Byte]] = &[
+        &[0x60, 0x35], // LD V0, 0x35
+        &[0x61, 0x64], // LD V1, 0x64
+        &[0x50, 0x11], // ADD V0, V1 (nibbles)
+    ];
+
+    let mut chip8 = chip8(&mut frontend, &mut logger, Variant::Chip8X, instructions);
+
+    emulate_instructions(&mut chip8, instructions.len());
+
+    // Each nibble wraps at 8: 3 + 6 = 1, 5 + 4 = 1.
+    //
+    assert_eq!(chip8.V[0], 0x11);
+}
+
+#[test]
+fn chip8x_colors_the_coarse_zones() {
+    let frame = run_chip8x_and_draw(
+        &[
+            &[0x60, 0x11], // LD V0, 0x11 (zones 1-2 horizontally)
+            &[0x61, 0x00], // LD V1, 0x00 (zone 0 vertically, 4 lines)
+            &[0x62, 0x04], // LD V2, 4 (green)
+            &[0xB0, 0x20], // COL V0, V2
+        ],
+        8,
+        0,
+    );
+
+    assert_eq!(frame_rgb(&frame, 8, 0), (0, 255, 0));
+    assert_eq!(frame_rgb(&frame, 11, 3), (0, 255, 0));
+
+    // Below the zone, the foreground is the default (red).
+    //
+    assert_eq!(frame_rgb(&frame, 8, 4), (255, 0, 0));
+
+    // Unset pixels are background (dark blue).
+    //
+    assert_eq!(frame_rgb(&frame, 9, 1), (0, 0, 128));
+}
+
+#[test]
+fn chip8x_colors_the_zones_lines() {
+    let frame = run_chip8x_and_draw(
+        &[
+            &[0x60, 0x10], // LD V0, 16 (zone 2 horizontally)
+            &[0x61, 0x01], // LD V1, 1
+            &[0x62, 0x02], // LD V2, 2 (blue)
+            &[0xB0, 0x22], // COL V0, V2, 2
+        ],
+        16,
+        0,
+    );
+
+    assert_eq!(frame_rgb(&frame, 16, 0), (255, 0, 0));
+    assert_eq!(frame_rgb(&frame, 16, 1), (0, 0, 255));
+    assert_eq!(frame_rgb(&frame, 19, 2), (0, 0, 255));
+    assert_eq!(frame_rgb(&frame, 16, 3), (255, 0, 0));
+}
+
+#[test]
+fn chip8x_steps_the_background_color() {
+    let frame = run_chip8x_and_draw(&[&[0x02, 0xA0]], 0, 0); // BGSTEP
+
+    assert_eq!(frame_rgb(&frame, 1, 1), (0, 0, 0));
+    assert_eq!(frame_rgb(&frame, 0, 0), (255, 0, 0));
+}
+
+#[test]
+fn chip8x_skips_on_the_second_keypad_keys() {
+    let mut frontend = TestFrontend::new();
+    frontend.events.push_back((EventCode::KeyKp5, true));
+
+    let mut logger = None;
+
+    let instructions: &[&[Byte]] = &[
+        &[0x60, 0x05], // LD V0, 5
+        &[0xE0, 0xF2], // SKP2 V0
+        &[0x61, 0x01], // LD V1, 1 (skipped)
+        &[0xE0, 0xF5], // SKNP2 V0
+        &[0x62, 0x01], // LD V2, 1
+        &[0x60, 0x06], // LD V0, 6
+        &[0xE0, 0xF5], // SKNP2 V0
+        &[0x63, 0x01], // LD V3, 1 (skipped)
+        &[0x60, 0x05], // LD V0, 5
+        &[0xE0, 0x9E], // SKP V0 (first keypad)
+        &[0x64, 0x01], // LD V4, 1
+    ];
+
+    let mut chip8 = chip8(&mut frontend, &mut logger, Variant::Chip8X, instructions);
+
+    chip8.set_keys(&mut true);
+
+    emulate_instructions(&mut chip8, 9);
+
+    // The key is on the second keypad only, so SKP doesn't skip.
+    //
+    assert_eq!(chip8.V[1..5], [0, 1, 0, 1]);
+}
+
+#[test]
+fn chip8x_outputs_the_sound_frequency_and_inputs_zero() {
+    let mut frontend = TestFrontend::new();
+    let samples = frontend.samples.clone();
+
+    let mut logger = None;
+
+    let instructions: &[&[Byte]] = &[
+        &[0x60, 0x3F], // LD V0, 0x3F
+        &[0xF0, 0xF8], // OUT V0
+        &[0xF0, 0xFB], // INP V0
+    ];
+
+    let mut chip8 = chip8(&mut frontend, &mut logger, Variant::Chip8X, instructions);
+
+    emulate_instructions(&mut chip8, instructions.len());
+
+    chip8.sound_board.as_mut().unwrap().advance_sound(44100);
+
+    // 27535 / (0x3F + 1) Hz; counted as upward zero crossings.
+    //
+    let periods = samples
+        .borrow()
+        .windows(2)
+        .filter(|window| window[0] < 0 && window[1] >= 0)
+        .count();
+
+    assert!((429..=431).contains(&periods), "{}", periods);
+    assert_eq!(chip8.V[0], 0);
+}
+
+#[test]
+#[should_panic(expected = "Unsupported instruction: 00FF")]
+fn chip8x_rejects_the_hires_mode() {
+    let mut frontend = TestFrontend::new();
+    let mut logger = None;
+
+    let mut chip8 = chip8(
+        &mut frontend,
+        &mut logger,
+        Variant::Chip8X,
+        &[&[0x00, 0xFF]],
+    );
+
+    emulate_instructions(&mut chip8, 1);
+}
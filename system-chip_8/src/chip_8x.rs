@@ -0,0 +1,162 @@
+use interfaces_frontend::{
+    audio::AudioStream, audio::AUDIO_DEVICE_FREQUENCY, events::EventCode, video::Pixel,
+};
+
+use std::f64::consts::PI;
+
+type Byte = u8;
+
+// Color zones are 8 pixels wide and one line high; the BXY0 instruction addresses zones of 4 lines.
+//
+const ZONE_WIDTH: usize = 8;
+const ZONES_PER_LINE: usize = 8;
+const ZONE_LINES: usize = 32;
+pub(crate) const COARSE_ZONE_HEIGHT: usize = 4;
+
+// VP-590 colors; the foreground is selected by a 3-bit value (bits: green, blue, red).
+//
+const FOREGROUND_COLORS: [Pixel; 8] = [
+    Pixel(0, 0, 0),       // Black
+    Pixel(255, 0, 0),     // Red
+    Pixel(0, 0, 255),     // Blue
+    Pixel(255, 0, 255),   // Violet
+    Pixel(0, 255, 0),     // Green
+    Pixel(255, 255, 0),   // Yellow
+    Pixel(0, 255, 255),   // Aqua
+    Pixel(255, 255, 255), // White
+];
+const BACKGROUND_COLORS: [Pixel; 4] = [
+    Pixel(0, 0, 128), // Dark blue
+    Pixel(0, 0, 0),   // Black
+    Pixel(0, 128, 0), // Green
+    Pixel(128, 0, 0), // Red
+];
+const DEFAULT_FOREGROUND_COLOR: Byte = 1;
+
+const AMPLITUDE: i16 = i16::MAX / 16; // Volume (i16::MAX = max)
+
+const DEFAULT_SOUND_FREQUENCY_LATCH: Byte = 0x80;
+
+/// VP-590 color attributes: the foreground color of each zone, and the background color.
+///
+pub(crate) struct ColorMap {
+    zones: [Byte; ZONES_PER_LINE * ZONE_LINES],
+    background: usize,
+}
+
+impl ColorMap {
+    pub fn new() -> ColorMap {
+        ColorMap {
+            zones: [DEFAULT_FOREGROUND_COLOR; ZONES_PER_LINE * ZONE_LINES],
+            background: 0,
+        }
+    }
+
+    /// Colors the zones in the given (start, count) ranges; the coordinates are in zones.
+    ///
+    pub fn set_zones_color(
+        &mut self,
+        (zone_x, zone_width): (usize, usize),
+        (zone_y, zone_height): (usize, usize),
+        color: Byte,
+    ) {
+        for y in zone_y..zone_y + zone_height {
+            for x in zone_x..zone_x + zone_width {
+                self.zones[(y % ZONE_LINES) * ZONES_PER_LINE + x % ZONES_PER_LINE] = color & 0b111;
+            }
+        }
+    }
+
+    pub fn step_background(&mut self) {
+        self.background = (self.background + 1) % BACKGROUND_COLORS.len();
+    }
+
+    /// `screen_index` is the index in the (64x32) framebuffer; any plane set is foreground.
+    ///
+    /// The zones are laid out on the standard display only, which is why the hires mode is not
+    /// supported by the variant.
+    ///
+    pub fn color(&self, screen_index: usize, planes: Byte) -> &Pixel {
+        if planes == 0 {
+            &BACKGROUND_COLORS[self.background]
+        } else {
+            let x = screen_index % (ZONES_PER_LINE * ZONE_WIDTH);
+            let y = screen_index / (ZONES_PER_LINE * ZONE_WIDTH);
+
+            &FOREGROUND_COLORS[self.zones[y * ZONES_PER_LINE + x / ZONE_WIDTH] as usize]
+        }
+    }
+}
+
+/// VP-580 (second) keypad; it's mapped to the keypad keys.
+///
+pub(crate) fn second_keypad_key_index(event_code: &EventCode) -> Option<usize> {
+    let key_index = match event_code {
+        EventCode::KeyKp0 => 0,
+        EventCode::KeyKp1 => 1,
+        EventCode::KeyKp2 => 2,
+        EventCode::KeyKp3 => 3,
+        EventCode::KeyKp4 => 4,
+        EventCode::KeyKp5 => 5,
+        EventCode::KeyKp6 => 6,
+        EventCode::KeyKp7 => 7,
+        EventCode::KeyKp8 => 8,
+        EventCode::KeyKp9 => 9,
+        EventCode::KeyKpA => 10,
+        EventCode::KeyKpB => 11,
+        EventCode::KeyKpC => 12,
+        EventCode::KeyKpD => 13,
+        EventCode::KeyKpE => 14,
+        EventCode::KeyKpF => 15,
+        _ => return None,
+    };
+
+    Some(key_index)
+}
+
+/// VP-595 sound board: a tone, whose frequency is derived from the latch value.
+///
+/// The frequency can change while the tone plays, so the samples are generated here (with the wave
+/// phase kept across changes), and queued to a mono audio stream.
+///
+pub(crate) struct SoundBoard {
+    frequency_latch: Byte,
+    phase: f64, // Position in the wave period (0.0 to 1.0)
+
+    audio_stream: Box<dyn AudioStream>,
+}
+
+impl SoundBoard {
+    pub fn new(audio_stream: Box<dyn AudioStream>) -> SoundBoard {
+        SoundBoard {
+            frequency_latch: DEFAULT_SOUND_FREQUENCY_LATCH,
+            phase: 0.0,
+
+            audio_stream,
+        }
+    }
+
+    /// Sets the frequency latch (FXF8); the board is on the output port 3.
+    ///
+    pub fn set_frequency_latch(&mut self, value: Byte) {
+        self.frequency_latch = value;
+    }
+
+    /// Queues the tone samples for the given duration.
+    ///
+    pub fn advance_sound(&mut self, output_samples_count: usize) {
+        let tone_frequency = 27535.0 / (self.frequency_latch as f64 + 1.0);
+        let step = tone_frequency / AUDIO_DEVICE_FREQUENCY as f64;
+
+        let mut output_samples = Vec::with_capacity(output_samples_count);
+
+        for _ in 0..output_samples_count {
+            let scale_factor = (self.phase * 2.0 * PI).sin();
+            output_samples.push((AMPLITUDE as f64 * scale_factor) as i16);
+
+            self.phase = (self.phase + step).fract();
+        }
+
+        self.audio_stream.queue(&output_samples);
+    }
+}
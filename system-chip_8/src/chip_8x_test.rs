@@ -0,0 +1,93 @@
+use super::chip_8x::{ColorMap, SoundBoard};
+use super::test_helpers::{rgb, CapturingAudioStream};
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+const SCREEN_WIDTH: usize = 64;
+
+fn capturing_sound_board() -> (SoundBoard, Rc<RefCell<Vec<i16>>>) {
+    let samples = Rc::new(RefCell::new(vec![]));
+    let sound_board = SoundBoard::new(Box::new(CapturingAudioStream(samples.clone())));
+
+    (sound_board, samples)
+}
+
+// Number of times the wave crosses zero upwards.
+//
+fn periods_count(samples: &[i16]) -> usize {
+    samples
+        .windows(2)
+        .filter(|window| window[0] < 0 && window[1] >= 0)
+        .count()
+}
+
+#[test]
+fn color_map_colors_the_zones() {
+    let mut color_map = ColorMap::new();
+
+    // Zone (1, 2), 2 lines high, green.
+    //
+    color_map.set_zones_color((1, 1), (2, 2), 0b100);
+
+    let screen_index = |x: usize, y: usize| y * SCREEN_WIDTH + x;
+
+    assert_eq!(rgb(color_map.color(screen_index(8, 2), 1)), (0, 255, 0));
+    assert_eq!(rgb(color_map.color(screen_index(15, 3), 1)), (0, 255, 0));
+
+    // Outside the zone, the foreground is the default (red).
+    //
+    assert_eq!(rgb(color_map.color(screen_index(16, 2), 1)), (255, 0, 0));
+    assert_eq!(rgb(color_map.color(screen_index(8, 4), 1)), (255, 0, 0));
+
+    // Unset pixels are background.
+    //
+    assert_eq!(rgb(color_map.color(screen_index(8, 2), 0)), (0, 0, 128));
+}
+
+#[test]
+fn color_map_steps_the_background_color() {
+    let mut color_map = ColorMap::new();
+
+    color_map.step_background();
+
+    assert_eq!(rgb(color_map.color(0, 0)), (0, 0, 0));
+
+    for _ in 0..3 {
+        color_map.step_background();
+    }
+
+    assert_eq!(rgb(color_map.color(0, 0)), (0, 0, 128));
+}
+
+#[test]
+fn sound_boards_have_independent_frequency_latches() {
+    let (mut low_sound_board, low_samples) = capturing_sound_board();
+    let (mut high_sound_board, high_samples) = capturing_sound_board();
+
+    // Frequencies: 27535 / (latch + 1) Hz.
+    //
+    low_sound_board.set_frequency_latch(0xFF);
+    high_sound_board.set_frequency_latch(0x3F);
+
+    low_sound_board.advance_sound(44100);
+    high_sound_board.advance_sound(44100);
+
+    let low_periods = periods_count(&low_samples.borrow());
+    let high_periods = periods_count(&high_samples.borrow());
+
+    assert!((106..=108).contains(&low_periods), "{}", low_periods);
+    assert!((429..=431).contains(&high_periods), "{}", high_periods);
+}
+
+#[test]
+fn sound_board_keeps_the_wave_phase_across_advances() {
+    let (mut sound_board, samples) = capturing_sound_board();
+    let (mut reference_sound_board, reference_samples) = capturing_sound_board();
+
+    sound_board.advance_sound(300);
+    sound_board.advance_sound(435);
+    reference_sound_board.advance_sound(735);
+
+    assert_eq!(*samples.borrow(), *reference_samples.borrow());
+}
@@ -1,3 +1,26 @@
 mod chip_8;
+mod chip_8x;
+mod megachip;
+mod palette;
+mod post_processing;
+mod rpl_flags_storage;
+mod variant;
+
+#[cfg(test)]
+mod chip_8_test;
+#[cfg(test)]
+mod chip_8x_test;
+#[cfg(test)]
+mod megachip_test;
+#[cfg(test)]
+mod palette_test;
+#[cfg(test)]
+mod post_processing_test;
+#[cfg(test)]
+mod test_helpers;
 
 pub use chip_8::Chip8;
+pub use palette::Palette;
+pub use post_processing::PostProcessing;
+pub use rpl_flags_storage::RplFlagsStorage;
+pub use variant::Variant;
@@ -0,0 +1,315 @@
+use super::palette::Palette;
+use interfaces_frontend::{audio::AudioStream, audio::AUDIO_DEVICE_FREQUENCY, video::Pixel};
+
+type Byte = u8;
+
+pub(crate) const SCREEN_WIDTH: usize = 256;
+pub(crate) const SCREEN_HEIGHT: usize = 192;
+
+// Header: sample rate (word), length (24 bits), and a reserved byte. Samples are unsigned bytes.
+//
+const SOUND_HEADER_SIZE: usize = 6;
+const SOUND_SAMPLE_SCALE: i16 = 64; // Sample-to-output multiplier; 64 = i16::MAX / 4
+
+/// Sprite blending mode; the numeric values are the ones set by the `080N` instruction.
+///
+#[derive(Clone, Copy)]
+enum BlendMode {
+    Normal, // Uses the alpha channel of the color
+    Opacity25,
+    Opacity50,
+    Opacity75,
+    Add,
+    Multiply,
+}
+
+// ARGB color, as loaded by LDPAL.
+//
+#[derive(Clone, Copy)]
+struct Color {
+    alpha: Byte,
+    red: Byte,
+    green: Byte,
+    blue: Byte,
+}
+
+struct Sound {
+    samples: Vec<Byte>,
+    sample_rate: u32,
+    looping: bool,
+    position: f64, // Index of the next sample; fractional, because of the resampling
+}
+
+/// MegaChip-8 extension state.
+///
+/// MegaChip mode has its own (colored) framebuffer, which is double buffered: sprites are drawn to
+/// the back buffer, and the `00E0` instruction, instead of just clearing, shows the back buffer,
+/// then clears it.
+///
+/// The sprite bytes are palette indexes (0 = transparent); each index maps to an ARGB color,
+/// blended with the background according to the blend mode.
+///
+pub(crate) struct MegaChip {
+    enabled: bool,
+
+    palette: [Color; 256],
+    sprite_width: usize,
+    sprite_height: usize,
+    screen_alpha: Byte,
+    blend_mode: BlendMode,
+    collision_index: Option<Byte>, // Not set until the first `09NN`
+
+    back_buffer: Vec<Pixel>,
+    back_buffer_indexes: Vec<Byte>, // Used for collisions
+    front_buffer: Vec<Pixel>,
+
+    sound: Option<Sound>,
+    audio_stream: Box<dyn AudioStream>,
+}
+
+impl MegaChip {
+    pub fn new(audio_stream: Box<dyn AudioStream>) -> MegaChip {
+        let black = Color {
+            alpha: 0xFF,
+            red: 0,
+            green: 0,
+            blue: 0,
+        };
+
+        // The sprite size registers start at 0, which stands for 256.
+        //
+        MegaChip {
+            enabled: false,
+
+            palette: [black; 256],
+            sprite_width: 256,
+            sprite_height: 256,
+            screen_alpha: 0xFF,
+            blend_mode: BlendMode::Normal,
+            collision_index: None,
+
+            back_buffer: vec![],
+            back_buffer_indexes: vec![],
+            front_buffer: vec![],
+
+            sound: None,
+            audio_stream,
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+
+        self.back_buffer = vec![Pixel::OFF; SCREEN_WIDTH * SCREEN_HEIGHT];
+        self.back_buffer_indexes = vec![0; SCREEN_WIDTH * SCREEN_HEIGHT];
+        self.front_buffer = vec![Pixel::OFF; SCREEN_WIDTH * SCREEN_HEIGHT];
+    }
+
+    /// Loads the colors (4 bytes each, ARGB) from index 1; index 0 is transparent. A trailing
+    /// incomplete color is ignored.
+    ///
+    pub fn load_palette(&mut self, colors_data: &[Byte]) {
+        for (color, color_data) in self.palette[1..]
+            .iter_mut()
+            .zip(colors_data.chunks_exact(4))
+        {
+            *color = Color {
+                alpha: color_data[0],
+                red: color_data[1],
+                green: color_data[2],
+                blue: color_data[3],
+            };
+        }
+    }
+
+    /// A value of 0 stands for 256.
+    ///
+    pub fn set_sprite_width(&mut self, value: Byte) {
+        self.sprite_width = if value == 0 { 256 } else { value as usize };
+    }
+
+    /// A value of 0 stands for 256.
+    ///
+    pub fn set_sprite_height(&mut self, value: Byte) {
+        self.sprite_height = if value == 0 { 256 } else { value as usize };
+    }
+
+    pub fn sprite_size(&self) -> usize {
+        self.sprite_width * self.sprite_height
+    }
+
+    pub fn set_screen_alpha(&mut self, value: Byte) {
+        self.screen_alpha = value;
+    }
+
+    /// Returns whether the value is a valid mode; invalid values are ignored.
+    ///
+    pub fn set_blend_mode(&mut self, value: Byte) -> bool {
+        self.blend_mode = match value {
+            0 => BlendMode::Normal,
+            1 => BlendMode::Opacity25,
+            2 => BlendMode::Opacity50,
+            3 => BlendMode::Opacity75,
+            4 => BlendMode::Add,
+            5 => BlendMode::Multiply,
+            _ => return false,
+        };
+
+        true
+    }
+
+    pub fn set_collision_index(&mut self, value: Byte) {
+        self.collision_index = Some(value);
+    }
+
+    /// Sprites are clipped at the screen borders. Returns whether a pixel of the collision color
+    /// index has been overwritten; before the index is set, there are no collisions.
+    ///
+    pub fn draw_sprite(&mut self, sprite_data: &[Byte], top_x: usize, top_y: usize) -> bool {
+        let mut sprite_collided = false;
+
+        for (y_shift, sprite_line) in sprite_data.chunks(self.sprite_width).enumerate() {
+            let pixel_y = top_y + y_shift;
+
+            if pixel_y >= SCREEN_HEIGHT {
+                break;
+            }
+
+            for (x_shift, color_index) in sprite_line.iter().enumerate() {
+                let pixel_x = top_x + x_shift;
+
+                if pixel_x >= SCREEN_WIDTH {
+                    break;
+                }
+
+                if *color_index != 0 {
+                    let pixel_screen_index = SCREEN_WIDTH * pixel_y + pixel_x;
+
+                    if Some(self.back_buffer_indexes[pixel_screen_index]) == self.collision_index {
+                        sprite_collided = true;
+                    }
+
+                    let color = self.palette[*color_index as usize];
+                    let background = &self.back_buffer[pixel_screen_index];
+
+                    self.back_buffer[pixel_screen_index] = self.blend(color, background);
+                    self.back_buffer_indexes[pixel_screen_index] = *color_index;
+                }
+            }
+        }
+
+        sprite_collided
+    }
+
+    /// Shows the back buffer, and clears it.
+    ///
+    pub fn flip(&mut self) {
+        self.front_buffer = std::mem::replace(
+            &mut self.back_buffer,
+            vec![Pixel::OFF; SCREEN_WIDTH * SCREEN_HEIGHT],
+        );
+        self.back_buffer_indexes = vec![0; SCREEN_WIDTH * SCREEN_HEIGHT];
+    }
+
+    /// The front buffer, faded by the screen alpha.
+    ///
+    pub fn frame(&self) -> Vec<Pixel> {
+        let screen_weight = self.screen_alpha as f32 / 255.0;
+
+        self.front_buffer
+            .iter()
+            .map(|pixel| Palette::mix(pixel, &Pixel::OFF, screen_weight))
+            .collect()
+    }
+
+    /// `sound_data` starts with the header; `looping` is false if the sound must be played once.
+    ///
+    /// The data may be truncated (when it extends past the end of the RAM): without a complete
+    /// header, the sound is stopped; otherwise, only the available samples are played.
+    ///
+    pub fn play_sound(&mut self, sound_data: &[Byte], looping: bool) {
+        if sound_data.len() < SOUND_HEADER_SIZE {
+            self.stop_sound();
+            return;
+        }
+
+        let sample_rate = ((sound_data[0] as u32) << 8) | sound_data[1] as u32;
+        let length = ((sound_data[2] as usize) << 16)
+            | ((sound_data[3] as usize) << 8)
+            | sound_data[4] as usize;
+
+        let samples_data = &sound_data[SOUND_HEADER_SIZE..];
+        let samples = samples_data[..length.min(samples_data.len())].to_vec();
+
+        self.audio_stream.clear();
+
+        self.sound = Some(Sound {
+            samples,
+            sample_rate,
+            looping,
+            position: 0.0,
+        });
+    }
+
+    pub fn stop_sound(&mut self) {
+        self.audio_stream.clear();
+        self.sound = None;
+    }
+
+    /// Queues the samples for the given duration, resampled to the audio device frequency.
+    ///
+    pub fn advance_sound(&mut self, output_samples_count: usize) {
+        if let Some(sound) = &mut self.sound {
+            let step = sound.sample_rate as f64 / AUDIO_DEVICE_FREQUENCY as f64;
+            let mut output_samples = Vec::with_capacity(output_samples_count);
+
+            for _ in 0..output_samples_count {
+                if sound.position as usize >= sound.samples.len() {
+                    if sound.looping && !sound.samples.is_empty() {
+                        sound.position -= sound.samples.len() as f64;
+                    } else {
+                        break;
+                    }
+                }
+
+                let sample = sound.samples[sound.position as usize];
+                output_samples.push((sample as i16 - 128) * SOUND_SAMPLE_SCALE);
+
+                sound.position += step;
+            }
+
+            let sound_ended = output_samples.len() < output_samples_count;
+
+            self.audio_stream.queue(&output_samples);
+
+            if sound_ended {
+                self.sound = None;
+            }
+        }
+    }
+
+    fn blend(&self, color: Color, background: &Pixel) -> Pixel {
+        let pixel = Pixel(color.red, color.green, color.blue);
+
+        match self.blend_mode {
+            BlendMode::Normal => Palette::mix(&pixel, background, color.alpha as f32 / 255.0),
+            BlendMode::Opacity25 => Palette::mix(&pixel, background, 0.25),
+            BlendMode::Opacity50 => Palette::mix(&pixel, background, 0.5),
+            BlendMode::Opacity75 => Palette::mix(&pixel, background, 0.75),
+            BlendMode::Add => Pixel(
+                pixel.0.saturating_add(background.0),
+                pixel.1.saturating_add(background.1),
+                pixel.2.saturating_add(background.2),
+            ),
+            BlendMode::Multiply => Pixel(
+                (pixel.0 as u16 * background.0 as u16 / 255) as Byte,
+                (pixel.1 as u16 * background.1 as u16 / 255) as Byte,
+                (pixel.2 as u16 * background.2 as u16 / 255) as Byte,
+            ),
+        }
+    }
+}
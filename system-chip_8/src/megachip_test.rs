@@ -0,0 +1,167 @@
+use super::megachip::{MegaChip, SCREEN_WIDTH};
+use super::test_helpers::{rgb, CapturingAudioStream};
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type Byte = u8;
+
+const OPAQUE_RED: [Byte; 4] = [0xFF, 0xFF, 0x00, 0x00];
+const OPAQUE_GREEN: [Byte; 4] = [0xFF, 0x00, 0xFF, 0x00];
+
+fn megachip() -> (MegaChip, Rc<RefCell<Vec<i16>>>) {
+    let samples = Rc::new(RefCell::new(vec![]));
+    let mut megachip = MegaChip::new(Box::new(CapturingAudioStream(samples.clone())));
+
+    megachip.set_enabled(true);
+
+    (megachip, samples)
+}
+
+fn frame_rgb(megachip: &MegaChip, x: usize, y: usize) -> (u8, u8, u8) {
+    rgb(&megachip.frame()[y * SCREEN_WIDTH + x])
+}
+
+fn sound_data(sample_rate: u32, length: usize, samples: &[Byte]) -> Vec<Byte> {
+    let mut sound_data = vec![
+        (sample_rate >> 8) as Byte,
+        sample_rate as Byte,
+        (length >> 16) as Byte,
+        (length >> 8) as Byte,
+        length as Byte,
+        0,
+    ];
+    sound_data.extend_from_slice(samples);
+    sound_data
+}
+
+#[test]
+fn sprite_size_defaults_to_256_by_256() {
+    let (mut megachip, _) = megachip();
+
+    megachip.load_palette(&OPAQUE_RED);
+
+    assert_eq!(megachip.sprite_size(), 256 * 256);
+
+    // The sprite data is truncated (e.g. at the end of the RAM), so only part of the first line
+    // is drawn.
+    //
+    megachip.draw_sprite(&[1, 0, 1], 10, 20);
+    megachip.flip();
+
+    assert_eq!(frame_rgb(&megachip, 10, 20), (255, 0, 0));
+    assert_eq!(frame_rgb(&megachip, 11, 20), (0, 0, 0));
+    assert_eq!(frame_rgb(&megachip, 12, 20), (255, 0, 0));
+    assert_eq!(frame_rgb(&megachip, 10, 21), (0, 0, 0));
+}
+
+#[test]
+fn sprites_are_drawn_line_by_line_with_the_sprite_width() {
+    let (mut megachip, _) = megachip();
+
+    megachip.load_palette(&OPAQUE_RED);
+    megachip.set_sprite_width(2);
+    megachip.set_sprite_height(2);
+
+    megachip.draw_sprite(&[1, 0, 0, 1], 0, 0);
+    megachip.flip();
+
+    assert_eq!(frame_rgb(&megachip, 0, 0), (255, 0, 0));
+    assert_eq!(frame_rgb(&megachip, 1, 0), (0, 0, 0));
+    assert_eq!(frame_rgb(&megachip, 0, 1), (0, 0, 0));
+    assert_eq!(frame_rgb(&megachip, 1, 1), (255, 0, 0));
+}
+
+#[test]
+fn palette_ignores_a_trailing_incomplete_color() {
+    let (mut megachip, _) = megachip();
+
+    let mut colors_data = OPAQUE_RED.to_vec();
+    colors_data.extend_from_slice(&OPAQUE_GREEN[..3]);
+
+    megachip.load_palette(&colors_data);
+    megachip.set_sprite_width(2);
+
+    megachip.draw_sprite(&[1, 2], 0, 0);
+    megachip.flip();
+
+    assert_eq!(frame_rgb(&megachip, 0, 0), (255, 0, 0));
+    assert_eq!(frame_rgb(&megachip, 1, 0), (0, 0, 0));
+}
+
+#[test]
+fn invalid_blend_mode_is_ignored() {
+    let (mut reference_megachip, _) = megachip();
+    let (mut megachip, _) = megachip();
+
+    for megachip in [&mut megachip, &mut reference_megachip] {
+        megachip.load_palette(&OPAQUE_RED);
+
+        assert!(megachip.set_blend_mode(2)); // 50% opacity
+    }
+
+    assert!(!megachip.set_blend_mode(6));
+
+    for megachip in [&mut megachip, &mut reference_megachip] {
+        megachip.draw_sprite(&[1], 0, 0);
+        megachip.flip();
+    }
+
+    assert_eq!(
+        frame_rgb(&megachip, 0, 0),
+        frame_rgb(&reference_megachip, 0, 0)
+    );
+    assert_ne!(frame_rgb(&megachip, 0, 0), (255, 0, 0));
+}
+
+#[test]
+fn sprites_do_not_collide_before_the_collision_index_is_set() {
+    let (mut megachip, _) = megachip();
+
+    megachip.load_palette(&OPAQUE_RED);
+    megachip.set_sprite_width(1);
+
+    // The back buffer is cleared to index 0.
+    //
+    assert!(!megachip.draw_sprite(&[1], 0, 0));
+
+    megachip.set_collision_index(1);
+
+    assert!(megachip.draw_sprite(&[1], 0, 0));
+    assert!(!megachip.draw_sprite(&[1], 1, 0));
+
+    megachip.set_collision_index(0);
+
+    assert!(megachip.draw_sprite(&[1], 2, 0));
+}
+
+#[test]
+fn sound_is_resampled_to_the_device_frequency() {
+    let (mut megachip, samples) = megachip();
+
+    megachip.play_sound(&sound_data(22050, 2, &[0x80, 0x81]), false);
+    megachip.advance_sound(8);
+
+    assert_eq!(*samples.borrow(), [0, 0, 64, 64]);
+}
+
+#[test]
+fn sound_with_truncated_samples_plays_the_available_ones() {
+    let (mut megachip, samples) = megachip();
+
+    megachip.play_sound(&sound_data(44100, 1000, &[0x81, 0x82, 0x83]), false);
+    megachip.advance_sound(10);
+
+    assert_eq!(*samples.borrow(), [64, 128, 192]);
+}
+
+#[test]
+fn sound_with_truncated_header_stops_the_playback() {
+    let (mut megachip, samples) = megachip();
+
+    megachip.play_sound(&sound_data(44100, 3, &[0x81, 0x82, 0x83]), true);
+    megachip.play_sound(&sound_data(44100, 3, &[])[..4], true);
+    megachip.advance_sound(10);
+
+    assert!(samples.borrow().is_empty());
+}
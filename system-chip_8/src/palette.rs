@@ -0,0 +1,62 @@
+use interfaces_frontend::video::Pixel;
+
+type Byte = u8;
+
+/// Colors used to convert the framebuffer into pixels.
+///
+/// The framebuffer stores, for each position, the bitmask of the planes where the pixel is set; the
+/// bitmask is the index of the palette color. Standard CHIP-8 and Super-CHIP draw only in the first
+/// plane, so they use the first two colors (background/foreground); XO-CHIP can use all the four.
+///
+#[derive(Clone)]
+pub struct Palette {
+    colors: [Pixel; 4],
+}
+
+impl Palette {
+    /// Classic white on black.
+    ///
+    pub fn standard() -> Palette {
+        Palette::new(Pixel::OFF, Pixel::ON)
+    }
+
+    /// Two-colors palette; the XO-CHIP planes 2 and 1+2 get two intermediate tones, so that programs
+    /// using them are still readable.
+    ///
+    pub fn new(background: Pixel, foreground: Pixel) -> Palette {
+        let second_plane = Palette::mix(&background, &foreground, 2.0 / 3.0);
+        let both_planes = Palette::mix(&background, &foreground, 1.0 / 3.0);
+
+        Palette {
+            colors: [background, foreground, second_plane, both_planes],
+        }
+    }
+
+    /// Four-colors (XO-CHIP) palette, in planes bitmask order: background, plane 1, plane 2, planes 1+2.
+    ///
+    pub fn xo_chip(colors: [Pixel; 4]) -> Palette {
+        Palette { colors }
+    }
+
+    pub fn background(&self) -> &Pixel {
+        &self.colors[0]
+    }
+
+    pub fn color(&self, planes: Byte) -> &Pixel {
+        &self.colors[planes as usize & 0b11]
+    }
+
+    /// Linear interpolation between two colors; `weight` is the weight of `color1` (0.0 to 1.0).
+    ///
+    pub(crate) fn mix(color1: &Pixel, color2: &Pixel, weight: f32) -> Pixel {
+        let mix_channel = |channel1: u8, channel2: u8| -> u8 {
+            (channel1 as f32 * weight + channel2 as f32 * (1.0 - weight)).round() as u8
+        };
+
+        Pixel(
+            mix_channel(color1.0, color2.0),
+            mix_channel(color1.1, color2.1),
+            mix_channel(color1.2, color2.2),
+        )
+    }
+}
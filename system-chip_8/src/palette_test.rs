@@ -0,0 +1,49 @@
+use super::palette::Palette;
+use super::test_helpers::rgb;
+use interfaces_frontend::video::Pixel;
+
+#[test]
+fn standard_palette_is_white_on_black() {
+    let palette = Palette::standard();
+
+    assert_eq!(rgb(palette.background()), (0, 0, 0));
+    assert_eq!(rgb(palette.color(0)), (0, 0, 0));
+    assert_eq!(rgb(palette.color(1)), (255, 255, 255));
+}
+
+#[test]
+fn two_colors_palette_derives_the_xo_chip_tones() {
+    let palette = Palette::new(Pixel(0, 0, 0), Pixel(0, 150, 255));
+
+    assert_eq!(rgb(palette.color(0b10)), (0, 50, 85));
+    assert_eq!(rgb(palette.color(0b11)), (0, 100, 170));
+}
+
+#[test]
+fn xo_chip_palette_is_indexed_by_the_planes_bitmask() {
+    let colors = [
+        Pixel(0, 0, 0),
+        Pixel(1, 1, 1),
+        Pixel(2, 2, 2),
+        Pixel(3, 3, 3),
+    ];
+    let palette = Palette::xo_chip(colors);
+
+    for planes in 0..4 {
+        assert_eq!(rgb(palette.color(planes)), (planes, planes, planes));
+    }
+
+    // Only the two planes bits are used.
+    //
+    assert_eq!(rgb(palette.color(0b1101)), (1, 1, 1));
+}
+
+#[test]
+fn mix_weights_the_first_color() {
+    let color1 = Pixel(200, 100, 0);
+    let color2 = Pixel(0, 100, 255);
+
+    assert_eq!(rgb(&Palette::mix(&color1, &color2, 1.0)), (200, 100, 0));
+    assert_eq!(rgb(&Palette::mix(&color1, &color2, 0.0)), (0, 100, 255));
+    assert_eq!(rgb(&Palette::mix(&color1, &color2, 0.5)), (100, 100, 128));
+}
@@ -0,0 +1,158 @@
+use super::chip_8x::ColorMap;
+use super::palette::Palette;
+use interfaces_frontend::video::Pixel;
+
+type Byte = u8;
+
+/// Post-processing applied when converting the framebuffer to pixels.
+///
+/// CHIP-8 programs redraw sprites by XORing them twice, which on modern displays causes heavy
+/// flickering; CRT-era machines didn't suffer (as much) from it, because the phosphors kept glowing
+/// for a short while after the beam passed.
+///
+#[derive(Clone, Copy)]
+pub enum PostProcessing {
+    None,
+    /// Cleared pixels fade out; `decay` is the fraction of brightness kept on each frame (0.0 to 1.0).
+    PhosphorDecay {
+        decay: f32,
+    },
+    /// Each frame is the average of the current and the previous one.
+    FrameBlend,
+}
+
+/// Converts the framebuffer to pixels, applying the palette (or the CHIP-8X color map, when passed)
+/// and the post-processing.
+///
+/// The post-processing state is advanced by `advance_frame()`, which must be invoked at the display
+/// refresh rate; `render()` can instead be invoked at any time (e.g. on every cycle), since it
+/// doesn't change the state. The pixels are rendered to a buffer owned by the renderer, so that no
+/// allocation is performed on each invocation.
+///
+pub(crate) struct Renderer {
+    palette: Palette,
+    post_processing: PostProcessing,
+
+    // Phosphor decay: last color of each pixel that was not background, and its residual brightness.
+    // Frame blend: colors of the previous frame (the brightness is unused).
+    //
+    afterglow: Vec<(Pixel, f32)>,
+
+    pixels: Vec<Pixel>,
+}
+
+impl Renderer {
+    pub fn new(palette: Palette, post_processing: PostProcessing) -> Renderer {
+        Renderer {
+            palette,
+            post_processing,
+            afterglow: vec![],
+            pixels: vec![],
+        }
+    }
+
+    pub fn set_palette(&mut self, palette: Palette) {
+        self.palette = palette;
+        self.reset();
+    }
+
+    pub fn set_post_processing(&mut self, post_processing: PostProcessing) {
+        self.post_processing = post_processing;
+        self.reset();
+    }
+
+    /// Must be invoked when the framebuffer size changes.
+    ///
+    pub fn resize(&mut self, framebuffer_size: usize) {
+        self.afterglow = vec![(self.palette.background().clone(), 0.0); framebuffer_size];
+        self.pixels = Vec::with_capacity(framebuffer_size);
+    }
+
+    pub fn render(&mut self, framebuffer: &[Byte], color_map: Option<&ColorMap>) -> &[Pixel] {
+        let palette = &self.palette;
+        let color = |index: usize, planes: Byte| Renderer::color(palette, color_map, index, planes);
+
+        self.pixels.clear();
+
+        match self.post_processing {
+            PostProcessing::None => self.pixels.extend(
+                framebuffer
+                    .iter()
+                    .enumerate()
+                    .map(|(index, planes)| color(index, *planes).clone()),
+            ),
+            PostProcessing::PhosphorDecay { .. } => self.pixels.extend(
+                framebuffer
+                    .iter()
+                    .zip(self.afterglow.iter())
+                    .enumerate()
+                    .map(|(index, (planes, (glow_color, brightness)))| {
+                        if *planes == 0 {
+                            Palette::mix(glow_color, color(index, 0), *brightness)
+                        } else {
+                            color(index, *planes).clone()
+                        }
+                    }),
+            ),
+            PostProcessing::FrameBlend => self.pixels.extend(
+                framebuffer
+                    .iter()
+                    .zip(self.afterglow.iter())
+                    .enumerate()
+                    .map(|(index, (planes, (previous_color, _)))| {
+                        Palette::mix(color(index, *planes), previous_color, 0.5)
+                    }),
+            ),
+        }
+
+        &self.pixels
+    }
+
+    pub fn advance_frame(&mut self, framebuffer: &[Byte], color_map: Option<&ColorMap>) {
+        let palette = &self.palette;
+        let color = |index: usize, planes: Byte| Renderer::color(palette, color_map, index, planes);
+
+        match self.post_processing {
+            PostProcessing::None => {}
+            PostProcessing::PhosphorDecay { decay } => {
+                for (index, (planes, (glow_color, brightness))) in framebuffer
+                    .iter()
+                    .zip(self.afterglow.iter_mut())
+                    .enumerate()
+                {
+                    if *planes == 0 {
+                        *brightness *= decay;
+                    } else {
+                        *glow_color = color(index, *planes).clone();
+                        *brightness = 1.0;
+                    }
+                }
+            }
+            PostProcessing::FrameBlend => {
+                for (index, (planes, (previous_color, _))) in framebuffer
+                    .iter()
+                    .zip(self.afterglow.iter_mut())
+                    .enumerate()
+                {
+                    *previous_color = color(index, *planes).clone();
+                }
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.resize(self.afterglow.len());
+    }
+
+    fn color<'b>(
+        palette: &'b Palette,
+        color_map: Option<&'b ColorMap>,
+        index: usize,
+        planes: Byte,
+    ) -> &'b Pixel {
+        match color_map {
+            Some(color_map) => color_map.color(index, planes),
+            None => palette.color(planes),
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use super::palette::Palette;
+use super::post_processing::{PostProcessing, Renderer};
+use super::test_helpers::rgbs;
+use interfaces_frontend::video::Pixel;
+
+type Byte = u8;
+
+const FRAMEBUFFER_SIZE: usize = 2;
+
+fn renderer(post_processing: PostProcessing) -> Renderer {
+    let mut renderer = Renderer::new(Palette::standard(), post_processing);
+    renderer.resize(FRAMEBUFFER_SIZE);
+    renderer
+}
+
+#[test]
+fn no_post_processing_renders_the_palette_colors() {
+    let mut renderer = renderer(PostProcessing::None);
+
+    let framebuffer: [Byte; FRAMEBUFFER_SIZE] = [1, 0];
+
+    // The post-processing state is unused.
+    //
+    renderer.advance_frame(&[0, 1], None);
+
+    assert_eq!(
+        rgbs(renderer.render(&framebuffer, None)),
+        [(255, 255, 255), (0, 0, 0)]
+    );
+}
+
+#[test]
+fn phosphor_decay_fades_the_cleared_pixels() {
+    let mut renderer = renderer(PostProcessing::PhosphorDecay { decay: 0.5 });
+
+    renderer.advance_frame(&[1, 1], None);
+
+    // Until the next frame, the glow is at full brightness.
+    //
+    assert_eq!(
+        rgbs(renderer.render(&[0, 1], None)),
+        [(255, 255, 255), (255, 255, 255)]
+    );
+
+    renderer.advance_frame(&[0, 1], None);
+
+    assert_eq!(
+        rgbs(renderer.render(&[0, 1], None)),
+        [(128, 128, 128), (255, 255, 255)]
+    );
+
+    renderer.advance_frame(&[0, 1], None);
+
+    assert_eq!(
+        rgbs(renderer.render(&[0, 1], None)),
+        [(64, 64, 64), (255, 255, 255)]
+    );
+}
+
+#[test]
+fn frame_blend_averages_with_the_previous_frame() {
+    let mut renderer = renderer(PostProcessing::FrameBlend);
+
+    renderer.advance_frame(&[1, 0], None);
+
+    assert_eq!(
+        rgbs(renderer.render(&[0, 0], None)),
+        [(128, 128, 128), (0, 0, 0)]
+    );
+    assert_eq!(
+        rgbs(renderer.render(&[1, 1], None)),
+        [(255, 255, 255), (128, 128, 128)]
+    );
+}
+
+#[test]
+fn changing_the_palette_resets_the_afterglow() {
+    let mut renderer = renderer(PostProcessing::PhosphorDecay { decay: 0.5 });
+
+    renderer.advance_frame(&[1, 1], None);
+    renderer.set_palette(Palette::new(Pixel(0, 0, 64), Pixel::ON));
+
+    assert_eq!(
+        rgbs(renderer.render(&[0, 1], None)),
+        [(0, 0, 64), (255, 255, 255)]
+    );
+}
+
+#[test]
+fn render_reuses_the_pixels_buffer() {
+    let mut renderer = renderer(PostProcessing::None);
+
+    let first_buffer = renderer.render(&[0, 1], None).as_ptr();
+    let second_buffer = renderer.render(&[1, 0], None).as_ptr();
+
+    assert_eq!(first_buffer, second_buffer);
+}
@@ -0,0 +1,19 @@
+use std::io;
+
+type Byte = u8;
+
+/// Persistence of the RPL user flags (`FX75`/`FX85`), provided by the host.
+///
+/// On the HP-48, the flags survive the program termination, and some Super-CHIP games rely on this
+/// (e.g. for storing the high scores); the storage makes them survive the emulator sessions.
+///
+pub trait RplFlagsStorage {
+    /// Returns the stored flags, if any; they may be less or more than the flags number of the
+    /// variant (e.g. when stored by an interpreter supporting a different number of flags).
+    ///
+    fn load(&mut self) -> Option<Vec<Byte>>;
+
+    /// Failures are not fatal: the system logs them, and saves again on the next dump.
+    ///
+    fn save(&mut self, flags: &[Byte]) -> io::Result<()>;
+}
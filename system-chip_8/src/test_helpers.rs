@@ -0,0 +1,30 @@
+// Helpers shared by the unit tests; each test module uses a subset of them.
+
+use interfaces_frontend::{audio::AudioStream, video::Pixel};
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub struct CapturingAudioStream(pub Rc<RefCell<Vec<i16>>>);
+
+impl AudioStream for CapturingAudioStream {
+    fn queue(&mut self, samples: &[i16]) {
+        self.0.borrow_mut().extend_from_slice(samples);
+    }
+
+    fn queued_frames(&self) -> usize {
+        self.0.borrow().len()
+    }
+
+    fn clear(&mut self) {
+        self.0.borrow_mut().clear();
+    }
+}
+
+pub fn rgb(pixel: &Pixel) -> (u8, u8, u8) {
+    (pixel.0, pixel.1, pixel.2)
+}
+
+pub fn rgbs(pixels: &[Pixel]) -> Vec<(u8, u8, u8)> {
+    pixels.iter().map(rgb).collect()
+}
@@ -0,0 +1,54 @@
+/// CHIP-8 variant the program is written for.
+///
+/// Variants can redefine existing instructions (e.g. CHIP-8X `BXYN`), so they must be known before
+/// running; they're decoded before the base set, which acts as fallback.
+///
+/// The extensions that don't conflict with the base instructions (e.g. Super-CHIP hires mode) are
+/// always enabled, so they don't need a dedicated variant.
+///
+/// Hybrid programs (CHIP-8 programs calling CDP1802 machine code routines via `0NNN`) are out of
+/// scope, since they require the CPU emulation; they're run by the COSMAC VIP system, along with
+/// the CHIP-8 interpreter.
+///
+#[derive(Clone, Copy, PartialEq)]
+pub enum Variant {
+    Chip8,
+    /// COSMAC VIP CHIP-8 extension, using the VP-590 color board, VP-580 second keypad and VP-595
+    /// sound board.
+    Chip8X,
+    /// MegaChip-8: 256x192 colored display, 24-bit addressing and sampled sound.
+    MegaChip,
+}
+
+impl Variant {
+    pub(crate) fn programs_location(&self) -> usize {
+        match self {
+            Variant::Chip8 | Variant::MegaChip => 0x200,
+            Variant::Chip8X => 0x300,
+        }
+    }
+
+    pub(crate) fn ram_size(&self) -> usize {
+        match self {
+            Variant::Chip8 | Variant::Chip8X => 4096,
+            Variant::MegaChip => 0x100_0000,
+        }
+    }
+
+    // MegaChip programs draw full frames of (large) sprites, so they require a much faster clock.
+    //
+    pub(crate) fn clock_speed(&self) -> u32 {
+        match self {
+            Variant::Chip8 | Variant::Chip8X => 500,
+            Variant::MegaChip => 60_000,
+        }
+    }
+
+    // Super-CHIP supports 8 flags; XO-CHIP, which extends them to 16, is not supported.
+    //
+    pub(crate) fn rpl_flags_count(&self) -> usize {
+        match self {
+            Variant::Chip8 | Variant::Chip8X | Variant::MegaChip => 8,
+        }
+    }
+}
@@ -0,0 +1,362 @@
+// For clarity, any register reference is upper case.
+#![allow(non_snake_case)]
+
+type Byte = u8;
+type Word = u16;
+
+/// Everything the CPU is connected to: memory, I/O ports, and external flag lines.
+///
+/// The CPU doesn't own the memory, since the DMA and the memory mapping are handled by the system.
+///
+pub(crate) trait Bus {
+    fn read(&mut self, address: Word) -> Byte;
+    fn write(&mut self, address: Word, value: Byte);
+
+    /// `port` is the value of the N lines (1 to 7).
+    ///
+    fn input(&mut self, port: Byte) -> Byte;
+    fn output(&mut self, port: Byte, value: Byte);
+
+    /// `line` is the external flag number (1 to 4).
+    ///
+    fn external_flag(&mut self, line: Byte) -> bool;
+}
+
+/// RCA CDP1802 (COSMAC).
+///
+/// Timing is expressed in machine cycles (8 clock cycles each); all the instructions take 2 of
+/// them, with the exception of the long branches/skips (and NOP), which take 3.
+///
+/// DMA and interrupt requests are serviced between instructions, which is where the real CPU
+/// services them as well.
+///
+pub(crate) struct Cdp1802 {
+    pub(crate) R: [Word; 16],
+    pub(crate) P: usize,
+    pub(crate) X: usize,
+    pub(crate) D: Byte,
+    pub(crate) DF: bool,
+    pub(crate) T: Byte,
+    pub(crate) IE: bool,
+    pub(crate) Q: bool,
+
+    idle: bool,
+}
+
+impl Cdp1802 {
+    /// State after the CLEAR (reset) signal: P, X and R0 are zeroed, and interrupts are enabled.
+    /// The other registers are undefined on the real hardware; here they're zeroed as well.
+    ///
+    pub fn new() -> Cdp1802 {
+        Cdp1802 {
+            R: [0; 16],
+            P: 0,
+            X: 0,
+            D: 0,
+            DF: false,
+            T: 0,
+            IE: true,
+            Q: false,
+
+            idle: false,
+        }
+    }
+
+    pub fn program_counter(&self) -> Word {
+        self.R[self.P]
+    }
+
+    /// Executes one instruction; returns the number of machine cycles spent.
+    ///
+    /// While idle (IDL), no instruction is executed, and a single machine cycle is spent, so that
+    /// the system can keep running until a DMA or interrupt request arrives.
+    ///
+    pub fn execute<B: Bus>(&mut self, bus: &mut B) -> u32 {
+        if self.idle {
+            return 1;
+        }
+
+        let instruction = self.fetch_immediate(bus);
+
+        let I = instruction >> 4;
+        let N = (instruction & 0x0F) as usize;
+
+        match (I, N) {
+            (0x0, 0) => {
+                self.idle = true;
+            }
+            (0x0, _) => {
+                self.D = bus.read(self.R[N]);
+            }
+            (0x1, _) => {
+                self.R[N] = self.R[N].wrapping_add(1);
+            }
+            (0x2, _) => {
+                self.R[N] = self.R[N].wrapping_sub(1);
+            }
+            (0x3, _) => {
+                // The branch is within the page of the immediate byte (not of the instruction).
+                //
+                let condition_matching = self.branch_condition(N, bus);
+                let address = self.R[self.P];
+                let target = self.fetch_immediate(bus);
+
+                if condition_matching {
+                    self.R[self.P] = (address & 0xFF00) | target as Word;
+                }
+            }
+            (0x4, _) => {
+                self.D = bus.read(self.R[N]);
+                self.R[N] = self.R[N].wrapping_add(1);
+            }
+            (0x5, _) => {
+                bus.write(self.R[N], self.D);
+            }
+            (0x6, 0) => {
+                self.R[self.X] = self.R[self.X].wrapping_add(1);
+            }
+            (0x6, 1..=7) => {
+                let value = bus.read(self.R[self.X]);
+                bus.output(N as Byte, value);
+                self.R[self.X] = self.R[self.X].wrapping_add(1);
+            }
+            (0x6, 8) => {
+                panic!("Unsupported instruction: 68 (CDP1804/5 extended instructions)");
+            }
+            (0x6, _) => {
+                let value = bus.input((N - 8) as Byte);
+                bus.write(self.R[self.X], value);
+                self.D = value;
+            }
+            (0x7, 0) | (0x7, 1) => {
+                let value = bus.read(self.R[self.X]);
+                self.R[self.X] = self.R[self.X].wrapping_add(1);
+                self.X = (value >> 4) as usize;
+                self.P = (value & 0x0F) as usize;
+                self.IE = N == 0;
+            }
+            (0x7, 2) => {
+                self.D = bus.read(self.R[self.X]);
+                self.R[self.X] = self.R[self.X].wrapping_add(1);
+            }
+            (0x7, 3) => {
+                bus.write(self.R[self.X], self.D);
+                self.R[self.X] = self.R[self.X].wrapping_sub(1);
+            }
+            (0x7, 4) => {
+                let operand = bus.read(self.R[self.X]);
+                self.add(operand, self.DF);
+            }
+            (0x7, 5) => {
+                let operand = bus.read(self.R[self.X]);
+                self.subtract(operand, self.D, self.DF);
+            }
+            (0x7, 6) => {
+                self.shift_right(self.DF);
+            }
+            (0x7, 7) => {
+                let operand = bus.read(self.R[self.X]);
+                self.subtract(self.D, operand, self.DF);
+            }
+            (0x7, 8) => {
+                bus.write(self.R[self.X], self.T);
+            }
+            (0x7, 9) => {
+                self.T = ((self.X << 4) | self.P) as Byte;
+                bus.write(self.R[2], self.T);
+                self.X = self.P;
+                self.R[2] = self.R[2].wrapping_sub(1);
+            }
+            (0x7, 0xA) | (0x7, 0xB) => {
+                self.Q = N == 0xB;
+            }
+            (0x7, 0xC) => {
+                let operand = self.fetch_immediate(bus);
+                self.add(operand, self.DF);
+            }
+            (0x7, 0xD) => {
+                let operand = self.fetch_immediate(bus);
+                self.subtract(operand, self.D, self.DF);
+            }
+            (0x7, 0xE) => {
+                self.shift_left(self.DF);
+            }
+            (0x7, 0xF) => {
+                let operand = self.fetch_immediate(bus);
+                self.subtract(self.D, operand, self.DF);
+            }
+            (0x8, _) => {
+                self.D = self.R[N] as Byte;
+            }
+            (0x9, _) => {
+                self.D = (self.R[N] >> 8) as Byte;
+            }
+            (0xA, _) => {
+                self.R[N] = (self.R[N] & 0xFF00) | self.D as Word;
+            }
+            (0xB, _) => {
+                self.R[N] = (self.R[N] & 0x00FF) | ((self.D as Word) << 8);
+            }
+            (0xC, _) => {
+                self.execute_long_branch_or_skip(N, bus);
+                return 3;
+            }
+            (0xD, _) => {
+                self.P = N;
+            }
+            (0xE, _) => {
+                self.X = N;
+            }
+            (0xF, 0x0) => {
+                self.D = bus.read(self.R[self.X]);
+            }
+            (0xF, 0x6) => {
+                self.shift_right(false);
+            }
+            (0xF, 0xE) => {
+                self.shift_left(false);
+            }
+            (0xF, 0x8) => {
+                self.D = self.fetch_immediate(bus);
+            }
+            (0xF, _) => {
+                // The immediate variants (F9-FF) have the same low three bits as the memory ones
+                // (F1-F7).
+                //
+                let operand = if N & 0x8 != 0 {
+                    self.fetch_immediate(bus)
+                } else {
+                    bus.read(self.R[self.X])
+                };
+
+                match N & 0x7 {
+                    1 => self.D |= operand,
+                    2 => self.D &= operand,
+                    3 => self.D ^= operand,
+                    4 => self.add(operand, false),
+                    5 => self.subtract(operand, self.D, true),
+                    7 => self.subtract(self.D, operand, true),
+                    _ => unreachable!(),
+                }
+            }
+            _ => unreachable!(),
+        }
+
+        2
+    }
+
+    /// DMA OUT cycle: returns the byte addressed by R0, which is then incremented.
+    ///
+    pub fn dma_out<B: Bus>(&mut self, bus: &mut B) -> Byte {
+        self.idle = false;
+
+        let value = bus.read(self.R[0]);
+        self.R[0] = self.R[0].wrapping_add(1);
+        value
+    }
+
+    /// Services an interrupt request, if enabled; returns whether it has been serviced.
+    ///
+    pub fn interrupt(&mut self) -> bool {
+        if self.IE {
+            self.idle = false;
+
+            self.T = ((self.X << 4) | self.P) as Byte;
+            self.P = 1;
+            self.X = 2;
+            self.IE = false;
+
+            true
+        } else {
+            false
+        }
+    }
+
+    // HELPERS /////////////////////////////////////////////////////////////////////////////////////
+
+    fn fetch_immediate<B: Bus>(&mut self, bus: &mut B) -> Byte {
+        let value = bus.read(self.R[self.P]);
+        self.R[self.P] = self.R[self.P].wrapping_add(1);
+        value
+    }
+
+    // Shared by the short (3N) and long (CN) branches; the low three bits select the condition, and
+    // bit 3 negates it.
+    //
+    // For the long skips (C5-C7, CC-CF), the logic is different, and handled by the caller.
+    //
+    fn branch_condition<B: Bus>(&mut self, N: usize, bus: &mut B) -> bool {
+        let condition = match N & 0x7 {
+            0 => true,
+            1 => self.Q,
+            2 => self.D == 0,
+            3 => self.DF,
+            line => bus.external_flag(line as Byte - 3),
+        };
+
+        condition ^ (N & 0x8 != 0)
+    }
+
+    fn execute_long_branch_or_skip<B: Bus>(&mut self, N: usize, bus: &mut B) {
+        let skip_condition = match N {
+            0x4 => Some(false), // NOP
+            0x5 => Some(!self.Q),
+            0x6 => Some(self.D != 0),
+            0x7 => Some(!self.DF),
+            0xC => Some(self.IE),
+            0xD => Some(self.Q),
+            0xE => Some(self.D == 0),
+            0xF => Some(self.DF),
+            _ => None,
+        };
+
+        if let Some(condition_matching) = skip_condition {
+            if condition_matching {
+                self.R[self.P] = self.R[self.P].wrapping_add(2);
+            }
+        } else {
+            // C8 (long skip) is the negated unconditional branch, so it fits the branch logic.
+            //
+            let condition_matching = self.branch_condition(N, bus);
+
+            if condition_matching {
+                let address_high = self.fetch_immediate(bus) as Word;
+                let address_low = self.fetch_immediate(bus) as Word;
+
+                self.R[self.P] = (address_high << 8) | address_low;
+            } else {
+                self.R[self.P] = self.R[self.P].wrapping_add(2);
+            }
+        }
+    }
+
+    fn add(&mut self, operand: Byte, carry_in: bool) {
+        let result = self.D as u16 + operand as u16 + carry_in as u16;
+
+        self.D = result as Byte;
+        self.DF = result > 0xFF;
+    }
+
+    // DF is set when there is no borrow; `no_borrow_in` follows the same convention.
+    //
+    fn subtract(&mut self, minuend: Byte, subtrahend: Byte, no_borrow_in: bool) {
+        let result = minuend as i16 - subtrahend as i16 - if no_borrow_in { 0 } else { 1 };
+
+        self.D = result as Byte;
+        self.DF = result >= 0;
+    }
+
+    fn shift_right(&mut self, carry_in: bool) {
+        let carry_out = self.D & 0b0000_0001 != 0;
+
+        self.D = (self.D >> 1) | if carry_in { 0b1000_0000 } else { 0 };
+        self.DF = carry_out;
+    }
+
+    fn shift_left(&mut self, carry_in: bool) {
+        let carry_out = self.D & 0b1000_0000 != 0;
+
+        self.D = (self.D << 1) | carry_in as Byte;
+        self.DF = carry_out;
+    }
+}
@@ -0,0 +1,415 @@
+// For clarity, any register reference is upper case.
+#![allow(non_snake_case)]
+
+use super::cdp1802::{Bus, Cdp1802};
+
+type Byte = u8;
+type Word = u16;
+
+const PROGRAM_ADDRESS: Word = 0x0100;
+const DATA_ADDRESS: Word = 0x0200;
+const STACK_ADDRESS: Word = 0x02FF;
+
+struct TestBus {
+    memory: Vec<Byte>,
+    external_flags: [bool; 4],
+    outputs: Vec<(Byte, Byte)>, // (port, value)
+}
+
+impl Bus for TestBus {
+    fn read(&mut self, address: Word) -> Byte {
+        self.memory[address as usize]
+    }
+
+    fn write(&mut self, address: Word, value: Byte) {
+        self.memory[address as usize] = value;
+    }
+
+    fn input(&mut self, port: Byte) -> Byte {
+        0x10 + port
+    }
+
+    fn output(&mut self, port: Byte, value: Byte) {
+        self.outputs.push((port, value));
+    }
+
+    fn external_flag(&mut self, line: Byte) -> bool {
+        self.external_flags[line as usize - 1]
+    }
+}
+
+// The program is placed at PROGRAM_ADDRESS, with R3 as program counter; R2 is the stack pointer.
+//
+fn setup(program: &[Byte]) -> (Cdp1802, TestBus) {
+    let mut bus = TestBus {
+        memory: vec![0; 0x10000],
+        external_flags: [false; 4],
+        outputs: vec![],
+    };
+
+    let program_address = PROGRAM_ADDRESS as usize;
+    bus.memory[program_address..program_address + program.len()].copy_from_slice(program);
+
+    let mut cpu = Cdp1802::new();
+    cpu.P = 3;
+    cpu.R[3] = PROGRAM_ADDRESS;
+    cpu.R[2] = STACK_ADDRESS;
+
+    (cpu, bus)
+}
+
+// Executes an arithmetic instruction (operating on M(R(X)) or on the immediate byte), with the
+// given D, memory operand and DF; returns D and DF.
+//
+fn execute_arithmetic(instruction: Byte, D: Byte, operand: Byte, DF: bool) -> (Byte, bool) {
+    let (mut cpu, mut bus) = setup(&[instruction, operand]);
+
+    cpu.X = 4;
+    cpu.R[4] = DATA_ADDRESS;
+    bus.memory[DATA_ADDRESS as usize] = operand;
+
+    cpu.D = D;
+    cpu.DF = DF;
+
+    cpu.execute(&mut bus);
+
+    (cpu.D, cpu.DF)
+}
+
+// BRANCHES/SKIPS //////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn short_branch_is_taken_within_the_page() {
+    let (mut cpu, mut bus) = setup(&[0x30, 0x42]); // BR 0x42
+
+    assert_eq!(cpu.execute(&mut bus), 2);
+    assert_eq!(cpu.R[3], 0x0142);
+}
+
+#[test]
+fn short_branch_uses_the_page_of_the_immediate_byte() {
+    let (mut cpu, mut bus) = setup(&[]);
+
+    cpu.R[3] = 0x01FF;
+    bus.memory[0x01FF] = 0x30; // BR 0x42
+    bus.memory[0x0200] = 0x42;
+
+    cpu.execute(&mut bus);
+
+    assert_eq!(cpu.R[3], 0x0242);
+}
+
+#[test]
+fn short_branch_not_taken_skips_the_immediate_byte() {
+    let (mut cpu, mut bus) = setup(&[0x3A, 0x42]); // BNZ 0x42
+
+    cpu.D = 0;
+    cpu.execute(&mut bus);
+
+    assert_eq!(cpu.R[3], 0x0102);
+}
+
+#[test]
+fn short_branches_test_the_conditions() {
+    // (instruction, D, DF, Q, external flags, taken)
+    //
+    let cases = [
+        (0x31, 0x00, false, true, [false; 4], true),   // BQ
+        (0x39, 0x00, false, true, [false; 4], false),  // BNQ
+        (0x32, 0x00, false, false, [false; 4], true),  // BZ
+        (0x32, 0x01, false, false, [false; 4], false), // BZ
+        (0x33, 0x00, true, false, [false; 4], true),   // BDF
+        (0x3B, 0x00, true, false, [false; 4], false),  // BNF
+        (0x34, 0x00, false, false, [true, false, false, false], true), // B1
+        (0x37, 0x00, false, false, [false, false, false, true], true), // B4
+        (0x3F, 0x00, false, false, [false, false, false, true], false), // BN4
+        (0x38, 0x00, false, false, [false; 4], false), // SKP
+    ];
+
+    for &(instruction, D, DF, Q, external_flags, taken) in cases.iter() {
+        let (mut cpu, mut bus) = setup(&[instruction, 0x42]);
+
+        cpu.D = D;
+        cpu.DF = DF;
+        cpu.Q = Q;
+        bus.external_flags = external_flags;
+
+        cpu.execute(&mut bus);
+
+        let expected_address = if taken { 0x0142 } else { 0x0102 };
+
+        assert_eq!(
+            cpu.R[3], expected_address,
+            "Instruction {:02X}",
+            instruction
+        );
+    }
+}
+
+#[test]
+fn long_branch_takes_three_machine_cycles() {
+    let (mut cpu, mut bus) = setup(&[0xC0, 0x12, 0x34]); // LBR 0x1234
+
+    assert_eq!(cpu.execute(&mut bus), 3);
+    assert_eq!(cpu.R[3], 0x1234);
+}
+
+#[test]
+fn long_branch_not_taken_skips_the_address() {
+    let (mut cpu, mut bus) = setup(&[0xC2, 0x12, 0x34]); // LBZ 0x1234
+
+    cpu.D = 1;
+
+    assert_eq!(cpu.execute(&mut bus), 3);
+    assert_eq!(cpu.R[3], 0x0103);
+}
+
+#[test]
+fn long_skips_test_the_conditions() {
+    // (instruction, D, DF, Q, IE, skipped)
+    //
+    let cases = [
+        (0xC4, 0x00, false, false, false, false), // NOP
+        (0xC8, 0x00, false, false, false, true),  // LSKP
+        (0xC5, 0x00, false, false, false, true),  // LSNQ
+        (0xCD, 0x00, false, false, false, false), // LSQ
+        (0xC6, 0x01, false, false, false, true),  // LSNZ
+        (0xCE, 0x01, false, false, false, false), // LSZ
+        (0xC7, 0x00, true, false, false, false),  // LSNF
+        (0xCF, 0x00, true, false, false, true),   // LSDF
+        (0xCC, 0x00, false, false, true, true),   // LSIE
+    ];
+
+    for &(instruction, D, DF, Q, IE, skipped) in cases.iter() {
+        let (mut cpu, mut bus) = setup(&[instruction]);
+
+        cpu.D = D;
+        cpu.DF = DF;
+        cpu.Q = Q;
+        cpu.IE = IE;
+
+        assert_eq!(cpu.execute(&mut bus), 3);
+
+        let expected_address = if skipped { 0x0103 } else { 0x0101 };
+
+        assert_eq!(
+            cpu.R[3], expected_address,
+            "Instruction {:02X}",
+            instruction
+        );
+    }
+}
+
+// ARITHMETIC //////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn add_sets_DF_on_carry() {
+    assert_eq!(execute_arithmetic(0xF4, 0x80, 0x7F, true), (0xFF, false)); // ADD
+    assert_eq!(execute_arithmetic(0xF4, 0x80, 0x81, false), (0x01, true)); // ADD
+    assert_eq!(execute_arithmetic(0xFC, 0x80, 0x81, false), (0x01, true)); // ADI
+}
+
+#[test]
+fn add_with_carry_adds_DF() {
+    assert_eq!(execute_arithmetic(0x74, 0x80, 0x7F, true), (0x00, true)); // ADC
+    assert_eq!(execute_arithmetic(0x7C, 0x10, 0x20, true), (0x31, false)); // ADCI
+}
+
+#[test]
+fn subtractions_set_DF_when_there_is_no_borrow() {
+    // SD: operand - D; SM: D - operand.
+    //
+    assert_eq!(execute_arithmetic(0xF5, 0x10, 0x30, false), (0x20, true)); // SD
+    assert_eq!(execute_arithmetic(0xF5, 0x30, 0x10, true), (0xE0, false)); // SD
+    assert_eq!(execute_arithmetic(0xFD, 0x10, 0x10, false), (0x00, true)); // SDI
+    assert_eq!(execute_arithmetic(0xF7, 0x30, 0x10, false), (0x20, true)); // SM
+    assert_eq!(execute_arithmetic(0xF7, 0x10, 0x30, true), (0xE0, false)); // SM
+    assert_eq!(execute_arithmetic(0xFF, 0x10, 0x10, false), (0x00, true)); // SMI
+}
+
+#[test]
+fn subtractions_with_borrow_subtract_the_negated_DF() {
+    assert_eq!(execute_arithmetic(0x75, 0x10, 0x30, false), (0x1F, true)); // SDB
+    assert_eq!(execute_arithmetic(0x75, 0x10, 0x30, true), (0x20, true)); // SDB
+    assert_eq!(execute_arithmetic(0x77, 0x10, 0x10, false), (0xFF, false)); // SMB
+    assert_eq!(execute_arithmetic(0x7F, 0x10, 0x10, true), (0x00, true)); // SMBI
+}
+
+#[test]
+fn shifts_move_the_bit_out_to_DF() {
+    let cases = [
+        (0xF6, 0b1000_0001, true, 0b0100_0000, true),  // SHR
+        (0x76, 0b1000_0000, true, 0b1100_0000, false), // SHRC
+        (0xFE, 0b1000_0001, true, 0b0000_0010, true),  // SHL
+        (0x7E, 0b0000_0001, true, 0b0000_0011, false), // SHLC
+    ];
+
+    for &(instruction, D, DF, expected_D, expected_DF) in cases.iter() {
+        let (mut cpu, mut bus) = setup(&[instruction]);
+
+        cpu.D = D;
+        cpu.DF = DF;
+        cpu.execute(&mut bus);
+
+        assert_eq!(
+            (cpu.D, cpu.DF),
+            (expected_D, expected_DF),
+            "Instruction {:02X}",
+            instruction
+        );
+    }
+}
+
+// MARK/RET/DIS, AND INTERRUPTS ////////////////////////////////////////////////////////////////////
+
+#[test]
+fn mark_saves_X_and_P_on_the_stack() {
+    let (mut cpu, mut bus) = setup(&[0x79]); // MARK
+
+    cpu.X = 5;
+    cpu.execute(&mut bus);
+
+    assert_eq!(cpu.T, 0x53);
+    assert_eq!(bus.memory[STACK_ADDRESS as usize], 0x53);
+    assert_eq!(cpu.R[2], STACK_ADDRESS - 1);
+    assert_eq!(cpu.X, 3);
+    assert_eq!(cpu.P, 3);
+}
+
+#[test]
+fn return_restores_X_and_P_and_enables_the_interrupts() {
+    let (mut cpu, mut bus) = setup(&[0x70]); // RET
+
+    cpu.X = 2;
+    cpu.IE = false;
+    bus.memory[STACK_ADDRESS as usize] = 0x54;
+
+    cpu.execute(&mut bus);
+
+    assert_eq!((cpu.X, cpu.P), (5, 4));
+    assert_eq!(cpu.R[2], STACK_ADDRESS + 1);
+    assert!(cpu.IE);
+}
+
+#[test]
+fn disable_restores_X_and_P_and_disables_the_interrupts() {
+    let (mut cpu, mut bus) = setup(&[0x71]); // DIS
+
+    cpu.X = 2;
+    bus.memory[STACK_ADDRESS as usize] = 0x54;
+
+    cpu.execute(&mut bus);
+
+    assert_eq!((cpu.X, cpu.P), (5, 4));
+    assert_eq!(cpu.R[2], STACK_ADDRESS + 1);
+    assert!(!cpu.IE);
+}
+
+#[test]
+fn interrupt_entry_saves_X_and_P_and_switches_to_R1() {
+    let (mut cpu, _) = setup(&[]);
+
+    cpu.X = 5;
+
+    assert!(cpu.interrupt());
+
+    assert_eq!(cpu.T, 0x53);
+    assert_eq!((cpu.X, cpu.P), (2, 1));
+    assert!(!cpu.IE);
+
+    // Interrupts are now disabled.
+    //
+    assert!(!cpu.interrupt());
+}
+
+#[test]
+fn interrupt_routine_returns_via_sav_and_ret() {
+    // The routine, at R1, saves T (SAV), then returns (RET) to the interrupted code.
+    //
+    let (mut cpu, mut bus) = setup(&[]);
+
+    cpu.X = 5;
+    cpu.R[1] = DATA_ADDRESS;
+    bus.memory[DATA_ADDRESS as usize..DATA_ADDRESS as usize + 3].copy_from_slice(&[
+        0x22, // DEC R2
+        0x78, // SAV
+        0x70, // RET
+    ]);
+
+    cpu.interrupt();
+
+    for _ in 0..3 {
+        cpu.execute(&mut bus);
+    }
+
+    assert_eq!((cpu.X, cpu.P), (5, 3));
+    assert_eq!(cpu.R[2], STACK_ADDRESS);
+    assert_eq!(cpu.program_counter(), PROGRAM_ADDRESS);
+    assert!(cpu.IE);
+}
+
+#[test]
+fn interrupt_wakes_up_the_cpu_from_idle() {
+    let (mut cpu, mut bus) = setup(&[0x00]); // IDL
+
+    cpu.execute(&mut bus);
+
+    assert_eq!(cpu.execute(&mut bus), 1);
+    assert_eq!(cpu.program_counter(), PROGRAM_ADDRESS + 1);
+
+    cpu.interrupt();
+    cpu.R[1] = DATA_ADDRESS;
+
+    assert_eq!(cpu.execute(&mut bus), 2);
+    assert_eq!(cpu.program_counter(), DATA_ADDRESS + 1);
+}
+
+// DMA/IO //////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn dma_out_reads_at_R0_and_increments_it() {
+    let (mut cpu, mut bus) = setup(&[]);
+
+    cpu.R[0] = DATA_ADDRESS;
+    bus.memory[DATA_ADDRESS as usize] = 0xAA;
+    bus.memory[DATA_ADDRESS as usize + 1] = 0x55;
+
+    assert_eq!(cpu.dma_out(&mut bus), 0xAA);
+    assert_eq!(cpu.dma_out(&mut bus), 0x55);
+    assert_eq!(cpu.R[0], DATA_ADDRESS + 2);
+}
+
+#[test]
+fn dma_out_wakes_up_the_cpu_from_idle() {
+    let (mut cpu, mut bus) = setup(&[0x00, 0xC4]); // IDL; NOP
+
+    cpu.execute(&mut bus);
+
+    assert_eq!(cpu.execute(&mut bus), 1);
+
+    cpu.dma_out(&mut bus);
+
+    assert_eq!(cpu.execute(&mut bus), 3);
+}
+
+#[test]
+fn output_and_input_use_the_N_lines() {
+    let (mut cpu, mut bus) = setup(&[
+        0x62, // OUT 2
+        0x6B, // INP 3
+    ]);
+
+    cpu.X = 4;
+    cpu.R[4] = DATA_ADDRESS;
+    bus.memory[DATA_ADDRESS as usize] = 0x42;
+
+    cpu.execute(&mut bus);
+
+    assert_eq!(bus.outputs, [(2, 0x42)]);
+    assert_eq!(cpu.R[4], DATA_ADDRESS + 1);
+
+    cpu.execute(&mut bus);
+
+    assert_eq!(cpu.D, 0x13);
+    assert_eq!(bus.memory[DATA_ADDRESS as usize + 1], 0x13);
+}
@@ -0,0 +1,115 @@
+type Byte = u8;
+
+// All the timings are in CPU machine cycles; the CDP1861 is clocked by the CPU, so the two are
+// always in sync.
+//
+const CYCLES_PER_LINE: u32 = 14;
+const LINES_PER_FRAME: u32 = 262;
+
+pub(crate) const DISPLAY_WIDTH: usize = 64;
+pub(crate) const DISPLAY_HEIGHT: usize = 128;
+
+const DISPLAY_START_LINE: u32 = 80;
+const DISPLAY_END_LINE: u32 = DISPLAY_START_LINE + DISPLAY_HEIGHT as u32;
+
+// The interrupt is raised two lines before the display, so that the interrupt routine can set up
+// R0; EF1 is asserted in the four lines preceding the start and the end of the display.
+//
+const INTERRUPT_START_LINE: u32 = DISPLAY_START_LINE - 2;
+const EF1_LINES_BEFORE: u32 = 4;
+
+pub(crate) const DMA_BYTES_PER_LINE: usize = DISPLAY_WIDTH / 8;
+
+/// RCA CDP1861 (Pixie) video display controller.
+///
+/// The chip doesn't have video memory: on each display line, it requests 8 DMA OUT cycles to the
+/// CPU, and shifts out the bits of the bytes received, which are the pixels of the line.
+/// The display must be enabled by the program (via I/O), otherwise no DMA/interrupt is requested.
+///
+pub(crate) struct Cdp1861 {
+    enabled: bool,
+
+    line: u32,
+    line_cycle: u32,
+    line_dma_done: bool,
+
+    // One byte per 8 pixels; MSB is the leftmost.
+    //
+    framebuffer: [Byte; DMA_BYTES_PER_LINE * DISPLAY_HEIGHT],
+}
+
+impl Cdp1861 {
+    pub fn new() -> Cdp1861 {
+        Cdp1861 {
+            enabled: false,
+
+            line: 0,
+            line_cycle: 0,
+            line_dma_done: false,
+
+            framebuffer: [0; DMA_BYTES_PER_LINE * DISPLAY_HEIGHT],
+        }
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Advances the beam; returns true if a frame has been completed.
+    ///
+    pub fn tick(&mut self, cycles: u32) -> bool {
+        let mut frame_completed = false;
+
+        self.line_cycle += cycles;
+
+        while self.line_cycle >= CYCLES_PER_LINE {
+            self.line_cycle -= CYCLES_PER_LINE;
+            self.line += 1;
+            self.line_dma_done = false;
+
+            if self.line == LINES_PER_FRAME {
+                self.line = 0;
+                frame_completed = true;
+            }
+        }
+
+        frame_completed
+    }
+
+    pub fn dma_requested(&self) -> bool {
+        self.enabled && self.is_display_line() && !self.line_dma_done
+    }
+
+    /// Stores the bytes of the current line, transferred via DMA.
+    ///
+    pub fn dma_out(&mut self, line_bytes: &[Byte; DMA_BYTES_PER_LINE]) {
+        let line_start = (self.line - DISPLAY_START_LINE) as usize * DMA_BYTES_PER_LINE;
+
+        self.framebuffer[line_start..line_start + DMA_BYTES_PER_LINE].copy_from_slice(line_bytes);
+        self.line_dma_done = true;
+    }
+
+    pub fn interrupt_requested(&self) -> bool {
+        self.enabled && (INTERRUPT_START_LINE..DISPLAY_START_LINE).contains(&self.line)
+    }
+
+    pub fn external_flag_1(&self) -> bool {
+        (DISPLAY_START_LINE - EF1_LINES_BEFORE..DISPLAY_START_LINE).contains(&self.line)
+            || (DISPLAY_END_LINE - EF1_LINES_BEFORE..DISPLAY_END_LINE).contains(&self.line)
+    }
+
+    /// Returns the frame pixels (true = lit), row-major. When the display is disabled, the frame
+    /// is blank.
+    ///
+    pub fn frame(&self) -> impl Iterator<Item = bool> + '_ {
+        self.framebuffer.iter().flat_map(move |byte| {
+            (0..8)
+                .rev()
+                .map(move |bit| self.enabled && byte & (1 << bit) != 0)
+        })
+    }
+
+    fn is_display_line(&self) -> bool {
+        (DISPLAY_START_LINE..DISPLAY_END_LINE).contains(&self.line)
+    }
+}
@@ -0,0 +1,365 @@
+// For clarity, any register reference is upper case.
+#![allow(non_snake_case)]
+
+use super::cdp1802::{Bus, Cdp1802};
+use super::cdp1861::{Cdp1861, DISPLAY_HEIGHT, DISPLAY_WIDTH, DMA_BYTES_PER_LINE};
+use interfaces_frontend::{
+    audio::AudioDevice, audio::AUDIO_DEVICE_FREQUENCY, events::EventCode, logging::Logger,
+    video::Pixel, IoFrontend,
+};
+
+use std::f64::consts::PI;
+use std::thread;
+use std::time::{Duration, Instant};
+
+type Byte = u8;
+type Word = u16;
+
+// Simplification: the below are words, however, since they're used in indexing, they're defined
+// as usize (as in the CHIP-8 system).
+//
+// The RAM is mirrored in the lower half of the address space, and the ROM in the upper half.
+//
+const RAM_SIZE: usize = 4096;
+const ROM_SIZE: usize = 512;
+const INTERPRETER_LOCATION: usize = 0;
+const PROGRAMS_LOCATION: usize = 0x200;
+
+// The CHIP-8 interpreter reserves the top of the RAM for the stack, variables and display buffer.
+//
+const INTERPRETER_RESERVED_SIZE: usize = 0x160;
+
+const ROM_ADDRESS_BIT: Word = 0x8000;
+
+// 3.52128 MHz crystal, divided by 2 for the CPU clock; one machine cycle is 8 clock cycles. The
+// CDP1861 frame (262 lines of 14 machine cycles) takes (almost exactly) 1/60th of second.
+//
+const MACHINE_CYCLES_SPEED: u32 = 3_521_280 / 2 / 8; // Herz
+const FRAME_MACHINE_CYCLES: u32 = 262 * 14;
+
+const TONE_FREQUENCY: f64 = 1400.0; // Herz; approximation of the VIP tone oscillator
+const AMPLITUDE: i16 = i16::MAX / 16; // Volume (i16::MAX = max)
+
+// The CDP1861 pixels are much wider than tall; they're repeated horizontally, so that frontends
+// can use square pixels (and the aspect ratio is the same as the CHIP-8 system one).
+//
+pub(crate) const PIXEL_WIDTH: usize = 4;
+pub(crate) const SCREEN_WIDTH: usize = DISPLAY_WIDTH * PIXEL_WIDTH;
+const SCREEN_HEIGHT: usize = DISPLAY_HEIGHT;
+
+// I/O ports (N lines).
+//
+const DISPLAY_PORT: Byte = 1; // INP: display on; OUT: display off
+const KEYPAD_PORT: Byte = 2; // OUT: keypad latch (low nibble)
+
+// External flags.
+//
+const DISPLAY_STATUS_FLAG: Byte = 1;
+const KEY_PRESSED_FLAG: Byte = 3;
+
+/// Everything connected to the CPU bus.
+///
+struct VipBus {
+    ram: [Byte; RAM_SIZE],
+    rom: [Byte; ROM_SIZE],
+
+    // On reset, the ROM is mapped to the lower addresses as well, until the first access to an
+    // address with the high bit set; this allows the CPU to start from the monitor, since its
+    // program counter is reset to 0.
+    //
+    rom_in_lower_addresses: bool,
+
+    video: Cdp1861,
+
+    keypad_latch: usize,
+    keys_status: [bool; 16],
+}
+
+impl VipBus {
+    // Read without side effects (the ROM mapping switch).
+    //
+    fn peek(&self, address: Word) -> Byte {
+        if address & ROM_ADDRESS_BIT != 0 || self.rom_in_lower_addresses {
+            self.rom[address as usize % ROM_SIZE]
+        } else {
+            self.ram[address as usize % RAM_SIZE]
+        }
+    }
+
+    fn update_rom_mapping(&mut self, address: Word) {
+        if address & ROM_ADDRESS_BIT != 0 {
+            self.rom_in_lower_addresses = false;
+        }
+    }
+}
+
+impl Bus for VipBus {
+    fn read(&mut self, address: Word) -> Byte {
+        self.update_rom_mapping(address);
+        self.peek(address)
+    }
+
+    fn write(&mut self, address: Word, value: Byte) {
+        self.update_rom_mapping(address);
+
+        if address & ROM_ADDRESS_BIT == 0 && !self.rom_in_lower_addresses {
+            self.ram[address as usize % RAM_SIZE] = value;
+        }
+    }
+
+    fn input(&mut self, port: Byte) -> Byte {
+        if port == DISPLAY_PORT {
+            self.video.set_enabled(true);
+        }
+
+        // Nothing drives the data bus.
+        //
+        0
+    }
+
+    fn output(&mut self, port: Byte, value: Byte) {
+        match port {
+            DISPLAY_PORT => self.video.set_enabled(false),
+            KEYPAD_PORT => self.keypad_latch = (value & 0x0F) as usize,
+            _ => {}
+        }
+    }
+
+    fn external_flag(&mut self, line: Byte) -> bool {
+        match line {
+            DISPLAY_STATUS_FLAG => self.video.external_flag_1(),
+            KEY_PRESSED_FLAG => self.keys_status[self.keypad_latch],
+            _ => false,
+        }
+    }
+}
+
+/// RCA COSMAC VIP: CDP1802 CPU, CDP1861 video, hex keypad and tone generator.
+///
+/// Differently from the CHIP-8 system, which interprets the CHIP-8 instructions, this system runs
+/// the original (machine code) interpreter, so that programs using machine code routines (`0NNN`)
+/// run, with the original timing.
+///
+/// The monitor ROM and the CHIP-8 interpreter are not included, and must be provided.
+///
+pub struct CosmacVip<'a, T: IoFrontend> {
+    cpu: Cdp1802,
+    bus: VipBus,
+
+    pixels: Vec<Pixel>, // Reused on each frame
+
+    io_frontend: &'a mut T,
+    audio_device: Box<dyn AudioDevice>,
+    logger: &'a mut Option<Box<dyn Logger>>,
+}
+
+impl<'a, T: IoFrontend> CosmacVip<'a, T> {
+    /// # Arguments
+    ///
+    /// * `monitor_rom` - the VIP ROM (512 bytes); it boots the program in RAM at address 0.
+    /// * `interpreter` - the CHIP-8 interpreter, loaded at address 0.
+    /// * `game_rom` - the CHIP-8 program, loaded at 0x200.
+    ///
+    pub fn new(
+        io_frontend: &'a mut T,
+        monitor_rom: &[Byte],
+        interpreter: &[Byte],
+        game_rom: &[Byte],
+        logger: &'a mut Option<Box<dyn Logger>>,
+    ) -> CosmacVip<'a, T> {
+        if monitor_rom.len() != ROM_SIZE {
+            panic!(
+                "Invalid monitor ROM size: {} bytes ({} required)",
+                monitor_rom.len(),
+                ROM_SIZE
+            );
+        }
+
+        if interpreter.len() > PROGRAMS_LOCATION - INTERPRETER_LOCATION {
+            panic!(
+                "Interpreter too big!: {} bytes ({} allowed)",
+                interpreter.len(),
+                PROGRAMS_LOCATION - INTERPRETER_LOCATION
+            );
+        }
+
+        let max_game_rom_size = RAM_SIZE - INTERPRETER_RESERVED_SIZE - PROGRAMS_LOCATION;
+
+        if game_rom.len() > max_game_rom_size {
+            panic!(
+                "Rom too big!: {} bytes ({} allowed)",
+                game_rom.len(),
+                max_game_rom_size
+            );
+        }
+
+        fn wave_generator(sample_i: u32) -> i16 {
+            const PERIOD: f64 = AUDIO_DEVICE_FREQUENCY as f64 / TONE_FREQUENCY;
+
+            let period_number = sample_i as f64 / PERIOD;
+            let scale_factor = (period_number * 2.0 * PI).sin();
+            (AMPLITUDE as f64 * scale_factor) as i16
+        }
+
+        let audio_device = io_frontend.audio_device(wave_generator);
+
+        let mut bus = VipBus {
+            ram: [0; RAM_SIZE],
+            rom: [0; ROM_SIZE],
+            rom_in_lower_addresses: true,
+            video: Cdp1861::new(),
+            keypad_latch: 0,
+            keys_status: [false; 16],
+        };
+
+        bus.rom.copy_from_slice(monitor_rom);
+
+        bus.ram[INTERPRETER_LOCATION..INTERPRETER_LOCATION + interpreter.len()]
+            .copy_from_slice(interpreter);
+
+        bus.ram[PROGRAMS_LOCATION..PROGRAMS_LOCATION + game_rom.len()].copy_from_slice(game_rom);
+
+        io_frontend.init(SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32);
+
+        CosmacVip {
+            cpu: Cdp1802::new(),
+            bus,
+
+            pixels: vec![Pixel::OFF; SCREEN_WIDTH * SCREEN_HEIGHT],
+
+            io_frontend,
+            audio_device,
+            logger,
+        }
+    }
+
+    /// Runs one frame at a time; the input, audio and timing are synchronized on each frame.
+    ///
+    pub fn run(&mut self, max_speed: bool) {
+        let frame_time_slice = Duration::from_nanos(
+            1_000_000_000 * FRAME_MACHINE_CYCLES as u64 / MACHINE_CYCLES_SPEED as u64,
+        );
+
+        let mut next_frame_time = Instant::now() + frame_time_slice;
+
+        let mut emulation_running = true;
+
+        while emulation_running {
+            let previous_Q = self.cpu.Q;
+
+            self.emulate_frame();
+
+            self.update_screen();
+            self.set_keys(&mut emulation_running);
+            self.handle_sound_playback(previous_Q);
+
+            let current_time = Instant::now();
+
+            if current_time >= next_frame_time || max_speed {
+                next_frame_time = current_time + frame_time_slice;
+            } else {
+                thread::sleep(next_frame_time - current_time);
+                next_frame_time += frame_time_slice;
+            }
+        }
+    }
+
+    // Priorities, as in the CPU: DMA, then interrupt, then instruction execution.
+    //
+    fn emulate_frame(&mut self) {
+        loop {
+            let cycles = if self.bus.video.dma_requested() {
+                let mut line_bytes = [0; DMA_BYTES_PER_LINE];
+
+                for byte in line_bytes.iter_mut() {
+                    *byte = self.cpu.dma_out(&mut self.bus);
+                }
+
+                self.bus.video.dma_out(&line_bytes);
+
+                DMA_BYTES_PER_LINE as u32
+            } else if self.bus.video.interrupt_requested() && self.cpu.interrupt() {
+                self.log(format!("[{:04X}] INT", self.cpu.program_counter()));
+
+                1
+            } else {
+                if self.logger.is_some() {
+                    let program_counter = self.cpu.program_counter();
+                    let instruction = self.bus.peek(program_counter);
+
+                    self.log(format!("[{:04X}] {:02X}", program_counter, instruction));
+                }
+
+                self.cpu.execute(&mut self.bus)
+            };
+
+            if self.bus.video.tick(cycles) {
+                return;
+            }
+        }
+    }
+
+    // Each display pixel is converted to PIXEL_WIDTH (horizontally) adjacent screen pixels.
+    //
+    fn update_screen(&mut self) {
+        for (lit, screen_pixels) in self
+            .bus
+            .video
+            .frame()
+            .zip(self.pixels.chunks_mut(PIXEL_WIDTH))
+        {
+            let pixel = if lit { Pixel::ON } else { Pixel::OFF };
+            screen_pixels.fill(pixel);
+        }
+
+        self.io_frontend.update_screen(&self.pixels, false);
+    }
+
+    fn set_keys(&mut self, emulation_running: &mut bool) {
+        while let Some((keycode, key_pressed)) = self.io_frontend.read_event(false) {
+            let key_index = match keycode {
+                EventCode::KeyNum0 => 0,
+                EventCode::KeyNum1 => 1,
+                EventCode::KeyNum2 => 2,
+                EventCode::KeyNum3 => 3,
+                EventCode::KeyNum4 => 4,
+                EventCode::KeyNum5 => 5,
+                EventCode::KeyNum6 => 6,
+                EventCode::KeyNum7 => 7,
+                EventCode::KeyNum8 => 8,
+                EventCode::KeyNum9 => 9,
+                EventCode::KeyA => 10,
+                EventCode::KeyB => 11,
+                EventCode::KeyC => 12,
+                EventCode::KeyD => 13,
+                EventCode::KeyE => 14,
+                EventCode::KeyF => 15,
+                EventCode::Quit => {
+                    *emulation_running = false;
+                    return;
+                }
+                _ => continue,
+            };
+
+            self.bus.keys_status[key_index] = key_pressed;
+        }
+    }
+
+    // The tone oscillator is gated by the Q output.
+    //
+    fn handle_sound_playback(&mut self, previous_Q: bool) {
+        if self.cpu.Q != previous_Q {
+            if self.cpu.Q {
+                self.audio_device.play();
+            } else {
+                self.audio_device.pause();
+            }
+        }
+    }
+
+    fn log(&mut self, message: String) {
+        if let Some(logger) = self.logger {
+            logger.log(message);
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use super::cosmac_vip::{CosmacVip, PIXEL_WIDTH, SCREEN_WIDTH};
+use interfaces_frontend::{events::EventCode, video::Pixel, IoFrontend};
+
+type Byte = u8;
+
+const FRAMES_COUNT: usize = 3;
+
+// Stand-in monitor: the first branch maps the ROM out of the lower addresses, then the interpreter is
+// booted at address 0, with R0 as program counter (like the VIP monitor does).
+//
+const MONITOR_CODE: [Byte; 6] = [
+    0xC0, 0x80, 0x03, // LBR 0x8003
+    0xC0, 0x00, 0x00, // LBR 0x0000
+];
+
+// Stand-in CHIP-8 interpreter. It supports only `0NNN`, which calls the machine code routine at NNN
+// (with R3 as program counter; the routine returns via SEP R4); any other instruction halts it.
+//
+// The interrupt routine points the display DMA (R0) to the display buffer, and increments its second
+// byte, as interrupts counter.
+//
+#[rustfmt::skip]
+const INTERPRETER_CODE: [Byte; 0x56] = [
+    // 0x00: setup
+    0xF8, 0x00, 0xB1, 0xF8, 0x40, 0xA1, // R1 = 0x0040 (interrupt routine)
+    0xF8, 0x0E, 0xB2, 0xF8, 0xCF, 0xA2, // R2 = 0x0ECF (stack)
+    0xF8, 0x02, 0xB5, 0xF8, 0x00, 0xA5, // R5 = 0x0200 (CHIP-8 program counter)
+    0xF8, 0x00, 0xB4, 0xF8, 0x1A, 0xA4, // R4 = 0x001A (interpreter program counter)
+    0xD4,                               // SEP R4
+    0x00,
+    // 0x1A: display on
+    0xE2,                               // SEX R2
+    0x69,                               // INP 1
+    // 0x1C: fetch/execute loop
+    0x45, 0xB3,                         // LDA R5; PHI R3
+    0x45, 0xA3,                         // LDA R5; PLO R3
+    0x93, 0xFA, 0xF0,                   // GHI R3; ANI 0xF0
+    0x3A, 0x28,                         // BNZ 0x28
+    0xD3,                               // SEP R3
+    0x30, 0x1C,                         // BR 0x1C
+    // 0x28: halt
+    0x00,                               // IDL
+    0x30, 0x28,                         // BR 0x28
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00,
+    // 0x3E: interrupt routine exit
+    0x72,                               // LDXA
+    0x70,                               // RET
+    // 0x40: interrupt routine
+    0x22, 0x78,                         // DEC R2; SAV
+    0x22, 0x52,                         // DEC R2; STR R2
+    0xF8, 0x0F, 0xB0, 0xF8, 0x00, 0xA0, // R0 = 0x0F00 (display buffer)
+    0xF8, 0x0F, 0xB7, 0xF8, 0x01, 0xA7, // R7 = 0x0F01
+    0x07, 0xFC, 0x01, 0x57,             // LDN R7; ADI 1; STR R7
+    0x30, 0x3E,                         // BR 0x3E
+];
+
+// Calls the machine code routine, which draws a pattern in the first byte of the display buffer;
+// then halts the interpreter.
+//
+#[rustfmt::skip]
+const GAME_ROM: [Byte; 14] = [
+    0x02, 0x04,                         // 0x200: 0204 (call 0x204)
+    0x10, 0x00,                         // 0x202: 1000 (halt)
+    0xF8, 0x0F, 0xB6, 0xF8, 0x00, 0xA6, // 0x204: R6 = 0x0F00
+    0xF8, 0xA5, 0x56,                   // LDI 0xA5; STR R6
+    0xD4,                               // SEP R4
+];
+
+// Captures the frames, and quits after FRAMES_COUNT.
+//
+struct TestFrontend {
+    frames: Vec<Vec<Pixel>>,
+}
+
+impl IoFrontend for TestFrontend {
+    fn init(&mut self, _screen_width: u32, _screen_height: u32) {}
+
+    fn update_screen(&mut self, pixels: &[Pixel], _force_update: bool) {
+        self.frames.push(pixels.to_vec());
+    }
+
+    fn read_event(&mut self, _blocking: bool) -> Option<(EventCode, bool)> {
+        if self.frames.len() == FRAMES_COUNT {
+            Some((EventCode::Quit, true))
+        } else {
+            None
+        }
+    }
+}
+
+fn monitor_rom() -> Vec<Byte> {
+    let mut monitor_rom = vec![0; 512];
+    monitor_rom[..MONITOR_CODE.len()].copy_from_slice(&MONITOR_CODE);
+    monitor_rom
+}
+
+// Bytes of the first display line; each display pixel spans PIXEL_WIDTH screen pixels.
+//
+fn first_line_bytes(frame: &[Pixel]) -> Vec<Byte> {
+    frame[..SCREEN_WIDTH]
+        .chunks(8 * PIXEL_WIDTH)
+        .map(|byte_pixels| {
+            byte_pixels
+                .iter()
+                .step_by(PIXEL_WIDTH)
+                .fold(0, |byte, pixel| (byte << 1) | (*pixel == Pixel::ON) as Byte)
+        })
+        .collect()
+}
+
+#[test]
+fn runs_a_machine_code_routine_called_by_the_booted_interpreter() {
+    let mut frontend = TestFrontend { frames: vec![] };
+    let mut logger = None;
+
+    let mut cosmac_vip = CosmacVip::new(
+        &mut frontend,
+        &monitor_rom(),
+        &INTERPRETER_CODE,
+        &GAME_ROM,
+        &mut logger,
+    );
+
+    cosmac_vip.run(true);
+
+    assert_eq!(frontend.frames.len(), FRAMES_COUNT);
+
+    // The routine runs before the first interrupt. There is one interrupt per frame, raised two
+    // lines before the display: R0 is set up before the display DMA starts, while the counter is
+    // incremented after the first line has been transferred, so each frame shows the count of the
+    // previous ones.
+    //
+    for (frame_i, frame) in frontend.frames.iter().enumerate() {
+        let line_bytes = first_line_bytes(frame);
+
+        assert_eq!(line_bytes[0], 0xA5);
+        assert_eq!(line_bytes[1], frame_i as Byte);
+    }
+}
@@ -0,0 +1,10 @@
+mod cdp1802;
+mod cdp1861;
+mod cosmac_vip;
+
+#[cfg(test)]
+mod cdp1802_test;
+#[cfg(test)]
+mod cosmac_vip_test;
+
+pub use cosmac_vip::CosmacVip;
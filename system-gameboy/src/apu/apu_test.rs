@@ -0,0 +1,177 @@
+use super::Apu;
+
+type Byte = u8;
+type Word = u16;
+
+const NR10_ADDRESS: Word = 0xFF10;
+const NR12_ADDRESS: Word = 0xFF12;
+const NR13_ADDRESS: Word = 0xFF13;
+const NR14_ADDRESS: Word = 0xFF14;
+const NR21_ADDRESS: Word = 0xFF16;
+const NR22_ADDRESS: Word = 0xFF17;
+const NR23_ADDRESS: Word = 0xFF18;
+const NR24_ADDRESS: Word = 0xFF19;
+const NR50_ADDRESS: Word = 0xFF24;
+const NR51_ADDRESS: Word = 0xFF25;
+const NR52_ADDRESS: Word = 0xFF26;
+
+const CHANNEL_1_STATUS_BIT: Byte = 0b0000_0001;
+const CHANNEL_2_STATUS_BIT: Byte = 0b0000_0010;
+
+const FRAME_SEQUENCER_PERIOD: u32 = 8192;
+
+fn powered_on_apu() -> Apu {
+    let mut apu = Apu::new();
+    apu.write(NR52_ADDRESS, 0x80);
+    apu
+}
+
+fn advance(apu: &mut Apu, cycles: u32) {
+    for _ in 0..(cycles / 4) {
+        apu.tick(4);
+    }
+}
+
+fn channel_enabled(apu: &Apu, status_bit: Byte) -> bool {
+    apu.read(NR52_ADDRESS) & status_bit != 0
+}
+
+// Channel 2 at max frequency and 75% duty, so that its duty steps change on each M-cycle.
+//
+fn trigger_channel_2(apu: &mut Apu, envelope: Byte) {
+    apu.write(NR21_ADDRESS, 0xC0);
+    apu.write(NR22_ADDRESS, envelope);
+    apu.write(NR23_ADDRESS, 0xFF);
+    apu.write(NR24_ADDRESS, 0x87);
+}
+
+// The volume is the output of the high duty steps; advances by 8 M-cycles (a waveform period).
+//
+fn channel_2_volume(apu: &mut Apu) -> Byte {
+    (0..8)
+        .map(|_| {
+            apu.tick(4);
+            apu.channel_2.output().unwrap()
+        })
+        .max()
+        .unwrap()
+}
+
+#[test]
+fn frame_sequencer_clocks_the_length_counters_on_the_even_steps() {
+    let mut apu = powered_on_apu();
+
+    apu.write(NR21_ADDRESS, 0x3E); // Length 2
+    apu.write(NR22_ADDRESS, 0xF0);
+    apu.write(NR24_ADDRESS, 0xC0); // Trigger, length enabled
+
+    advance(&mut apu, 3 * FRAME_SEQUENCER_PERIOD - 4);
+
+    assert!(channel_enabled(&apu, CHANNEL_2_STATUS_BIT));
+
+    advance(&mut apu, 4);
+
+    assert!(!channel_enabled(&apu, CHANNEL_2_STATUS_BIT));
+}
+
+// The sweep overflows on the second clock.
+//
+#[test]
+fn frame_sequencer_clocks_the_sweep_on_steps_2_and_6() {
+    let mut apu = powered_on_apu();
+
+    apu.write(NR10_ADDRESS, 0x12); // Period 1, shift 2
+    apu.write(NR12_ADDRESS, 0xF0);
+    apu.write(NR13_ADDRESS, 0xB0);
+    apu.write(NR14_ADDRESS, 0x84); // Trigger, frequency 1200
+
+    advance(&mut apu, 7 * FRAME_SEQUENCER_PERIOD - 4);
+
+    assert!(channel_enabled(&apu, CHANNEL_1_STATUS_BIT));
+
+    advance(&mut apu, 4);
+
+    assert!(!channel_enabled(&apu, CHANNEL_1_STATUS_BIT));
+}
+
+#[test]
+fn frame_sequencer_clocks_the_envelopes_on_step_7() {
+    let mut apu = powered_on_apu();
+
+    trigger_channel_2(&mut apu, 0xF1);
+
+    advance(&mut apu, 8 * FRAME_SEQUENCER_PERIOD - 64);
+
+    assert_eq!(channel_2_volume(&mut apu), 15);
+
+    advance(&mut apu, 32);
+
+    assert_eq!(channel_2_volume(&mut apu), 14);
+}
+
+#[test]
+fn powering_off_clears_the_registers_and_ignores_the_writes() {
+    let mut apu = powered_on_apu();
+
+    apu.write(NR50_ADDRESS, 0x77);
+    apu.write(NR52_ADDRESS, 0x00);
+
+    assert_eq!(apu.read(NR50_ADDRESS), 0x00);
+
+    apu.write(NR50_ADDRESS, 0x77);
+
+    assert_eq!(apu.read(NR50_ADDRESS), 0x00);
+    assert_eq!(apu.read(NR52_ADDRESS), 0x70);
+}
+
+// With the other DACs disabled, only channel 2 contributes, with an analog output of 1.0 (volume
+// 15), divided by the channels count.
+//
+#[test]
+fn mixer_routes_the_channels_according_to_nr51() {
+    let mut apu = powered_on_apu();
+
+    trigger_channel_2(&mut apu, 0xF0);
+    apu.tick(4);
+
+    apu.write(NR50_ADDRESS, 0x77);
+
+    apu.write(NR51_ADDRESS, 0x20);
+    assert_eq!(apu.mix(), (0.25, 0.0));
+
+    apu.write(NR51_ADDRESS, 0x02);
+    assert_eq!(apu.mix(), (0.0, 0.25));
+
+    apu.write(NR51_ADDRESS, 0x22);
+    assert_eq!(apu.mix(), (0.25, 0.25));
+
+    apu.write(NR51_ADDRESS, 0xDD);
+    assert_eq!(apu.mix(), (0.0, 0.0));
+}
+
+#[test]
+fn mixer_scales_the_sides_according_to_nr50() {
+    let mut apu = powered_on_apu();
+
+    trigger_channel_2(&mut apu, 0xF0);
+    apu.tick(4);
+
+    apu.write(NR51_ADDRESS, 0x22);
+    apu.write(NR50_ADDRESS, 0x30);
+
+    assert_eq!(apu.mix(), (0.125, 0.03125));
+}
+
+#[test]
+fn mixer_outputs_silence_when_powered_off() {
+    let mut apu = powered_on_apu();
+
+    trigger_channel_2(&mut apu, 0xF0);
+    apu.tick(4);
+
+    apu.write(NR50_ADDRESS, 0x77);
+    apu.write(NR51_ADDRESS, 0xFF);
+    apu.write(NR52_ADDRESS, 0x00);
+
+    assert_eq!(apu.mix(), (0.0, 0.0));
+}
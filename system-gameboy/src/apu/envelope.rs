@@ -0,0 +1,77 @@
+type Byte = u8;
+
+const INITIAL_VOLUME_SHIFT: Byte = 4;
+const INCREASE_BIT: Byte = 0b0000_1000;
+const PERIOD_MASK: Byte = 0b0000_0111;
+
+// The DAC is enabled when any of the upper 5 bits is set.
+//
+const DAC_ENABLE_MASK: Byte = 0b1111_1000;
+
+const MAX_VOLUME: Byte = 15;
+
+/// Volume envelope (NRx2), of the square and noise channels; it's clocked at 64 Hz by the frame
+/// sequencer.
+///
+pub(crate) struct Envelope {
+    register: Byte,
+    volume: Byte,
+    timer: Byte,
+}
+
+impl Envelope {
+    pub fn new() -> Envelope {
+        Envelope {
+            register: 0,
+            volume: 0,
+            timer: 0,
+        }
+    }
+
+    pub fn write(&mut self, value: Byte) {
+        self.register = value;
+    }
+
+    pub fn volume(&self) -> Byte {
+        self.volume
+    }
+
+    pub fn dac_enabled(&self) -> bool {
+        self.register & DAC_ENABLE_MASK != 0
+    }
+
+    pub fn trigger(&mut self) {
+        self.volume = self.register >> INITIAL_VOLUME_SHIFT;
+        self.timer = self.period();
+    }
+
+    // A period of 0 stops the envelope.
+    //
+    pub fn clock(&mut self) {
+        if self.register & PERIOD_MASK == 0 {
+            return;
+        }
+
+        if self.timer > 1 {
+            self.timer -= 1;
+            return;
+        }
+
+        self.timer = self.period();
+
+        if self.register & INCREASE_BIT != 0 {
+            self.volume = (self.volume + 1).min(MAX_VOLUME);
+        } else {
+            self.volume = self.volume.saturating_sub(1);
+        }
+    }
+
+    // The timer treats a period of 0 as 8.
+    //
+    fn period(&self) -> Byte {
+        match self.register & PERIOD_MASK {
+            0 => 8,
+            period => period,
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use super::envelope::Envelope;
+
+type Byte = u8;
+
+fn triggered_envelope(register: Byte) -> Envelope {
+    let mut envelope = Envelope::new();
+    envelope.write(register);
+    envelope.trigger();
+    envelope
+}
+
+#[test]
+fn trigger_loads_the_initial_volume() {
+    let mut envelope = triggered_envelope(0xA3);
+
+    assert_eq!(envelope.volume(), 10);
+
+    // The new register value is used only on the next trigger.
+    //
+    envelope.write(0x53);
+
+    assert_eq!(envelope.volume(), 10);
+}
+
+#[test]
+fn volume_is_decreased_at_the_period() {
+    let mut envelope = triggered_envelope(0xF3);
+
+    envelope.clock();
+    envelope.clock();
+
+    assert_eq!(envelope.volume(), 15);
+
+    envelope.clock();
+
+    assert_eq!(envelope.volume(), 14);
+
+    for _ in 0..3 {
+        envelope.clock();
+    }
+
+    assert_eq!(envelope.volume(), 13);
+}
+
+#[test]
+fn volume_is_increased_up_to_the_max() {
+    let mut envelope = triggered_envelope(0xE9);
+
+    envelope.clock();
+
+    assert_eq!(envelope.volume(), 15);
+
+    envelope.clock();
+
+    assert_eq!(envelope.volume(), 15);
+}
+
+#[test]
+fn volume_is_decreased_down_to_zero() {
+    let mut envelope = triggered_envelope(0x11);
+
+    envelope.clock();
+    envelope.clock();
+
+    assert_eq!(envelope.volume(), 0);
+}
+
+#[test]
+fn period_zero_stops_the_envelope() {
+    let mut envelope = triggered_envelope(0xF0);
+
+    for _ in 0..16 {
+        envelope.clock();
+    }
+
+    assert_eq!(envelope.volume(), 15);
+}
+
+#[test]
+fn dac_is_enabled_by_the_upper_five_bits() {
+    assert!(triggered_envelope(0x08).dac_enabled());
+    assert!(triggered_envelope(0x10).dac_enabled());
+    assert!(!triggered_envelope(0x07).dac_enabled());
+}
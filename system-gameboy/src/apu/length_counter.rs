@@ -0,0 +1,43 @@
+type Byte = u8;
+
+/// Length counter; when enabled, it disables the channel once it reaches 0. It's clocked at 256 Hz
+/// by the frame sequencer.
+///
+pub(crate) struct LengthCounter {
+    max_length: u16,
+    counter: u16,
+    pub enabled: bool,
+}
+
+impl LengthCounter {
+    pub fn new(max_length: u16) -> LengthCounter {
+        LengthCounter {
+            max_length,
+            counter: 0,
+            enabled: false,
+        }
+    }
+
+    /// `value` is the length register, already masked.
+    ///
+    pub fn load(&mut self, value: Byte) {
+        self.counter = self.max_length - value as u16;
+    }
+
+    pub fn trigger(&mut self) {
+        if self.counter == 0 {
+            self.counter = self.max_length;
+        }
+    }
+
+    /// Returns true if the channel must be disabled.
+    ///
+    pub fn clock(&mut self) -> bool {
+        if self.enabled && self.counter > 0 {
+            self.counter -= 1;
+            self.counter == 0
+        } else {
+            false
+        }
+    }
+}
@@ -0,0 +1,336 @@
+mod envelope;
+mod length_counter;
+mod noise_channel;
+mod square_channel;
+mod wave_channel;
+
+#[cfg(test)]
+mod apu_test;
+#[cfg(test)]
+mod envelope_test;
+#[cfg(test)]
+mod noise_channel_test;
+#[cfg(test)]
+mod square_channel_test;
+
+use super::game_boy::CLOCK_SPEED;
+use interfaces_frontend::audio::AUDIO_DEVICE_FREQUENCY;
+use noise_channel::NoiseChannel;
+use square_channel::SquareChannel;
+use wave_channel::{WaveChannel, WAVE_RAM_SIZE};
+
+use std::mem;
+
+type Byte = u8;
+type Word = u16;
+
+const MCYCLE_TICKS: u8 = 4;
+
+// The frame sequencer runs at 512 Hz; on the real hardware, it's clocked by the DIV register.
+//
+const FRAME_SEQUENCER_PERIOD: u32 = CLOCK_SPEED / 512;
+const FRAME_SEQUENCER_STEPS: u8 = 8;
+
+const CHANNEL_1_ADDRESS: Word = 0xFF10;
+const CHANNEL_2_ADDRESS: Word = 0xFF15;
+const CHANNEL_3_ADDRESS: Word = 0xFF1A;
+const CHANNEL_4_ADDRESS: Word = 0xFF1F;
+const NR50_ADDRESS: Word = 0xFF24;
+const NR51_ADDRESS: Word = 0xFF25;
+const NR52_ADDRESS: Word = 0xFF26;
+const WAVE_RAM_ADDRESS: Word = 0xFF30;
+
+const REGISTERS_ADDRESS: Word = 0xFF10;
+const REGISTERS_COUNT: usize = 0x20;
+
+// Bits read as 1, for each register (0xFF10-0xFF2F); the unmapped registers read as 0xFF.
+//
+const READ_MASKS: [Byte; REGISTERS_COUNT] = [
+    0x80, 0x3F, 0x00, 0xFF, 0xBF, // NR10-NR14
+    0xFF, 0x3F, 0x00, 0xFF, 0xBF, // NR20-NR24
+    0x7F, 0xFF, 0x9F, 0xFF, 0xBF, // NR30-NR34
+    0xFF, 0xFF, 0x00, 0x00, 0xBF, // NR40-NR44
+    0x00, 0x00, 0x70, // NR50-NR52
+    0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+];
+
+const POWER_BIT: Byte = 0b1000_0000;
+
+const LEFT_VOLUME_SHIFT: Byte = 4;
+const VOLUME_MASK: Byte = 0b0000_0111;
+const LEFT_CHANNELS_SHIFT: Byte = 4;
+
+const CHANNELS_COUNT: usize = 4;
+
+const AMPLITUDE: f32 = (i16::MAX / 4) as f32; // Volume (i16::MAX = max)
+
+// Charge factor of the high-pass filter (per clock tick), which removes the DC offset of the DACs.
+//
+const HIGH_PASS_CHARGE_PER_CYCLE: f32 = 0.999_958;
+
+/// Audio processing unit: two square channels (the first with frequency sweep), the wave channel
+/// and the noise channel, sequenced by the frame sequencer (length counters, sweep and envelopes),
+/// and mixed into stereo according to NR50/NR51.
+///
+/// The output is sampled at AUDIO_DEVICE_FREQUENCY, averaging the mixed output of each M-cycle
+/// in the sample period, and buffered as interleaved stereo samples.
+///
+/// While the APU is powered off, the registers are cleared, and the writes (except to NR52 and the
+/// wave RAM) are ignored.
+///
+pub(crate) struct Apu {
+    channel_1: SquareChannel,
+    channel_2: SquareChannel,
+    channel_3: WaveChannel,
+    channel_4: NoiseChannel,
+
+    registers: [Byte; REGISTERS_COUNT],
+    powered_on: bool,
+
+    frame_sequencer_timer: u32,
+    frame_sequencer_step: u8,
+
+    // Incremented by AUDIO_DEVICE_FREQUENCY on each clock tick; a sample is produced each time it
+    // reaches CLOCK_SPEED.
+    //
+    sample_timer: u32,
+    output_sums: [f32; 2],
+    output_sums_count: u32,
+    high_pass_charge: f32,
+    high_pass_capacitors: [f32; 2],
+
+    samples: Vec<i16>,
+}
+
+impl Apu {
+    pub fn new() -> Apu {
+        let sample_period_cycles = CLOCK_SPEED as f32 / AUDIO_DEVICE_FREQUENCY as f32;
+
+        Apu {
+            channel_1: SquareChannel::new(true),
+            channel_2: SquareChannel::new(false),
+            channel_3: WaveChannel::new([0; WAVE_RAM_SIZE]),
+            channel_4: NoiseChannel::new(),
+
+            registers: [0; REGISTERS_COUNT],
+            powered_on: false,
+
+            frame_sequencer_timer: 0,
+            frame_sequencer_step: 0,
+
+            sample_timer: 0,
+            output_sums: [0.0; 2],
+            output_sums_count: 0,
+            high_pass_charge: HIGH_PASS_CHARGE_PER_CYCLE.powf(sample_period_cycles),
+            high_pass_capacitors: [0.0; 2],
+
+            samples: vec![],
+        }
+    }
+
+    pub fn read(&self, address: Word) -> Byte {
+        if address >= WAVE_RAM_ADDRESS {
+            return self.channel_3.wave_ram[(address - WAVE_RAM_ADDRESS) as usize];
+        }
+
+        let register_index = (address - REGISTERS_ADDRESS) as usize;
+
+        if address == NR52_ADDRESS {
+            let channels_status = [
+                self.channel_1.enabled,
+                self.channel_2.enabled,
+                self.channel_3.enabled,
+                self.channel_4.enabled,
+            ]
+            .iter()
+            .enumerate()
+            .fold(0, |status, (i, enabled)| status | ((*enabled as Byte) << i));
+
+            let power_status = if self.powered_on { POWER_BIT } else { 0 };
+
+            READ_MASKS[register_index] | power_status | channels_status
+        } else {
+            READ_MASKS[register_index] | self.registers[register_index]
+        }
+    }
+
+    pub fn write(&mut self, address: Word, value: Byte) {
+        if address >= WAVE_RAM_ADDRESS {
+            self.channel_3.wave_ram[(address - WAVE_RAM_ADDRESS) as usize] = value;
+            return;
+        }
+
+        if address == NR52_ADDRESS {
+            self.write_NR52(value);
+            return;
+        }
+
+        if !self.powered_on {
+            return;
+        }
+
+        self.registers[(address - REGISTERS_ADDRESS) as usize] = value;
+
+        match address {
+            CHANNEL_1_ADDRESS..=0xFF14 => self
+                .channel_1
+                .write((address - CHANNEL_1_ADDRESS) as usize, value),
+            CHANNEL_2_ADDRESS..=0xFF19 => self
+                .channel_2
+                .write((address - CHANNEL_2_ADDRESS) as usize, value),
+            CHANNEL_3_ADDRESS..=0xFF1E => self
+                .channel_3
+                .write((address - CHANNEL_3_ADDRESS) as usize, value),
+            CHANNEL_4_ADDRESS..=0xFF23 => self
+                .channel_4
+                .write((address - CHANNEL_4_ADDRESS) as usize, value),
+            _ => {}
+        }
+    }
+
+    /// Returns the samples produced since the last invocation (interleaved stereo).
+    ///
+    pub fn take_samples(&mut self) -> Vec<i16> {
+        mem::take(&mut self.samples)
+    }
+
+    /// Advances by the given clock ticks (a multiple of an M-cycle).
+    ///
+    pub fn tick(&mut self, cycles: u8) {
+        for _ in 0..(cycles / MCYCLE_TICKS) {
+            if self.powered_on {
+                self.channel_1.tick(MCYCLE_TICKS);
+                self.channel_2.tick(MCYCLE_TICKS);
+                self.channel_3.tick(MCYCLE_TICKS);
+                self.channel_4.tick(MCYCLE_TICKS);
+
+                self.tick_frame_sequencer();
+            }
+
+            self.sample_output();
+        }
+    }
+
+    // Turning the power off clears the registers; the wave RAM is preserved.
+    //
+    fn write_NR52(&mut self, value: Byte) {
+        let powered_on = value & POWER_BIT != 0;
+
+        if !powered_on && self.powered_on {
+            self.channel_1 = SquareChannel::new(true);
+            self.channel_2 = SquareChannel::new(false);
+            self.channel_3 = WaveChannel::new(self.channel_3.wave_ram);
+            self.channel_4 = NoiseChannel::new();
+
+            self.registers = [0; REGISTERS_COUNT];
+        } else if powered_on && !self.powered_on {
+            self.frame_sequencer_step = 0;
+        }
+
+        self.powered_on = powered_on;
+    }
+
+    // Steps: length counters on the even ones, sweep on 2 and 6, envelopes on 7.
+    //
+    fn tick_frame_sequencer(&mut self) {
+        self.frame_sequencer_timer += MCYCLE_TICKS as u32;
+
+        if self.frame_sequencer_timer < FRAME_SEQUENCER_PERIOD {
+            return;
+        }
+
+        self.frame_sequencer_timer -= FRAME_SEQUENCER_PERIOD;
+
+        match self.frame_sequencer_step {
+            0 | 4 => self.clock_lengths(),
+            2 | 6 => {
+                self.clock_lengths();
+                self.channel_1.clock_sweep();
+            }
+            7 => {
+                self.channel_1.clock_envelope();
+                self.channel_2.clock_envelope();
+                self.channel_4.clock_envelope();
+            }
+            _ => {}
+        }
+
+        self.frame_sequencer_step = (self.frame_sequencer_step + 1) % FRAME_SEQUENCER_STEPS;
+    }
+
+    fn clock_lengths(&mut self) {
+        self.channel_1.clock_length();
+        self.channel_2.clock_length();
+        self.channel_3.clock_length();
+        self.channel_4.clock_length();
+    }
+
+    fn sample_output(&mut self) {
+        let (left_output, right_output) = self.mix();
+
+        self.output_sums[0] += left_output;
+        self.output_sums[1] += right_output;
+        self.output_sums_count += 1;
+
+        self.sample_timer += AUDIO_DEVICE_FREQUENCY * MCYCLE_TICKS as u32;
+
+        if self.sample_timer >= CLOCK_SPEED {
+            self.sample_timer -= CLOCK_SPEED;
+
+            for side in 0..2 {
+                let input = self.output_sums[side] / self.output_sums_count as f32;
+                let output = input - self.high_pass_capacitors[side];
+
+                self.high_pass_capacitors[side] = input - output * self.high_pass_charge;
+
+                self.samples.push((output * AMPLITUDE) as i16);
+            }
+
+            self.output_sums = [0.0; 2];
+            self.output_sums_count = 0;
+        }
+    }
+
+    // Each DAC converts the digital output (0-15) to an analog value (-1.0 to 1.0); the disabled
+    // DACs output 0. The channels are routed to the sides according to NR51, then scaled by the
+    // volumes in NR50.
+    //
+    fn mix(&self) -> (f32, f32) {
+        if !self.powered_on {
+            return (0.0, 0.0);
+        }
+
+        let NR50 = self.registers[(NR50_ADDRESS - REGISTERS_ADDRESS) as usize];
+        let NR51 = self.registers[(NR51_ADDRESS - REGISTERS_ADDRESS) as usize];
+
+        let outputs = [
+            self.channel_1.output(),
+            self.channel_2.output(),
+            self.channel_3.output(),
+            self.channel_4.output(),
+        ];
+
+        let mut left_output = 0.0;
+        let mut right_output = 0.0;
+
+        for (i, output) in outputs.iter().enumerate() {
+            let analog_output = output.map_or(0.0, |output| output as f32 / 7.5 - 1.0);
+
+            if NR51 & (1 << (i as Byte + LEFT_CHANNELS_SHIFT)) != 0 {
+                left_output += analog_output;
+            }
+
+            if NR51 & (1 << i) != 0 {
+                right_output += analog_output;
+            }
+        }
+
+        let left_volume = ((NR50 >> LEFT_VOLUME_SHIFT) & VOLUME_MASK) + 1;
+        let right_volume = (NR50 & VOLUME_MASK) + 1;
+
+        (
+            left_output * left_volume as f32 / 8.0 / CHANNELS_COUNT as f32,
+            right_output * right_volume as f32 / 8.0 / CHANNELS_COUNT as f32,
+        )
+    }
+}
@@ -0,0 +1,129 @@
+use super::envelope::Envelope;
+use super::length_counter::LengthCounter;
+
+type Byte = u8;
+
+const MAX_LENGTH: u16 = 64;
+const LENGTH_MASK: Byte = 0b0011_1111;
+
+const CLOCK_SHIFT_SHIFT: Byte = 4;
+const WIDTH_MODE_BIT: Byte = 0b0000_1000;
+const DIVISOR_CODE_MASK: Byte = 0b0000_0111;
+
+const LENGTH_ENABLE_BIT: Byte = 0b0100_0000;
+const TRIGGER_BIT: Byte = 0b1000_0000;
+
+// Clock ticks, for each divisor code.
+//
+const DIVISORS: [i32; 8] = [8, 16, 32, 48, 64, 80, 96, 112];
+
+const LFSR_INITIAL_VALUE: u16 = 0x7FFF;
+
+/// Noise channel (4): the output is driven by a 15 bit LFSR, which can be shortened to 7 bits
+/// (width mode), producing a more regular sound.
+///
+/// Registers (relative index): length (1), envelope (2), polynomial counter (3), trigger/length
+/// enable (4).
+///
+pub(crate) struct NoiseChannel {
+    pub enabled: bool,
+
+    polynomial_register: Byte,
+    length_counter: LengthCounter,
+    envelope: Envelope,
+
+    frequency_timer: i32,
+    lfsr: u16,
+}
+
+impl NoiseChannel {
+    pub fn new() -> NoiseChannel {
+        NoiseChannel {
+            enabled: false,
+
+            polynomial_register: 0,
+            length_counter: LengthCounter::new(MAX_LENGTH),
+            envelope: Envelope::new(),
+
+            frequency_timer: 0,
+            lfsr: LFSR_INITIAL_VALUE,
+        }
+    }
+
+    pub fn write(&mut self, register: usize, value: Byte) {
+        match register {
+            1 => self.length_counter.load(value & LENGTH_MASK),
+            2 => {
+                self.envelope.write(value);
+                self.enabled &= self.envelope.dac_enabled();
+            }
+            3 => self.polynomial_register = value,
+            4 => {
+                self.length_counter.enabled = value & LENGTH_ENABLE_BIT != 0;
+
+                if value & TRIGGER_BIT != 0 {
+                    self.trigger();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Digital output (0-15); None if the DAC is disabled.
+    ///
+    pub fn output(&self) -> Option<Byte> {
+        if !self.envelope.dac_enabled() {
+            None
+        } else if self.enabled && self.lfsr & 1 == 0 {
+            Some(self.envelope.volume())
+        } else {
+            Some(0)
+        }
+    }
+
+    pub fn tick(&mut self, cycles: u8) {
+        self.frequency_timer -= cycles as i32;
+
+        while self.frequency_timer <= 0 {
+            self.frequency_timer += self.period();
+            self.shift_lfsr();
+        }
+    }
+
+    pub fn clock_length(&mut self) {
+        if self.length_counter.clock() {
+            self.enabled = false;
+        }
+    }
+
+    pub fn clock_envelope(&mut self) {
+        self.envelope.clock();
+    }
+
+    fn trigger(&mut self) {
+        self.enabled = self.envelope.dac_enabled();
+        self.length_counter.trigger();
+        self.frequency_timer = self.period();
+        self.envelope.trigger();
+        self.lfsr = LFSR_INITIAL_VALUE;
+    }
+
+    // The XOR of the two lowest bits is shifted in from the top (bit 14), and, in width mode, also
+    // into bit 6.
+    //
+    fn shift_lfsr(&mut self) {
+        let xor_bit = (self.lfsr ^ (self.lfsr >> 1)) & 1;
+
+        self.lfsr = (self.lfsr >> 1) | (xor_bit << 14);
+
+        if self.polynomial_register & WIDTH_MODE_BIT != 0 {
+            self.lfsr = (self.lfsr & !(1 << 6)) | (xor_bit << 6);
+        }
+    }
+
+    fn period(&self) -> i32 {
+        let divisor = DIVISORS[(self.polynomial_register & DIVISOR_CODE_MASK) as usize];
+
+        divisor << (self.polynomial_register >> CLOCK_SHIFT_SHIFT)
+    }
+}
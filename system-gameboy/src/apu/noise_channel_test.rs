@@ -0,0 +1,105 @@
+use super::noise_channel::NoiseChannel;
+
+type Byte = u8;
+
+const MAX_VOLUME_ENVELOPE: Byte = 0xF0;
+const TRIGGER_BIT: Byte = 0b1000_0000;
+const WIDTH_MODE_BIT: Byte = 0b0000_1000;
+
+// With divisor code 0 and clock shift 0, the LFSR is shifted every 8 clock ticks.
+//
+const SHIFT_TICKS: u8 = 8;
+
+fn triggered_channel(polynomial: Byte) -> NoiseChannel {
+    let mut channel = NoiseChannel::new();
+
+    channel.write(2, MAX_VOLUME_ENVELOPE);
+    channel.write(3, polynomial);
+    channel.write(4, TRIGGER_BIT);
+
+    channel
+}
+
+// The output is high when the LFSR bit 0 is clear; one entry per shift.
+//
+fn outputs(channel: &mut NoiseChannel, count: usize) -> Vec<bool> {
+    (0..count)
+        .map(|_| {
+            channel.tick(SHIFT_TICKS);
+            channel.output() == Some(15)
+        })
+        .collect()
+}
+
+fn ticks_to_high_output(channel: &mut NoiseChannel) -> u32 {
+    let mut ticks = 0;
+
+    while channel.output() != Some(15) {
+        channel.tick(4);
+        ticks += 4;
+    }
+
+    ticks
+}
+
+// The LFSR starts with all the bits set, so the first cleared bit reaches the bit 0 after the
+// (width) shifts.
+//
+#[test]
+fn lfsr_output_is_high_first_after_15_shifts() {
+    let mut channel = triggered_channel(0x00);
+
+    let outputs = outputs(&mut channel, 15);
+
+    assert!(outputs[..14].iter().all(|&high| !high));
+    assert!(outputs[14]);
+}
+
+#[test]
+fn lfsr_output_is_high_first_after_7_shifts_in_width_mode() {
+    let mut channel = triggered_channel(WIDTH_MODE_BIT);
+
+    let outputs = outputs(&mut channel, 7);
+
+    assert!(outputs[..6].iter().all(|&high| !high));
+    assert!(outputs[6]);
+}
+
+#[test]
+fn lfsr_sequence_repeats_every_32767_shifts() {
+    let mut channel = triggered_channel(0x00);
+
+    let outputs = outputs(&mut channel, 2 * 32767);
+
+    assert_eq!(outputs[..32767], outputs[32767..]);
+    assert_ne!(outputs[..16383], outputs[16383..2 * 16383]);
+}
+
+#[test]
+fn lfsr_sequence_repeats_every_127_shifts_in_width_mode() {
+    let mut channel = triggered_channel(WIDTH_MODE_BIT);
+
+    let outputs = outputs(&mut channel, 2 * 127);
+
+    assert_eq!(outputs[..127], outputs[127..]);
+    assert_ne!(outputs[..63], outputs[63..2 * 63]);
+}
+
+#[test]
+fn shift_rate_follows_the_polynomial_register() {
+    assert_eq!(ticks_to_high_output(&mut triggered_channel(0x00)), 15 * 8);
+
+    // Divisor code 1 (16 ticks), clock shift 2.
+    //
+    assert_eq!(ticks_to_high_output(&mut triggered_channel(0x21)), 15 * 64);
+}
+
+#[test]
+fn trigger_resets_the_lfsr() {
+    let mut channel = triggered_channel(0x00);
+
+    outputs(&mut channel, 20);
+    channel.write(4, TRIGGER_BIT);
+
+    assert_eq!(ticks_to_high_output(&mut channel), 15 * 8);
+}
@@ -0,0 +1,205 @@
+use super::envelope::Envelope;
+use super::length_counter::LengthCounter;
+
+type Byte = u8;
+type Word = u16;
+
+const MAX_LENGTH: u16 = 64;
+const LENGTH_MASK: Byte = 0b0011_1111;
+const DUTY_SHIFT: Byte = 6;
+
+const FREQUENCY_HIGH_MASK: Byte = 0b0000_0111;
+const LENGTH_ENABLE_BIT: Byte = 0b0100_0000;
+const TRIGGER_BIT: Byte = 0b1000_0000;
+
+const SWEEP_PERIOD_SHIFT: Byte = 4;
+const SWEEP_PERIOD_MASK: Byte = 0b0111;
+const SWEEP_NEGATE_BIT: Byte = 0b0000_1000;
+const SWEEP_SHIFT_MASK: Byte = 0b0000_0111;
+
+const MAX_FREQUENCY: Word = 2047;
+
+// Clock ticks per duty step, for each frequency unit.
+//
+const PERIOD_MULTIPLIER: i32 = 4;
+
+// 12.5%, 25%, 50% and 75%; the LSB is the first step.
+//
+const DUTY_WAVEFORMS: [Byte; 4] = [0b1000_0000, 0b1000_0001, 0b1110_0001, 0b0111_1110];
+
+/// Square channel (1 and 2); channel 1 has the frequency sweep, which is clocked at 128 Hz by the
+/// frame sequencer.
+///
+/// Registers (relative index): sweep (0, channel 1 only), duty/length (1), envelope (2), frequency
+/// low (3), trigger/length enable/frequency high (4).
+///
+pub(crate) struct SquareChannel {
+    has_sweep: bool,
+    pub enabled: bool,
+
+    duty: Byte,
+    frequency: Word,
+    length_counter: LengthCounter,
+    envelope: Envelope,
+
+    frequency_timer: i32,
+    duty_step: Byte,
+
+    sweep_register: Byte,
+    sweep_timer: Byte,
+    sweep_enabled: bool,
+    shadow_frequency: Word,
+}
+
+impl SquareChannel {
+    pub fn new(has_sweep: bool) -> SquareChannel {
+        SquareChannel {
+            has_sweep,
+            enabled: false,
+
+            duty: 0,
+            frequency: 0,
+            length_counter: LengthCounter::new(MAX_LENGTH),
+            envelope: Envelope::new(),
+
+            frequency_timer: 0,
+            duty_step: 0,
+
+            sweep_register: 0,
+            sweep_timer: 0,
+            sweep_enabled: false,
+            shadow_frequency: 0,
+        }
+    }
+
+    pub fn write(&mut self, register: usize, value: Byte) {
+        match register {
+            0 if self.has_sweep => self.sweep_register = value,
+            1 => {
+                self.duty = value >> DUTY_SHIFT;
+                self.length_counter.load(value & LENGTH_MASK);
+            }
+            2 => {
+                self.envelope.write(value);
+                self.enabled &= self.envelope.dac_enabled();
+            }
+            3 => self.frequency = (self.frequency & 0xFF00) | value as Word,
+            4 => {
+                self.frequency =
+                    (self.frequency & 0x00FF) | (((value & FREQUENCY_HIGH_MASK) as Word) << 8);
+                self.length_counter.enabled = value & LENGTH_ENABLE_BIT != 0;
+
+                if value & TRIGGER_BIT != 0 {
+                    self.trigger();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Digital output (0-15); None if the DAC is disabled.
+    ///
+    pub fn output(&self) -> Option<Byte> {
+        if !self.envelope.dac_enabled() {
+            None
+        } else if self.enabled && DUTY_WAVEFORMS[self.duty as usize] & (1 << self.duty_step) != 0 {
+            Some(self.envelope.volume())
+        } else {
+            Some(0)
+        }
+    }
+
+    pub fn tick(&mut self, cycles: u8) {
+        self.frequency_timer -= cycles as i32;
+
+        while self.frequency_timer <= 0 {
+            self.frequency_timer += self.period();
+            self.duty_step = (self.duty_step + 1) % 8;
+        }
+    }
+
+    pub fn clock_length(&mut self) {
+        if self.length_counter.clock() {
+            self.enabled = false;
+        }
+    }
+
+    pub fn clock_envelope(&mut self) {
+        self.envelope.clock();
+    }
+
+    pub fn clock_sweep(&mut self) {
+        if self.sweep_timer > 1 {
+            self.sweep_timer -= 1;
+            return;
+        }
+
+        self.sweep_timer = self.sweep_period();
+
+        if self.sweep_enabled && self.sweep_register_period() != 0 {
+            let new_frequency = self.calculate_sweep();
+
+            if new_frequency <= MAX_FREQUENCY && self.sweep_register & SWEEP_SHIFT_MASK != 0 {
+                self.frequency = new_frequency;
+                self.shadow_frequency = new_frequency;
+
+                // The overflow check is performed again, with the new frequency.
+                //
+                self.calculate_sweep();
+            }
+        }
+    }
+
+    fn trigger(&mut self) {
+        self.enabled = self.envelope.dac_enabled();
+        self.length_counter.trigger();
+        self.frequency_timer = self.period();
+        self.envelope.trigger();
+
+        if self.has_sweep {
+            self.shadow_frequency = self.frequency;
+            self.sweep_timer = self.sweep_period();
+            self.sweep_enabled =
+                self.sweep_register_period() != 0 || self.sweep_register & SWEEP_SHIFT_MASK != 0;
+
+            if self.sweep_register & SWEEP_SHIFT_MASK != 0 {
+                self.calculate_sweep();
+            }
+        }
+    }
+
+    // Disables the channel on overflow.
+    //
+    fn calculate_sweep(&mut self) -> Word {
+        let delta = self.shadow_frequency >> (self.sweep_register & SWEEP_SHIFT_MASK);
+
+        let new_frequency = if self.sweep_register & SWEEP_NEGATE_BIT != 0 {
+            self.shadow_frequency - delta
+        } else {
+            self.shadow_frequency + delta
+        };
+
+        if new_frequency > MAX_FREQUENCY {
+            self.enabled = false;
+        }
+
+        new_frequency
+    }
+
+    fn sweep_register_period(&self) -> Byte {
+        (self.sweep_register >> SWEEP_PERIOD_SHIFT) & SWEEP_PERIOD_MASK
+    }
+
+    // The timer treats a period of 0 as 8.
+    //
+    fn sweep_period(&self) -> Byte {
+        match self.sweep_register_period() {
+            0 => 8,
+            period => period,
+        }
+    }
+
+    fn period(&self) -> i32 {
+        (2048 - self.frequency as i32) * PERIOD_MULTIPLIER
+    }
+}
@@ -0,0 +1,124 @@
+use super::square_channel::SquareChannel;
+
+type Byte = u8;
+type Word = u16;
+
+const MAX_VOLUME_ENVELOPE: Byte = 0xF0;
+const TRIGGER_BIT: Byte = 0b1000_0000;
+
+// With the 12.5% duty, the output is high only on the last duty step, so there's a rising edge per
+// waveform period.
+//
+const DUTY_12_5: Byte = 0b0000_0000;
+
+fn triggered_channel(sweep: Byte, frequency: Word) -> SquareChannel {
+    let mut channel = SquareChannel::new(true);
+
+    channel.write(0, sweep);
+    channel.write(1, DUTY_12_5);
+    channel.write(2, MAX_VOLUME_ENVELOPE);
+    channel.write(3, frequency as Byte);
+    channel.write(4, TRIGGER_BIT | (frequency >> 8) as Byte);
+
+    channel
+}
+
+fn ticks_to_rising_edge(channel: &mut SquareChannel) -> u32 {
+    let mut ticks = 0;
+
+    loop {
+        let previous_output = channel.output();
+
+        channel.tick(4);
+        ticks += 4;
+
+        if previous_output == Some(0) && channel.output() == Some(15) {
+            return ticks;
+        }
+    }
+}
+
+// Clock ticks between two rising edges of the output.
+//
+fn waveform_period(channel: &mut SquareChannel) -> u32 {
+    ticks_to_rising_edge(channel);
+    ticks_to_rising_edge(channel)
+}
+
+fn expected_waveform_period(frequency: Word) -> u32 {
+    (2048 - frequency as u32) * 4 * 8
+}
+
+#[test]
+fn waveform_period_follows_the_frequency() {
+    let mut channel = triggered_channel(0x00, 1024);
+
+    assert_eq!(
+        waveform_period(&mut channel),
+        expected_waveform_period(1024)
+    );
+}
+
+#[test]
+fn sweep_increases_the_frequency() {
+    let mut channel = triggered_channel(0x11, 256);
+
+    channel.clock_sweep();
+
+    assert_eq!(waveform_period(&mut channel), expected_waveform_period(384));
+}
+
+#[test]
+fn sweep_decreases_the_frequency_when_negated() {
+    let mut channel = triggered_channel(0x19, 256);
+
+    channel.clock_sweep();
+
+    assert_eq!(waveform_period(&mut channel), expected_waveform_period(128));
+}
+
+#[test]
+fn sweep_is_clocked_at_its_period() {
+    let mut channel = triggered_channel(0x31, 256);
+
+    channel.clock_sweep();
+    channel.clock_sweep();
+
+    assert_eq!(waveform_period(&mut channel), expected_waveform_period(256));
+
+    channel.clock_sweep();
+
+    assert_eq!(waveform_period(&mut channel), expected_waveform_period(384));
+}
+
+#[test]
+fn sweep_with_shift_zero_does_not_change_the_frequency() {
+    let mut channel = triggered_channel(0x10, 256);
+
+    channel.clock_sweep();
+
+    assert!(channel.enabled);
+    assert_eq!(waveform_period(&mut channel), expected_waveform_period(256));
+}
+
+#[test]
+fn sweep_overflow_on_trigger_disables_the_channel() {
+    let channel = triggered_channel(0x01, 2047);
+
+    assert!(!channel.enabled);
+}
+
+// The overflow check is performed both on the new frequency, and on the following one.
+//
+#[test]
+fn sweep_overflow_disables_the_channel() {
+    let mut channel = triggered_channel(0x12, 1200);
+
+    channel.clock_sweep();
+
+    assert!(channel.enabled);
+
+    channel.clock_sweep();
+
+    assert!(!channel.enabled);
+}
@@ -0,0 +1,128 @@
+use super::length_counter::LengthCounter;
+
+type Byte = u8;
+type Word = u16;
+
+pub(crate) const WAVE_RAM_SIZE: usize = 16;
+const SAMPLES_COUNT: Byte = WAVE_RAM_SIZE as Byte * 2;
+
+const MAX_LENGTH: u16 = 256;
+
+const DAC_ENABLE_BIT: Byte = 0b1000_0000;
+const VOLUME_CODE_SHIFT: Byte = 5;
+const VOLUME_CODE_MASK: Byte = 0b0000_0011;
+
+const FREQUENCY_HIGH_MASK: Byte = 0b0000_0111;
+const LENGTH_ENABLE_BIT: Byte = 0b0100_0000;
+const TRIGGER_BIT: Byte = 0b1000_0000;
+
+// Right shift of the samples, for each volume code (mute, 100%, 50%, 25%).
+//
+const VOLUME_SHIFTS: [Byte; 4] = [4, 0, 1, 2];
+
+// Clock ticks per sample, for each frequency unit.
+//
+const PERIOD_MULTIPLIER: i32 = 2;
+
+/// Wave channel (3): plays the 32 4-bit samples of the wave RAM (high nibble first).
+///
+/// Registers (relative index): DAC enable (0), length (1), volume (2), frequency low (3),
+/// trigger/length enable/frequency high (4).
+///
+pub(crate) struct WaveChannel {
+    pub enabled: bool,
+    pub wave_ram: [Byte; WAVE_RAM_SIZE],
+
+    dac_enabled: bool,
+    volume_code: Byte,
+    frequency: Word,
+    length_counter: LengthCounter,
+
+    frequency_timer: i32,
+    sample_index: Byte,
+}
+
+impl WaveChannel {
+    pub fn new(wave_ram: [Byte; WAVE_RAM_SIZE]) -> WaveChannel {
+        WaveChannel {
+            enabled: false,
+            wave_ram,
+
+            dac_enabled: false,
+            volume_code: 0,
+            frequency: 0,
+            length_counter: LengthCounter::new(MAX_LENGTH),
+
+            frequency_timer: 0,
+            sample_index: 0,
+        }
+    }
+
+    pub fn write(&mut self, register: usize, value: Byte) {
+        match register {
+            0 => {
+                self.dac_enabled = value & DAC_ENABLE_BIT != 0;
+                self.enabled &= self.dac_enabled;
+            }
+            1 => self.length_counter.load(value),
+            2 => self.volume_code = (value >> VOLUME_CODE_SHIFT) & VOLUME_CODE_MASK,
+            3 => self.frequency = (self.frequency & 0xFF00) | value as Word,
+            4 => {
+                self.frequency =
+                    (self.frequency & 0x00FF) | (((value & FREQUENCY_HIGH_MASK) as Word) << 8);
+                self.length_counter.enabled = value & LENGTH_ENABLE_BIT != 0;
+
+                if value & TRIGGER_BIT != 0 {
+                    self.trigger();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Digital output (0-15); None if the DAC is disabled.
+    ///
+    pub fn output(&self) -> Option<Byte> {
+        if !self.dac_enabled {
+            None
+        } else if self.enabled {
+            let sample_byte = self.wave_ram[self.sample_index as usize / 2];
+
+            let sample = if self.sample_index & 1 == 0 {
+                sample_byte >> 4
+            } else {
+                sample_byte & 0x0F
+            };
+
+            Some(sample >> VOLUME_SHIFTS[self.volume_code as usize])
+        } else {
+            Some(0)
+        }
+    }
+
+    pub fn tick(&mut self, cycles: u8) {
+        self.frequency_timer -= cycles as i32;
+
+        while self.frequency_timer <= 0 {
+            self.frequency_timer += self.period();
+            self.sample_index = (self.sample_index + 1) % SAMPLES_COUNT;
+        }
+    }
+
+    pub fn clock_length(&mut self) {
+        if self.length_counter.clock() {
+            self.enabled = false;
+        }
+    }
+
+    fn trigger(&mut self) {
+        self.enabled = self.dac_enabled;
+        self.length_counter.trigger();
+        self.frequency_timer = self.period();
+        self.sample_index = 0;
+    }
+
+    fn period(&self) -> i32 {
+        (2048 - self.frequency as i32) * PERIOD_MULTIPLIER
+    }
+}
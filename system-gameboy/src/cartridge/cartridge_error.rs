@@ -0,0 +1,47 @@
+use std::error::Error;
+use std::fmt;
+
+type Byte = u8;
+
+/// Errors found while validating the cartridge header against the ROM.
+///
+#[derive(Debug, PartialEq)]
+pub enum CartridgeError {
+    RomTooSmall(usize),
+    UnsupportedCartridgeType(Byte),
+    InvalidRomSize(Byte),
+    InvalidRamSize(Byte),
+    RomSizeMismatch { header: usize, actual: usize },
+    HeaderChecksumMismatch { header: Byte, actual: Byte },
+}
+
+impl fmt::Display for CartridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CartridgeError::RomTooSmall(size) => {
+                write!(f, "ROM too small to contain the header: {} bytes", size)
+            }
+            CartridgeError::UnsupportedCartridgeType(code) => {
+                write!(f, "Unsupported cartridge type: ${:02X}", code)
+            }
+            CartridgeError::InvalidRomSize(code) => {
+                write!(f, "Invalid ROM size code: ${:02X}", code)
+            }
+            CartridgeError::InvalidRamSize(code) => {
+                write!(f, "Invalid RAM size code: ${:02X}", code)
+            }
+            CartridgeError::RomSizeMismatch { header, actual } => write!(
+                f,
+                "ROM size mismatch: {} bytes in the header, {} actual",
+                header, actual
+            ),
+            CartridgeError::HeaderChecksumMismatch { header, actual } => write!(
+                f,
+                "Header checksum mismatch: ${:02X} in the header, ${:02X} actual",
+                header, actual
+            ),
+        }
+    }
+}
+
+impl Error for CartridgeError {}
@@ -0,0 +1,183 @@
+use super::CartridgeError;
+
+type Byte = u8;
+type Word = u16;
+
+pub(crate) const ROM_BANK_SIZE: usize = 0x4000;
+
+const HEADER_END_ADDRESS: usize = 0x150;
+
+const TITLE_ADDRESS: usize = 0x134;
+const TITLE_END_ADDRESS: usize = 0x144; // The last byte is the CGB flag, on newer cartridges
+const CGB_FLAG_ADDRESS: usize = 0x143;
+const CARTRIDGE_TYPE_ADDRESS: usize = 0x147;
+const ROM_SIZE_ADDRESS: usize = 0x148;
+const RAM_SIZE_ADDRESS: usize = 0x149;
+const HEADER_CHECKSUM_ADDRESS: usize = 0x14D;
+const GLOBAL_CHECKSUM_ADDRESS: usize = 0x14E;
+
+const CGB_FLAG_BIT: Byte = 0b1000_0000;
+
+const MAX_ROM_SIZE_CODE: Byte = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MapperKind {
+    RomOnly,
+    Mbc1,
+    Mbc2,
+    Mbc3,
+    Mbc5,
+}
+
+/// Hardware of the cartridge, as encoded by the cartridge type byte.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CartridgeType {
+    pub mapper: MapperKind,
+    pub battery: bool,
+    pub timer: bool,
+    pub rumble: bool,
+}
+
+impl CartridgeType {
+    fn decode(code: Byte) -> Result<CartridgeType, CartridgeError> {
+        use MapperKind::*;
+
+        let (mapper, battery, timer, rumble) = match code {
+            0x00 | 0x08 => (RomOnly, false, false, false),
+            0x09 => (RomOnly, true, false, false),
+            0x01 | 0x02 => (Mbc1, false, false, false),
+            0x03 => (Mbc1, true, false, false),
+            0x05 => (Mbc2, false, false, false),
+            0x06 => (Mbc2, true, false, false),
+            0x0F | 0x10 => (Mbc3, true, true, false),
+            0x11 | 0x12 => (Mbc3, false, false, false),
+            0x13 => (Mbc3, true, false, false),
+            0x19 | 0x1A => (Mbc5, false, false, false),
+            0x1B => (Mbc5, true, false, false),
+            0x1C | 0x1D => (Mbc5, false, false, true),
+            0x1E => (Mbc5, true, false, true),
+            _ => return Err(CartridgeError::UnsupportedCartridgeType(code)),
+        };
+
+        Ok(CartridgeType {
+            mapper,
+            battery,
+            timer,
+            rumble,
+        })
+    }
+}
+
+/// Cartridge header (0x0100-0x014F).
+///
+/// The header checksum is verified by the boot ROM, which locks up on mismatch; the global checksum
+/// is not verified by the hardware, so a mismatch (e.g. in a patched ROM) is only reported, via
+/// `global_checksum_matches()`.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct CartridgeHeader {
+    pub title: String,
+    pub cgb_support: bool,
+    pub cartridge_type: CartridgeType,
+    pub rom_size: usize,
+    pub ram_size: usize,
+    pub header_checksum: Byte,
+    pub global_checksum: Word,
+    pub actual_global_checksum: Word,
+}
+
+impl CartridgeHeader {
+    pub fn parse(rom: &[Byte]) -> Result<CartridgeHeader, CartridgeError> {
+        if rom.len() < HEADER_END_ADDRESS {
+            return Err(CartridgeError::RomTooSmall(rom.len()));
+        }
+
+        let cgb_support = rom[CGB_FLAG_ADDRESS] & CGB_FLAG_BIT != 0;
+
+        let title_end = if cgb_support {
+            CGB_FLAG_ADDRESS
+        } else {
+            TITLE_END_ADDRESS
+        };
+
+        let title = rom[TITLE_ADDRESS..title_end]
+            .iter()
+            .take_while(|&&byte| byte != 0)
+            .map(|&byte| byte as char)
+            .collect();
+
+        let cartridge_type = CartridgeType::decode(rom[CARTRIDGE_TYPE_ADDRESS])?;
+
+        let rom_size_code = rom[ROM_SIZE_ADDRESS];
+
+        if rom_size_code > MAX_ROM_SIZE_CODE {
+            return Err(CartridgeError::InvalidRomSize(rom_size_code));
+        }
+
+        let rom_size = (2 * ROM_BANK_SIZE) << rom_size_code;
+
+        let ram_size_code = rom[RAM_SIZE_ADDRESS];
+
+        let ram_size = match ram_size_code {
+            0 => 0,
+            1 => 0x800, // Unofficial
+            2 => 0x2000,
+            3 => 0x8000,
+            4 => 0x2_0000,
+            5 => 0x1_0000,
+            _ => return Err(CartridgeError::InvalidRamSize(ram_size_code)),
+        };
+
+        let header_checksum = rom[HEADER_CHECKSUM_ADDRESS];
+        let global_checksum = ((rom[GLOBAL_CHECKSUM_ADDRESS] as Word) << 8)
+            | rom[GLOBAL_CHECKSUM_ADDRESS + 1] as Word;
+
+        if rom.len() != rom_size {
+            return Err(CartridgeError::RomSizeMismatch {
+                header: rom_size,
+                actual: rom.len(),
+            });
+        }
+
+        let actual_header_checksum = rom[TITLE_ADDRESS..HEADER_CHECKSUM_ADDRESS]
+            .iter()
+            .fold(0 as Byte, |checksum, &byte| {
+                checksum.wrapping_sub(byte).wrapping_sub(1)
+            });
+
+        if actual_header_checksum != header_checksum {
+            return Err(CartridgeError::HeaderChecksumMismatch {
+                header: header_checksum,
+                actual: actual_header_checksum,
+            });
+        }
+
+        // The checksum bytes themselves are excluded.
+        //
+        let actual_global_checksum = rom
+            .iter()
+            .enumerate()
+            .filter(|(address, _)| {
+                !(GLOBAL_CHECKSUM_ADDRESS..=GLOBAL_CHECKSUM_ADDRESS + 1).contains(address)
+            })
+            .fold(0 as Word, |checksum, (_, &byte)| {
+                checksum.wrapping_add(byte as Word)
+            });
+
+        Ok(CartridgeHeader {
+            title,
+            cgb_support,
+            cartridge_type,
+            rom_size,
+            ram_size,
+            header_checksum,
+            global_checksum,
+            actual_global_checksum,
+        })
+    }
+
+    pub fn global_checksum_matches(&self) -> bool {
+        self.global_checksum == self.actual_global_checksum
+    }
+}
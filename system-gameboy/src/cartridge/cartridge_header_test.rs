@@ -0,0 +1,165 @@
+use super::cartridge_header::{CartridgeHeader, CartridgeType, MapperKind};
+use super::CartridgeError;
+
+type Byte = u8;
+
+const TITLE_ADDRESS: usize = 0x134;
+const CGB_FLAG_ADDRESS: usize = 0x143;
+const CARTRIDGE_TYPE_ADDRESS: usize = 0x147;
+const ROM_SIZE_ADDRESS: usize = 0x148;
+const RAM_SIZE_ADDRESS: usize = 0x149;
+const HEADER_CHECKSUM_ADDRESS: usize = 0x14D;
+const GLOBAL_CHECKSUM_ADDRESS: usize = 0x14E;
+
+const MBC1_RAM_BATTERY: Byte = 0x03;
+
+// 32 KiB ROM, with both the checksums set.
+//
+fn rom(title: &[Byte], cartridge_type: Byte, ram_size_code: Byte) -> Vec<Byte> {
+    let mut rom = vec![0; 0x8000];
+
+    rom[TITLE_ADDRESS..TITLE_ADDRESS + title.len()].copy_from_slice(title);
+    rom[CARTRIDGE_TYPE_ADDRESS] = cartridge_type;
+    rom[RAM_SIZE_ADDRESS] = ram_size_code;
+
+    set_checksums(&mut rom);
+
+    rom
+}
+
+fn set_checksums(rom: &mut [Byte]) {
+    rom[HEADER_CHECKSUM_ADDRESS] = rom[TITLE_ADDRESS..HEADER_CHECKSUM_ADDRESS]
+        .iter()
+        .fold(0, |checksum: Byte, &byte| {
+            checksum.wrapping_sub(byte).wrapping_sub(1)
+        });
+
+    rom[GLOBAL_CHECKSUM_ADDRESS..GLOBAL_CHECKSUM_ADDRESS + 2].copy_from_slice(&[0, 0]);
+
+    let global_checksum = rom
+        .iter()
+        .fold(0, |checksum: u16, &byte| checksum.wrapping_add(byte as u16));
+
+    rom[GLOBAL_CHECKSUM_ADDRESS..GLOBAL_CHECKSUM_ADDRESS + 2]
+        .copy_from_slice(&global_checksum.to_be_bytes());
+}
+
+#[test]
+fn parses_the_header() {
+    let header = CartridgeHeader::parse(&rom(b"TEST", MBC1_RAM_BATTERY, 3)).unwrap();
+
+    assert_eq!(header.title, "TEST");
+    assert!(!header.cgb_support);
+    assert_eq!(
+        header.cartridge_type,
+        CartridgeType {
+            mapper: MapperKind::Mbc1,
+            battery: true,
+            timer: false,
+            rumble: false,
+        }
+    );
+    assert_eq!(header.rom_size, 0x8000);
+    assert_eq!(header.ram_size, 0x8000);
+    assert!(header.global_checksum_matches());
+}
+
+#[test]
+fn cgb_flag_is_excluded_from_the_title() {
+    let mut rom = rom(b"FIFTEEN_CHARS__", 0x00, 0);
+    rom[CGB_FLAG_ADDRESS] = 0x80;
+    set_checksums(&mut rom);
+
+    let header = CartridgeHeader::parse(&rom).unwrap();
+
+    assert_eq!(header.title, "FIFTEEN_CHARS__");
+    assert!(header.cgb_support);
+}
+
+#[test]
+fn decodes_the_cartridge_hardware() {
+    let decode = |cartridge_type| {
+        let header = CartridgeHeader::parse(&rom(b"", cartridge_type, 0)).unwrap();
+        let cartridge_type = header.cartridge_type;
+
+        (
+            cartridge_type.mapper,
+            cartridge_type.battery,
+            cartridge_type.timer,
+            cartridge_type.rumble,
+        )
+    };
+
+    assert_eq!(decode(0x00), (MapperKind::RomOnly, false, false, false));
+    assert_eq!(decode(0x06), (MapperKind::Mbc2, true, false, false));
+    assert_eq!(decode(0x10), (MapperKind::Mbc3, true, true, false));
+    assert_eq!(decode(0x13), (MapperKind::Mbc3, true, false, false));
+    assert_eq!(decode(0x1C), (MapperKind::Mbc5, false, false, true));
+    assert_eq!(decode(0x1E), (MapperKind::Mbc5, true, false, true));
+}
+
+#[test]
+fn rejects_the_invalid_headers() {
+    assert_eq!(
+        CartridgeHeader::parse(&[0; 0x14F]),
+        Err(CartridgeError::RomTooSmall(0x14F))
+    );
+
+    assert_eq!(
+        CartridgeHeader::parse(&rom(b"", 0xFF, 0)),
+        Err(CartridgeError::UnsupportedCartridgeType(0xFF))
+    );
+
+    assert_eq!(
+        CartridgeHeader::parse(&rom(b"", 0x00, 6)),
+        Err(CartridgeError::InvalidRamSize(6))
+    );
+
+    let mut rom_with_invalid_size = rom(b"", 0x00, 0);
+    rom_with_invalid_size[ROM_SIZE_ADDRESS] = 9;
+
+    assert_eq!(
+        CartridgeHeader::parse(&rom_with_invalid_size),
+        Err(CartridgeError::InvalidRomSize(9))
+    );
+}
+
+#[test]
+fn rejects_a_rom_size_mismatch() {
+    let mut rom = rom(b"", 0x00, 0);
+    rom[ROM_SIZE_ADDRESS] = 1;
+    set_checksums(&mut rom);
+
+    assert_eq!(
+        CartridgeHeader::parse(&rom),
+        Err(CartridgeError::RomSizeMismatch {
+            header: 0x1_0000,
+            actual: 0x8000,
+        })
+    );
+}
+
+#[test]
+fn rejects_a_header_checksum_mismatch() {
+    let mut rom = rom(b"TEST", 0x00, 0);
+    rom[HEADER_CHECKSUM_ADDRESS] ^= 0xFF;
+
+    assert!(matches!(
+        CartridgeHeader::parse(&rom),
+        Err(CartridgeError::HeaderChecksumMismatch { .. })
+    ));
+}
+
+#[test]
+fn reports_a_global_checksum_mismatch_without_rejecting_the_rom() {
+    let mut rom = rom(b"TEST", 0x00, 0);
+    rom[0x4000] = 0x01;
+
+    let header = CartridgeHeader::parse(&rom).unwrap();
+
+    assert!(!header.global_checksum_matches());
+    assert_eq!(
+        header.actual_global_checksum,
+        header.global_checksum.wrapping_add(1)
+    );
+}
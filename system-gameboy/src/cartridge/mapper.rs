@@ -0,0 +1,63 @@
+use super::cartridge_header::ROM_BANK_SIZE;
+use super::rtc::Rtc;
+
+type Byte = u8;
+type Word = u16;
+
+const RAM_BANK_SIZE: usize = 0x2000;
+
+const BANK_OFFSET_MASK: Word = 0x3FFF;
+const RAM_BANK_OFFSET_MASK: Word = 0x1FFF;
+
+/// Memory bank controller of a cartridge.
+///
+/// The ROM is mapped at 0x0000-0x7FFF, and the RAM at 0xA000-0xBFFF; writes to the ROM area set the
+/// controller registers. The addresses passed are the bus ones.
+///
+pub(crate) trait Mapper {
+    fn read_rom(&self, address: Word) -> Byte;
+
+    fn write_rom(&mut self, address: Word, value: Byte);
+
+    fn read_ram(&self, address: Word) -> Byte;
+
+    fn write_ram(&mut self, address: Word, value: Byte);
+
+    /// Whole RAM, as stored in the save files.
+    ///
+    fn ram(&self) -> &[Byte];
+
+    fn ram_mut(&mut self) -> &mut [Byte];
+
+    fn rtc(&self) -> Option<&Rtc> {
+        None
+    }
+
+    fn rtc_mut(&mut self) -> Option<&mut Rtc> {
+        None
+    }
+
+    /// Advances the time-based hardware (e.g. RTC), if present.
+    ///
+    fn tick(&mut self, _cycles: u8) {}
+
+    /// State of the rumble motor, if present.
+    ///
+    fn rumble(&self) -> bool {
+        false
+    }
+}
+
+// The bank numbers are truncated to the size of the ROM, like the unconnected address lines do.
+//
+pub(crate) fn rom_index(rom: &[Byte], bank: usize, address: Word) -> usize {
+    let banks_count = rom.len() / ROM_BANK_SIZE;
+
+    (bank % banks_count) * ROM_BANK_SIZE + (address & BANK_OFFSET_MASK) as usize
+}
+
+// RAM smaller than a bank (2 KiB) is mirrored. Must not be invoked with an empty RAM.
+//
+pub(crate) fn ram_index(ram: &[Byte], bank: usize, address: Word) -> usize {
+    (bank * RAM_BANK_SIZE + (address & RAM_BANK_OFFSET_MASK) as usize) % ram.len()
+}
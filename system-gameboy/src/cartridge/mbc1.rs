@@ -0,0 +1,143 @@
+use super::cartridge_header::ROM_BANK_SIZE;
+use super::mapper::{ram_index, rom_index, Mapper};
+
+type Byte = u8;
+type Word = u16;
+
+const RAM_ENABLE_MASK: Byte = 0b0000_1111;
+const RAM_ENABLE_VALUE: Byte = 0x0A;
+
+const BANK_1_MASK: Byte = 0b0001_1111;
+const BANK_2_MASK: Byte = 0b0000_0011;
+const MODE_MASK: Byte = 0b0000_0001;
+
+// On multicarts, the bit 4 of BANK1 is not connected, so BANK2 selects one of the four 256 KiB
+// games.
+//
+const BANK_2_SHIFT: u8 = 5;
+const MULTICART_BANK_2_SHIFT: u8 = 4;
+const MULTICART_BANK_1_MASK: usize = 0b0000_1111;
+
+const MULTICART_SIZE: usize = 0x10_0000;
+
+// Multicarts are detected by the presence of the logo in the header of the second game.
+//
+const LOGO_ADDRESS: usize = 0x104;
+const LOGO_SIZE: usize = 0x30;
+const MULTICART_SECOND_GAME_BANK: usize = 0x10;
+
+/// MBC1: up to 2 MiB of ROM and 32 KiB of RAM.
+///
+/// The 2 bit register BANK2 selects, depending on the mode, either the upper bits of the ROM bank
+/// (in mode 1, also for the area 0x0000-0x3FFF), or the RAM bank.
+/// A value of 0 for the 5 bit register BANK1 is translated to 1, so that the banks 0x00, 0x20, 0x40
+/// and 0x60 can't be mapped at 0x4000-0x7FFF.
+///
+pub(crate) struct Mbc1 {
+    rom: Vec<Byte>,
+    ram: Vec<Byte>,
+
+    ram_enabled: bool,
+    bank_1: Byte,
+    bank_2: Byte,
+    mode: Byte,
+
+    multicart: bool,
+}
+
+impl Mbc1 {
+    pub fn new(rom: Vec<Byte>, ram_size: usize) -> Mbc1 {
+        let multicart = Self::is_multicart(&rom);
+
+        Mbc1 {
+            rom,
+            ram: vec![0; ram_size],
+
+            ram_enabled: false,
+            bank_1: 1,
+            bank_2: 0,
+            mode: 0,
+
+            multicart,
+        }
+    }
+
+    fn is_multicart(rom: &[Byte]) -> bool {
+        if rom.len() != MULTICART_SIZE {
+            return false;
+        }
+
+        let second_game_logo_address = MULTICART_SECOND_GAME_BANK * ROM_BANK_SIZE + LOGO_ADDRESS;
+
+        rom[LOGO_ADDRESS..LOGO_ADDRESS + LOGO_SIZE]
+            == rom[second_game_logo_address..second_game_logo_address + LOGO_SIZE]
+    }
+
+    fn upper_rom_bank(&self) -> usize {
+        if self.multicart {
+            (self.bank_2 as usize) << MULTICART_BANK_2_SHIFT
+        } else {
+            (self.bank_2 as usize) << BANK_2_SHIFT
+        }
+    }
+
+    fn lower_rom_bank(&self) -> usize {
+        if self.multicart {
+            self.bank_1 as usize & MULTICART_BANK_1_MASK
+        } else {
+            self.bank_1 as usize
+        }
+    }
+
+    fn ram_bank(&self) -> usize {
+        if self.mode == 1 {
+            self.bank_2 as usize
+        } else {
+            0
+        }
+    }
+}
+
+impl Mapper for Mbc1 {
+    fn read_rom(&self, address: Word) -> Byte {
+        let bank = match address {
+            0..=0x3FFF if self.mode == 1 => self.upper_rom_bank(),
+            0..=0x3FFF => 0,
+            _ => self.upper_rom_bank() | self.lower_rom_bank(),
+        };
+
+        self.rom[rom_index(&self.rom, bank, address)]
+    }
+
+    fn write_rom(&mut self, address: Word, value: Byte) {
+        match address {
+            0x0000..=0x1FFF => self.ram_enabled = value & RAM_ENABLE_MASK == RAM_ENABLE_VALUE,
+            0x2000..=0x3FFF => self.bank_1 = (value & BANK_1_MASK).max(1),
+            0x4000..=0x5FFF => self.bank_2 = value & BANK_2_MASK,
+            _ => self.mode = value & MODE_MASK,
+        }
+    }
+
+    fn read_ram(&self, address: Word) -> Byte {
+        if !self.ram_enabled || self.ram.is_empty() {
+            return 0xFF;
+        }
+
+        self.ram[ram_index(&self.ram, self.ram_bank(), address)]
+    }
+
+    fn write_ram(&mut self, address: Word, value: Byte) {
+        if self.ram_enabled && !self.ram.is_empty() {
+            let index = ram_index(&self.ram, self.ram_bank(), address);
+            self.ram[index] = value;
+        }
+    }
+
+    fn ram(&self) -> &[Byte] {
+        &self.ram
+    }
+
+    fn ram_mut(&mut self) -> &mut [Byte] {
+        &mut self.ram
+    }
+}
@@ -0,0 +1,148 @@
+use super::cartridge_header::ROM_BANK_SIZE;
+use super::mapper::Mapper;
+use super::mbc1::Mbc1;
+use super::test_helpers::{banked_rom, mapped_bank};
+
+type Byte = u8;
+
+const LOGO_ADDRESS: usize = 0x104;
+
+// A multicart is made of four 256 KiB games, each with the logo in the header.
+//
+fn multicart_rom() -> Vec<Byte> {
+    let mut rom = banked_rom(64);
+
+    for game in 0..4 {
+        let logo_address = game * 0x10 * ROM_BANK_SIZE + LOGO_ADDRESS;
+        rom[logo_address..logo_address + 0x30].copy_from_slice(&[0xCE; 0x30]);
+    }
+
+    rom
+}
+
+#[test]
+fn maps_the_banks_selected_by_bank_1() {
+    let mut mapper = Mbc1::new(banked_rom(128), 0);
+
+    assert_eq!(mapped_bank(&mapper, 0x0000), 0);
+    assert_eq!(mapped_bank(&mapper, 0x4000), 1);
+
+    mapper.write_rom(0x2000, 0x05);
+
+    assert_eq!(mapped_bank(&mapper, 0x0000), 0);
+    assert_eq!(mapped_bank(&mapper, 0x4000), 5);
+}
+
+#[test]
+fn translates_bank_1_zero_to_one() {
+    let mut mapper = Mbc1::new(banked_rom(128), 0);
+
+    mapper.write_rom(0x2000, 0x00);
+    assert_eq!(mapped_bank(&mapper, 0x4000), 1);
+
+    mapper.write_rom(0x2000, 0x20);
+    assert_eq!(mapped_bank(&mapper, 0x4000), 1);
+
+    mapper.write_rom(0x4000, 0x01);
+    assert_eq!(mapped_bank(&mapper, 0x4000), 0x21);
+}
+
+#[test]
+fn maps_the_upper_bank_bits_at_0x0000_in_mode_1() {
+    let mut mapper = Mbc1::new(banked_rom(128), 0);
+
+    mapper.write_rom(0x4000, 0x02);
+    mapper.write_rom(0x2000, 0x03);
+
+    assert_eq!(mapped_bank(&mapper, 0x0000), 0x00);
+    assert_eq!(mapped_bank(&mapper, 0x4000), 0x43);
+
+    mapper.write_rom(0x6000, 0x01);
+
+    assert_eq!(mapped_bank(&mapper, 0x0000), 0x40);
+    assert_eq!(mapped_bank(&mapper, 0x4000), 0x43);
+}
+
+#[test]
+fn truncates_the_bank_to_the_rom_size() {
+    let mut mapper = Mbc1::new(banked_rom(4), 0);
+
+    mapper.write_rom(0x2000, 0x06);
+
+    assert_eq!(mapped_bank(&mapper, 0x4000), 2);
+}
+
+#[test]
+fn ram_is_accessible_only_when_enabled() {
+    let mut mapper = Mbc1::new(banked_rom(4), 0x2000);
+
+    mapper.write_ram(0xA000, 0x42);
+    assert_eq!(mapper.read_ram(0xA000), 0xFF);
+
+    mapper.write_rom(0x0000, 0x1A);
+    mapper.write_ram(0xA000, 0x42);
+    assert_eq!(mapper.read_ram(0xA000), 0x42);
+
+    mapper.write_rom(0x0000, 0x00);
+    assert_eq!(mapper.read_ram(0xA000), 0xFF);
+}
+
+#[test]
+fn maps_the_ram_bank_selected_by_bank_2_in_mode_1() {
+    let mut mapper = Mbc1::new(banked_rom(4), 0x8000);
+
+    mapper.write_rom(0x0000, 0x0A);
+    mapper.write_rom(0x4000, 0x02);
+    mapper.write_ram(0xA001, 0x42);
+
+    mapper.write_rom(0x6000, 0x01);
+    mapper.write_ram(0xA001, 0x24);
+
+    assert_eq!(mapper.read_ram(0xA001), 0x24);
+
+    // In mode 0, the first write went to bank 0.
+    //
+    mapper.write_rom(0x4000, 0x00);
+
+    assert_eq!(mapper.read_ram(0xA001), 0x42);
+}
+
+#[test]
+fn multicart_uses_4_bits_of_bank_1() {
+    let mut mapper = Mbc1::new(multicart_rom(), 0);
+
+    mapper.write_rom(0x4000, 0x01);
+    mapper.write_rom(0x2000, 0x12);
+
+    assert_eq!(mapped_bank(&mapper, 0x4000), 0x12);
+
+    mapper.write_rom(0x6000, 0x01);
+
+    assert_eq!(mapped_bank(&mapper, 0x0000), 0x10);
+}
+
+// Bank 1 is translated from 0 before the masking, so the first bank of each game can be mapped at
+// 0x4000.
+//
+#[test]
+fn multicart_maps_the_first_bank_of_a_game_at_0x4000() {
+    let mut mapper = Mbc1::new(multicart_rom(), 0);
+
+    mapper.write_rom(0x4000, 0x02);
+    mapper.write_rom(0x2000, 0x10);
+
+    assert_eq!(mapped_bank(&mapper, 0x4000), 0x20);
+}
+
+#[test]
+fn roms_of_multicart_size_without_the_logos_are_regular() {
+    let mut rom = multicart_rom();
+    rom[0x10 * ROM_BANK_SIZE + LOGO_ADDRESS] = 0x00;
+
+    let mut mapper = Mbc1::new(rom, 0);
+
+    mapper.write_rom(0x4000, 0x01);
+    mapper.write_rom(0x2000, 0x12);
+
+    assert_eq!(mapped_bank(&mapper, 0x4000), 0x32);
+}
@@ -0,0 +1,85 @@
+use super::mapper::{rom_index, Mapper};
+
+type Byte = u8;
+type Word = u16;
+
+const RAM_SIZE: usize = 0x200;
+const RAM_ADDRESS_MASK: Word = 0x01FF;
+
+// The upper nibble of each RAM location is not connected, and reads high.
+//
+const RAM_VALUE_MASK: Byte = 0b0000_1111;
+const RAM_UNUSED_BITS: Byte = 0b1111_0000;
+
+const RAM_ENABLE_MASK: Byte = 0b0000_1111;
+const RAM_ENABLE_VALUE: Byte = 0x0A;
+const ROM_BANK_MASK: Byte = 0b0000_1111;
+
+// In the area 0x0000-0x3FFF, the address bit 8 selects the register.
+//
+const REGISTER_SELECT_BIT: Word = 0b1_0000_0000;
+
+/// MBC2: up to 256 KiB of ROM, and 512x4 bits of built-in RAM, mirrored over 0xA000-0xBFFF.
+///
+pub(crate) struct Mbc2 {
+    rom: Vec<Byte>,
+    ram: [Byte; RAM_SIZE],
+
+    ram_enabled: bool,
+    rom_bank: Byte,
+}
+
+impl Mbc2 {
+    pub fn new(rom: Vec<Byte>) -> Mbc2 {
+        Mbc2 {
+            rom,
+            ram: [0; RAM_SIZE],
+
+            ram_enabled: false,
+            rom_bank: 1,
+        }
+    }
+}
+
+impl Mapper for Mbc2 {
+    fn read_rom(&self, address: Word) -> Byte {
+        let bank = match address {
+            0..=0x3FFF => 0,
+            _ => self.rom_bank as usize,
+        };
+
+        self.rom[rom_index(&self.rom, bank, address)]
+    }
+
+    fn write_rom(&mut self, address: Word, value: Byte) {
+        match address {
+            0x0000..=0x3FFF if address & REGISTER_SELECT_BIT == 0 => {
+                self.ram_enabled = value & RAM_ENABLE_MASK == RAM_ENABLE_VALUE
+            }
+            0x0000..=0x3FFF => self.rom_bank = (value & ROM_BANK_MASK).max(1),
+            _ => {}
+        }
+    }
+
+    fn read_ram(&self, address: Word) -> Byte {
+        if !self.ram_enabled {
+            return 0xFF;
+        }
+
+        RAM_UNUSED_BITS | self.ram[(address & RAM_ADDRESS_MASK) as usize]
+    }
+
+    fn write_ram(&mut self, address: Word, value: Byte) {
+        if self.ram_enabled {
+            self.ram[(address & RAM_ADDRESS_MASK) as usize] = value & RAM_VALUE_MASK;
+        }
+    }
+
+    fn ram(&self) -> &[Byte] {
+        &self.ram
+    }
+
+    fn ram_mut(&mut self) -> &mut [Byte] {
+        &mut self.ram
+    }
+}
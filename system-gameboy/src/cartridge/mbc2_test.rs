@@ -0,0 +1,50 @@
+use super::mapper::Mapper;
+use super::mbc2::Mbc2;
+use super::test_helpers::{banked_rom, mapped_bank};
+
+#[test]
+fn address_bit_8_selects_the_rom_bank_register() {
+    let mut mapper = Mbc2::new(banked_rom(16));
+
+    assert_eq!(mapped_bank(&mapper, 0x0000), 0);
+    assert_eq!(mapped_bank(&mapper, 0x4000), 1);
+
+    mapper.write_rom(0x2100, 0x03);
+    assert_eq!(mapped_bank(&mapper, 0x4000), 3);
+
+    mapper.write_rom(0x2000, 0x05);
+    assert_eq!(mapped_bank(&mapper, 0x4000), 3);
+
+    mapper.write_rom(0x3FFF, 0x13);
+    assert_eq!(mapped_bank(&mapper, 0x4000), 3);
+
+    mapper.write_rom(0x0100, 0x00);
+    assert_eq!(mapped_bank(&mapper, 0x4000), 1);
+}
+
+#[test]
+fn address_bit_8_clear_selects_the_ram_enable_register() {
+    let mut mapper = Mbc2::new(banked_rom(16));
+
+    mapper.write_rom(0x0100, 0x0A);
+    assert_eq!(mapper.read_ram(0xA000), 0xFF);
+
+    mapper.write_rom(0x3EFF, 0x0A);
+    mapper.write_ram(0xA000, 0x05);
+    assert_eq!(mapper.read_ram(0xA000), 0xF5);
+
+    mapper.write_rom(0x0000, 0x00);
+    assert_eq!(mapper.read_ram(0xA000), 0xFF);
+}
+
+#[test]
+fn ram_stores_the_lower_nibble_and_is_mirrored() {
+    let mut mapper = Mbc2::new(banked_rom(16));
+
+    mapper.write_rom(0x0000, 0x0A);
+    mapper.write_ram(0xA1FF, 0xAB);
+
+    assert_eq!(mapper.read_ram(0xA1FF), 0xFB);
+    assert_eq!(mapper.read_ram(0xA3FF), 0xFB);
+    assert_eq!(mapper.read_ram(0xBFFF), 0xFB);
+}
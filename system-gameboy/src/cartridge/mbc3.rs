@@ -0,0 +1,126 @@
+use super::mapper::{ram_index, rom_index, Mapper};
+use super::rtc::{Rtc, RTC_REGISTERS_COUNT};
+
+type Byte = u8;
+type Word = u16;
+
+const RAM_ENABLE_MASK: Byte = 0b0000_1111;
+const RAM_ENABLE_VALUE: Byte = 0x0A;
+
+const ROM_BANK_MASK: Byte = 0b0111_1111;
+
+// Values of the RAM bank register selecting the RTC registers.
+//
+const RTC_REGISTERS_SELECT: Byte = 0x08;
+const RTC_REGISTERS_SELECT_END: Byte = RTC_REGISTERS_SELECT + RTC_REGISTERS_COUNT as Byte - 1;
+
+/// MBC3: up to 2 MiB of ROM, 32 KiB of RAM and, optionally, the real time clock.
+///
+/// The RAM bank register selects either a RAM bank (0x00-0x03), or an RTC register (0x08-0x0C),
+/// which is then accessed through the RAM area.
+///
+pub(crate) struct Mbc3 {
+    rom: Vec<Byte>,
+    ram: Vec<Byte>,
+    rtc: Option<Rtc>,
+
+    ram_enabled: bool,
+    rom_bank: Byte,
+    ram_bank: Byte,
+}
+
+impl Mbc3 {
+    pub fn new(rom: Vec<Byte>, ram_size: usize, has_rtc: bool) -> Mbc3 {
+        Mbc3 {
+            rom,
+            ram: vec![0; ram_size],
+            rtc: if has_rtc { Some(Rtc::new()) } else { None },
+
+            ram_enabled: false,
+            rom_bank: 1,
+            ram_bank: 0,
+        }
+    }
+
+    fn selected_rtc_register(&self) -> Option<usize> {
+        match self.ram_bank {
+            RTC_REGISTERS_SELECT..=RTC_REGISTERS_SELECT_END if self.rtc.is_some() => {
+                Some((self.ram_bank - RTC_REGISTERS_SELECT) as usize)
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Mapper for Mbc3 {
+    fn read_rom(&self, address: Word) -> Byte {
+        let bank = match address {
+            0..=0x3FFF => 0,
+            _ => self.rom_bank as usize,
+        };
+
+        self.rom[rom_index(&self.rom, bank, address)]
+    }
+
+    fn write_rom(&mut self, address: Word, value: Byte) {
+        match address {
+            0x0000..=0x1FFF => self.ram_enabled = value & RAM_ENABLE_MASK == RAM_ENABLE_VALUE,
+            0x2000..=0x3FFF => self.rom_bank = (value & ROM_BANK_MASK).max(1),
+            0x4000..=0x5FFF => self.ram_bank = value,
+            _ => {
+                if let Some(rtc) = &mut self.rtc {
+                    rtc.write_latch(value);
+                }
+            }
+        }
+    }
+
+    fn read_ram(&self, address: Word) -> Byte {
+        if !self.ram_enabled {
+            return 0xFF;
+        }
+
+        if let Some(register) = self.selected_rtc_register() {
+            self.rtc.as_ref().unwrap().read(register)
+        } else if self.ram_bank < RTC_REGISTERS_SELECT && !self.ram.is_empty() {
+            self.ram[ram_index(&self.ram, self.ram_bank as usize, address)]
+        } else {
+            0xFF
+        }
+    }
+
+    fn write_ram(&mut self, address: Word, value: Byte) {
+        if !self.ram_enabled {
+            return;
+        }
+
+        if let Some(register) = self.selected_rtc_register() {
+            self.rtc.as_mut().unwrap().write(register, value);
+        } else if self.ram_bank < RTC_REGISTERS_SELECT && !self.ram.is_empty() {
+            let index = ram_index(&self.ram, self.ram_bank as usize, address);
+            self.ram[index] = value;
+        }
+    }
+
+    fn ram(&self) -> &[Byte] {
+        &self.ram
+    }
+
+    fn ram_mut(&mut self) -> &mut [Byte] {
+        &mut self.ram
+    }
+
+    fn rtc(&self) -> Option<&Rtc> {
+        self.rtc.as_ref()
+    }
+
+    fn rtc_mut(&mut self) -> Option<&mut Rtc> {
+        self.rtc.as_mut()
+    }
+
+    fn tick(&mut self, cycles: u8) {
+        if let Some(rtc) = &mut self.rtc {
+            rtc.tick(cycles);
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use super::mapper::Mapper;
+use super::mbc3::Mbc3;
+use super::test_helpers::{banked_rom, mapped_bank};
+
+fn enabled_mapper(ram_size: usize, has_rtc: bool) -> Mbc3 {
+    let mut mapper = Mbc3::new(banked_rom(128), ram_size, has_rtc);
+    mapper.write_rom(0x0000, 0x0A);
+    mapper
+}
+
+#[test]
+fn maps_the_rom_bank_with_7_bits() {
+    let mut mapper = Mbc3::new(banked_rom(128), 0, false);
+
+    mapper.write_rom(0x2000, 0x7F);
+    assert_eq!(mapped_bank(&mapper, 0x4000), 0x7F);
+
+    mapper.write_rom(0x2000, 0x00);
+    assert_eq!(mapped_bank(&mapper, 0x4000), 0x01);
+
+    mapper.write_rom(0x2000, 0xA0);
+    assert_eq!(mapped_bank(&mapper, 0x4000), 0x20);
+
+    assert_eq!(mapped_bank(&mapper, 0x0000), 0x00);
+}
+
+#[test]
+fn maps_the_ram_bank() {
+    let mut mapper = enabled_mapper(0x8000, false);
+
+    mapper.write_rom(0x4000, 0x03);
+    mapper.write_ram(0xA010, 0x42);
+
+    assert_eq!(mapper.read_ram(0xA010), 0x42);
+
+    mapper.write_rom(0x4000, 0x00);
+
+    assert_eq!(mapper.read_ram(0xA010), 0x00);
+}
+
+#[test]
+fn ram_is_accessible_only_when_enabled() {
+    let mut mapper = enabled_mapper(0x2000, false);
+
+    mapper.write_ram(0xA000, 0x42);
+    mapper.write_rom(0x0000, 0x00);
+    mapper.write_ram(0xA000, 0x24);
+
+    assert_eq!(mapper.read_ram(0xA000), 0xFF);
+
+    mapper.write_rom(0x0000, 0x0A);
+
+    assert_eq!(mapper.read_ram(0xA000), 0x42);
+}
+
+#[test]
+fn rtc_registers_are_accessed_through_the_ram_area() {
+    let mut mapper = enabled_mapper(0x2000, true);
+
+    mapper.write_rom(0x4000, 0x09); // Minutes
+    mapper.write_ram(0xA000, 0x2A);
+
+    // The registers are read after the latch.
+    //
+    assert_eq!(mapper.read_ram(0xA000), 0x00);
+
+    mapper.write_rom(0x6000, 0x00);
+    mapper.write_rom(0x6000, 0x01);
+
+    assert_eq!(mapper.read_ram(0xA000), 0x2A);
+
+    mapper.write_rom(0x4000, 0x00);
+
+    assert_eq!(mapper.read_ram(0xA000), 0x00);
+}
+
+#[test]
+fn rtc_register_selection_without_rtc_reads_high() {
+    let mut mapper = enabled_mapper(0x2000, false);
+
+    mapper.write_rom(0x4000, 0x08);
+    mapper.write_ram(0xA000, 0x2A);
+
+    assert_eq!(mapper.read_ram(0xA000), 0xFF);
+
+    mapper.write_rom(0x4000, 0x00);
+
+    assert_eq!(mapper.read_ram(0xA000), 0x00);
+}
@@ -0,0 +1,100 @@
+use super::mapper::{ram_index, rom_index, Mapper};
+
+type Byte = u8;
+type Word = u16;
+
+// Unlike the other MBCs, all the bits are compared.
+//
+const RAM_ENABLE_VALUE: Byte = 0x0A;
+
+const ROM_BANK_HIGH_MASK: Byte = 0b0000_0001;
+const RAM_BANK_MASK: Byte = 0b0000_1111;
+
+// On rumble cartridges, the bit 3 of the RAM bank register drives the motor.
+//
+const RUMBLE_BIT: Byte = 0b0000_1000;
+
+/// MBC5: up to 8 MiB of ROM and 128 KiB of RAM; optionally, the rumble motor.
+///
+/// The ROM bank is 9 bits wide, and, unlike the other MBCs, bank 0 can be mapped at 0x4000-0x7FFF.
+///
+pub(crate) struct Mbc5 {
+    rom: Vec<Byte>,
+    ram: Vec<Byte>,
+    has_rumble: bool,
+
+    ram_enabled: bool,
+    rom_bank: Word,
+    ram_bank: Byte,
+    rumble: bool,
+}
+
+impl Mbc5 {
+    pub fn new(rom: Vec<Byte>, ram_size: usize, has_rumble: bool) -> Mbc5 {
+        Mbc5 {
+            rom,
+            ram: vec![0; ram_size],
+            has_rumble,
+
+            ram_enabled: false,
+            rom_bank: 1,
+            ram_bank: 0,
+            rumble: false,
+        }
+    }
+}
+
+impl Mapper for Mbc5 {
+    fn read_rom(&self, address: Word) -> Byte {
+        let bank = match address {
+            0..=0x3FFF => 0,
+            _ => self.rom_bank as usize,
+        };
+
+        self.rom[rom_index(&self.rom, bank, address)]
+    }
+
+    fn write_rom(&mut self, address: Word, value: Byte) {
+        match address {
+            0x0000..=0x1FFF => self.ram_enabled = value == RAM_ENABLE_VALUE,
+            0x2000..=0x2FFF => self.rom_bank = (self.rom_bank & 0xFF00) | value as Word,
+            0x3000..=0x3FFF => {
+                self.rom_bank =
+                    (self.rom_bank & 0x00FF) | (((value & ROM_BANK_HIGH_MASK) as Word) << 8)
+            }
+            0x4000..=0x5FFF if self.has_rumble => {
+                self.rumble = value & RUMBLE_BIT != 0;
+                self.ram_bank = value & RAM_BANK_MASK & !RUMBLE_BIT;
+            }
+            0x4000..=0x5FFF => self.ram_bank = value & RAM_BANK_MASK,
+            _ => {}
+        }
+    }
+
+    fn read_ram(&self, address: Word) -> Byte {
+        if !self.ram_enabled || self.ram.is_empty() {
+            return 0xFF;
+        }
+
+        self.ram[ram_index(&self.ram, self.ram_bank as usize, address)]
+    }
+
+    fn write_ram(&mut self, address: Word, value: Byte) {
+        if self.ram_enabled && !self.ram.is_empty() {
+            let index = ram_index(&self.ram, self.ram_bank as usize, address);
+            self.ram[index] = value;
+        }
+    }
+
+    fn ram(&self) -> &[Byte] {
+        &self.ram
+    }
+
+    fn ram_mut(&mut self) -> &mut [Byte] {
+        &mut self.ram
+    }
+
+    fn rumble(&self) -> bool {
+        self.rumble
+    }
+}
@@ -0,0 +1,77 @@
+use super::mapper::Mapper;
+use super::mbc5::Mbc5;
+use super::test_helpers::{banked_rom, mapped_bank};
+
+#[test]
+fn maps_the_rom_bank_with_9_bits() {
+    let mut mapper = Mbc5::new(banked_rom(512), 0, false);
+
+    mapper.write_rom(0x2000, 0x23);
+    mapper.write_rom(0x3000, 0x01);
+
+    assert_eq!(mapped_bank(&mapper, 0x4000), 0x123);
+
+    mapper.write_rom(0x3000, 0xFE);
+
+    assert_eq!(mapped_bank(&mapper, 0x4000), 0x023);
+    assert_eq!(mapped_bank(&mapper, 0x0000), 0x000);
+}
+
+#[test]
+fn maps_bank_0_at_0x4000() {
+    let mut mapper = Mbc5::new(banked_rom(4), 0, false);
+
+    mapper.write_rom(0x2000, 0x00);
+
+    assert_eq!(mapped_bank(&mapper, 0x4000), 0);
+}
+
+#[test]
+fn ram_is_enabled_only_by_0x0a() {
+    let mut mapper = Mbc5::new(banked_rom(4), 0x2000, false);
+
+    mapper.write_rom(0x0000, 0x1A);
+    mapper.write_ram(0xA000, 0x42);
+
+    assert_eq!(mapper.read_ram(0xA000), 0xFF);
+
+    mapper.write_rom(0x0000, 0x0A);
+    mapper.write_ram(0xA000, 0x42);
+
+    assert_eq!(mapper.read_ram(0xA000), 0x42);
+}
+
+#[test]
+fn maps_the_ram_bank() {
+    let mut mapper = Mbc5::new(banked_rom(4), 0x2_0000, false);
+
+    mapper.write_rom(0x0000, 0x0A);
+    mapper.write_rom(0x4000, 0x0B);
+    mapper.write_ram(0xA000, 0x42);
+
+    assert_eq!(mapper.read_ram(0xA000), 0x42);
+    assert!(!mapper.rumble());
+
+    mapper.write_rom(0x4000, 0x03);
+
+    assert_eq!(mapper.read_ram(0xA000), 0x00);
+}
+
+#[test]
+fn ram_bank_bit_3_drives_the_rumble_motor() {
+    let mut mapper = Mbc5::new(banked_rom(4), 0x8000, true);
+
+    mapper.write_rom(0x0000, 0x0A);
+    mapper.write_rom(0x4000, 0x0B);
+
+    assert!(mapper.rumble());
+
+    mapper.write_ram(0xA000, 0x42);
+
+    // The bit is not part of the bank number.
+    //
+    mapper.write_rom(0x4000, 0x03);
+
+    assert!(!mapper.rumble());
+    assert_eq!(mapper.read_ram(0xA000), 0x42);
+}
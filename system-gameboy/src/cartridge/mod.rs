@@ -0,0 +1,60 @@
+mod cartridge_error;
+mod cartridge_header;
+mod mapper;
+mod mbc1;
+mod mbc2;
+mod mbc3;
+mod mbc5;
+mod rom_only;
+mod rtc;
+mod save_data;
+
+#[cfg(test)]
+mod cartridge_header_test;
+#[cfg(test)]
+mod mbc1_test;
+#[cfg(test)]
+mod mbc2_test;
+#[cfg(test)]
+mod mbc3_test;
+#[cfg(test)]
+mod mbc5_test;
+#[cfg(test)]
+mod rtc_test;
+#[cfg(test)]
+mod save_data_test;
+#[cfg(test)]
+mod test_helpers;
+
+pub use cartridge_error::CartridgeError;
+pub use cartridge_header::{CartridgeHeader, CartridgeType, MapperKind};
+pub(crate) use mapper::Mapper;
+pub(crate) use save_data::{decode as decode_save_data, encode as encode_save_data};
+
+use mbc1::Mbc1;
+use mbc2::Mbc2;
+use mbc3::Mbc3;
+use mbc5::Mbc5;
+use rom_only::RomOnly;
+
+type Byte = u8;
+
+/// Validates the header, and creates the mapper for the cartridge type.
+///
+pub(crate) fn load(rom: &[Byte]) -> Result<(CartridgeHeader, Box<dyn Mapper>), CartridgeError> {
+    let header = CartridgeHeader::parse(rom)?;
+
+    let rom = rom.to_vec();
+    let ram_size = header.ram_size;
+    let cartridge_type = header.cartridge_type;
+
+    let mapper: Box<dyn Mapper> = match cartridge_type.mapper {
+        MapperKind::RomOnly => Box::new(RomOnly::new(rom, ram_size)),
+        MapperKind::Mbc1 => Box::new(Mbc1::new(rom, ram_size)),
+        MapperKind::Mbc2 => Box::new(Mbc2::new(rom)),
+        MapperKind::Mbc3 => Box::new(Mbc3::new(rom, ram_size, cartridge_type.timer)),
+        MapperKind::Mbc5 => Box::new(Mbc5::new(rom, ram_size, cartridge_type.rumble)),
+    };
+
+    Ok((header, mapper))
+}
@@ -0,0 +1,51 @@
+use super::mapper::{ram_index, Mapper};
+
+type Byte = u8;
+type Word = u16;
+
+/// Cartridge without memory bank controller: 32 KiB of ROM, and optionally up to 8 KiB of RAM.
+///
+pub(crate) struct RomOnly {
+    rom: Vec<Byte>,
+    ram: Vec<Byte>,
+}
+
+impl RomOnly {
+    pub fn new(rom: Vec<Byte>, ram_size: usize) -> RomOnly {
+        RomOnly {
+            rom,
+            ram: vec![0; ram_size],
+        }
+    }
+}
+
+impl Mapper for RomOnly {
+    fn read_rom(&self, address: Word) -> Byte {
+        self.rom[address as usize]
+    }
+
+    fn write_rom(&mut self, _address: Word, _value: Byte) {}
+
+    fn read_ram(&self, address: Word) -> Byte {
+        if self.ram.is_empty() {
+            0xFF
+        } else {
+            self.ram[ram_index(&self.ram, 0, address)]
+        }
+    }
+
+    fn write_ram(&mut self, address: Word, value: Byte) {
+        if !self.ram.is_empty() {
+            let index = ram_index(&self.ram, 0, address);
+            self.ram[index] = value;
+        }
+    }
+
+    fn ram(&self) -> &[Byte] {
+        &self.ram
+    }
+
+    fn ram_mut(&mut self) -> &mut [Byte] {
+        &mut self.ram
+    }
+}
@@ -0,0 +1,189 @@
+use crate::game_boy::CLOCK_SPEED;
+
+type Byte = u8;
+
+pub(crate) const RTC_REGISTERS_COUNT: usize = 5;
+
+// Save file footer (VBA-M/BGB layout): the live and latched registers, as 32 bit values, followed by
+// the UNIX timestamp of the save, as 64 bit value (32 bit in the older variant); all little endian.
+//
+const FOOTER_REGISTER_SIZE: usize = 4;
+const FOOTER_TIMESTAMP_ADDRESS: usize = 2 * RTC_REGISTERS_COUNT * FOOTER_REGISTER_SIZE;
+pub(crate) const FOOTER_SIZE: usize = FOOTER_TIMESTAMP_ADDRESS + 8;
+pub(crate) const LEGACY_FOOTER_SIZE: usize = FOOTER_TIMESTAMP_ADDRESS + 4;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+const SECONDS: usize = 0;
+const MINUTES: usize = 1;
+const HOURS: usize = 2;
+const DAYS_LOW: usize = 3;
+const DAYS_HIGH: usize = 4;
+
+// Bits implemented by each register; the other ones read as 0.
+//
+const REGISTER_MASKS: [Byte; RTC_REGISTERS_COUNT] = [0x3F, 0x3F, 0x1F, 0xFF, 0xC1];
+
+const DAYS_HIGH_BIT: Byte = 0b0000_0001;
+const DAYS_MASK: u64 = 0x1FF;
+const HALT_BIT: Byte = 0b0100_0000;
+const DAYS_CARRY_BIT: Byte = 0b1000_0000;
+
+/// MBC3 real time clock: seconds, minutes, hours, and a 9 bit day counter with carry flag.
+///
+/// The CPU reads a latched copy of the registers, which is updated by writing 0x00 then 0x01 to
+/// the latch register. The clock is driven by the system clock, so that it's deterministic.
+/// Counters set to out-of-range values keep incrementing up to their bit width, then wrap to 0
+/// without carry, like on the real hardware.
+///
+pub(crate) struct Rtc {
+    registers: [Byte; RTC_REGISTERS_COUNT],
+    latched_registers: [Byte; RTC_REGISTERS_COUNT],
+
+    subsecond_cycles: u32,
+    latch_armed: bool,
+}
+
+impl Rtc {
+    pub fn new() -> Rtc {
+        Rtc {
+            registers: [0; RTC_REGISTERS_COUNT],
+            latched_registers: [0; RTC_REGISTERS_COUNT],
+
+            subsecond_cycles: 0,
+            latch_armed: false,
+        }
+    }
+
+    pub fn read(&self, register: usize) -> Byte {
+        self.latched_registers[register]
+    }
+
+    // Writing the seconds also resets the subsecond counter.
+    //
+    pub fn write(&mut self, register: usize, value: Byte) {
+        self.registers[register] = value & REGISTER_MASKS[register];
+
+        if register == SECONDS {
+            self.subsecond_cycles = 0;
+        }
+    }
+
+    pub fn write_latch(&mut self, value: Byte) {
+        if self.latch_armed && value == 0x01 {
+            self.latched_registers = self.registers;
+        }
+
+        self.latch_armed = value == 0x00;
+    }
+
+    pub fn encode_footer(&self, timestamp: u64) -> Vec<Byte> {
+        let mut footer = Vec::with_capacity(FOOTER_SIZE);
+
+        for &register in self.registers.iter().chain(self.latched_registers.iter()) {
+            footer.extend_from_slice(&(register as u32).to_le_bytes());
+        }
+
+        footer.extend_from_slice(&timestamp.to_le_bytes());
+
+        footer
+    }
+
+    /// Restores the registers from a footer (either variant), and advances the clock by the time
+    /// elapsed since the save.
+    ///
+    pub fn decode_footer(&mut self, footer: &[Byte], current_timestamp: u64) {
+        let register_values = footer[..FOOTER_TIMESTAMP_ADDRESS]
+            .chunks(FOOTER_REGISTER_SIZE)
+            .map(|value| value[0])
+            .enumerate()
+            .map(|(i, value)| value & REGISTER_MASKS[i % RTC_REGISTERS_COUNT])
+            .collect::<Vec<Byte>>();
+
+        self.registers
+            .copy_from_slice(&register_values[..RTC_REGISTERS_COUNT]);
+        self.latched_registers
+            .copy_from_slice(&register_values[RTC_REGISTERS_COUNT..]);
+
+        let mut timestamp_bytes = [0; 8];
+        let timestamp_size = footer.len() - FOOTER_TIMESTAMP_ADDRESS;
+        timestamp_bytes[..timestamp_size].copy_from_slice(&footer[FOOTER_TIMESTAMP_ADDRESS..]);
+        let timestamp = u64::from_le_bytes(timestamp_bytes);
+
+        if self.registers[DAYS_HIGH] & HALT_BIT == 0 {
+            self.advance_seconds(current_timestamp.saturating_sub(timestamp));
+        }
+    }
+
+    pub fn tick(&mut self, cycles: u8) {
+        if self.registers[DAYS_HIGH] & HALT_BIT != 0 {
+            return;
+        }
+
+        self.subsecond_cycles += cycles as u32;
+
+        if self.subsecond_cycles >= CLOCK_SPEED {
+            self.subsecond_cycles -= CLOCK_SPEED;
+            self.increment_seconds();
+        }
+    }
+
+    // The counters are stepped until the start of a day, so that the whole days can be added in
+    // bulk (stepping handles the out-of-range values); the remainder is stepped again.
+    //
+    fn advance_seconds(&mut self, mut seconds: u64) {
+        while seconds > 0 && self.registers[SECONDS..=HOURS] != [0, 0, 0] {
+            self.increment_seconds();
+            seconds -= 1;
+        }
+
+        self.add_days(seconds / SECONDS_PER_DAY);
+
+        for _ in 0..(seconds % SECONDS_PER_DAY) {
+            self.increment_seconds();
+        }
+    }
+
+    fn increment_seconds(&mut self) {
+        if !Self::increment_counter(&mut self.registers[SECONDS], 60, REGISTER_MASKS[SECONDS]) {
+            return;
+        }
+
+        if !Self::increment_counter(&mut self.registers[MINUTES], 60, REGISTER_MASKS[MINUTES]) {
+            return;
+        }
+
+        if !Self::increment_counter(&mut self.registers[HOURS], 24, REGISTER_MASKS[HOURS]) {
+            return;
+        }
+
+        self.add_days(1);
+    }
+
+    // The carry is sticky; it's cleared only by writing the register.
+    //
+    fn add_days(&mut self, added_days: u64) {
+        let days_high_bit = (self.registers[DAYS_HIGH] & DAYS_HIGH_BIT) as u64;
+        let days = ((days_high_bit << 8) | self.registers[DAYS_LOW] as u64) + added_days;
+
+        self.registers[DAYS_LOW] = days as Byte;
+        self.registers[DAYS_HIGH] &= !DAYS_HIGH_BIT;
+        self.registers[DAYS_HIGH] |= ((days >> 8) as Byte) & DAYS_HIGH_BIT;
+
+        if days > DAYS_MASK {
+            self.registers[DAYS_HIGH] |= DAYS_CARRY_BIT;
+        }
+    }
+
+    // Returns whether the counter carried into the next one.
+    //
+    fn increment_counter(counter: &mut Byte, modulo: Byte, mask: Byte) -> bool {
+        if *counter == modulo - 1 {
+            *counter = 0;
+            true
+        } else {
+            *counter = (*counter + 1) & mask;
+            false
+        }
+    }
+}
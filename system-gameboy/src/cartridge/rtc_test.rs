@@ -0,0 +1,149 @@
+use super::rtc::{Rtc, FOOTER_SIZE, LEGACY_FOOTER_SIZE, RTC_REGISTERS_COUNT};
+
+type Byte = u8;
+
+const HALT_BIT: Byte = 0b0100_0000;
+
+// Footer with live registers 00:00:32 day 0x1AB (halted), latched ones 05:52:18 day 0x1AB (halted),
+// and timestamp 0x0102030405060708.
+//
+#[rustfmt::skip]
+const FOOTER: [Byte; FOOTER_SIZE] = [
+    0x20, 0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0xAB, 0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00,
+    0x12, 0x00, 0x00, 0x00, 0x34, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0x00, 0xAB, 0x00, 0x00, 0x00, 0x41, 0x00, 0x00, 0x00,
+    0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01,
+];
+
+// Older variant: 32 bit timestamp (1000); live registers 00:00:10 day 0, latched ones all 0.
+//
+#[rustfmt::skip]
+const LEGACY_FOOTER: [Byte; LEGACY_FOOTER_SIZE] = [
+    0x0A, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0xE8, 0x03, 0x00, 0x00,
+];
+
+fn rtc_with_registers(registers: [Byte; RTC_REGISTERS_COUNT]) -> Rtc {
+    let mut rtc = Rtc::new();
+
+    for (register, &value) in registers.iter().enumerate() {
+        rtc.write(register, value);
+    }
+
+    rtc
+}
+
+fn latch(rtc: &mut Rtc) {
+    rtc.write_latch(0x00);
+    rtc.write_latch(0x01);
+}
+
+fn read_registers(rtc: &Rtc) -> [Byte; RTC_REGISTERS_COUNT] {
+    let mut registers = [0; RTC_REGISTERS_COUNT];
+
+    for (register, value) in registers.iter_mut().enumerate() {
+        *value = rtc.read(register);
+    }
+
+    registers
+}
+
+// Restores a footer with the given live registers and timestamp, and returns the registers after
+// the clock has been advanced to the current timestamp.
+//
+fn advanced_registers(
+    registers: [Byte; RTC_REGISTERS_COUNT],
+    timestamp: u64,
+    current_timestamp: u64,
+) -> [Byte; RTC_REGISTERS_COUNT] {
+    let footer = rtc_with_registers(registers).encode_footer(timestamp);
+
+    let mut rtc = Rtc::new();
+    rtc.decode_footer(&footer, current_timestamp);
+    latch(&mut rtc);
+
+    read_registers(&rtc)
+}
+
+#[test]
+fn encodes_the_footer() {
+    let mut rtc = rtc_with_registers([0x12, 0x34, 0x05, 0xAB, HALT_BIT | 0x01]);
+
+    latch(&mut rtc);
+    rtc.write(0, 0x20);
+
+    assert_eq!(rtc.encode_footer(0x0102_0304_0506_0708), FOOTER);
+}
+
+#[test]
+fn decodes_the_footer() {
+    let mut rtc = Rtc::new();
+
+    rtc.decode_footer(&FOOTER, 0x0102_0304_0506_0708 + 1000);
+
+    assert_eq!(read_registers(&rtc), [0x12, 0x34, 0x05, 0xAB, 0x41]);
+
+    // Halted, so not advanced.
+    //
+    latch(&mut rtc);
+
+    assert_eq!(read_registers(&rtc), [0x20, 0x34, 0x05, 0xAB, 0x41]);
+}
+
+#[test]
+fn decodes_the_legacy_footer() {
+    let mut rtc = Rtc::new();
+
+    rtc.decode_footer(&LEGACY_FOOTER, 1000 + 90);
+
+    assert_eq!(read_registers(&rtc), [0; RTC_REGISTERS_COUNT]);
+
+    latch(&mut rtc);
+
+    assert_eq!(read_registers(&rtc), [40, 1, 0, 0, 0]);
+}
+
+#[test]
+fn decoding_masks_the_unimplemented_bits() {
+    let mut footer = FOOTER;
+    footer[0] = 0xFF;
+    footer[16] = 0xFF;
+
+    let mut rtc = Rtc::new();
+    rtc.decode_footer(&footer, 0x0102_0304_0506_0708);
+    latch(&mut rtc);
+
+    assert_eq!(read_registers(&rtc), [0x3F, 0x34, 0x05, 0xAB, 0xC1]);
+}
+
+#[test]
+fn advances_by_the_elapsed_time_with_day_carry() {
+    // 23:59:50 of day 511; 10 seconds, 2 days and 5 seconds elapsed.
+    //
+    let registers = advanced_registers([50, 59, 23, 0xFF, 0x01], 1000, 1000 + 10 + 2 * 86400 + 5);
+
+    assert_eq!(registers, [5, 0, 0, 2, 0x80]);
+}
+
+#[test]
+fn advances_the_out_of_range_counters_without_carry() {
+    // Seconds at 62: they count up to 63, then wrap to 0 without incrementing the minutes.
+    //
+    let registers = advanced_registers([62, 0, 0, 0, 0], 1000, 1000 + 3);
+
+    assert_eq!(registers, [1, 0, 0, 0, 0]);
+}
+
+#[test]
+fn does_not_advance_when_halted() {
+    let registers = advanced_registers([50, 59, 23, 0xFF, HALT_BIT], 1000, 1000 + 86400);
+
+    assert_eq!(registers, [50, 59, 23, 0xFF, HALT_BIT]);
+}
+
+#[test]
+fn does_not_advance_when_the_save_is_in_the_future() {
+    let registers = advanced_registers([10, 0, 0, 0, 0], 1000, 900);
+
+    assert_eq!(registers, [10, 0, 0, 0, 0]);
+}
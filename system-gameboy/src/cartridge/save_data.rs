@@ -0,0 +1,36 @@
+use super::mapper::Mapper;
+use super::rtc::{FOOTER_SIZE, LEGACY_FOOTER_SIZE};
+
+type Byte = u8;
+
+/// Encodes the battery-backed state, in the common `.sav` layout: the RAM, followed, if the
+/// cartridge has the RTC, by the RTC footer.
+///
+pub(crate) fn encode(mapper: &dyn Mapper, timestamp: u64) -> Vec<Byte> {
+    let mut data = mapper.ram().to_vec();
+
+    if let Some(rtc) = mapper.rtc() {
+        data.extend_from_slice(&rtc.encode_footer(timestamp));
+    }
+
+    data
+}
+
+// The decoding is lenient, since other emulators may store the data slightly differently: a short
+// RAM is loaded partially, and a missing or unrecognized footer is ignored.
+//
+pub(crate) fn decode(mapper: &mut dyn Mapper, data: &[Byte], current_timestamp: u64) {
+    let ram = mapper.ram_mut();
+    let ram_size = ram.len();
+    let loaded_size = ram_size.min(data.len());
+
+    ram[..loaded_size].copy_from_slice(&data[..loaded_size]);
+
+    if let Some(rtc) = mapper.rtc_mut() {
+        let footer = &data[loaded_size..];
+
+        if footer.len() == FOOTER_SIZE || footer.len() == LEGACY_FOOTER_SIZE {
+            rtc.decode_footer(footer, current_timestamp);
+        }
+    }
+}
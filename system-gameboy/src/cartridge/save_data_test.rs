@@ -0,0 +1,118 @@
+use super::mapper::Mapper;
+use super::mbc3::Mbc3;
+use super::rtc::{FOOTER_SIZE, LEGACY_FOOTER_SIZE};
+use super::save_data::{decode, encode};
+
+type Byte = u8;
+
+const RAM_SIZE: usize = 0x2000;
+
+const TIMESTAMP: u64 = 0x6000_0000;
+
+fn mapper(has_rtc: bool) -> Mbc3 {
+    Mbc3::new(vec![0; 0x8000], RAM_SIZE, has_rtc)
+}
+
+// Live RTC registers 00:00:10 day 0, latched ones all 0.
+//
+fn rtc_footer(legacy: bool) -> Vec<Byte> {
+    let mut footer = vec![
+        0;
+        if legacy {
+            LEGACY_FOOTER_SIZE
+        } else {
+            FOOTER_SIZE
+        }
+    ];
+
+    footer[0] = 10;
+    footer[40..44].copy_from_slice(&(TIMESTAMP as u32).to_le_bytes());
+
+    footer
+}
+
+fn read_seconds(mapper: &mut Mbc3) -> Byte {
+    mapper.write_rom(0x0000, 0x0A);
+    mapper.write_rom(0x4000, 0x08);
+    mapper.write_rom(0x6000, 0x00);
+    mapper.write_rom(0x6000, 0x01);
+
+    mapper.read_ram(0xA000)
+}
+
+#[test]
+fn encodes_the_ram_followed_by_the_rtc_footer() {
+    let mut mapper = mapper(true);
+    mapper.ram_mut()[0] = 0x42;
+    mapper.ram_mut()[RAM_SIZE - 1] = 0x24;
+
+    let data = encode(&mapper, TIMESTAMP);
+
+    assert_eq!(data.len(), RAM_SIZE + FOOTER_SIZE);
+    assert_eq!(data[..RAM_SIZE], *mapper.ram());
+    assert_eq!(
+        data[RAM_SIZE..],
+        *mapper.rtc().unwrap().encode_footer(TIMESTAMP)
+    );
+    assert_eq!(data[RAM_SIZE + 40..], TIMESTAMP.to_le_bytes());
+}
+
+#[test]
+fn encodes_only_the_ram_without_rtc() {
+    let mapper = mapper(false);
+
+    assert_eq!(encode(&mapper, TIMESTAMP).len(), RAM_SIZE);
+}
+
+#[test]
+fn round_trips_the_ram_and_the_rtc() {
+    let mut source_mapper = mapper(true);
+    source_mapper.ram_mut()[0x1234] = 0x42;
+    source_mapper.rtc_mut().unwrap().write(0, 10);
+
+    let mut mapper = mapper(true);
+
+    decode(
+        &mut mapper,
+        &encode(&source_mapper, TIMESTAMP),
+        TIMESTAMP + 5,
+    );
+
+    assert_eq!(mapper.ram(), source_mapper.ram());
+    assert_eq!(read_seconds(&mut mapper), 15);
+}
+
+#[test]
+fn decodes_the_legacy_rtc_footer() {
+    let mut mapper = mapper(true);
+
+    let mut data = vec![0; RAM_SIZE];
+    data.extend_from_slice(&rtc_footer(true));
+
+    decode(&mut mapper, &data, TIMESTAMP + 20);
+
+    assert_eq!(read_seconds(&mut mapper), 30);
+}
+
+#[test]
+fn decodes_a_short_ram_partially() {
+    let mut mapper = mapper(true);
+    mapper.ram_mut()[3] = 0x99;
+
+    decode(&mut mapper, &[0x11, 0x22, 0x33], TIMESTAMP);
+
+    assert_eq!(mapper.ram()[..4], [0x11, 0x22, 0x33, 0x99]);
+    assert_eq!(read_seconds(&mut mapper), 0);
+}
+
+#[test]
+fn ignores_an_unrecognized_footer() {
+    let mut mapper = mapper(true);
+
+    let mut data = vec![0; RAM_SIZE];
+    data.extend_from_slice(&rtc_footer(false)[..FOOTER_SIZE - 1]);
+
+    decode(&mut mapper, &data, TIMESTAMP);
+
+    assert_eq!(read_seconds(&mut mapper), 0);
+}
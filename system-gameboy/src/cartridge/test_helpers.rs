@@ -0,0 +1,25 @@
+// Helpers shared by the mapper tests.
+
+use super::cartridge_header::ROM_BANK_SIZE;
+use super::mapper::Mapper;
+
+type Byte = u8;
+type Word = u16;
+
+/// Each bank starts with its number (little endian).
+///
+pub fn banked_rom(banks_count: usize) -> Vec<Byte> {
+    let mut rom = vec![0; banks_count * ROM_BANK_SIZE];
+
+    for (bank, bank_data) in rom.chunks_mut(ROM_BANK_SIZE).enumerate() {
+        bank_data[..2].copy_from_slice(&(bank as Word).to_le_bytes());
+    }
+
+    rom
+}
+
+/// Number of the ROM bank mapped at the given (bank start) address.
+///
+pub fn mapped_bank(mapper: &dyn Mapper, address: Word) -> usize {
+    u16::from_le_bytes([mapper.read_rom(address), mapper.read_rom(address + 1)]) as usize
+}
@@ -0,0 +1,348 @@
+use super::cartridge::{self, CartridgeError};
+use super::joypad::Button;
+use super::memory_bus::MemoryBus;
+use super::ppu::{DISPLAY_HEIGHT, DISPLAY_WIDTH};
+use super::save_storage::SaveStorage;
+use super::serial_link::SerialLink;
+use component_sharp_lr35902::bus::Bus;
+use component_sharp_lr35902::cpu::Reg16;
+use component_sharp_lr35902::power_on::{Model, PowerOn};
+use component_sharp_lr35902::SharpLr35902;
+use interfaces_frontend::{
+    audio::AudioStream, audio::AUDIO_DEVICE_FREQUENCY, events::EventCode, logging::Logger,
+    IoFrontend,
+};
+
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+type Byte = u8;
+type Word = u16;
+
+// 4.194304 MHz clock; a frame (154 lines of 456 clock ticks) takes ~1/59.7th of second.
+//
+pub(crate) const CLOCK_SPEED: u32 = 4_194_304; // Herz
+const FRAME_CYCLES: u32 = 154 * 456;
+
+// The APU output is stereo (left/right interleaved).
+//
+const AUDIO_CHANNELS: u8 = 2;
+
+// When running faster than real time, the samples exceeding this latency are dropped.
+//
+const MAX_QUEUED_AUDIO_FRAMES: usize = AUDIO_DEVICE_FREQUENCY as usize / 10;
+
+// The modified save data is flushed about once per second.
+//
+const SAVE_FLUSH_INTERVAL_FRAMES: u32 = 60;
+
+// `LD B, B`; used by the test ROMs as debug breakpoint.
+//
+const DEBUG_BREAKPOINT_OPCODE: Byte = 0x40;
+
+/// Nintendo Game Boy (DMG) and Game Boy Color (CGB): Sharp LR35902 CPU, PPU, APU, timer, serial port
+/// and joypad.
+///
+/// The cartridges supporting the CGB run in CGB mode, while the other ones run on a DMG (the CGB
+/// compatibility mode is not emulated).
+///
+/// The cartridges supported are ROM only, MBC1 (including multicarts), MBC2, MBC3 (with RTC) and MBC5
+/// (with rumble).
+///
+/// If a boot ROM (DMG or CGB) is passed, it's run from the power-on state; otherwise, the system
+/// starts from the cartridge entry point, with the state the boot ROM leaves.
+///
+/// The battery-backed state of the cartridge is persisted through the save storage, if set; it's
+/// flushed periodically (when modified), and when the emulation ends.
+///
+pub struct GameBoy<'a, T: IoFrontend> {
+    cpu: SharpLr35902<MemoryBus>,
+
+    battery: bool,
+    save_storage: Option<Box<dyn SaveStorage>>,
+    frames_since_save_flush: u32,
+
+    // Clock ticks spent in the current frame; kept when the emulation stops on a breakpoint.
+    //
+    frame_cycles: u32,
+
+    io_frontend: &'a mut T,
+    audio_stream: Box<dyn AudioStream>,
+    logger: &'a mut Option<Box<dyn Logger>>,
+}
+
+impl<'a, T: IoFrontend> GameBoy<'a, T> {
+    /// Fails if the cartridge header is invalid, or doesn't match the ROM; a global checksum mismatch
+    /// is not an error (the hardware doesn't verify it), and it's only logged.
+    ///
+    pub fn new(
+        io_frontend: &'a mut T,
+        game_rom: &[Byte],
+        boot_rom: Option<&[Byte]>,
+        logger: &'a mut Option<Box<dyn Logger>>,
+    ) -> Result<GameBoy<'a, T>, CartridgeError> {
+        let (header, mapper) = cartridge::load(game_rom)?;
+
+        io_frontend.init(DISPLAY_WIDTH as u32, DISPLAY_HEIGHT as u32);
+
+        let audio_stream = io_frontend.audio_stream(AUDIO_CHANNELS);
+
+        let cgb_mode = header.cgb_support;
+        let model = if cgb_mode { Model::Cgb } else { Model::Dmg };
+
+        let power_on = if boot_rom.is_some() {
+            PowerOn::Zeroed
+        } else {
+            PowerOn::PostBootRom(model)
+        };
+
+        let bus = MemoryBus::new(mapper, cgb_mode, boot_rom.map(|boot_rom| boot_rom.to_vec()));
+
+        let mut game_boy = GameBoy {
+            cpu: SharpLr35902::with_power_on(bus, power_on),
+
+            battery: header.cartridge_type.battery,
+            save_storage: None,
+            frames_since_save_flush: 0,
+
+            frame_cycles: 0,
+
+            io_frontend,
+            audio_stream,
+            logger,
+        };
+
+        if !header.global_checksum_matches() {
+            game_boy.log(format!(
+                "Global checksum mismatch: ${:04X} in the header, ${:04X} actual",
+                header.global_checksum, header.actual_global_checksum
+            ));
+        }
+
+        Ok(game_boy)
+    }
+
+    /// Sets the storage of the battery-backed state, and loads it from it. Cartridges without battery
+    /// don't use the storage.
+    ///
+    pub fn set_save_storage(&mut self, mut save_storage: Box<dyn SaveStorage>) {
+        if !self.battery {
+            return;
+        }
+
+        if let Some(save_data) = save_storage.load() {
+            self.cpu.bus.load_save_data(&save_data, current_timestamp());
+        }
+
+        self.save_storage = Some(save_storage);
+    }
+
+    /// Connects the serial port to a link (by default, it's unplugged).
+    ///
+    pub fn set_serial_link(&mut self, serial_link: Box<dyn SerialLink>) {
+        self.cpu.bus.serial.set_link(serial_link);
+    }
+
+    /// Runs one frame at a time; the input and timing are synchronized on each frame.
+    ///
+    pub fn run(&mut self, max_speed: bool) {
+        let frame_time_slice =
+            Duration::from_nanos(1_000_000_000 * FRAME_CYCLES as u64 / CLOCK_SPEED as u64);
+
+        let mut next_frame_time = Instant::now() + frame_time_slice;
+
+        let mut emulation_running = true;
+
+        while emulation_running {
+            self.emulate_frame(false);
+
+            self.update_screen();
+            self.set_keys(&mut emulation_running);
+            self.play_audio();
+            self.flush_save_data(false);
+            self.log_serial_link_error();
+
+            let current_time = Instant::now();
+
+            if current_time >= next_frame_time || max_speed {
+                next_frame_time = current_time + frame_time_slice;
+            } else {
+                thread::sleep(next_frame_time - current_time);
+                next_frame_time += frame_time_slice;
+            }
+        }
+
+        self.flush_save_data(true);
+    }
+
+    /// Runs the given number of frames at maximum speed, without processing the events; intended
+    /// for headless runs (e.g. testing).
+    ///
+    pub fn run_frames(&mut self, frames: u32) {
+        for _ in 0..frames {
+            self.emulate_frame(false);
+            self.update_screen();
+            self.play_audio();
+            self.flush_save_data(false);
+            self.log_serial_link_error();
+        }
+
+        self.flush_save_data(true);
+    }
+
+    /// State of the cartridge rumble motor (false if not present); intended to be polled by the host
+    /// after each frame.
+    ///
+    pub fn rumble(&self) -> bool {
+        self.cpu.bus.rumble()
+    }
+
+    /// Runs one frame, like `run_frames`, but stops right after a debug breakpoint (`LD B, B`) is
+    /// executed; returns whether it has been, in which case the next invocation resumes the frame.
+    ///
+    pub(crate) fn run_frame_until_breakpoint(&mut self) -> bool {
+        let breakpoint_hit = self.emulate_frame(true);
+
+        if !breakpoint_hit {
+            self.update_screen();
+            self.play_audio();
+            self.flush_save_data(false);
+            self.log_serial_link_error();
+        }
+
+        breakpoint_hit
+    }
+
+    /// Returns BC, DE and HL; intended for inspecting the test ROMs results.
+    ///
+    pub(crate) fn debug_registers(&self) -> [Word; 3] {
+        [
+            self.cpu.reg16(Reg16::BC),
+            self.cpu.reg16(Reg16::DE),
+            self.cpu.reg16(Reg16::HL),
+        ]
+    }
+
+    // A frame ends when the PPU enters V-Blank; while the LCD is off, it's timed on the clock
+    // instead (which, in double speed mode, ticks twice per frame cycle).
+    // Returns whether the emulation has been stopped on a debug breakpoint.
+    //
+    fn emulate_frame(&mut self, stop_on_breakpoint: bool) -> bool {
+        let mut frame_completed = false;
+
+        while !frame_completed {
+            if self.logger.is_some() {
+                let program_counter = self.cpu.reg16(Reg16::PC);
+                let instruction = self.cpu.bus.read8(program_counter);
+
+                self.log(format!("[{:04X}] {:02X}", program_counter, instruction));
+            }
+
+            let breakpoint_hit = stop_on_breakpoint && {
+                let program_counter = self.cpu.reg16(Reg16::PC);
+                self.cpu.bus.read8(program_counter) == DEBUG_BREAKPOINT_OPCODE
+            };
+
+            self.frame_cycles += self.cpu.step() as u32 + self.cpu.bus.take_dma_stall_cycles();
+
+            if breakpoint_hit {
+                return true;
+            }
+
+            frame_completed = self.cpu.bus.ppu.frame_completed()
+                || (!self.cpu.bus.ppu.lcd_enabled()
+                    && self.frame_cycles >= self.max_frame_cycles());
+        }
+
+        self.frame_cycles = 0;
+
+        false
+    }
+
+    fn max_frame_cycles(&self) -> u32 {
+        if self.cpu.bus.double_speed() {
+            2 * FRAME_CYCLES
+        } else {
+            FRAME_CYCLES
+        }
+    }
+
+    fn update_screen(&mut self) {
+        self.io_frontend
+            .update_screen(self.cpu.bus.ppu.frame(), false);
+    }
+
+    fn set_keys(&mut self, emulation_running: &mut bool) {
+        while let Some((keycode, key_pressed)) = self.io_frontend.read_event(false) {
+            let button = match keycode {
+                EventCode::KeyRight => Button::Right,
+                EventCode::KeyLeft => Button::Left,
+                EventCode::KeyUp => Button::Up,
+                EventCode::KeyDown => Button::Down,
+                EventCode::KeyX => Button::A,
+                EventCode::KeyZ => Button::B,
+                EventCode::KeyBackspace => Button::Select,
+                EventCode::KeyReturn => Button::Start,
+                EventCode::Quit => {
+                    *emulation_running = false;
+                    return;
+                }
+                _ => continue,
+            };
+
+            let interrupts = self.cpu.bus.joypad.set_button(button, key_pressed);
+            self.cpu.bus.request_interrupts(interrupts);
+        }
+    }
+
+    fn play_audio(&mut self) {
+        let samples = self.cpu.bus.apu.take_samples();
+
+        if self.audio_stream.queued_frames() < MAX_QUEUED_AUDIO_FRAMES {
+            self.audio_stream.queue(&samples);
+        }
+    }
+
+    // The RTC state changes continuously, but it doesn't need to be flushed periodically, since it's
+    // advanced by the elapsed time on load.
+    // On failure, the data is still considered modified, so that the save is retried on the next
+    // flush.
+    //
+    fn flush_save_data(&mut self, force: bool) {
+        self.frames_since_save_flush += 1;
+
+        let flush_due = self.frames_since_save_flush >= SAVE_FLUSH_INTERVAL_FRAMES
+            && self.cpu.bus.save_data_modified;
+
+        if let Some(save_storage) = &mut self.save_storage {
+            if force || flush_due {
+                let result = save_storage.save(&self.cpu.bus.save_data(current_timestamp()));
+
+                self.frames_since_save_flush = 0;
+
+                match result {
+                    Ok(()) => self.cpu.bus.save_data_modified = false,
+                    Err(error) => self.log(format!("Error saving the cartridge data: {}", error)),
+                }
+            }
+        }
+    }
+
+    fn log_serial_link_error(&mut self) {
+        if let Some(error) = self.cpu.bus.serial.take_link_error() {
+            self.log(error.to_string());
+        }
+    }
+
+    fn log(&mut self, message: String) {
+        if let Some(logger) = self.logger {
+            logger.log(message);
+        }
+    }
+}
+
+fn current_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
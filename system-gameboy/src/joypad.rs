@@ -0,0 +1,97 @@
+use component_sharp_lr35902::cpu::JOYPAD_INTERRUPT;
+
+type Byte = u8;
+
+// P1 select lines; a line is selected when its bit is 0.
+//
+const SELECT_DIRECTIONS_BIT: Byte = 0b0001_0000;
+const SELECT_ACTIONS_BIT: Byte = 0b0010_0000;
+const SELECT_MASK: Byte = SELECT_DIRECTIONS_BIT | SELECT_ACTIONS_BIT;
+
+const UNUSED_BITS: Byte = 0b1100_0000;
+const INPUT_LINES_MASK: Byte = 0b0000_1111;
+
+/// Buttons; the lower four are the directions, and the upper four the actions, each in the order of
+/// the P1 input lines.
+///
+#[derive(Copy, Clone)]
+pub(crate) enum Button {
+    Right,
+    Left,
+    Up,
+    Down,
+    A,
+    B,
+    Select,
+    Start,
+}
+
+/// Joypad (P1 register).
+///
+/// The input lines (bits 0-3) read the buttons of the selected group(s); they're active low, both
+/// for the selection and the pressed buttons. The interrupt is requested when an input line goes
+/// low.
+///
+pub(crate) struct Joypad {
+    select: Byte,
+    pressed_buttons: Byte,
+}
+
+impl Joypad {
+    pub fn new() -> Joypad {
+        Joypad {
+            select: SELECT_MASK,
+            pressed_buttons: 0,
+        }
+    }
+
+    pub fn P1(&self) -> Byte {
+        UNUSED_BITS | self.select | (!self.input_lines() & INPUT_LINES_MASK)
+    }
+
+    /// Returns the interrupts requested.
+    ///
+    pub fn write_P1(&mut self, value: Byte) -> Byte {
+        let previous_input_lines = self.input_lines();
+        self.select = value & SELECT_MASK;
+        self.interrupts(previous_input_lines)
+    }
+
+    /// Returns the interrupts requested.
+    ///
+    pub fn set_button(&mut self, button: Button, pressed: bool) -> Byte {
+        let previous_input_lines = self.input_lines();
+
+        if pressed {
+            self.pressed_buttons |= 1 << button as Byte;
+        } else {
+            self.pressed_buttons &= !(1 << button as Byte);
+        }
+
+        self.interrupts(previous_input_lines)
+    }
+
+    // Pressed buttons of the selected groups, as active high bitmask.
+    //
+    fn input_lines(&self) -> Byte {
+        let mut input_lines = 0;
+
+        if self.select & SELECT_DIRECTIONS_BIT == 0 {
+            input_lines |= self.pressed_buttons & INPUT_LINES_MASK;
+        }
+
+        if self.select & SELECT_ACTIONS_BIT == 0 {
+            input_lines |= self.pressed_buttons >> 4;
+        }
+
+        input_lines
+    }
+
+    fn interrupts(&self, previous_input_lines: Byte) -> Byte {
+        if self.input_lines() & !previous_input_lines != 0 {
+            JOYPAD_INTERRUPT
+        } else {
+            0
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use super::joypad::{Button, Joypad};
+use component_sharp_lr35902::cpu::JOYPAD_INTERRUPT;
+
+type Byte = u8;
+
+const SELECT_DIRECTIONS: Byte = 0b0010_0000;
+const SELECT_ACTIONS: Byte = 0b0001_0000;
+const SELECT_BOTH: Byte = 0b0000_0000;
+const SELECT_NONE: Byte = 0b0011_0000;
+
+#[test]
+fn p1_reads_no_buttons_when_nothing_is_selected() {
+    let mut joypad = Joypad::new();
+
+    assert_eq!(joypad.P1(), 0xFF);
+
+    joypad.set_button(Button::Start, true);
+
+    assert_eq!(joypad.P1(), 0xFF);
+}
+
+#[test]
+fn p1_reads_the_buttons_of_the_selected_groups() {
+    let mut joypad = Joypad::new();
+
+    joypad.set_button(Button::Left, true);
+    joypad.set_button(Button::Start, true);
+
+    joypad.write_P1(SELECT_DIRECTIONS);
+    assert_eq!(joypad.P1(), 0b1110_1101);
+
+    joypad.write_P1(SELECT_ACTIONS);
+    assert_eq!(joypad.P1(), 0b1101_0111);
+
+    joypad.write_P1(SELECT_BOTH);
+    assert_eq!(joypad.P1(), 0b1100_0101);
+}
+
+#[test]
+fn p1_write_ignores_the_input_lines() {
+    let mut joypad = Joypad::new();
+
+    joypad.write_P1(SELECT_NONE | 0b0000_0101);
+
+    assert_eq!(joypad.P1(), 0xFF);
+}
+
+#[test]
+fn pressing_a_selected_button_requests_the_interrupt() {
+    let mut joypad = Joypad::new();
+    joypad.write_P1(SELECT_DIRECTIONS);
+
+    assert_eq!(joypad.set_button(Button::Down, true), JOYPAD_INTERRUPT);
+}
+
+#[test]
+fn pressing_an_unselected_button_does_not_request_the_interrupt() {
+    let mut joypad = Joypad::new();
+    joypad.write_P1(SELECT_DIRECTIONS);
+
+    assert_eq!(joypad.set_button(Button::A, true), 0);
+}
+
+#[test]
+fn releasing_a_button_does_not_request_the_interrupt() {
+    let mut joypad = Joypad::new();
+    joypad.write_P1(SELECT_DIRECTIONS);
+    joypad.set_button(Button::Up, true);
+
+    assert_eq!(joypad.set_button(Button::Up, false), 0);
+}
+
+#[test]
+fn pressing_a_button_on_an_already_low_line_does_not_request_the_interrupt() {
+    let mut joypad = Joypad::new();
+    joypad.write_P1(SELECT_BOTH);
+    joypad.set_button(Button::Right, true);
+
+    // A shares the input line with Right.
+    //
+    assert_eq!(joypad.set_button(Button::A, true), 0);
+    assert_eq!(joypad.set_button(Button::B, true), JOYPAD_INTERRUPT);
+}
+
+#[test]
+fn selecting_a_group_with_a_pressed_button_requests_the_interrupt() {
+    let mut joypad = Joypad::new();
+
+    assert_eq!(joypad.set_button(Button::Select, true), 0);
+
+    assert_eq!(joypad.write_P1(SELECT_DIRECTIONS), 0);
+    assert_eq!(joypad.write_P1(SELECT_ACTIONS), JOYPAD_INTERRUPT);
+    assert_eq!(joypad.write_P1(SELECT_ACTIONS), 0);
+    assert_eq!(joypad.write_P1(SELECT_NONE), 0);
+}
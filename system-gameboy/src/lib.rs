@@ -0,0 +1,35 @@
+#![allow(non_snake_case)]
+
+mod apu;
+mod cartridge;
+mod game_boy;
+mod joypad;
+mod memory_bus;
+mod ppu;
+mod save_file;
+mod save_storage;
+mod serial;
+mod serial_capture;
+mod serial_link;
+mod socket_link;
+mod test_rom_runner;
+mod timer;
+
+#[cfg(test)]
+mod joypad_test;
+#[cfg(test)]
+mod memory_bus_test;
+#[cfg(test)]
+mod timer_test;
+
+pub use cartridge::{CartridgeError, CartridgeHeader, CartridgeType, MapperKind};
+pub use game_boy::GameBoy;
+pub use save_file::SaveFile;
+pub use save_storage::SaveStorage;
+pub use serial_capture::SerialCapture;
+pub use serial_link::SerialLink;
+pub use socket_link::SocketLink;
+pub use test_rom_runner::{
+    run_test_rom, run_test_rom_directory, summary_table, TestRomOutcome, TestRomResult,
+    DEFAULT_MAX_FRAMES,
+};
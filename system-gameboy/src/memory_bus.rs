@@ -0,0 +1,573 @@
+use super::apu::Apu;
+use super::cartridge::{self, Mapper};
+use super::joypad::Joypad;
+use super::ppu::{Ppu, OAM_SIZE, VRAM_BANK_SIZE};
+use super::serial::Serial;
+use super::timer::Timer;
+use component_sharp_lr35902::bus::Bus;
+use component_sharp_lr35902::cpu::{INTERRUPT_ENABLE_ADDRESS, INTERRUPT_FLAG_ADDRESS};
+
+type Byte = u8;
+type Word = u16;
+
+// Interrupt request bits (IF/IE); the joypad one is defined by the CPU, since it also exits STOP.
+//
+pub(crate) const VBLANK_INTERRUPT: Byte = 0b0000_0001;
+pub(crate) const STAT_INTERRUPT: Byte = 0b0000_0010;
+pub(crate) const TIMER_INTERRUPT: Byte = 0b0000_0100;
+pub(crate) const SERIAL_INTERRUPT: Byte = 0b0000_1000;
+
+const INTERRUPT_FLAG_UNUSED_BITS: Byte = 0b1110_0000;
+
+// In CGB mode, the second half of the WRAM is switchable (banks 1-7).
+//
+const WRAM_BANK_SIZE: usize = 0x1000;
+const WRAM_BANKS_COUNT: usize = 8;
+const HRAM_SIZE: usize = 0x7F;
+const IO_REGISTERS_SIZE: usize = 0x80;
+
+const MCYCLE_TICKS: u8 = 4;
+
+// Memory map.
+//
+const VRAM_ADDRESS: Word = 0x8000;
+const WRAM_ADDRESS: Word = 0xC000;
+const OAM_ADDRESS: Word = 0xFE00;
+const IO_REGISTERS_ADDRESS: Word = 0xFF00;
+const HRAM_ADDRESS: Word = 0xFF80;
+
+// I/O registers.
+//
+const P1_ADDRESS: Word = 0xFF00;
+const SB_ADDRESS: Word = 0xFF01;
+const SC_ADDRESS: Word = 0xFF02;
+const DIV_ADDRESS: Word = 0xFF04;
+const TIMA_ADDRESS: Word = 0xFF05;
+const TMA_ADDRESS: Word = 0xFF06;
+const TAC_ADDRESS: Word = 0xFF07;
+const LCDC_ADDRESS: Word = 0xFF40;
+const STAT_ADDRESS: Word = 0xFF41;
+const SCY_ADDRESS: Word = 0xFF42;
+const SCX_ADDRESS: Word = 0xFF43;
+const LY_ADDRESS: Word = 0xFF44;
+const LYC_ADDRESS: Word = 0xFF45;
+const DMA_ADDRESS: Word = 0xFF46;
+const BGP_ADDRESS: Word = 0xFF47;
+const OBP0_ADDRESS: Word = 0xFF48;
+const OBP1_ADDRESS: Word = 0xFF49;
+const WY_ADDRESS: Word = 0xFF4A;
+const WX_ADDRESS: Word = 0xFF4B;
+const BOOT_ADDRESS: Word = 0xFF50;
+const APU_REGISTERS_ADDRESS: Word = 0xFF10;
+const APU_REGISTERS_END_ADDRESS: Word = 0xFF3F;
+
+// CGB I/O registers; in DMG mode, they're plain storage.
+//
+const KEY1_ADDRESS: Word = 0xFF4D;
+const VBK_ADDRESS: Word = 0xFF4F;
+const HDMA1_ADDRESS: Word = 0xFF51;
+const HDMA2_ADDRESS: Word = 0xFF52;
+const HDMA3_ADDRESS: Word = 0xFF53;
+const HDMA4_ADDRESS: Word = 0xFF54;
+const HDMA5_ADDRESS: Word = 0xFF55;
+const BCPS_ADDRESS: Word = 0xFF68;
+const BCPD_ADDRESS: Word = 0xFF69;
+const OCPS_ADDRESS: Word = 0xFF6A;
+const OCPD_ADDRESS: Word = 0xFF6B;
+const SVBK_ADDRESS: Word = 0xFF70;
+
+const KEY1_SWITCH_ARMED_BIT: Byte = 0b0000_0001;
+const KEY1_DOUBLE_SPEED_BIT: Byte = 0b1000_0000;
+const KEY1_UNUSED_BITS: Byte = 0b0111_1110;
+
+const SVBK_BANK_MASK: Byte = 0b0000_0111;
+const SVBK_UNUSED_BITS: Byte = 0b1111_1000;
+
+// The HDMA copies blocks of 16 bytes, from ROM/RAM to VRAM; the lower 4 bits of the addresses are
+// ignored.
+//
+const HDMA_BLOCK_SIZE: Word = 0x10;
+const HDMA_SOURCE_LOW_MASK: Byte = 0b1111_0000;
+const HDMA_DESTINATION_HIGH_MASK: Byte = 0b0001_1111;
+const HDMA_DESTINATION_LOW_MASK: Byte = 0b1111_0000;
+const HDMA5_HBLANK_MODE_BIT: Byte = 0b1000_0000;
+const HDMA5_LENGTH_MASK: Byte = 0b0111_1111;
+
+// The general purpose DMA stalls the CPU for 8 (normal speed) M-cycles per block.
+//
+const GDMA_BLOCK_CYCLES: u8 = 32;
+
+// The boot ROM (0x100 bytes on DMG, 0x900 on CGB) is mapped over the cartridge ROM, except for the
+// cartridge header area, until a nonzero value is written to the BOOT register.
+//
+const BOOT_ROM_HEADER_HOLE_ADDRESS: Word = 0x0100;
+const BOOT_ROM_HEADER_HOLE_END_ADDRESS: Word = 0x01FF;
+
+// State of the I/O registers left by the DMG boot ROM.
+//
+const POST_BOOT_ROM_SYSTEM_COUNTER: Word = 0xABCC;
+const POST_BOOT_ROM_LCDC: Byte = 0x91;
+const POST_BOOT_ROM_BGP: Byte = 0xFC;
+const POST_BOOT_ROM_INTERRUPT_FLAG: Byte = 0xE1;
+const POST_BOOT_ROM_APU_REGISTERS: [(Word, Byte); 6] = [
+    (0xFF26, 0x80), // NR52 (powered on first)
+    (0xFF10, 0x80), // NR10
+    (0xFF11, 0xBF), // NR11
+    (0xFF12, 0xF3), // NR12
+    (0xFF24, 0x77), // NR50
+    (0xFF25, 0xF3), // NR51
+];
+
+/// DMG/CGB memory map: cartridge ROM/RAM (through the mapper), VRAM, WRAM (and its echo), OAM, I/O
+/// registers, HRAM and IE.
+///
+/// The OAM DMA copies one byte per M-cycle; while it's active, the OAM is not accessible by the CPU.
+/// The unimplemented I/O registers are plain storage.
+///
+/// In CGB mode, there are also VRAM/WRAM banking, the color palettes, the VRAM DMA (general purpose,
+/// or one block per H-Blank), and the double speed mode, which is switched via STOP. In double
+/// speed mode, the CPU, timer, serial port and OAM DMA run at twice the clock, while the PPU, APU
+/// and cartridge hardware are unaffected. The CPU is not stalled during the H-Blank DMA transfers.
+///
+pub(crate) struct MemoryBus {
+    mapper: Box<dyn Mapper>,
+    boot_rom: Option<Vec<Byte>>,
+
+    // Set on writes to the cartridge RAM area (including the RTC registers); reset by the owner when
+    // the save data is persisted.
+    //
+    pub save_data_modified: bool,
+
+    cgb_mode: bool,
+    double_speed: bool,
+    speed_switch_armed: bool,
+    normal_speed_cycles_pending: u8,
+
+    wram: [Byte; WRAM_BANK_SIZE * WRAM_BANKS_COUNT],
+    SVBK: Byte,
+    hram: [Byte; HRAM_SIZE],
+    io_registers: [Byte; IO_REGISTERS_SIZE],
+
+    interrupt_flag: Byte,
+    interrupt_enable: Byte,
+
+    dma_source: Word,
+    dma_index: Option<usize>,
+
+    // The destination is relative to the VRAM start. After a transfer is completed or cancelled,
+    // HDMA5 reads the remaining blocks (minus one), with the bit 7 set.
+    //
+    hdma_source: Word,
+    hdma_destination: Word,
+    hdma_remaining_blocks: Byte,
+    hblank_dma_active: bool,
+
+    // The general purpose DMA stall is ticked on the bus, but not accounted by the CPU step, so it's
+    // accumulated here, for the owner to account it (in CPU clock ticks).
+    //
+    dma_stall_cycles: u32,
+
+    pub ppu: Ppu,
+    pub apu: Apu,
+    pub timer: Timer,
+    pub serial: Serial,
+    pub joypad: Joypad,
+}
+
+impl MemoryBus {
+    /// If a boot ROM is passed, it's mapped, and the state is the power-on one; otherwise, the state
+    /// is the one left by the boot ROM.
+    ///
+    pub fn new(mapper: Box<dyn Mapper>, cgb_mode: bool, boot_rom: Option<Vec<Byte>>) -> MemoryBus {
+        let mut ppu = Ppu::new(cgb_mode);
+        let mut apu = Apu::new();
+
+        let (system_counter, interrupt_flag) = if boot_rom.is_some() {
+            (0, 0)
+        } else {
+            ppu.write_LCDC(POST_BOOT_ROM_LCDC);
+            ppu.BGP = POST_BOOT_ROM_BGP;
+
+            for &(address, value) in POST_BOOT_ROM_APU_REGISTERS.iter() {
+                apu.write(address, value);
+            }
+
+            (POST_BOOT_ROM_SYSTEM_COUNTER, POST_BOOT_ROM_INTERRUPT_FLAG)
+        };
+
+        MemoryBus {
+            mapper,
+            boot_rom,
+
+            save_data_modified: false,
+
+            cgb_mode,
+            double_speed: false,
+            speed_switch_armed: false,
+            normal_speed_cycles_pending: 0,
+
+            wram: [0; WRAM_BANK_SIZE * WRAM_BANKS_COUNT],
+            SVBK: 0,
+            hram: [0; HRAM_SIZE],
+            io_registers: [0; IO_REGISTERS_SIZE],
+
+            interrupt_flag,
+            interrupt_enable: 0,
+
+            dma_source: 0,
+            dma_index: None,
+
+            hdma_source: 0,
+            hdma_destination: 0,
+            hdma_remaining_blocks: 0,
+            hblank_dma_active: false,
+
+            dma_stall_cycles: 0,
+
+            ppu,
+            apu,
+            timer: Timer::new(system_counter),
+            serial: Serial::new(cgb_mode),
+            joypad: Joypad::new(),
+        }
+    }
+
+    pub fn double_speed(&self) -> bool {
+        self.double_speed
+    }
+
+    /// Returns the clock ticks the CPU has been stalled by the general purpose DMA since the last
+    /// invocation.
+    ///
+    pub fn take_dma_stall_cycles(&mut self) -> u32 {
+        let dma_stall_cycles = self.dma_stall_cycles;
+        self.dma_stall_cycles = 0;
+        dma_stall_cycles
+    }
+
+    pub fn rumble(&self) -> bool {
+        self.mapper.rumble()
+    }
+
+    pub fn save_data(&self, timestamp: u64) -> Vec<Byte> {
+        cartridge::encode_save_data(self.mapper.as_ref(), timestamp)
+    }
+
+    pub fn load_save_data(&mut self, data: &[Byte], current_timestamp: u64) {
+        cartridge::decode_save_data(self.mapper.as_mut(), data, current_timestamp);
+    }
+
+    pub fn request_interrupts(&mut self, interrupts: Byte) {
+        self.interrupt_flag |= interrupts;
+    }
+
+    fn read_rom(&self, address: Word) -> Byte {
+        match &self.boot_rom {
+            Some(boot_rom)
+                if (address as usize) < boot_rom.len()
+                    && !(BOOT_ROM_HEADER_HOLE_ADDRESS..=BOOT_ROM_HEADER_HOLE_END_ADDRESS)
+                        .contains(&address) =>
+            {
+                boot_rom[address as usize]
+            }
+            _ => self.mapper.read_rom(address),
+        }
+    }
+
+    // The WRAM echo is included. Bank 0 can't be mapped in the switchable area.
+    //
+    fn wram_index(&self, address: Word) -> usize {
+        let offset = (address - WRAM_ADDRESS) as usize % (2 * WRAM_BANK_SIZE);
+
+        if offset < WRAM_BANK_SIZE {
+            offset
+        } else {
+            let bank = (self.SVBK & SVBK_BANK_MASK).max(1) as usize;
+            bank * WRAM_BANK_SIZE + offset - WRAM_BANK_SIZE
+        }
+    }
+
+    // The DMAs read the memory directly; the addresses from 0xE000 map to WRAM.
+    //
+    fn read_dma_source(&self, address: Word) -> Byte {
+        match address {
+            0..=0x7FFF => self.mapper.read_rom(address),
+            0x8000..=0x9FFF => self.ppu.read_vram((address - VRAM_ADDRESS) as usize),
+            0xA000..=0xBFFF => self.mapper.read_ram(address),
+            _ => self.wram[self.wram_index(address)],
+        }
+    }
+
+    fn transfer_dma_byte(&mut self) {
+        if let Some(dma_index) = self.dma_index {
+            let address = self.dma_source + dma_index as Word;
+
+            self.ppu.oam[dma_index] = self.read_dma_source(address);
+
+            self.dma_index = Some(dma_index + 1).filter(|&dma_index| dma_index < OAM_SIZE);
+        }
+    }
+
+    fn transfer_hdma_block(&mut self) {
+        for i in 0..HDMA_BLOCK_SIZE {
+            let value = self.read_dma_source(self.hdma_source.wrapping_add(i));
+            let destination = (self.hdma_destination + i) as usize % VRAM_BANK_SIZE;
+
+            self.ppu.write_vram(destination, value);
+        }
+
+        self.hdma_source = self.hdma_source.wrapping_add(HDMA_BLOCK_SIZE);
+        self.hdma_destination = (self.hdma_destination + HDMA_BLOCK_SIZE) % VRAM_BANK_SIZE as Word;
+        self.hdma_remaining_blocks -= 1;
+
+        if self.hdma_remaining_blocks == 0 {
+            self.hblank_dma_active = false;
+        }
+    }
+
+    // A general purpose DMA is performed immediately; an H-Blank DMA is started, or, if one is
+    // active and the mode bit is clear, cancelled.
+    //
+    fn write_HDMA5(&mut self, value: Byte) {
+        if self.hblank_dma_active && value & HDMA5_HBLANK_MODE_BIT == 0 {
+            self.hblank_dma_active = false;
+            return;
+        }
+
+        self.hdma_remaining_blocks = (value & HDMA5_LENGTH_MASK) + 1;
+
+        if value & HDMA5_HBLANK_MODE_BIT != 0 {
+            self.hblank_dma_active = true;
+        } else {
+            let stall_cycles = GDMA_BLOCK_CYCLES * self.speed_factor();
+
+            while self.hdma_remaining_blocks > 0 {
+                self.transfer_hdma_block();
+                self.tick(stall_cycles);
+                self.dma_stall_cycles += stall_cycles as u32;
+            }
+        }
+    }
+
+    fn HDMA5(&self) -> Byte {
+        let remaining_blocks = self.hdma_remaining_blocks.wrapping_sub(1) & HDMA5_LENGTH_MASK;
+
+        if self.hblank_dma_active {
+            remaining_blocks
+        } else {
+            HDMA5_HBLANK_MODE_BIT | remaining_blocks
+        }
+    }
+
+    fn KEY1(&self) -> Byte {
+        let double_speed = if self.double_speed {
+            KEY1_DOUBLE_SPEED_BIT
+        } else {
+            0
+        };
+
+        KEY1_UNUSED_BITS | double_speed | self.speed_switch_armed as Byte
+    }
+
+    fn speed_factor(&self) -> u8 {
+        if self.double_speed {
+            2
+        } else {
+            1
+        }
+    }
+
+    // Converts the CPU clock ticks to the normal speed ones, in M-cycle units; in double speed mode,
+    // the odd M-cycles are carried over.
+    //
+    fn normal_speed_cycles(&mut self, cycles: u8) -> u8 {
+        if !self.double_speed {
+            return cycles;
+        }
+
+        let cycles = self.normal_speed_cycles_pending + cycles / 2;
+        self.normal_speed_cycles_pending = cycles % MCYCLE_TICKS;
+
+        cycles - self.normal_speed_cycles_pending
+    }
+
+    fn read_io_register(&self, address: Word) -> Byte {
+        match address {
+            P1_ADDRESS => self.joypad.P1(),
+            SB_ADDRESS => self.serial.SB(),
+            SC_ADDRESS => self.serial.SC(),
+            DIV_ADDRESS => self.timer.DIV(),
+            TIMA_ADDRESS => self.timer.TIMA(),
+            TMA_ADDRESS => self.timer.TMA(),
+            TAC_ADDRESS => self.timer.TAC(),
+            INTERRUPT_FLAG_ADDRESS => self.interrupt_flag | INTERRUPT_FLAG_UNUSED_BITS,
+            LCDC_ADDRESS => self.ppu.LCDC(),
+            STAT_ADDRESS => self.ppu.STAT(),
+            SCY_ADDRESS => self.ppu.SCY,
+            SCX_ADDRESS => self.ppu.SCX,
+            LY_ADDRESS => self.ppu.LY(),
+            LYC_ADDRESS => self.ppu.LYC(),
+            BGP_ADDRESS => self.ppu.BGP,
+            OBP0_ADDRESS => self.ppu.OBP0,
+            OBP1_ADDRESS => self.ppu.OBP1,
+            WY_ADDRESS => self.ppu.WY,
+            WX_ADDRESS => self.ppu.WX,
+            BOOT_ADDRESS => 0xFF,
+            APU_REGISTERS_ADDRESS..=APU_REGISTERS_END_ADDRESS => self.apu.read(address),
+            KEY1_ADDRESS if self.cgb_mode => self.KEY1(),
+            VBK_ADDRESS if self.cgb_mode => self.ppu.VBK(),
+            HDMA1_ADDRESS..=HDMA4_ADDRESS if self.cgb_mode => 0xFF,
+            HDMA5_ADDRESS if self.cgb_mode => self.HDMA5(),
+            BCPS_ADDRESS if self.cgb_mode => self.ppu.bg_palettes.spec(),
+            BCPD_ADDRESS if self.cgb_mode => self.ppu.bg_palettes.data(),
+            OCPS_ADDRESS if self.cgb_mode => self.ppu.obj_palettes.spec(),
+            OCPD_ADDRESS if self.cgb_mode => self.ppu.obj_palettes.data(),
+            SVBK_ADDRESS if self.cgb_mode => SVBK_UNUSED_BITS | self.SVBK,
+            _ => self.io_registers[(address - IO_REGISTERS_ADDRESS) as usize],
+        }
+    }
+
+    fn write_io_register(&mut self, address: Word, value: Byte) {
+        match address {
+            P1_ADDRESS => {
+                let interrupts = self.joypad.write_P1(value);
+                self.request_interrupts(interrupts);
+            }
+            SB_ADDRESS => self.serial.write_SB(value),
+            SC_ADDRESS => self.serial.write_SC(value),
+            DIV_ADDRESS => self.timer.write_DIV(),
+            TIMA_ADDRESS => self.timer.write_TIMA(value),
+            TMA_ADDRESS => self.timer.write_TMA(value),
+            TAC_ADDRESS => self.timer.write_TAC(value),
+            INTERRUPT_FLAG_ADDRESS => self.interrupt_flag = value & !INTERRUPT_FLAG_UNUSED_BITS,
+            LCDC_ADDRESS => self.ppu.write_LCDC(value),
+            STAT_ADDRESS => {
+                let interrupts = self.ppu.write_STAT(value);
+                self.request_interrupts(interrupts);
+            }
+            SCY_ADDRESS => self.ppu.SCY = value,
+            SCX_ADDRESS => self.ppu.SCX = value,
+            LY_ADDRESS => {}
+            LYC_ADDRESS => {
+                let interrupts = self.ppu.write_LYC(value);
+                self.request_interrupts(interrupts);
+            }
+            DMA_ADDRESS => {
+                self.io_registers[(address - IO_REGISTERS_ADDRESS) as usize] = value;
+                self.dma_source = (value as Word) << 8;
+                self.dma_index = Some(0);
+            }
+            BGP_ADDRESS => self.ppu.BGP = value,
+            OBP0_ADDRESS => self.ppu.OBP0 = value,
+            OBP1_ADDRESS => self.ppu.OBP1 = value,
+            WY_ADDRESS => self.ppu.WY = value,
+            WX_ADDRESS => self.ppu.WX = value,
+            BOOT_ADDRESS => {
+                if value != 0 {
+                    self.boot_rom = None;
+                }
+            }
+            APU_REGISTERS_ADDRESS..=APU_REGISTERS_END_ADDRESS => self.apu.write(address, value),
+            KEY1_ADDRESS if self.cgb_mode => {
+                self.speed_switch_armed = value & KEY1_SWITCH_ARMED_BIT != 0
+            }
+            VBK_ADDRESS if self.cgb_mode => self.ppu.write_VBK(value),
+            HDMA1_ADDRESS if self.cgb_mode => {
+                self.hdma_source = (self.hdma_source & 0x00FF) | ((value as Word) << 8)
+            }
+            HDMA2_ADDRESS if self.cgb_mode => {
+                self.hdma_source =
+                    (self.hdma_source & 0xFF00) | (value & HDMA_SOURCE_LOW_MASK) as Word
+            }
+            HDMA3_ADDRESS if self.cgb_mode => {
+                self.hdma_destination = (self.hdma_destination & 0x00FF)
+                    | (((value & HDMA_DESTINATION_HIGH_MASK) as Word) << 8)
+            }
+            HDMA4_ADDRESS if self.cgb_mode => {
+                self.hdma_destination =
+                    (self.hdma_destination & 0xFF00) | (value & HDMA_DESTINATION_LOW_MASK) as Word
+            }
+            HDMA5_ADDRESS if self.cgb_mode => self.write_HDMA5(value),
+            BCPS_ADDRESS if self.cgb_mode => self.ppu.bg_palettes.write_spec(value),
+            BCPD_ADDRESS if self.cgb_mode => self.ppu.bg_palettes.write_data(value),
+            OCPS_ADDRESS if self.cgb_mode => self.ppu.obj_palettes.write_spec(value),
+            OCPD_ADDRESS if self.cgb_mode => self.ppu.obj_palettes.write_data(value),
+            SVBK_ADDRESS if self.cgb_mode => self.SVBK = value & SVBK_BANK_MASK,
+            _ => self.io_registers[(address - IO_REGISTERS_ADDRESS) as usize] = value,
+        }
+    }
+}
+
+impl Bus for MemoryBus {
+    fn read8(&mut self, address: Word) -> Byte {
+        match address {
+            0..=0x7FFF => self.read_rom(address),
+            0x8000..=0x9FFF => self.ppu.read_vram((address - VRAM_ADDRESS) as usize),
+            0xA000..=0xBFFF => self.mapper.read_ram(address),
+            0xC000..=0xFDFF => self.wram[self.wram_index(address)],
+            0xFE00..=0xFE9F if self.dma_index.is_some() => 0xFF,
+            0xFE00..=0xFE9F => self.ppu.oam[(address - OAM_ADDRESS) as usize],
+            0xFEA0..=0xFEFF => 0,
+            0xFF00..=0xFF7F => self.read_io_register(address),
+            0xFF80..=0xFFFE => self.hram[(address - HRAM_ADDRESS) as usize],
+            INTERRUPT_ENABLE_ADDRESS => self.interrupt_enable,
+        }
+    }
+
+    fn write8(&mut self, address: Word, value: Byte) {
+        match address {
+            0..=0x7FFF => self.mapper.write_rom(address, value),
+            0x8000..=0x9FFF => self
+                .ppu
+                .write_vram((address - VRAM_ADDRESS) as usize, value),
+            0xA000..=0xBFFF => {
+                self.mapper.write_ram(address, value);
+                self.save_data_modified = true;
+            }
+            0xC000..=0xFDFF => self.wram[self.wram_index(address)] = value,
+            0xFE00..=0xFE9F if self.dma_index.is_some() => {}
+            0xFE00..=0xFE9F => self.ppu.oam[(address - OAM_ADDRESS) as usize] = value,
+            0xFEA0..=0xFEFF => {}
+            0xFF00..=0xFF7F => self.write_io_register(address, value),
+            0xFF80..=0xFFFE => self.hram[(address - HRAM_ADDRESS) as usize] = value,
+            INTERRUPT_ENABLE_ADDRESS => self.interrupt_enable = value,
+        }
+    }
+
+    fn tick(&mut self, cycles: u8) {
+        for _ in 0..(cycles / MCYCLE_TICKS) {
+            self.transfer_dma_byte();
+        }
+
+        let timer_interrupts = self.timer.tick(cycles);
+        let serial_interrupts = self.serial.tick(cycles);
+
+        let normal_speed_cycles = self.normal_speed_cycles(cycles);
+
+        self.apu.tick(normal_speed_cycles);
+        self.mapper.tick(normal_speed_cycles);
+
+        let ppu_interrupts = self.ppu.tick(normal_speed_cycles);
+
+        if self.ppu.hblank_started() && self.hblank_dma_active {
+            self.transfer_hdma_block();
+        }
+
+        self.request_interrupts(timer_interrupts | serial_interrupts | ppu_interrupts);
+    }
+
+    // STOP resets the system counter; in CGB mode, if a speed switch is armed, it's performed,
+    // without stopping.
+    //
+    fn stop(&mut self) -> bool {
+        self.timer.write_DIV();
+
+        if self.speed_switch_armed {
+            self.double_speed = !self.double_speed;
+            self.speed_switch_armed = false;
+            true
+        } else {
+            false
+        }
+    }
+}
@@ -0,0 +1,403 @@
+use super::cartridge::Mapper;
+use super::memory_bus::MemoryBus;
+use super::ppu::OAM_SIZE;
+use component_sharp_lr35902::bus::Bus;
+
+type Byte = u8;
+type Word = u16;
+
+const INTERRUPT_FLAG_ADDRESS: Word = 0xFF0F;
+const DIV_ADDRESS: Word = 0xFF04;
+const LY_ADDRESS: Word = 0xFF44;
+const DMA_ADDRESS: Word = 0xFF46;
+const KEY1_ADDRESS: Word = 0xFF4D;
+const VBK_ADDRESS: Word = 0xFF4F;
+const HDMA1_ADDRESS: Word = 0xFF51;
+const HDMA2_ADDRESS: Word = 0xFF52;
+const HDMA3_ADDRESS: Word = 0xFF53;
+const HDMA4_ADDRESS: Word = 0xFF54;
+const HDMA5_ADDRESS: Word = 0xFF55;
+const SVBK_ADDRESS: Word = 0xFF70;
+
+const OAM_ADDRESS: Word = 0xFE00;
+const DMA_SOURCE_ADDRESS: Word = 0xC100;
+
+const HDMA_SOURCE_ADDRESS: Word = 0xC000;
+const HDMA_DESTINATION_ADDRESS: Word = 0x8100;
+const HDMA_BLOCK_SIZE: Word = 0x10;
+
+// From the start of the line (the state after the boot ROM).
+//
+const HBLANK_START_CYCLES: u32 = 252;
+const CYCLES_PER_LINE: u32 = 456;
+
+// Plain 32 KiB ROM, without RAM.
+//
+struct TestMapper {
+    rom: Vec<Byte>,
+}
+
+impl Mapper for TestMapper {
+    fn read_rom(&self, address: Word) -> Byte {
+        self.rom[address as usize]
+    }
+
+    fn write_rom(&mut self, _address: Word, _value: Byte) {}
+
+    fn read_ram(&self, _address: Word) -> Byte {
+        0xFF
+    }
+
+    fn write_ram(&mut self, _address: Word, _value: Byte) {}
+
+    fn ram(&self) -> &[Byte] {
+        &[]
+    }
+
+    fn ram_mut(&mut self) -> &mut [Byte] {
+        &mut []
+    }
+}
+
+fn memory_bus(cgb_mode: bool) -> MemoryBus {
+    let mapper = TestMapper {
+        rom: vec![0; 0x8000],
+    };
+
+    MemoryBus::new(Box::new(mapper), cgb_mode, None)
+}
+
+fn tick(memory_bus: &mut MemoryBus, cycles: u32) {
+    for _ in 0..cycles / 4 {
+        memory_bus.tick(4);
+    }
+}
+
+// Fills the DMA source with distinct (nonzero) values, and starts the OAM DMA.
+//
+fn start_oam_dma(memory_bus: &mut MemoryBus) {
+    for i in 0..OAM_SIZE as Word {
+        memory_bus.write8(DMA_SOURCE_ADDRESS + i, i as Byte + 1);
+    }
+
+    memory_bus.write8(DMA_ADDRESS, (DMA_SOURCE_ADDRESS >> 8) as Byte);
+}
+
+// Fills the source with distinct (nonzero) values, and sets the HDMA addresses.
+//
+fn prepare_hdma(memory_bus: &mut MemoryBus, blocks: Word) {
+    for i in 0..blocks * HDMA_BLOCK_SIZE {
+        memory_bus.write8(HDMA_SOURCE_ADDRESS + i, i as Byte + 1);
+    }
+
+    memory_bus.write8(HDMA1_ADDRESS, (HDMA_SOURCE_ADDRESS >> 8) as Byte);
+    memory_bus.write8(HDMA2_ADDRESS, HDMA_SOURCE_ADDRESS as Byte);
+    memory_bus.write8(HDMA3_ADDRESS, (HDMA_DESTINATION_ADDRESS >> 8) as Byte);
+    memory_bus.write8(HDMA4_ADDRESS, HDMA_DESTINATION_ADDRESS as Byte);
+}
+
+// Whether the given block has been copied to the destination.
+//
+fn hdma_block_copied(memory_bus: &mut MemoryBus, block: Word) -> bool {
+    (0..HDMA_BLOCK_SIZE).all(|i| {
+        let offset = block * HDMA_BLOCK_SIZE + i;
+        memory_bus.read8(HDMA_DESTINATION_ADDRESS + offset) == offset as Byte + 1
+    })
+}
+
+#[test]
+fn echo_ram_mirrors_the_wram() {
+    let mut memory_bus = memory_bus(false);
+
+    memory_bus.write8(0xC123, 0x42);
+    assert_eq!(memory_bus.read8(0xE123), 0x42);
+
+    memory_bus.write8(0xFDFF, 0x24);
+    assert_eq!(memory_bus.read8(0xDDFF), 0x24);
+}
+
+#[test]
+fn echo_ram_follows_the_wram_bank_in_cgb_mode() {
+    let mut memory_bus = memory_bus(true);
+
+    memory_bus.write8(SVBK_ADDRESS, 2);
+    memory_bus.write8(0xD000, 0x42);
+
+    assert_eq!(memory_bus.read8(0xF000), 0x42);
+
+    memory_bus.write8(SVBK_ADDRESS, 3);
+
+    assert_eq!(memory_bus.read8(0xF000), 0x00);
+
+    // Bank 0 selects bank 1.
+    //
+    memory_bus.write8(SVBK_ADDRESS, 1);
+    memory_bus.write8(0xF000, 0x24);
+    memory_bus.write8(SVBK_ADDRESS, 0);
+
+    assert_eq!(memory_bus.read8(0xD000), 0x24);
+}
+
+#[test]
+fn unusable_area_reads_zero_and_ignores_writes() {
+    let mut memory_bus = memory_bus(false);
+
+    memory_bus.write8(0xFEA0, 0x42);
+
+    assert_eq!(memory_bus.read8(0xFEA0), 0x00);
+}
+
+#[test]
+fn interrupt_flag_reads_the_unused_bits_as_set() {
+    let mut memory_bus = memory_bus(false);
+
+    memory_bus.write8(INTERRUPT_FLAG_ADDRESS, 0x00);
+
+    assert_eq!(memory_bus.read8(INTERRUPT_FLAG_ADDRESS), 0b1110_0000);
+}
+
+#[test]
+fn oam_dma_copies_one_byte_per_mcycle() {
+    let mut memory_bus = memory_bus(false);
+
+    start_oam_dma(&mut memory_bus);
+
+    memory_bus.tick(4);
+
+    assert_eq!(memory_bus.ppu.oam[..2], [0x01, 0x00]);
+
+    for _ in 1..OAM_SIZE {
+        memory_bus.tick(4);
+    }
+
+    let expected_oam = (1..=OAM_SIZE as Byte).collect::<Vec<Byte>>();
+
+    assert_eq!(memory_bus.ppu.oam[..], expected_oam[..]);
+}
+
+#[test]
+fn oam_is_not_accessible_during_the_oam_dma() {
+    let mut memory_bus = memory_bus(false);
+
+    memory_bus.write8(OAM_ADDRESS + 0x9F, 0x42);
+
+    start_oam_dma(&mut memory_bus);
+    memory_bus.tick(4);
+
+    assert_eq!(memory_bus.read8(OAM_ADDRESS), 0xFF);
+    assert_eq!(memory_bus.read8(OAM_ADDRESS + 0x9F), 0xFF);
+
+    memory_bus.write8(OAM_ADDRESS + 0x9E, 0x24);
+
+    assert_eq!(memory_bus.ppu.oam[0x9E], 0x00);
+    assert_eq!(memory_bus.ppu.oam[0x9F], 0x42);
+
+    for _ in 1..OAM_SIZE {
+        memory_bus.tick(4);
+    }
+
+    assert_eq!(memory_bus.read8(OAM_ADDRESS), 0x01);
+    assert_eq!(memory_bus.read8(OAM_ADDRESS + 0x9F), 0xA0);
+
+    memory_bus.write8(OAM_ADDRESS, 0x24);
+
+    assert_eq!(memory_bus.read8(OAM_ADDRESS), 0x24);
+}
+
+#[test]
+fn oam_dma_reads_the_echo_ram_as_wram() {
+    let mut memory_bus = memory_bus(false);
+
+    memory_bus.write8(0xC000, 0x42);
+    memory_bus.write8(DMA_ADDRESS, 0xE0);
+    memory_bus.tick(4);
+
+    assert_eq!(memory_bus.ppu.oam[0], 0x42);
+}
+
+#[test]
+fn vram_bank_is_selected_by_vbk_in_cgb_mode() {
+    let mut memory_bus = memory_bus(true);
+
+    memory_bus.write8(VBK_ADDRESS, 0xFF);
+    memory_bus.write8(0x8000, 0x42);
+
+    assert_eq!(memory_bus.read8(VBK_ADDRESS), 0xFF);
+
+    memory_bus.write8(VBK_ADDRESS, 0x00);
+
+    assert_eq!(memory_bus.read8(VBK_ADDRESS), 0xFE);
+    assert_eq!(memory_bus.read8(0x8000), 0x00);
+
+    memory_bus.write8(VBK_ADDRESS, 0x01);
+
+    assert_eq!(memory_bus.read8(0x8000), 0x42);
+}
+
+#[test]
+fn cgb_banking_registers_are_plain_storage_in_dmg_mode() {
+    let mut memory_bus = memory_bus(false);
+
+    memory_bus.write8(VBK_ADDRESS, 0x01);
+    memory_bus.write8(SVBK_ADDRESS, 0x02);
+    memory_bus.write8(0x8000, 0x42);
+    memory_bus.write8(0xD000, 0x24);
+
+    assert_eq!(memory_bus.read8(VBK_ADDRESS), 0x01);
+    assert_eq!(memory_bus.read8(SVBK_ADDRESS), 0x02);
+
+    memory_bus.write8(VBK_ADDRESS, 0x00);
+    memory_bus.write8(SVBK_ADDRESS, 0x01);
+
+    assert_eq!(memory_bus.read8(0x8000), 0x42);
+    assert_eq!(memory_bus.read8(0xD000), 0x24);
+}
+
+#[test]
+fn svbk_reads_the_unused_bits_as_set() {
+    let mut memory_bus = memory_bus(true);
+
+    memory_bus.write8(SVBK_ADDRESS, 0xFD);
+
+    assert_eq!(memory_bus.read8(SVBK_ADDRESS), 0xFD);
+
+    memory_bus.write8(SVBK_ADDRESS, 0x00);
+
+    assert_eq!(memory_bus.read8(SVBK_ADDRESS), 0xF8);
+}
+
+#[test]
+fn general_purpose_dma_copies_all_the_blocks_immediately() {
+    let mut memory_bus = memory_bus(true);
+
+    prepare_hdma(&mut memory_bus, 3);
+    memory_bus.write8(HDMA5_ADDRESS, 0x02);
+
+    for block in 0..3 {
+        assert!(hdma_block_copied(&mut memory_bus, block));
+    }
+
+    assert_eq!(memory_bus.read8(HDMA5_ADDRESS), 0xFF);
+}
+
+#[test]
+fn general_purpose_dma_ignores_the_lower_address_bits() {
+    let mut memory_bus = memory_bus(true);
+
+    prepare_hdma(&mut memory_bus, 1);
+    memory_bus.write8(HDMA2_ADDRESS, 0x0F);
+    memory_bus.write8(HDMA3_ADDRESS, 0xE1); // The upper 3 bits are ignored as well.
+    memory_bus.write8(HDMA4_ADDRESS, 0x0F);
+    memory_bus.write8(HDMA5_ADDRESS, 0x00);
+
+    assert!(hdma_block_copied(&mut memory_bus, 0));
+}
+
+#[test]
+fn general_purpose_dma_stalls_the_cpu_for_8_mcycles_per_block() {
+    let mut memory_bus = memory_bus(true);
+
+    prepare_hdma(&mut memory_bus, 2);
+    memory_bus.write8(HDMA5_ADDRESS, 0x01);
+
+    assert_eq!(memory_bus.take_dma_stall_cycles(), 64);
+    assert_eq!(memory_bus.take_dma_stall_cycles(), 0);
+
+    // The stall is in normal speed M-cycles, so the CPU clock ticks double in double speed mode.
+    //
+    memory_bus.write8(KEY1_ADDRESS, 0x01);
+    memory_bus.stop();
+
+    memory_bus.write8(HDMA5_ADDRESS, 0x01);
+
+    assert_eq!(memory_bus.take_dma_stall_cycles(), 128);
+}
+
+#[test]
+fn hblank_dma_copies_one_block_per_hblank() {
+    let mut memory_bus = memory_bus(true);
+
+    prepare_hdma(&mut memory_bus, 2);
+    memory_bus.write8(HDMA5_ADDRESS, 0x81);
+
+    assert_eq!(memory_bus.read8(HDMA5_ADDRESS), 0x01);
+    assert_eq!(memory_bus.take_dma_stall_cycles(), 0);
+
+    tick(&mut memory_bus, HBLANK_START_CYCLES - 4);
+
+    assert!(!hdma_block_copied(&mut memory_bus, 0));
+
+    tick(&mut memory_bus, 4);
+
+    assert!(hdma_block_copied(&mut memory_bus, 0));
+    assert!(!hdma_block_copied(&mut memory_bus, 1));
+    assert_eq!(memory_bus.read8(HDMA5_ADDRESS), 0x00);
+
+    tick(&mut memory_bus, CYCLES_PER_LINE);
+
+    assert!(hdma_block_copied(&mut memory_bus, 1));
+    assert_eq!(memory_bus.read8(HDMA5_ADDRESS), 0xFF);
+}
+
+#[test]
+fn hblank_dma_is_cancelled_by_writing_hdma5_with_the_bit_7_clear() {
+    let mut memory_bus = memory_bus(true);
+
+    prepare_hdma(&mut memory_bus, 4);
+    memory_bus.write8(HDMA5_ADDRESS, 0x83);
+
+    tick(&mut memory_bus, HBLANK_START_CYCLES);
+
+    memory_bus.write8(HDMA5_ADDRESS, 0x00);
+
+    // The remaining blocks (minus one) are still readable.
+    //
+    assert_eq!(memory_bus.read8(HDMA5_ADDRESS), 0x82);
+
+    tick(&mut memory_bus, CYCLES_PER_LINE);
+
+    assert!(hdma_block_copied(&mut memory_bus, 0));
+    assert!(!hdma_block_copied(&mut memory_bus, 1));
+    assert_eq!(memory_bus.take_dma_stall_cycles(), 0);
+}
+
+#[test]
+fn speed_switch_is_performed_by_stop_when_armed() {
+    let mut memory_bus = memory_bus(true);
+
+    assert!(!memory_bus.stop());
+    assert_eq!(memory_bus.read8(KEY1_ADDRESS), 0x7E);
+
+    memory_bus.write8(KEY1_ADDRESS, 0x01);
+
+    assert_eq!(memory_bus.read8(KEY1_ADDRESS), 0x7F);
+    assert!(memory_bus.stop());
+    assert!(memory_bus.double_speed());
+    assert_eq!(memory_bus.read8(KEY1_ADDRESS), 0xFE);
+
+    memory_bus.write8(KEY1_ADDRESS, 0x01);
+
+    assert!(memory_bus.stop());
+    assert!(!memory_bus.double_speed());
+    assert_eq!(memory_bus.read8(KEY1_ADDRESS), 0x7E);
+}
+
+// The timer runs on the CPU clock, while the PPU is unaffected by the speed.
+//
+#[test]
+fn double_speed_halves_the_ppu_clock_relative_to_the_cpu() {
+    let mut memory_bus = memory_bus(true);
+
+    memory_bus.write8(KEY1_ADDRESS, 0x01);
+    memory_bus.stop();
+
+    tick(&mut memory_bus, CYCLES_PER_LINE);
+
+    assert_eq!(memory_bus.read8(LY_ADDRESS), 0);
+    assert_eq!(memory_bus.read8(DIV_ADDRESS), 1);
+
+    tick(&mut memory_bus, CYCLES_PER_LINE);
+
+    assert_eq!(memory_bus.read8(LY_ADDRESS), 1);
+    assert_eq!(memory_bus.read8(DIV_ADDRESS), 3);
+}
@@ -0,0 +1,88 @@
+use interfaces_frontend::video::Pixel;
+
+type Byte = u8;
+type Word = u16;
+
+const PALETTES_COUNT: usize = 8;
+const COLORS_PER_PALETTE: usize = 4;
+const COLOR_SIZE: usize = 2;
+const PALETTE_RAM_SIZE: usize = PALETTES_COUNT * COLORS_PER_PALETTE * COLOR_SIZE;
+
+const SPEC_INDEX_MASK: Byte = 0b0011_1111;
+const SPEC_AUTO_INCREMENT_BIT: Byte = 0b1000_0000;
+const SPEC_UNUSED_BITS: Byte = 0b0100_0000;
+
+const COLOR_COMPONENT_MASK: Word = 0b1_1111;
+
+/// CGB color palettes (either BG or OBJ): 8 palettes of 4 colors, stored as 15 bit RGB (little
+/// endian), and accessed through the specification (index) and data registers. When the auto
+/// increment is enabled, the index is incremented on each data write.
+///
+/// The palette RAM is always accessible by the CPU.
+///
+pub(crate) struct ColorPalettes {
+    ram: [Byte; PALETTE_RAM_SIZE],
+    index: Byte,
+    auto_increment: bool,
+}
+
+impl ColorPalettes {
+    // Initialized to white, like the boot ROM does for the BG palettes.
+    //
+    pub fn new() -> ColorPalettes {
+        ColorPalettes {
+            ram: [0xFF; PALETTE_RAM_SIZE],
+            index: 0,
+            auto_increment: false,
+        }
+    }
+
+    pub fn spec(&self) -> Byte {
+        let auto_increment = if self.auto_increment {
+            SPEC_AUTO_INCREMENT_BIT
+        } else {
+            0
+        };
+
+        SPEC_UNUSED_BITS | auto_increment | self.index
+    }
+
+    pub fn write_spec(&mut self, value: Byte) {
+        self.index = value & SPEC_INDEX_MASK;
+        self.auto_increment = value & SPEC_AUTO_INCREMENT_BIT != 0;
+    }
+
+    pub fn data(&self) -> Byte {
+        self.ram[self.index as usize]
+    }
+
+    pub fn write_data(&mut self, value: Byte) {
+        self.ram[self.index as usize] = value;
+
+        if self.auto_increment {
+            self.index = (self.index + 1) & SPEC_INDEX_MASK;
+        }
+    }
+
+    pub fn color(&self, palette: Byte, color: Byte) -> Pixel {
+        let address = (palette as usize * COLORS_PER_PALETTE + color as usize) * COLOR_SIZE;
+        let rgb = ((self.ram[address + 1] as Word) << 8) | self.ram[address] as Word;
+
+        Self::correct_color(rgb)
+    }
+
+    // The CGB LCD colors are less saturated than the raw values, and the channels bleed into each
+    // other; this is the conversion used by Gambatte, which maps 31 to 248.
+    //
+    fn correct_color(rgb: Word) -> Pixel {
+        let r = rgb & COLOR_COMPONENT_MASK;
+        let g = (rgb >> 5) & COLOR_COMPONENT_MASK;
+        let b = (rgb >> 10) & COLOR_COMPONENT_MASK;
+
+        Pixel(
+            ((r * 13 + g * 2 + b) >> 1) as Byte,
+            ((g * 3 + b) << 1) as Byte,
+            ((r * 3 + g * 2 + b * 11) >> 1) as Byte,
+        )
+    }
+}
@@ -0,0 +1,108 @@
+use super::color_palettes::ColorPalettes;
+use interfaces_frontend::video::Pixel;
+
+const SPEC_AUTO_INCREMENT: u8 = 0b1000_0000;
+
+fn rgb(pixel: Pixel) -> (u8, u8, u8) {
+    (pixel.0, pixel.1, pixel.2)
+}
+
+#[test]
+fn spec_reads_the_index_and_the_auto_increment() {
+    let mut palettes = ColorPalettes::new();
+
+    palettes.write_spec(SPEC_AUTO_INCREMENT | 0x2A);
+
+    assert_eq!(palettes.spec(), 0xEA);
+
+    palettes.write_spec(0x3F);
+
+    assert_eq!(palettes.spec(), 0x7F);
+}
+
+#[test]
+fn data_writes_auto_increment_the_index() {
+    let mut palettes = ColorPalettes::new();
+
+    palettes.write_spec(SPEC_AUTO_INCREMENT | 0x02);
+    palettes.write_data(0x12);
+    palettes.write_data(0x34);
+
+    assert_eq!(palettes.spec(), 0xC4);
+
+    palettes.write_spec(0x02);
+
+    assert_eq!(palettes.data(), 0x12);
+
+    palettes.write_spec(0x03);
+
+    assert_eq!(palettes.data(), 0x34);
+}
+
+// Reads don't increment the index.
+//
+#[test]
+fn data_reads_and_writes_without_auto_increment_keep_the_index() {
+    let mut palettes = ColorPalettes::new();
+
+    palettes.write_spec(0x05);
+    palettes.write_data(0x12);
+    palettes.write_data(0x34);
+
+    assert_eq!(palettes.spec(), 0x45);
+    assert_eq!(palettes.data(), 0x34);
+
+    palettes.write_spec(SPEC_AUTO_INCREMENT | 0x05);
+    palettes.data();
+
+    assert_eq!(palettes.spec(), 0xC5);
+}
+
+#[test]
+fn auto_increment_wraps_at_the_end_of_the_palette_ram() {
+    let mut palettes = ColorPalettes::new();
+
+    palettes.write_spec(SPEC_AUTO_INCREMENT | 0x3F);
+    palettes.write_data(0x12);
+    palettes.write_data(0x34);
+
+    assert_eq!(palettes.spec(), 0xC1);
+
+    palettes.write_spec(0x3F);
+
+    assert_eq!(palettes.data(), 0x12);
+
+    palettes.write_spec(0x00);
+
+    assert_eq!(palettes.data(), 0x34);
+}
+
+#[test]
+fn palette_ram_is_initialized_to_white() {
+    let palettes = ColorPalettes::new();
+
+    assert_eq!(rgb(palettes.color(0, 0)), (248, 248, 248));
+    assert_eq!(rgb(palettes.color(7, 3)), (248, 248, 248));
+}
+
+// Colors are little endian, 15 bit: red in the lowest bits.
+//
+#[test]
+fn colors_are_corrected_from_15_bit_rgb() {
+    let mut palettes = ColorPalettes::new();
+
+    palettes.write_spec(SPEC_AUTO_INCREMENT | 0x08); // Palette 1, color 0
+    palettes.write_data(0x1F); // Red
+    palettes.write_data(0x00);
+    palettes.write_data(0xE0); // Green
+    palettes.write_data(0x03);
+    palettes.write_data(0x00); // Blue
+    palettes.write_data(0x7C);
+    palettes.write_data(0x00); // Black
+    palettes.write_data(0x00);
+
+    assert_eq!(rgb(palettes.color(1, 0)), (201, 0, 46));
+    assert_eq!(rgb(palettes.color(1, 1)), (31, 186, 31));
+    assert_eq!(rgb(palettes.color(1, 2)), (15, 62, 170));
+    assert_eq!(rgb(palettes.color(1, 3)), (0, 0, 0));
+}
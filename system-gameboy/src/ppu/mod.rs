@@ -0,0 +1,658 @@
+mod color_palettes;
+
+#[cfg(test)]
+mod color_palettes_test;
+#[cfg(test)]
+mod ppu_test;
+
+use super::memory_bus::{STAT_INTERRUPT, VBLANK_INTERRUPT};
+use color_palettes::ColorPalettes;
+use interfaces_frontend::video::Pixel;
+
+type Byte = u8;
+
+pub(crate) const DISPLAY_WIDTH: usize = 160;
+pub(crate) const DISPLAY_HEIGHT: usize = 144;
+
+pub(crate) const OAM_SIZE: usize = 0xA0;
+pub(crate) const VRAM_BANK_SIZE: usize = 0x2000;
+const VRAM_BANKS_COUNT: usize = 2;
+const VBK_UNUSED_BITS: Byte = 0b1111_1110;
+
+// All the timings are in clock ticks. The pixel transfer length is fixed, while on the real hardware
+// it's extended by scrolling, window and sprites.
+//
+const MCYCLE_TICKS: u8 = 4;
+const CYCLES_PER_LINE: u16 = 456;
+const LINES_PER_FRAME: Byte = 154;
+const OAM_SCAN_CYCLES: u16 = 80;
+const PIXEL_TRANSFER_CYCLES: u16 = 172;
+
+const LCDC_ENABLE_BIT: Byte = 0b1000_0000;
+const LCDC_WINDOW_TILEMAP_BIT: Byte = 0b0100_0000;
+const LCDC_WINDOW_ENABLE_BIT: Byte = 0b0010_0000;
+const LCDC_TILE_DATA_BIT: Byte = 0b0001_0000;
+const LCDC_BG_TILEMAP_BIT: Byte = 0b0000_1000;
+const LCDC_SPRITES_SIZE_BIT: Byte = 0b0000_0100;
+const LCDC_SPRITES_ENABLE_BIT: Byte = 0b0000_0010;
+const LCDC_BG_WINDOW_ENABLE_BIT: Byte = 0b0000_0001;
+
+const STAT_LYC_INTERRUPT_BIT: Byte = 0b0100_0000;
+const STAT_OAM_SCAN_INTERRUPT_BIT: Byte = 0b0010_0000;
+const STAT_VBLANK_INTERRUPT_BIT: Byte = 0b0001_0000;
+const STAT_HBLANK_INTERRUPT_BIT: Byte = 0b0000_1000;
+const STAT_COINCIDENCE_BIT: Byte = 0b0000_0100;
+const STAT_WRITABLE_BITS: Byte = 0b0111_1000;
+const STAT_UNUSED_BITS: Byte = 0b1000_0000;
+
+const TILEMAP_0_ADDRESS: usize = 0x1800;
+const TILEMAP_1_ADDRESS: usize = 0x1C00;
+const TILEMAP_WIDTH: usize = 32;
+const TILE_SIZE: usize = 16;
+
+// Relative to VRAM; the BG/window tile numbers are unsigned (0x8000 mode) or signed (0x8800 mode),
+// while the sprites always use the unsigned mode.
+//
+const TILE_DATA_UNSIGNED_ADDRESS: usize = 0x0000;
+const TILE_DATA_SIGNED_ADDRESS: usize = 0x1000;
+
+// CGB BG/window tile attributes; they're stored in VRAM bank 1, at the same address of the tile
+// numbers.
+//
+const BG_PALETTE_MASK: Byte = 0b0000_0111;
+const BG_VRAM_BANK_BIT: Byte = 0b0000_1000;
+const BG_X_FLIP_BIT: Byte = 0b0010_0000;
+const BG_Y_FLIP_BIT: Byte = 0b0100_0000;
+const BG_PRIORITY_BIT: Byte = 0b1000_0000;
+
+const SPRITE_ATTRIBUTES_SIZE: usize = 4;
+const SPRITES_COUNT: usize = OAM_SIZE / SPRITE_ATTRIBUTES_SIZE;
+const MAX_SPRITES_PER_LINE: usize = 10;
+
+// The sprite coordinates are offset, so that sprites can be partially displayed on the top/left
+// edges; the window X coordinate is offset as well.
+//
+const SPRITE_Y_OFFSET: i16 = 16;
+const SPRITE_X_OFFSET: i16 = 8;
+const WINDOW_X_OFFSET: usize = 7;
+
+const SPRITE_BG_PRIORITY_BIT: Byte = 0b1000_0000;
+const SPRITE_Y_FLIP_BIT: Byte = 0b0100_0000;
+const SPRITE_X_FLIP_BIT: Byte = 0b0010_0000;
+const SPRITE_PALETTE_BIT: Byte = 0b0001_0000;
+const SPRITE_VRAM_BANK_BIT: Byte = 0b0000_1000; // CGB only
+const SPRITE_COLOR_PALETTE_MASK: Byte = 0b0000_0111; // CGB only
+
+// Shades of the DMG palette colors, from lightest to darkest.
+//
+const SHADES: [Pixel; 4] = [
+    Pixel(0xFF, 0xFF, 0xFF),
+    Pixel(0xAA, 0xAA, 0xAA),
+    Pixel(0x55, 0x55, 0x55),
+    Pixel(0x00, 0x00, 0x00),
+];
+
+#[derive(Copy, Clone, PartialEq)]
+enum Mode {
+    HBlank = 0,
+    VBlank = 1,
+    OamScan = 2,
+    PixelTransfer = 3,
+}
+
+// Color number (0-3), and, in CGB mode, palette and priority over the sprites.
+//
+#[derive(Copy, Clone, Default)]
+struct BgPixel {
+    color: Byte,
+    palette: Byte,
+    priority: bool,
+}
+
+struct Sprite {
+    oam_index: usize,
+    y: i16,
+    x: i16,
+    tile: Byte,
+    attributes: Byte,
+}
+
+/// Picture processing unit.
+///
+/// Each visible line goes through OAM scan (mode 2), pixel transfer (mode 3) and H-Blank (mode 0);
+/// they're followed by 10 lines of V-Blank (mode 1). The line is rendered in a single pass at the
+/// end of the pixel transfer, so the register changes performed during the line are applied from
+/// the next one.
+///
+/// The STAT interrupt is requested on the rising edge of the OR of the enabled sources (LY=LYC and
+/// modes 0-2), so that sources active at the same time don't request multiple interrupts.
+///
+/// In CGB mode, the VRAM has two banks (the second holding the BG tile attributes, and more tile
+/// data), the colors are taken from the palette RAMs, the sprites priority is determined only by
+/// the OAM index, and the LCDC bit 0 controls the BG/window priority, rather than their display.
+///
+/// VRAM and OAM are always accessible by the CPU.
+///
+pub(crate) struct Ppu {
+    cgb_mode: bool,
+
+    vram: [Byte; VRAM_BANK_SIZE * VRAM_BANKS_COUNT],
+    vram_bank: usize,
+    pub oam: [Byte; OAM_SIZE],
+
+    pub bg_palettes: ColorPalettes,
+    pub obj_palettes: ColorPalettes,
+
+    pub SCY: Byte,
+    pub SCX: Byte,
+    pub BGP: Byte,
+    pub OBP0: Byte,
+    pub OBP1: Byte,
+    pub WY: Byte,
+    pub WX: Byte,
+
+    LCDC: Byte,
+    STAT: Byte,
+    LY: Byte,
+    LYC: Byte,
+
+    line_cycles: u16,
+    stat_line_high: bool,
+
+    // The first line after the LCD is enabled has no OAM scan; mode 0 is reported instead.
+    //
+    first_line_after_enable: bool,
+
+    // The window is enabled for the rest of the frame once LY matches WY; its lines are counted
+    // separately, since they're not advanced when the window is not displayed.
+    //
+    window_triggered: bool,
+    window_line: usize,
+
+    frame: Vec<Pixel>,
+    frame_completed: bool,
+    hblank_started: bool,
+}
+
+impl Ppu {
+    pub fn new(cgb_mode: bool) -> Ppu {
+        Ppu {
+            cgb_mode,
+
+            vram: [0; VRAM_BANK_SIZE * VRAM_BANKS_COUNT],
+            vram_bank: 0,
+            oam: [0; OAM_SIZE],
+
+            bg_palettes: ColorPalettes::new(),
+            obj_palettes: ColorPalettes::new(),
+
+            SCY: 0,
+            SCX: 0,
+            BGP: 0,
+            OBP0: 0,
+            OBP1: 0,
+            WY: 0,
+            WX: 0,
+
+            LCDC: 0,
+            STAT: 0,
+            LY: 0,
+            LYC: 0,
+
+            line_cycles: 0,
+            stat_line_high: false,
+
+            first_line_after_enable: false,
+
+            window_triggered: false,
+            window_line: 0,
+
+            frame: vec![SHADES[0].clone(); DISPLAY_WIDTH * DISPLAY_HEIGHT],
+            frame_completed: false,
+            hblank_started: false,
+        }
+    }
+
+    /// Reads from the current VRAM bank; the address is relative to the VRAM start.
+    ///
+    pub fn read_vram(&self, address: usize) -> Byte {
+        self.vram[self.vram_bank * VRAM_BANK_SIZE + address]
+    }
+
+    pub fn write_vram(&mut self, address: usize, value: Byte) {
+        self.vram[self.vram_bank * VRAM_BANK_SIZE + address] = value;
+    }
+
+    pub fn VBK(&self) -> Byte {
+        VBK_UNUSED_BITS | self.vram_bank as Byte
+    }
+
+    pub fn write_VBK(&mut self, value: Byte) {
+        self.vram_bank = (value & !VBK_UNUSED_BITS) as usize;
+    }
+
+    pub fn LCDC(&self) -> Byte {
+        self.LCDC
+    }
+
+    pub fn STAT(&self) -> Byte {
+        let coincidence = if self.lcd_enabled() && self.LY == self.LYC {
+            STAT_COINCIDENCE_BIT
+        } else {
+            0
+        };
+
+        STAT_UNUSED_BITS | self.STAT | coincidence | self.mode() as Byte
+    }
+
+    pub fn LY(&self) -> Byte {
+        self.LY
+    }
+
+    pub fn LYC(&self) -> Byte {
+        self.LYC
+    }
+
+    // When the LCD is turned off, the line counter is reset, the STAT line is low, and the screen is
+    // blank.
+    //
+    pub fn write_LCDC(&mut self, value: Byte) {
+        if value & LCDC_ENABLE_BIT != 0 && !self.lcd_enabled() {
+            self.first_line_after_enable = true;
+        } else if value & LCDC_ENABLE_BIT == 0 && self.lcd_enabled() {
+            self.LY = 0;
+            self.line_cycles = 0;
+            self.stat_line_high = false;
+            self.window_triggered = false;
+            self.window_line = 0;
+            self.frame
+                .iter_mut()
+                .for_each(|pixel| *pixel = SHADES[0].clone());
+        }
+
+        self.LCDC = value;
+    }
+
+    /// Returns the interrupts requested.
+    ///
+    pub fn write_STAT(&mut self, value: Byte) -> Byte {
+        self.STAT = value & STAT_WRITABLE_BITS;
+        self.update_stat_line()
+    }
+
+    /// Returns the interrupts requested.
+    ///
+    pub fn write_LYC(&mut self, value: Byte) -> Byte {
+        self.LYC = value;
+        self.update_stat_line()
+    }
+
+    /// Current frame; it's complete when `frame_completed()` returns true.
+    ///
+    pub fn frame(&self) -> &[Pixel] {
+        &self.frame
+    }
+
+    /// Returns true (once) if a frame has been completed (V-Blank has been entered) since the last
+    /// invocation.
+    ///
+    pub fn frame_completed(&mut self) -> bool {
+        let frame_completed = self.frame_completed;
+        self.frame_completed = false;
+        frame_completed
+    }
+
+    /// Returns true (once) if H-Blank has been entered since the last invocation.
+    ///
+    pub fn hblank_started(&mut self) -> bool {
+        let hblank_started = self.hblank_started;
+        self.hblank_started = false;
+        hblank_started
+    }
+
+    pub fn lcd_enabled(&self) -> bool {
+        self.LCDC & LCDC_ENABLE_BIT != 0
+    }
+
+    /// Advances by the given clock ticks (a multiple of an M-cycle); returns the interrupts
+    /// requested.
+    ///
+    pub fn tick(&mut self, cycles: u8) -> Byte {
+        if !self.lcd_enabled() {
+            return 0;
+        }
+
+        let mut interrupts = 0;
+
+        for _ in 0..(cycles / MCYCLE_TICKS) {
+            interrupts |= self.tick_mcycle();
+        }
+
+        interrupts
+    }
+
+    fn tick_mcycle(&mut self) -> Byte {
+        let mut interrupts = 0;
+
+        self.line_cycles += MCYCLE_TICKS as u16;
+
+        if self.line_cycles == OAM_SCAN_CYCLES {
+            self.first_line_after_enable = false;
+        }
+
+        if self.line_cycles == CYCLES_PER_LINE {
+            self.line_cycles = 0;
+            self.LY = (self.LY + 1) % LINES_PER_FRAME;
+
+            if self.LY == 0 {
+                self.window_triggered = false;
+                self.window_line = 0;
+            }
+
+            if self.LY as usize == DISPLAY_HEIGHT {
+                self.frame_completed = true;
+                interrupts |= VBLANK_INTERRUPT;
+            }
+        }
+
+        if (self.LY as usize) < DISPLAY_HEIGHT
+            && self.line_cycles == OAM_SCAN_CYCLES + PIXEL_TRANSFER_CYCLES
+        {
+            self.render_line();
+            self.hblank_started = true;
+        }
+
+        interrupts | self.update_stat_line()
+    }
+
+    fn mode(&self) -> Mode {
+        if !self.lcd_enabled() {
+            Mode::HBlank
+        } else if self.LY as usize >= DISPLAY_HEIGHT {
+            Mode::VBlank
+        } else if self.line_cycles < OAM_SCAN_CYCLES && self.first_line_after_enable {
+            Mode::HBlank
+        } else if self.line_cycles < OAM_SCAN_CYCLES {
+            Mode::OamScan
+        } else if self.line_cycles < OAM_SCAN_CYCLES + PIXEL_TRANSFER_CYCLES {
+            Mode::PixelTransfer
+        } else {
+            Mode::HBlank
+        }
+    }
+
+    // Returns the STAT interrupt, if the line has risen.
+    //
+    fn update_stat_line(&mut self) -> Byte {
+        let mode_source_bit = match self.mode() {
+            Mode::HBlank => STAT_HBLANK_INTERRUPT_BIT,
+            Mode::VBlank => STAT_VBLANK_INTERRUPT_BIT,
+            Mode::OamScan => STAT_OAM_SCAN_INTERRUPT_BIT,
+            Mode::PixelTransfer => 0,
+        };
+
+        let stat_line_high = self.lcd_enabled()
+            && (self.STAT & mode_source_bit != 0
+                || (self.STAT & STAT_LYC_INTERRUPT_BIT != 0 && self.LY == self.LYC));
+
+        let rising_edge = stat_line_high && !self.stat_line_high;
+        self.stat_line_high = stat_line_high;
+
+        if rising_edge {
+            STAT_INTERRUPT
+        } else {
+            0
+        }
+    }
+
+    fn render_line(&mut self) {
+        let y = self.LY as usize;
+
+        if self.LY == self.WY {
+            self.window_triggered = true;
+        }
+
+        let mut bg_pixels = [BgPixel::default(); DISPLAY_WIDTH];
+
+        if self.cgb_mode || self.LCDC & LCDC_BG_WINDOW_ENABLE_BIT != 0 {
+            self.render_bg_window_line(&mut bg_pixels);
+        }
+
+        for (x, bg_pixel) in bg_pixels.iter().enumerate() {
+            self.frame[y * DISPLAY_WIDTH + x] = if self.cgb_mode {
+                self.bg_palettes.color(bg_pixel.palette, bg_pixel.color)
+            } else {
+                let shade = Self::palette_shade(self.BGP, bg_pixel.color);
+                SHADES[shade as usize].clone()
+            };
+        }
+
+        if self.LCDC & LCDC_SPRITES_ENABLE_BIT != 0 {
+            self.render_sprites_line(&bg_pixels);
+        }
+    }
+
+    // Fills the BG/window pixels of the current line.
+    //
+    fn render_bg_window_line(&mut self, bg_pixels: &mut [BgPixel; DISPLAY_WIDTH]) {
+        let y = self.LY as usize;
+
+        let window_start_x = if self.window_triggered && self.LCDC & LCDC_WINDOW_ENABLE_BIT != 0 {
+            Some((self.WX as usize).saturating_sub(WINDOW_X_OFFSET))
+                .filter(|&window_start_x| window_start_x < DISPLAY_WIDTH)
+        } else {
+            None
+        };
+
+        let bg_tilemap_address = Self::tilemap_address(self.LCDC, LCDC_BG_TILEMAP_BIT);
+        let window_tilemap_address = Self::tilemap_address(self.LCDC, LCDC_WINDOW_TILEMAP_BIT);
+
+        for (x, bg_pixel) in bg_pixels.iter_mut().enumerate() {
+            *bg_pixel = match window_start_x {
+                // The WX values below 7 shift the window left.
+                //
+                Some(window_start_x) if x >= window_start_x => {
+                    let window_x = x + WINDOW_X_OFFSET - self.WX as usize;
+                    self.tilemap_pixel(window_tilemap_address, window_x, self.window_line)
+                }
+                _ => {
+                    let bg_x = (x + self.SCX as usize) % 256;
+                    let bg_y = (y + self.SCY as usize) % 256;
+                    self.tilemap_pixel(bg_tilemap_address, bg_x, bg_y)
+                }
+            };
+        }
+
+        if window_start_x.is_some() {
+            self.window_line += 1;
+        }
+    }
+
+    // Sprites priority: among the non transparent pixels, the one of the sprite with the lowest X
+    // wins (on tie, the lowest OAM index; in CGB mode, only the OAM index is considered); then, the
+    // sprite pixel is displayed according to the BG priority (see `sprite_over_bg()`).
+    //
+    fn render_sprites_line(&mut self, bg_pixels: &[BgPixel; DISPLAY_WIDTH]) {
+        let y = self.LY as i16;
+        let sprites_height = if self.LCDC & LCDC_SPRITES_SIZE_BIT != 0 {
+            16
+        } else {
+            8
+        };
+
+        let mut sprites = self.line_sprites(sprites_height);
+
+        if !self.cgb_mode {
+            sprites.sort_by_key(|sprite| (sprite.x, sprite.oam_index));
+        }
+
+        for (x, bg_pixel) in bg_pixels.iter().enumerate() {
+            let screen_x = x as i16 + SPRITE_X_OFFSET;
+
+            let sprite_pixel = sprites
+                .iter()
+                .filter(|sprite| sprite.x <= screen_x && screen_x < sprite.x + 8)
+                .map(|sprite| {
+                    let color = self.sprite_color(sprite, y, screen_x, sprites_height);
+                    (sprite, color)
+                })
+                .find(|(_, color)| *color != 0);
+
+            if let Some((sprite, color)) = sprite_pixel {
+                if self.sprite_over_bg(sprite, bg_pixel) {
+                    self.frame[y as usize * DISPLAY_WIDTH + x] =
+                        self.sprite_pixel_color(sprite, color);
+                }
+            }
+        }
+    }
+
+    // The sprites are always displayed over the BG/window color 0; otherwise, the sprite BG priority
+    // flag (and, in CGB mode, the BG one) gives priority to the BG/window. In CGB mode, clearing the
+    // LCDC bit 0 gives priority to the sprites, regardless of the flags.
+    //
+    fn sprite_over_bg(&self, sprite: &Sprite, bg_pixel: &BgPixel) -> bool {
+        if bg_pixel.color == 0 || (self.cgb_mode && self.LCDC & LCDC_BG_WINDOW_ENABLE_BIT == 0) {
+            true
+        } else {
+            sprite.attributes & SPRITE_BG_PRIORITY_BIT == 0 && !bg_pixel.priority
+        }
+    }
+
+    fn sprite_pixel_color(&self, sprite: &Sprite, color: Byte) -> Pixel {
+        if self.cgb_mode {
+            let palette = sprite.attributes & SPRITE_COLOR_PALETTE_MASK;
+            self.obj_palettes.color(palette, color)
+        } else {
+            let palette = if sprite.attributes & SPRITE_PALETTE_BIT != 0 {
+                self.OBP1
+            } else {
+                self.OBP0
+            };
+
+            SHADES[Self::palette_shade(palette, color) as usize].clone()
+        }
+    }
+
+    // The first (up to) 10 sprites in OAM order, intersecting the current line; the X coordinate is
+    // not considered.
+    //
+    fn line_sprites(&self, sprites_height: i16) -> Vec<Sprite> {
+        let screen_y = self.LY as i16 + SPRITE_Y_OFFSET;
+
+        (0..SPRITES_COUNT)
+            .map(|oam_index| {
+                let attributes = &self.oam[oam_index * SPRITE_ATTRIBUTES_SIZE..];
+
+                Sprite {
+                    oam_index,
+                    y: attributes[0] as i16,
+                    x: attributes[1] as i16,
+                    tile: attributes[2],
+                    attributes: attributes[3],
+                }
+            })
+            .filter(|sprite| sprite.y <= screen_y && screen_y < sprite.y + sprites_height)
+            .take(MAX_SPRITES_PER_LINE)
+            .collect()
+    }
+
+    // In 8x16 mode, the lower bit of the tile number is ignored.
+    //
+    fn sprite_color(&self, sprite: &Sprite, y: i16, screen_x: i16, sprites_height: i16) -> Byte {
+        let mut sprite_y = y + SPRITE_Y_OFFSET - sprite.y;
+        let mut sprite_x = screen_x - sprite.x;
+
+        if sprite.attributes & SPRITE_Y_FLIP_BIT != 0 {
+            sprite_y = sprites_height - 1 - sprite_y;
+        }
+
+        if sprite.attributes & SPRITE_X_FLIP_BIT != 0 {
+            sprite_x = 7 - sprite_x;
+        }
+
+        let tile = if sprites_height == 16 {
+            sprite.tile & 0xFE
+        } else {
+            sprite.tile
+        };
+
+        let bank_address = if self.cgb_mode && sprite.attributes & SPRITE_VRAM_BANK_BIT != 0 {
+            VRAM_BANK_SIZE
+        } else {
+            0
+        };
+
+        let tile_address = bank_address + TILE_DATA_UNSIGNED_ADDRESS + tile as usize * TILE_SIZE;
+
+        self.tile_color(tile_address, sprite_x as usize, sprite_y as usize)
+    }
+
+    fn tilemap_address(LCDC: Byte, tilemap_bit: Byte) -> usize {
+        if LCDC & tilemap_bit != 0 {
+            TILEMAP_1_ADDRESS
+        } else {
+            TILEMAP_0_ADDRESS
+        }
+    }
+
+    // Pixel at the given position of a (BG/window) tilemap.
+    //
+    fn tilemap_pixel(&self, tilemap_address: usize, map_x: usize, map_y: usize) -> BgPixel {
+        let tile_number_address = tilemap_address + (map_y / 8) * TILEMAP_WIDTH + map_x / 8;
+        let tile_number = self.vram[tile_number_address];
+
+        let attributes = if self.cgb_mode {
+            self.vram[VRAM_BANK_SIZE + tile_number_address]
+        } else {
+            0
+        };
+
+        let bank_address = if attributes & BG_VRAM_BANK_BIT != 0 {
+            VRAM_BANK_SIZE
+        } else {
+            0
+        };
+
+        let tile_address = if self.LCDC & LCDC_TILE_DATA_BIT != 0 {
+            TILE_DATA_UNSIGNED_ADDRESS + tile_number as usize * TILE_SIZE
+        } else {
+            (TILE_DATA_SIGNED_ADDRESS as isize + tile_number as i8 as isize * TILE_SIZE as isize)
+                as usize
+        };
+
+        let mut tile_x = map_x % 8;
+        let mut tile_y = map_y % 8;
+
+        if attributes & BG_X_FLIP_BIT != 0 {
+            tile_x = 7 - tile_x;
+        }
+
+        if attributes & BG_Y_FLIP_BIT != 0 {
+            tile_y = 7 - tile_y;
+        }
+
+        BgPixel {
+            color: self.tile_color(bank_address + tile_address, tile_x, tile_y),
+            palette: attributes & BG_PALETTE_MASK,
+            priority: attributes & BG_PRIORITY_BIT != 0,
+        }
+    }
+
+    // Each tile line is two bytes: the low and high bits of the color numbers, with the leftmost
+    // pixel in the MSB. `tile_y` can span two tiles (8x16 sprites).
+    //
+    fn tile_color(&self, tile_address: usize, tile_x: usize, tile_y: usize) -> Byte {
+        let line_address = tile_address + tile_y * 2;
+        let bit = 7 - tile_x;
+
+        let low_bit = (self.vram[line_address] >> bit) & 1;
+        let high_bit = (self.vram[line_address + 1] >> bit) & 1;
+
+        (high_bit << 1) | low_bit
+    }
+
+    fn palette_shade(palette: Byte, color: Byte) -> Byte {
+        (palette >> (color * 2)) & 0b11
+    }
+}
@@ -0,0 +1,499 @@
+use super::super::memory_bus::{STAT_INTERRUPT, VBLANK_INTERRUPT};
+use super::{Ppu, DISPLAY_WIDTH};
+
+type Byte = u8;
+
+// LCD and BG enabled, unsigned tile data, BG tilemap 0, window tilemap 1.
+//
+const LCDC_BASE: Byte = 0b1101_0001;
+const LCDC_WINDOW_ENABLE_BIT: Byte = 0b0010_0000;
+const LCDC_SPRITES_SIZE_BIT: Byte = 0b0000_0100;
+const LCDC_SPRITES_ENABLE_BIT: Byte = 0b0000_0010;
+const LCDC_ENABLE_BIT: Byte = 0b1000_0000;
+
+const STAT_LYC_SOURCE: Byte = 0b0100_0000;
+const STAT_OAM_SCAN_SOURCE: Byte = 0b0010_0000;
+const STAT_HBLANK_SOURCE: Byte = 0b0000_1000;
+const STAT_COINCIDENCE_BIT: Byte = 0b0000_0100;
+const STAT_MODE_MASK: Byte = 0b0000_0011;
+
+const CYCLES_PER_LINE: u32 = 456;
+const LINES_PER_FRAME: u32 = 154;
+const OAM_SCAN_CYCLES: u32 = 80;
+const PIXEL_TRANSFER_CYCLES: u32 = 172;
+
+// Identity palette: color N -> shade N.
+//
+const IDENTITY_PALETTE: Byte = 0b1110_0100;
+
+const WINDOW_TILEMAP_ADDRESS: usize = 0x1C00;
+const TILEMAP_SIZE: usize = 0x400;
+const TILE_SIZE: usize = 16;
+
+const WHITE: (u8, u8, u8) = (0xFF, 0xFF, 0xFF);
+const LIGHT_GRAY: (u8, u8, u8) = (0xAA, 0xAA, 0xAA);
+const DARK_GRAY: (u8, u8, u8) = (0x55, 0x55, 0x55);
+const BLACK: (u8, u8, u8) = (0x00, 0x00, 0x00);
+
+// Tile 0 is blank (color 0), so that the BG is white.
+//
+const SOLID_COLOR_1_TILE: Byte = 2;
+const SOLID_COLOR_3_TILE: Byte = 3;
+const ALL_COLORS_TILE: Byte = 4;
+const WINDOW_TILE: Byte = 5;
+
+// Enabled, and at the start of a frame; the first line after the enable has no OAM scan, so a
+// whole frame is run.
+//
+fn ppu(cgb_mode: bool) -> Ppu {
+    let mut ppu = Ppu::new(cgb_mode);
+
+    ppu.BGP = IDENTITY_PALETTE;
+    ppu.OBP0 = IDENTITY_PALETTE;
+
+    set_tile_line(&mut ppu, SOLID_COLOR_3_TILE, None, 0xFF, 0xFF);
+    set_tile_line(&mut ppu, SOLID_COLOR_1_TILE, None, 0xFF, 0x00);
+
+    // Pixels 0-3 have colors 0-3; the rest is color 0.
+    //
+    set_tile_line(&mut ppu, ALL_COLORS_TILE, None, 0b0101_0000, 0b0011_0000);
+
+    ppu.write_LCDC(LCDC_BASE);
+    tick(&mut ppu, LINES_PER_FRAME * CYCLES_PER_LINE);
+
+    ppu.frame_completed();
+    ppu.hblank_started();
+
+    ppu
+}
+
+// Sets the given line of a tile (all the lines, if not specified); the low and high bits of the
+// color numbers are passed.
+//
+fn set_tile_line(ppu: &mut Ppu, tile: Byte, line: Option<usize>, low_bits: Byte, high_bits: Byte) {
+    let lines = line.map_or(0..8, |line| line..line + 1);
+
+    for line in lines {
+        let line_address = tile as usize * TILE_SIZE + line * 2;
+
+        ppu.write_vram(line_address, low_bits);
+        ppu.write_vram(line_address + 1, high_bits);
+    }
+}
+
+fn set_sprite(ppu: &mut Ppu, index: usize, y: Byte, x: Byte, tile: Byte, attributes: Byte) {
+    ppu.oam[index * 4..index * 4 + 4].copy_from_slice(&[y, x, tile, attributes]);
+}
+
+// Returns the interrupts requested.
+//
+fn tick(ppu: &mut Ppu, cycles: u32) -> Byte {
+    let mut interrupts = 0;
+
+    for _ in 0..(cycles / 4) {
+        interrupts |= ppu.tick(4);
+    }
+
+    interrupts
+}
+
+// Renders the current line, then moves to the start of the next one.
+//
+fn render_line(ppu: &mut Ppu) {
+    tick(ppu, CYCLES_PER_LINE);
+}
+
+fn mode(ppu: &Ppu) -> Byte {
+    ppu.STAT() & STAT_MODE_MASK
+}
+
+fn pixel(ppu: &Ppu, x: usize, y: usize) -> (u8, u8, u8) {
+    let pixel = &ppu.frame()[y * DISPLAY_WIDTH + x];
+    (pixel.0, pixel.1, pixel.2)
+}
+
+#[test]
+fn visible_lines_go_through_the_oam_scan_pixel_transfer_and_hblank() {
+    let mut ppu = ppu(false);
+
+    assert_eq!(mode(&ppu), 2);
+
+    tick(&mut ppu, OAM_SCAN_CYCLES - 4);
+    assert_eq!(mode(&ppu), 2);
+
+    tick(&mut ppu, 4);
+    assert_eq!(mode(&ppu), 3);
+
+    tick(&mut ppu, PIXEL_TRANSFER_CYCLES - 4);
+    assert_eq!(mode(&ppu), 3);
+
+    tick(&mut ppu, 4);
+    assert_eq!(mode(&ppu), 0);
+    assert!(ppu.hblank_started());
+
+    tick(
+        &mut ppu,
+        CYCLES_PER_LINE - OAM_SCAN_CYCLES - PIXEL_TRANSFER_CYCLES - 4,
+    );
+    assert_eq!((ppu.LY(), mode(&ppu)), (0, 0));
+
+    tick(&mut ppu, 4);
+    assert_eq!((ppu.LY(), mode(&ppu)), (1, 2));
+}
+
+#[test]
+fn vblank_lasts_10_lines() {
+    let mut ppu = ppu(false);
+
+    let interrupts = tick(&mut ppu, 144 * CYCLES_PER_LINE - 4);
+
+    assert_eq!(interrupts & VBLANK_INTERRUPT, 0);
+    assert!(!ppu.frame_completed());
+
+    let interrupts = tick(&mut ppu, 4);
+
+    assert_eq!((ppu.LY(), mode(&ppu)), (144, 1));
+    assert_eq!(interrupts, VBLANK_INTERRUPT);
+    assert!(ppu.frame_completed());
+    assert!(!ppu.frame_completed());
+
+    tick(&mut ppu, 10 * CYCLES_PER_LINE - 4);
+    assert_eq!((ppu.LY(), mode(&ppu)), (153, 1));
+
+    tick(&mut ppu, 4);
+    assert_eq!((ppu.LY(), mode(&ppu)), (0, 2));
+}
+
+#[test]
+fn lcd_enable_starts_the_first_line_in_mode_0() {
+    let mut ppu = Ppu::new(false);
+
+    assert_eq!(mode(&ppu), 0);
+
+    ppu.write_LCDC(LCDC_BASE);
+
+    assert_eq!((ppu.LY(), mode(&ppu)), (0, 0));
+
+    tick(&mut ppu, OAM_SCAN_CYCLES - 4);
+    assert_eq!(mode(&ppu), 0);
+
+    tick(&mut ppu, 4);
+    assert_eq!(mode(&ppu), 3);
+
+    tick(&mut ppu, CYCLES_PER_LINE - OAM_SCAN_CYCLES);
+    assert_eq!((ppu.LY(), mode(&ppu)), (1, 2));
+}
+
+#[test]
+fn lcd_disable_resets_ly_and_the_stat_line() {
+    let mut ppu = ppu(false);
+
+    tick(&mut ppu, 3 * CYCLES_PER_LINE);
+
+    ppu.write_LCDC(LCDC_BASE & !LCDC_ENABLE_BIT);
+
+    assert_eq!((ppu.LY(), mode(&ppu)), (0, 0));
+    assert_eq!(tick(&mut ppu, CYCLES_PER_LINE), 0);
+    assert_eq!(ppu.LY(), 0);
+
+    ppu.write_LCDC(LCDC_BASE);
+
+    assert_eq!(ppu.write_STAT(STAT_LYC_SOURCE), STAT_INTERRUPT);
+
+    // The line is lowered, so re-enabling the LCD on a coincidence raises it again.
+    //
+    ppu.write_LCDC(LCDC_BASE & !LCDC_ENABLE_BIT);
+    ppu.write_LCDC(LCDC_BASE);
+
+    assert_eq!(tick(&mut ppu, 4), STAT_INTERRUPT);
+}
+
+#[test]
+fn stat_interrupt_is_requested_on_the_rising_edge_of_the_sources() {
+    let mut ppu = ppu(false);
+
+    ppu.write_STAT(STAT_HBLANK_SOURCE | STAT_OAM_SCAN_SOURCE);
+
+    // The line is already high, due to the OAM scan.
+    //
+    assert_eq!(tick(&mut ppu, 4), 0);
+
+    assert_eq!(
+        tick(&mut ppu, OAM_SCAN_CYCLES + PIXEL_TRANSFER_CYCLES - 8),
+        0
+    );
+    assert_eq!(tick(&mut ppu, 4), STAT_INTERRUPT);
+
+    // The H-Blank source is followed by the OAM scan one, so the line stays high.
+    //
+    assert_eq!(
+        tick(
+            &mut ppu,
+            CYCLES_PER_LINE - OAM_SCAN_CYCLES - PIXEL_TRANSFER_CYCLES
+        ),
+        0
+    );
+    assert_eq!(mode(&ppu), 2);
+}
+
+#[test]
+fn stat_interrupt_is_requested_once_for_overlapping_sources() {
+    let mut ppu = ppu(false);
+
+    ppu.write_STAT(STAT_LYC_SOURCE | STAT_HBLANK_SOURCE);
+    ppu.write_LYC(1);
+
+    assert_eq!(
+        tick(&mut ppu, OAM_SCAN_CYCLES + PIXEL_TRANSFER_CYCLES),
+        STAT_INTERRUPT
+    );
+
+    // At the start of line 1, the LYC source rises while the H-Blank one falls.
+    //
+    assert_eq!(
+        tick(
+            &mut ppu,
+            CYCLES_PER_LINE - OAM_SCAN_CYCLES - PIXEL_TRANSFER_CYCLES
+        ),
+        0
+    );
+    assert_eq!(ppu.LY(), 1);
+
+    // The H-Blank of line 1 overlaps with the coincidence.
+    //
+    assert_eq!(tick(&mut ppu, CYCLES_PER_LINE), 0);
+    assert_eq!(ppu.LY(), 2);
+}
+
+#[test]
+fn lyc_coincidence_sets_the_stat_flag_and_requests_the_interrupt() {
+    let mut ppu = ppu(false);
+
+    ppu.write_STAT(STAT_LYC_SOURCE);
+    ppu.write_LYC(2);
+
+    assert_eq!(ppu.STAT() & STAT_COINCIDENCE_BIT, 0);
+    assert_eq!(tick(&mut ppu, CYCLES_PER_LINE), 0);
+    assert_eq!(tick(&mut ppu, CYCLES_PER_LINE), STAT_INTERRUPT);
+    assert_ne!(ppu.STAT() & STAT_COINCIDENCE_BIT, 0);
+
+    assert_eq!(tick(&mut ppu, CYCLES_PER_LINE - 4), 0);
+    assert_ne!(ppu.STAT() & STAT_COINCIDENCE_BIT, 0);
+
+    tick(&mut ppu, 4);
+    assert_eq!(ppu.STAT() & STAT_COINCIDENCE_BIT, 0);
+
+    // Writing LYC can cause the coincidence as well.
+    //
+    assert_eq!(ppu.write_LYC(3), STAT_INTERRUPT);
+}
+
+#[test]
+fn bg_colors_are_mapped_through_bgp() {
+    let mut ppu = ppu(false);
+
+    ppu.write_vram(0x1800, ALL_COLORS_TILE);
+    ppu.BGP = 0b0001_1011;
+
+    render_line(&mut ppu);
+
+    assert_eq!(pixel(&ppu, 0, 0), BLACK);
+    assert_eq!(pixel(&ppu, 1, 0), DARK_GRAY);
+    assert_eq!(pixel(&ppu, 2, 0), LIGHT_GRAY);
+    assert_eq!(pixel(&ppu, 3, 0), WHITE);
+}
+
+// The sprite color 0 is transparent, and the palette is selected by the attribute bit 4.
+//
+#[test]
+fn sprite_colors_are_mapped_through_obp0_and_obp1() {
+    let mut ppu = ppu(false);
+
+    ppu.write_LCDC(LCDC_BASE | LCDC_SPRITES_ENABLE_BIT);
+    ppu.OBP0 = 0b0001_1011;
+    ppu.OBP1 = 0b1110_0000;
+
+    set_sprite(&mut ppu, 0, 16, 8, ALL_COLORS_TILE, 0b0000_0000);
+    set_sprite(&mut ppu, 1, 16, 16, ALL_COLORS_TILE, 0b0001_0000);
+
+    render_line(&mut ppu);
+
+    assert_eq!(pixel(&ppu, 0, 0), WHITE);
+    assert_eq!(pixel(&ppu, 1, 0), DARK_GRAY);
+    assert_eq!(pixel(&ppu, 2, 0), LIGHT_GRAY);
+    assert_eq!(pixel(&ppu, 3, 0), WHITE);
+
+    assert_eq!(pixel(&ppu, 8, 0), WHITE);
+    assert_eq!(pixel(&ppu, 9, 0), WHITE);
+    assert_eq!(pixel(&ppu, 10, 0), DARK_GRAY);
+    assert_eq!(pixel(&ppu, 11, 0), BLACK);
+}
+
+// The sprites are selected in OAM order, regardless of X (even if offscreen).
+//
+#[test]
+fn up_to_10_sprites_are_displayed_per_line() {
+    let mut ppu = ppu(false);
+
+    ppu.write_LCDC(LCDC_BASE | LCDC_SPRITES_ENABLE_BIT);
+
+    for index in 1..11 {
+        set_sprite(
+            &mut ppu,
+            index,
+            16,
+            8 * index as Byte,
+            SOLID_COLOR_3_TILE,
+            0,
+        );
+    }
+
+    // Offscreen, and intersecting the lines 0-6.
+    //
+    set_sprite(&mut ppu, 0, 15, 0, SOLID_COLOR_3_TILE, 0);
+
+    render_line(&mut ppu);
+
+    for index in 1..10 {
+        assert_eq!(pixel(&ppu, 8 * (index - 1), 0), BLACK, "Sprite {}", index);
+    }
+
+    assert_eq!(pixel(&ppu, 8 * 9, 0), WHITE);
+
+    tick(&mut ppu, 7 * CYCLES_PER_LINE);
+
+    assert_eq!(pixel(&ppu, 8 * 9, 7), BLACK);
+}
+
+#[test]
+fn lower_x_sprites_have_priority_in_dmg_mode() {
+    let mut ppu = ppu(false);
+
+    ppu.write_LCDC(LCDC_BASE | LCDC_SPRITES_ENABLE_BIT);
+
+    set_sprite(&mut ppu, 0, 16, 12, SOLID_COLOR_3_TILE, 0);
+    set_sprite(&mut ppu, 1, 16, 8, SOLID_COLOR_1_TILE, 0);
+
+    // On tie, the lower OAM index wins.
+    //
+    set_sprite(&mut ppu, 2, 16, 40, SOLID_COLOR_3_TILE, 0);
+    set_sprite(&mut ppu, 3, 16, 40, SOLID_COLOR_1_TILE, 0);
+
+    render_line(&mut ppu);
+
+    assert_eq!(pixel(&ppu, 0, 0), LIGHT_GRAY);
+    assert_eq!(pixel(&ppu, 7, 0), LIGHT_GRAY);
+    assert_eq!(pixel(&ppu, 8, 0), BLACK);
+    assert_eq!(pixel(&ppu, 32, 0), BLACK);
+}
+
+// The palette colors are white on reset, so the color 1 of the OBJ palette 0 is set to black.
+//
+#[test]
+fn lower_oam_index_sprites_have_priority_in_cgb_mode() {
+    let mut ppu = ppu(true);
+
+    ppu.write_LCDC(LCDC_BASE | LCDC_SPRITES_ENABLE_BIT);
+    ppu.obj_palettes.write_spec(0b1000_0010);
+    ppu.obj_palettes.write_data(0x00);
+    ppu.obj_palettes.write_data(0x00);
+
+    set_sprite(&mut ppu, 0, 16, 12, SOLID_COLOR_3_TILE, 0);
+    set_sprite(&mut ppu, 1, 16, 8, SOLID_COLOR_1_TILE, 0);
+
+    render_line(&mut ppu);
+
+    assert_eq!(pixel(&ppu, 0, 0), BLACK);
+    assert_eq!(pixel(&ppu, 4, 0), (248, 248, 248));
+}
+
+// In 8x16 mode, the top tile has the lower bit of the tile number cleared.
+//
+#[test]
+fn tall_sprites_ignore_the_lower_bit_of_the_tile_number() {
+    let mut ppu = ppu(false);
+
+    ppu.write_LCDC(LCDC_BASE | LCDC_SPRITES_ENABLE_BIT | LCDC_SPRITES_SIZE_BIT);
+
+    set_sprite(&mut ppu, 0, 16, 8, SOLID_COLOR_3_TILE, 0);
+
+    for _ in 0..16 {
+        render_line(&mut ppu);
+    }
+
+    assert_eq!(pixel(&ppu, 0, 0), LIGHT_GRAY);
+    assert_eq!(pixel(&ppu, 0, 7), LIGHT_GRAY);
+    assert_eq!(pixel(&ppu, 0, 8), BLACK);
+    assert_eq!(pixel(&ppu, 0, 15), BLACK);
+
+    // Y flip applies to the whole sprite.
+    //
+    set_sprite(&mut ppu, 0, 16, 8, SOLID_COLOR_1_TILE, 0b0100_0000);
+
+    tick(&mut ppu, (LINES_PER_FRAME - 16) * CYCLES_PER_LINE);
+    render_line(&mut ppu);
+
+    assert_eq!(pixel(&ppu, 0, 0), BLACK);
+}
+
+// Tile line 1 is black, the others are white, so that only the window line 1 is black.
+//
+#[test]
+fn window_lines_are_counted_only_when_displayed() {
+    let mut ppu = ppu(false);
+
+    set_tile_line(&mut ppu, WINDOW_TILE, Some(1), 0xFF, 0xFF);
+
+    for address in WINDOW_TILEMAP_ADDRESS..WINDOW_TILEMAP_ADDRESS + TILEMAP_SIZE {
+        ppu.write_vram(address, WINDOW_TILE);
+    }
+
+    ppu.WY = 0;
+    ppu.WX = 7;
+    ppu.write_LCDC(LCDC_BASE | LCDC_WINDOW_ENABLE_BIT);
+
+    render_line(&mut ppu);
+
+    ppu.write_LCDC(LCDC_BASE);
+
+    for _ in 0..4 {
+        render_line(&mut ppu);
+    }
+
+    ppu.write_LCDC(LCDC_BASE | LCDC_WINDOW_ENABLE_BIT);
+
+    render_line(&mut ppu);
+    render_line(&mut ppu);
+
+    assert_eq!(pixel(&ppu, 0, 0), WHITE);
+    assert_eq!(pixel(&ppu, 0, 5), BLACK);
+    assert_eq!(pixel(&ppu, 0, 6), WHITE);
+}
+
+#[test]
+fn window_x_below_7_shifts_the_window_left() {
+    let mut ppu = ppu(false);
+
+    set_tile_line(&mut ppu, WINDOW_TILE, None, 0x0F, 0x0F);
+
+    for address in WINDOW_TILEMAP_ADDRESS..WINDOW_TILEMAP_ADDRESS + TILEMAP_SIZE {
+        ppu.write_vram(address, WINDOW_TILE);
+    }
+
+    ppu.WY = 0;
+    ppu.WX = 7;
+    ppu.write_LCDC(LCDC_BASE | LCDC_WINDOW_ENABLE_BIT);
+
+    render_line(&mut ppu);
+
+    ppu.WX = 3;
+
+    render_line(&mut ppu);
+
+    assert_eq!(pixel(&ppu, 0, 0), WHITE);
+    assert_eq!(pixel(&ppu, 4, 0), BLACK);
+
+    assert_eq!(pixel(&ppu, 0, 1), BLACK);
+    assert_eq!(pixel(&ppu, 3, 1), BLACK);
+    assert_eq!(pixel(&ppu, 4, 1), WHITE);
+}
@@ -0,0 +1,44 @@
+use super::save_storage::SaveStorage;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+type Byte = u8;
+
+const SAVE_FILE_EXTENSION: &str = "sav";
+
+/// Default save storage: a `.sav` file, by convention alongside the ROM.
+///
+/// The layout is the one shared by the most common emulators (e.g. VBA-M, BGB, SameBoy): the raw
+/// cartridge RAM, followed, for MBC3 cartridges with RTC, by a 48 bytes footer with the clock
+/// registers and the save timestamp.
+///
+pub struct SaveFile {
+    path: PathBuf,
+}
+
+impl SaveFile {
+    pub fn new(path: PathBuf) -> SaveFile {
+        SaveFile { path }
+    }
+
+    /// The save file has the ROM filename, with `.sav` extension.
+    ///
+    pub fn for_rom(rom_path: &Path) -> SaveFile {
+        SaveFile::new(rom_path.with_extension(SAVE_FILE_EXTENSION))
+    }
+}
+
+impl SaveStorage for SaveFile {
+    fn load(&mut self) -> Option<Vec<Byte>> {
+        fs::read(&self.path).ok()
+    }
+
+    // The path is added to the error, since the system reporting it doesn't know it.
+    //
+    fn save(&mut self, data: &[Byte]) -> io::Result<()> {
+        fs::write(&self.path, data)
+            .map_err(|error| io::Error::new(error.kind(), format!("{:?}: {}", self.path, error)))
+    }
+}
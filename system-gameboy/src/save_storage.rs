@@ -0,0 +1,18 @@
+use std::io;
+
+type Byte = u8;
+
+/// Persistence of the battery-backed cartridge state (RAM and RTC), provided by the host.
+///
+/// The data is in the common `.sav` layout (see `SaveFile`), so that the saves interoperate with
+/// other emulators.
+///
+pub trait SaveStorage {
+    /// Returns the stored data, if any.
+    ///
+    fn load(&mut self) -> Option<Vec<Byte>>;
+
+    /// Failures are not fatal: the system logs them, and retries on the next flush.
+    ///
+    fn save(&mut self, data: &[Byte]) -> io::Result<()>;
+}
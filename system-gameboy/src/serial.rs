@@ -0,0 +1,172 @@
+use super::memory_bus::SERIAL_INTERRUPT;
+use super::serial_link::SerialLink;
+
+use std::io;
+
+type Byte = u8;
+
+const SC_TRANSFER_BIT: Byte = 0b1000_0000;
+const SC_FAST_CLOCK_BIT: Byte = 0b0000_0010;
+const SC_INTERNAL_CLOCK_BIT: Byte = 0b0000_0001;
+const SC_DMG_UNUSED_BITS: Byte = 0b0111_1110;
+const SC_CGB_UNUSED_BITS: Byte = 0b0111_1100;
+
+// Clock ticks per bit, with the internal clock: 8192 Hz, or, in CGB mode, 262144 Hz with the fast
+// clock.
+//
+const BIT_CYCLES: u32 = 512;
+const FAST_BIT_CYCLES: u32 = 16;
+const BITS_PER_TRANSFER: u32 = 8;
+
+// Interval between the polls of the link, for transfers clocked by the peer.
+//
+const POLL_INTERVAL_CYCLES: u32 = BIT_CYCLES;
+
+/// SB/SC serial port.
+///
+/// With the internal clock, a transfer completes after 8 serial clocks, and the byte is exchanged
+/// with the link (if there's none, 0xFF is received, like with a disconnected cable); if the peer
+/// answer is not available yet, the link is polled periodically, and the transfer completes when it
+/// is. With the external clock, the transfer completes when the peer exchanges a byte; the link is
+/// polled periodically, and, while no transfer is armed, the bytes received are discarded.
+///
+/// The clock runs at the CPU speed (so twice as fast in double speed mode).
+///
+pub(crate) struct Serial {
+    SB: Byte,
+    SC: Byte,
+
+    cgb_mode: bool,
+
+    transfer_remaining_cycles: u32,
+    awaiting_answer: bool,
+    poll_cycles: u32,
+
+    link: Option<Box<dyn SerialLink>>,
+}
+
+impl Serial {
+    pub fn new(cgb_mode: bool) -> Serial {
+        Serial {
+            SB: 0,
+            SC: 0,
+
+            cgb_mode,
+
+            transfer_remaining_cycles: 0,
+            awaiting_answer: false,
+            poll_cycles: 0,
+
+            link: None,
+        }
+    }
+
+    pub fn set_link(&mut self, link: Box<dyn SerialLink>) {
+        self.link = Some(link);
+    }
+
+    pub fn take_link_error(&mut self) -> Option<io::Error> {
+        self.link.as_mut()?.take_error()
+    }
+
+    pub fn SB(&self) -> Byte {
+        self.SB
+    }
+
+    pub fn SC(&self) -> Byte {
+        if self.cgb_mode {
+            self.SC | SC_CGB_UNUSED_BITS
+        } else {
+            self.SC | SC_DMG_UNUSED_BITS
+        }
+    }
+
+    pub fn write_SB(&mut self, value: Byte) {
+        self.SB = value;
+    }
+
+    pub fn write_SC(&mut self, value: Byte) {
+        let used_bits = if self.cgb_mode {
+            SC_TRANSFER_BIT | SC_FAST_CLOCK_BIT | SC_INTERNAL_CLOCK_BIT
+        } else {
+            SC_TRANSFER_BIT | SC_INTERNAL_CLOCK_BIT
+        };
+
+        self.SC = value & used_bits;
+
+        let bit_cycles = if self.SC & SC_FAST_CLOCK_BIT != 0 {
+            FAST_BIT_CYCLES
+        } else {
+            BIT_CYCLES
+        };
+
+        self.transfer_remaining_cycles = BITS_PER_TRANSFER * bit_cycles;
+    }
+
+    /// Returns the interrupts requested.
+    ///
+    pub fn tick(&mut self, cycles: u8) -> Byte {
+        let transfer_armed = self.SC & SC_TRANSFER_BIT != 0;
+        let internal_clock = self.SC & SC_INTERNAL_CLOCK_BIT != 0;
+
+        // The answer is received even if the transfer has been aborted in the meantime, so that it's
+        // not mistaken for an exchange initiated by the peer.
+        //
+        if self.awaiting_answer {
+            if self.poll_due(cycles) {
+                let answer = self.link.as_mut().and_then(|link| link.receive_answer());
+
+                if let Some(received) = answer {
+                    self.awaiting_answer = false;
+
+                    if transfer_armed && internal_clock {
+                        return self.complete_transfer(received);
+                    }
+                }
+            }
+        } else if transfer_armed && internal_clock {
+            self.transfer_remaining_cycles =
+                self.transfer_remaining_cycles.saturating_sub(cycles as u32);
+
+            if self.transfer_remaining_cycles == 0 {
+                let received = match &mut self.link {
+                    Some(link) => link.exchange(self.SB),
+                    None => Some(0xFF),
+                };
+
+                match received {
+                    Some(received) => return self.complete_transfer(received),
+                    None => self.awaiting_answer = true,
+                }
+            }
+        } else if self.link.is_some() && self.poll_due(cycles) {
+            let sent = self.SB;
+
+            if let Some(received) = self.link.as_mut().and_then(|link| link.poll(sent)) {
+                if transfer_armed {
+                    return self.complete_transfer(received);
+                }
+            }
+        }
+
+        0
+    }
+
+    fn poll_due(&mut self, cycles: u8) -> bool {
+        self.poll_cycles += cycles as u32;
+
+        if self.poll_cycles >= POLL_INTERVAL_CYCLES {
+            self.poll_cycles -= POLL_INTERVAL_CYCLES;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn complete_transfer(&mut self, received: Byte) -> Byte {
+        self.SB = received;
+        self.SC &= !SC_TRANSFER_BIT;
+
+        SERIAL_INTERRUPT
+    }
+}
@@ -0,0 +1,73 @@
+use super::serial_link::SerialLink;
+
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::Path;
+use std::rc::Rc;
+
+type Byte = u8;
+
+/// Serial link without peer, which captures the bytes sent (e.g. the results printed by the test
+/// ROMs), to a buffer and, optionally, to a file.
+///
+/// The buffer is shared, so that it can be inspected while the system owns the link.
+///
+pub struct SerialCapture {
+    buffer: Rc<RefCell<Vec<Byte>>>,
+    file: Option<File>,
+    error: Option<io::Error>,
+}
+
+impl SerialCapture {
+    pub fn new() -> SerialCapture {
+        SerialCapture {
+            buffer: Rc::new(RefCell::new(vec![])),
+            file: None,
+            error: None,
+        }
+    }
+
+    /// Captures also to the given file, which is created (or truncated).
+    ///
+    pub fn with_file(path: &Path) -> io::Result<SerialCapture> {
+        Ok(SerialCapture {
+            file: Some(File::create(path)?),
+            ..SerialCapture::new()
+        })
+    }
+
+    pub fn buffer(&self) -> Rc<RefCell<Vec<Byte>>> {
+        Rc::clone(&self.buffer)
+    }
+}
+
+impl Default for SerialCapture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SerialLink for SerialCapture {
+    // Failures to write the file are not fatal; the file capture is stopped, and the error is logged
+    // by the system.
+    //
+    fn exchange(&mut self, sent: Byte) -> Option<Byte> {
+        self.buffer.borrow_mut().push(sent);
+
+        if let Some(file) = &mut self.file {
+            if let Err(error) = file.write_all(&[sent]) {
+                let message = format!("Error writing the serial output: {}", error);
+
+                self.error = Some(io::Error::new(error.kind(), message));
+                self.file = None;
+            }
+        }
+
+        Some(0xFF)
+    }
+
+    fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+}
@@ -0,0 +1,36 @@
+use std::io;
+
+type Byte = u8;
+
+/// Serial link cable, provided by the host (see `SerialCapture` and `SocketLink`).
+///
+/// Each transfer exchanges one byte in each direction. The side providing the clock (internal
+/// clock) initiates the exchange when its transfer completes; the other side answers it when polled.
+/// The provider doesn't wait for the answer: its transfer completes when the answer is received.
+///
+pub trait SerialLink {
+    /// Sends a byte, as clock provider, and returns the byte received from the peer (0xFF if there's
+    /// no peer), if it's already available; otherwise, it's returned by `receive_answer`.
+    ///
+    fn exchange(&mut self, sent: Byte) -> Option<Byte>;
+
+    /// Returns the byte received from the peer, for the pending exchange, once available.
+    ///
+    fn receive_answer(&mut self) -> Option<Byte> {
+        Some(0xFF)
+    }
+
+    /// If the peer has initiated an exchange, answers it with the given byte, and returns the byte
+    /// received.
+    ///
+    fn poll(&mut self, _sent: Byte) -> Option<Byte> {
+        None
+    }
+
+    /// Returns the error occurred since the last invocation, if any; the link errors are not fatal,
+    /// and they're logged by the system.
+    ///
+    fn take_error(&mut self) -> Option<io::Error> {
+        None
+    }
+}
@@ -0,0 +1,170 @@
+use super::serial_link::SerialLink;
+
+use std::io::{self, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream, ToSocketAddrs};
+#[cfg(unix)]
+use std::os::unix::net::{UnixListener, UnixStream};
+#[cfg(unix)]
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+type Byte = u8;
+
+// The peer answers when it polls, which happens only while its emulation is running (it's paused
+// between frames, when running in real time); after the timeout, it's considered disconnected.
+//
+const EXCHANGE_TIMEOUT: Duration = Duration::from_secs(1);
+
+trait Stream: Read + Write {}
+
+impl<T: Read + Write> Stream for T {}
+
+/// Serial link cable between two emulator instances, over a local TCP or Unix socket.
+///
+/// The clock provider sends its byte when its transfer completes, and the peer answers with its own
+/// when it polls. The provider doesn't block while waiting: its emulation keeps running, and the
+/// transfer completes when the answer is received, so it's delayed by the time the peer takes to
+/// answer. The protocol is a plain exchange of single bytes.
+///
+/// On errors (including the timeout), the link is disconnected, and behaves like an unplugged cable.
+///
+pub struct SocketLink {
+    stream: Option<Box<dyn Stream>>,
+    error: Option<io::Error>,
+    exchange_time: Option<Instant>, // When the pending exchange has been initiated
+}
+
+impl SocketLink {
+    pub fn tcp(stream: TcpStream) -> io::Result<SocketLink> {
+        stream.set_nodelay(true)?;
+        stream.set_nonblocking(true)?;
+
+        Ok(SocketLink {
+            stream: Some(Box::new(stream)),
+            error: None,
+            exchange_time: None,
+        })
+    }
+
+    /// Waits for the peer to connect.
+    ///
+    pub fn listen_tcp<A: ToSocketAddrs>(address: A) -> io::Result<SocketLink> {
+        let (stream, _) = TcpListener::bind(address)?.accept()?;
+
+        SocketLink::tcp(stream)
+    }
+
+    pub fn connect_tcp<A: ToSocketAddrs>(address: A) -> io::Result<SocketLink> {
+        SocketLink::tcp(TcpStream::connect(address)?)
+    }
+
+    #[cfg(unix)]
+    pub fn unix(stream: UnixStream) -> io::Result<SocketLink> {
+        stream.set_nonblocking(true)?;
+
+        Ok(SocketLink {
+            stream: Some(Box::new(stream)),
+            error: None,
+            exchange_time: None,
+        })
+    }
+
+    /// Waits for the peer to connect.
+    ///
+    #[cfg(unix)]
+    pub fn listen_unix(path: &Path) -> io::Result<SocketLink> {
+        let (stream, _) = UnixListener::bind(path)?.accept()?;
+
+        SocketLink::unix(stream)
+    }
+
+    #[cfg(unix)]
+    pub fn connect_unix(path: &Path) -> io::Result<SocketLink> {
+        SocketLink::unix(UnixStream::connect(path)?)
+    }
+
+    pub fn connected(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    fn send(&mut self, value: Byte) {
+        if let Some(stream) = &mut self.stream {
+            if let Err(error) = stream.write_all(&[value]) {
+                self.disconnect(error);
+            }
+        }
+    }
+
+    // Returns None if there's no data available, or on disconnection.
+    //
+    fn try_receive(&mut self) -> Option<Byte> {
+        let stream = self.stream.as_mut()?;
+        let mut buffer = [0];
+
+        loop {
+            match stream.read(&mut buffer) {
+                Ok(0) => {
+                    self.disconnect(ErrorKind::UnexpectedEof.into());
+                    return None;
+                }
+                Ok(_) => return Some(buffer[0]),
+                Err(error) if error.kind() == ErrorKind::Interrupted => continue,
+                Err(error) if error.kind() == ErrorKind::WouldBlock => return None,
+                Err(error) => {
+                    self.disconnect(error);
+                    return None;
+                }
+            }
+        }
+    }
+
+    fn disconnect(&mut self, error: io::Error) {
+        let message = format!("Serial link disconnected: {}", error);
+
+        self.error = Some(io::Error::new(error.kind(), message));
+        self.stream = None;
+    }
+}
+
+impl SerialLink for SocketLink {
+    fn exchange(&mut self, sent: Byte) -> Option<Byte> {
+        self.send(sent);
+        self.exchange_time = Some(Instant::now());
+
+        self.receive_answer()
+    }
+
+    fn receive_answer(&mut self) -> Option<Byte> {
+        if let Some(received) = self.try_receive() {
+            self.exchange_time = None;
+            return Some(received);
+        }
+
+        let timed_out = self
+            .exchange_time
+            .is_none_or(|exchange_time| exchange_time.elapsed() >= EXCHANGE_TIMEOUT);
+
+        if self.connected() && timed_out {
+            self.disconnect(ErrorKind::TimedOut.into());
+        }
+
+        if self.connected() {
+            None
+        } else {
+            self.exchange_time = None;
+            Some(0xFF)
+        }
+    }
+
+    fn poll(&mut self, sent: Byte) -> Option<Byte> {
+        let received = self.try_receive()?;
+
+        self.send(sent);
+
+        Some(received)
+    }
+
+    fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+}
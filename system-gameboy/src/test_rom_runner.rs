@@ -0,0 +1,240 @@
+use super::game_boy::GameBoy;
+use super::serial_capture::SerialCapture;
+use interfaces_frontend::{events::EventCode, video::Pixel, IoFrontend};
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+type Byte = u8;
+type Word = u16;
+
+/// Two minutes of emulated time; enough for the longest Blargg tests (e.g. the `cpu_instrs`
+/// combined ROM).
+///
+pub const DEFAULT_MAX_FRAMES: u32 = 2 * 60 * 60;
+
+const ROM_EXTENSIONS: [&str; 2] = ["gb", "gbc"];
+
+// Blargg's tests print the result over serial.
+//
+const SERIAL_PASSED_TEXT: &[Byte] = b"Passed";
+const SERIAL_FAILED_TEXT: &[Byte] = b"Failed";
+
+// Mooneye's tests execute the debug breakpoint, with BC/DE/HL set to the first Fibonacci numbers
+// (3, 5, 8, 13, 21, 34) on success, and to 0x42 on failure.
+//
+const MOONEYE_PASSED_REGISTERS: [Word; 3] = [0x0305, 0x080D, 0x1522];
+const MOONEYE_FAILED_REGISTERS: [Word; 3] = [0x4242, 0x4242, 0x4242];
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum TestRomOutcome {
+    Passed,
+    Failed,
+    TimedOut,
+    Error(String),
+}
+
+impl fmt::Display for TestRomOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TestRomOutcome::Passed => write!(f, "PASSED"),
+            TestRomOutcome::Failed => write!(f, "FAILED"),
+            TestRomOutcome::TimedOut => write!(f, "TIMEOUT"),
+            TestRomOutcome::Error(_) => write!(f, "ERROR"),
+        }
+    }
+}
+
+pub struct TestRomResult {
+    /// Relative to the directory of the run.
+    ///
+    pub name: String,
+    pub outcome: TestRomOutcome,
+    pub frames: u32,
+}
+
+// Headless: the video and audio are discarded, and there's no input.
+//
+struct NullFrontend {}
+
+impl IoFrontend for NullFrontend {
+    fn init(&mut self, _screen_width: u32, _screen_height: u32) {}
+
+    fn update_screen(&mut self, _pixels: &[Pixel], _force_update: bool) {}
+
+    fn read_event(&mut self, _blocking: bool) -> Option<(EventCode, bool)> {
+        None
+    }
+}
+
+/// Runs a test ROM, headless, for up to the given number of frames, or until the result is
+/// detected, either via serial output (Blargg), or via debug breakpoint (mooneye); returns the
+/// outcome, and the frames run.
+///
+/// The debug breakpoints with an unknown registers signature are ignored (the frame is resumed),
+/// since the instruction is also used by regular code (e.g. by the CPU tests).
+///
+/// Emulator panics are reported as errors, so that a ROM can't abort the run of a directory.
+///
+pub fn run_test_rom(game_rom: &[Byte], max_frames: u32) -> (TestRomOutcome, u32) {
+    // The emulator state is discarded on panic, so it can't be observed in a broken state.
+    //
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        run_test_rom_unguarded(game_rom, max_frames)
+    }))
+    .unwrap_or_else(|payload| {
+        let message = if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            String::from("Emulator panic")
+        };
+
+        (TestRomOutcome::Error(message), 0)
+    })
+}
+
+fn run_test_rom_unguarded(game_rom: &[Byte], max_frames: u32) -> (TestRomOutcome, u32) {
+    let mut frontend = NullFrontend {};
+    let mut logger = None;
+
+    let mut game_boy = match GameBoy::new(&mut frontend, game_rom, None, &mut logger) {
+        Ok(game_boy) => game_boy,
+        Err(error) => return (TestRomOutcome::Error(error.to_string()), 0),
+    };
+
+    let serial_capture = SerialCapture::new();
+    let serial_output = serial_capture.buffer();
+
+    game_boy.set_serial_link(Box::new(serial_capture));
+
+    let mut frame = 1;
+
+    while frame <= max_frames {
+        let breakpoint_hit = game_boy.run_frame_until_breakpoint();
+
+        if breakpoint_hit {
+            match game_boy.debug_registers() {
+                MOONEYE_PASSED_REGISTERS => return (TestRomOutcome::Passed, frame),
+                MOONEYE_FAILED_REGISTERS => return (TestRomOutcome::Failed, frame),
+                _ => {}
+            }
+        }
+
+        let serial_output = serial_output.borrow();
+
+        if contains(&serial_output, SERIAL_PASSED_TEXT) {
+            return (TestRomOutcome::Passed, frame);
+        } else if contains(&serial_output, SERIAL_FAILED_TEXT) {
+            return (TestRomOutcome::Failed, frame);
+        }
+
+        if !breakpoint_hit {
+            frame += 1;
+        }
+    }
+
+    (TestRomOutcome::TimedOut, max_frames)
+}
+
+/// Runs all the ROMs (`.gb`/`.gbc`) in the directory, recursively, in path order; the ROMs that
+/// can't be read or loaded, or that panic the emulator, are reported as errors.
+///
+pub fn run_test_rom_directory(directory: &Path, max_frames: u32) -> io::Result<Vec<TestRomResult>> {
+    let mut rom_paths = vec![];
+    find_roms(directory, &mut rom_paths)?;
+    rom_paths.sort();
+
+    let results = rom_paths
+        .iter()
+        .map(|rom_path| {
+            let (outcome, frames) = match fs::read(rom_path) {
+                Ok(game_rom) => run_test_rom(&game_rom, max_frames),
+                Err(error) => (TestRomOutcome::Error(error.to_string()), 0),
+            };
+
+            let name = rom_path
+                .strip_prefix(directory)
+                .unwrap_or(rom_path)
+                .display()
+                .to_string();
+
+            TestRomResult {
+                name,
+                outcome,
+                frames,
+            }
+        })
+        .collect();
+
+    Ok(results)
+}
+
+/// Table with a line per ROM (outcome, frames, name and, for errors, the cause), followed by the
+/// totals.
+///
+pub fn summary_table(results: &[TestRomResult]) -> String {
+    let mut table = format!("{:<8} {:>7}  {}\n", "RESULT", "FRAMES", "ROM");
+
+    for result in results {
+        table += &format!(
+            "{:<8} {:>7}  {}",
+            result.outcome.to_string(),
+            result.frames,
+            result.name
+        );
+
+        if let TestRomOutcome::Error(cause) = &result.outcome {
+            table += &format!(" ({})", cause);
+        }
+
+        table += "\n";
+    }
+
+    let count = |predicate: fn(&TestRomOutcome) -> bool| {
+        results
+            .iter()
+            .filter(|result| predicate(&result.outcome))
+            .count()
+    };
+
+    table += &format!(
+        "\n{} passed, {} failed, {} timed out, {} errors\n",
+        count(|outcome| *outcome == TestRomOutcome::Passed),
+        count(|outcome| *outcome == TestRomOutcome::Failed),
+        count(|outcome| *outcome == TestRomOutcome::TimedOut),
+        count(|outcome| matches!(outcome, TestRomOutcome::Error(_))),
+    );
+
+    table
+}
+
+fn find_roms(directory: &Path, rom_paths: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(directory)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            find_roms(&path, rom_paths)?;
+        } else if is_rom(&path) {
+            rom_paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn is_rom(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| ROM_EXTENSIONS.contains(&extension.to_lowercase().as_str()))
+}
+
+fn contains(haystack: &[Byte], needle: &[Byte]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
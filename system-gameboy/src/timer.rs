@@ -0,0 +1,120 @@
+use super::memory_bus::TIMER_INTERRUPT;
+
+type Byte = u8;
+type Word = u16;
+
+const MCYCLE_TICKS: u8 = 4;
+
+// Bit of the system counter feeding TIMA, for each TAC clock select value (4096, 262144, 65536 and
+// 16384 Hz).
+//
+const TIMA_COUNTER_BITS: [Word; 4] = [1 << 9, 1 << 3, 1 << 5, 1 << 7];
+
+const TAC_ENABLE_BIT: Byte = 0b0000_0100;
+const TAC_CLOCK_SELECT_MASK: Byte = 0b0000_0011;
+const TAC_UNUSED_BITS: Byte = 0b1111_1000;
+
+/// DIV/TIMA/TMA/TAC timer.
+///
+/// DIV is the upper byte of a 16 bit counter, incremented on each clock tick; TIMA is incremented on
+/// the falling edge of the counter bit selected by TAC (ANDed with the enable bit), so that writes
+/// to DIV and TAC can increment it, like on the real hardware.
+/// On overflow, TIMA is reloaded with TMA, and the interrupt requested, one M-cycle later; a write to
+/// TIMA during that M-cycle cancels the reload.
+///
+pub(crate) struct Timer {
+    system_counter: Word,
+
+    TIMA: Byte,
+    TMA: Byte,
+    TAC: Byte,
+
+    reload_pending: bool,
+}
+
+impl Timer {
+    pub fn new(system_counter: Word) -> Timer {
+        Timer {
+            system_counter,
+
+            TIMA: 0,
+            TMA: 0,
+            TAC: 0,
+
+            reload_pending: false,
+        }
+    }
+
+    pub fn DIV(&self) -> Byte {
+        (self.system_counter >> 8) as Byte
+    }
+
+    pub fn TIMA(&self) -> Byte {
+        self.TIMA
+    }
+
+    pub fn TMA(&self) -> Byte {
+        self.TMA
+    }
+
+    pub fn TAC(&self) -> Byte {
+        self.TAC | TAC_UNUSED_BITS
+    }
+
+    pub fn write_DIV(&mut self) {
+        let previous_input = self.timer_input();
+        self.system_counter = 0;
+        self.increment_on_falling_edge(previous_input);
+    }
+
+    pub fn write_TIMA(&mut self, value: Byte) {
+        self.TIMA = value;
+        self.reload_pending = false;
+    }
+
+    pub fn write_TMA(&mut self, value: Byte) {
+        self.TMA = value;
+    }
+
+    pub fn write_TAC(&mut self, value: Byte) {
+        let previous_input = self.timer_input();
+        self.TAC = value & !TAC_UNUSED_BITS;
+        self.increment_on_falling_edge(previous_input);
+    }
+
+    /// Advances the counter by the given clock ticks (a multiple of an M-cycle); returns the
+    /// interrupts requested.
+    ///
+    pub fn tick(&mut self, cycles: u8) -> Byte {
+        let mut interrupts = 0;
+
+        for _ in 0..(cycles / MCYCLE_TICKS) {
+            if self.reload_pending {
+                self.TIMA = self.TMA;
+                self.reload_pending = false;
+                interrupts |= TIMER_INTERRUPT;
+            }
+
+            let previous_input = self.timer_input();
+            self.system_counter = self.system_counter.wrapping_add(MCYCLE_TICKS as Word);
+            self.increment_on_falling_edge(previous_input);
+        }
+
+        interrupts
+    }
+
+    fn timer_input(&self) -> bool {
+        let counter_bit = TIMA_COUNTER_BITS[(self.TAC & TAC_CLOCK_SELECT_MASK) as usize];
+
+        self.TAC & TAC_ENABLE_BIT != 0 && self.system_counter & counter_bit != 0
+    }
+
+    fn increment_on_falling_edge(&mut self, previous_input: bool) {
+        if previous_input && !self.timer_input() {
+            let (new_TIMA, overflow) = self.TIMA.overflowing_add(1);
+
+            self.TIMA = new_TIMA;
+            self.reload_pending = overflow;
+        }
+    }
+}
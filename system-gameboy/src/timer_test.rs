@@ -0,0 +1,167 @@
+use super::memory_bus::TIMER_INTERRUPT;
+use super::timer::Timer;
+
+type Byte = u8;
+
+// Enabled, 262144 Hz: TIMA is fed by the bit 3 of the system counter, so it's incremented every 16
+// clock ticks.
+//
+const TAC_ENABLED_BIT_3: Byte = 0b0000_0101;
+const TAC_DISABLED_BIT_3: Byte = 0b0000_0001;
+const TAC_ENABLED_BIT_9: Byte = 0b0000_0100;
+
+// The system counter has the bit 3 set, so that the timer input is high.
+//
+fn timer_with_input_high() -> Timer {
+    let mut timer = Timer::new(0b0000_1000);
+    timer.write_TAC(TAC_ENABLED_BIT_3);
+    timer
+}
+
+// Overflows TIMA via DIV write; the input must be high.
+//
+fn overflow(timer: &mut Timer) {
+    timer.write_TIMA(0xFF);
+    timer.write_TMA(0x42);
+    timer.write_DIV();
+}
+
+#[test]
+fn div_is_the_upper_byte_of_the_system_counter() {
+    let mut timer = Timer::new(0xABCC);
+
+    assert_eq!(timer.DIV(), 0xAB);
+
+    timer.tick(0x34);
+
+    assert_eq!(timer.DIV(), 0xAC);
+
+    timer.write_DIV();
+
+    assert_eq!(timer.DIV(), 0x00);
+}
+
+#[test]
+fn tac_reads_the_unused_bits_as_set() {
+    let mut timer = Timer::new(0);
+    timer.write_TAC(0xFF);
+
+    assert_eq!(timer.TAC(), 0xFF);
+
+    timer.write_TAC(TAC_ENABLED_BIT_3);
+
+    assert_eq!(timer.TAC(), 0b1111_1101);
+}
+
+#[test]
+fn tima_is_incremented_at_the_selected_frequency() {
+    let mut timer = Timer::new(0);
+    timer.write_TAC(TAC_ENABLED_BIT_3);
+
+    timer.tick(12);
+    assert_eq!(timer.TIMA(), 0);
+
+    timer.tick(4);
+    assert_eq!(timer.TIMA(), 1);
+
+    for _ in 0..10 {
+        timer.tick(16);
+    }
+
+    assert_eq!(timer.TIMA(), 11);
+}
+
+#[test]
+fn tima_is_not_incremented_when_disabled() {
+    let mut timer = Timer::new(0);
+    timer.write_TAC(TAC_DISABLED_BIT_3);
+
+    for _ in 0..10 {
+        timer.tick(16);
+    }
+
+    assert_eq!(timer.TIMA(), 0);
+}
+
+#[test]
+fn div_write_increments_tima_on_the_falling_edge() {
+    let mut timer = timer_with_input_high();
+
+    assert_eq!(timer.TIMA(), 0);
+
+    timer.write_DIV();
+
+    assert_eq!(timer.TIMA(), 1);
+
+    // The input is now low, so a further write has no effect.
+    //
+    timer.write_DIV();
+
+    assert_eq!(timer.TIMA(), 1);
+}
+
+#[test]
+fn div_write_does_not_increment_tima_when_the_input_is_low() {
+    let mut timer = Timer::new(0b0001_0000);
+    timer.write_TAC(TAC_ENABLED_BIT_3);
+
+    timer.write_DIV();
+
+    assert_eq!(timer.TIMA(), 0);
+}
+
+#[test]
+fn tac_write_increments_tima_on_the_falling_edge() {
+    let mut timer = timer_with_input_high();
+    timer.write_TAC(TAC_DISABLED_BIT_3);
+
+    assert_eq!(timer.TIMA(), 1);
+
+    let mut timer = timer_with_input_high();
+    timer.write_TAC(TAC_ENABLED_BIT_9);
+
+    assert_eq!(timer.TIMA(), 1);
+
+    // The input stays high.
+    //
+    let mut timer = timer_with_input_high();
+    timer.write_TAC(TAC_ENABLED_BIT_3);
+
+    assert_eq!(timer.TIMA(), 0);
+}
+
+#[test]
+fn overflow_reloads_tima_and_requests_the_interrupt_one_mcycle_later() {
+    let mut timer = timer_with_input_high();
+
+    overflow(&mut timer);
+
+    assert_eq!(timer.TIMA(), 0x00);
+
+    assert_eq!(timer.tick(4), TIMER_INTERRUPT);
+    assert_eq!(timer.TIMA(), 0x42);
+
+    assert_eq!(timer.tick(4), 0);
+}
+
+#[test]
+fn tima_write_cancels_the_pending_reload() {
+    let mut timer = timer_with_input_high();
+
+    overflow(&mut timer);
+    timer.write_TIMA(0x10);
+
+    assert_eq!(timer.tick(4), 0);
+    assert_eq!(timer.TIMA(), 0x10);
+}
+
+#[test]
+fn tma_write_before_the_reload_is_used() {
+    let mut timer = timer_with_input_high();
+
+    overflow(&mut timer);
+    timer.write_TMA(0x24);
+
+    assert_eq!(timer.tick(4), TIMER_INTERRUPT);
+    assert_eq!(timer.TIMA(), 0x24);
+}
@@ -0,0 +1,209 @@
+// Boot ROM execution vs. skipping it: a stand-in boot ROM, which sets up the same state as the one
+// the emulator presets when skipping, must lead a test ROM to the same results.
+//
+// The test ROM dumps the registers and some I/O registers (the timing independent ones) to the
+// battery-backed RAM, then, with the LCD re-enabled (which synchronizes the PPU), draws a tile on
+// the whole screen; both the save data and the frame are compared.
+
+mod common;
+
+use common::{build_rom, CapturingStorage, HeadlessFrontend};
+use interfaces_frontend::video::Pixel;
+use system_gameboy::GameBoy;
+
+type Byte = u8;
+type Word = u16;
+
+const FRAMES_COUNT: u32 = 10;
+
+const DMG_BOOT_ROM_SIZE: usize = 0x100;
+const CGB_BOOT_ROM_SIZE: usize = 0x900;
+
+// The CGB boot ROM code is placed in the area after the cartridge header, so that its mapping is
+// exercised.
+//
+const CGB_BOOT_ROM_CODE_ADDRESS: Word = 0x0200;
+const BOOT_ROM_EXIT_ADDRESS: Word = 0x00FE;
+
+// Values left by the boot ROMs: AF, BC, DE, HL.
+//
+const DMG_POST_BOOT_REGISTERS: [Word; 4] = [0x01B0, 0x0013, 0x00D8, 0x014D];
+const CGB_POST_BOOT_REGISTERS: [Word; 4] = [0x1180, 0x0000, 0xFF56, 0x000D];
+
+// Register address, value.
+//
+const POST_BOOT_IO_REGISTERS: [(Byte, Byte); 9] = [
+    (0x26, 0x80), // NR52 (powered on first)
+    (0x10, 0x80), // NR10
+    (0x11, 0xBF), // NR11
+    (0x12, 0xF3), // NR12
+    (0x24, 0x77), // NR50
+    (0x25, 0xF3), // NR51
+    (0x47, 0xFC), // BGP
+    (0x0F, 0x01), // IF
+    (0x40, 0x91), // LCDC
+];
+
+const DUMPED_IO_REGISTERS: [Byte; 12] = [
+    0x00, 0x07, 0x0F, 0x10, 0x11, 0x12, 0x24, 0x25, 0x26, 0x40, 0x42, 0x47,
+];
+
+// Dump layout: SP, then AF, BC, DE, HL (as pushed: low byte first), then the I/O registers.
+//
+const DUMP_ADDRESS: Word = 0xC000;
+const DUMP_SIZE: Byte = 0x20;
+
+fn lo(value: Word) -> Byte {
+    value as Byte
+}
+
+fn hi(value: Word) -> Byte {
+    (value >> 8) as Byte
+}
+
+// Sets the I/O registers, then the CPU registers; F is set via the stack, whose content is then
+// cleared. The last instruction (writing A, which is nonzero, to BOOT) ends at the entry point.
+//
+fn stand_in_boot_rom(size: usize, registers: [Word; 4]) -> Vec<Byte> {
+    let mut boot_rom = vec![0x00; size];
+
+    let code_address = if size == CGB_BOOT_ROM_SIZE {
+        CGB_BOOT_ROM_CODE_ADDRESS
+    } else {
+        0
+    };
+
+    let mut code = vec![];
+
+    if code_address != 0 {
+        code.extend_from_slice(&[0xC3, lo(code_address), hi(code_address)]); // JP nn
+        boot_rom[..code.len()].copy_from_slice(&code);
+        code.clear();
+    }
+
+    code.extend_from_slice(&[0x31, 0xFE, 0xFF]); // LD SP, 0xFFFE
+
+    for &(register, value) in POST_BOOT_IO_REGISTERS.iter() {
+        code.extend_from_slice(&[0x3E, value, 0xE0, register]); // LD A, n; LDH (n), A
+    }
+
+    let [af, bc, de, hl] = registers;
+
+    let instructions: &[&[Byte]] = &[
+        &[0x01, lo(af), hi(af)],                                       // LD BC, nn
+        &[0xC5],                                                       // PUSH BC
+        &[0xF1],                                                       // POP AF
+        &[0x21, 0xFD, 0xFF],                                           // LD HL, 0xFFFD
+        &[0x36, 0x00],                                                 // LD (HL), 0
+        &[0x2E, 0xFC],                                                 // LD L, 0xFC
+        &[0x36, 0x00],                                                 // LD (HL), 0
+        &[0x01, lo(bc), hi(bc)],                                       // LD BC, nn
+        &[0x11, lo(de), hi(de)],                                       // LD DE, nn
+        &[0x21, lo(hl), hi(hl)],                                       // LD HL, nn
+        &[0xC3, lo(BOOT_ROM_EXIT_ADDRESS), hi(BOOT_ROM_EXIT_ADDRESS)], // JP nn
+    ];
+
+    code.extend(instructions.concat());
+
+    let code_address = code_address as usize;
+    boot_rom[code_address..code_address + code.len()].copy_from_slice(&code);
+
+    let exit_address = BOOT_ROM_EXIT_ADDRESS as usize;
+    boot_rom[exit_address..exit_address + 2].copy_from_slice(&[0xE0, 0x50]); // LDH (0x50), A
+
+    boot_rom
+}
+
+fn test_rom(cgb_support: bool) -> Vec<Byte> {
+    let save_registers_instructions: &[&[Byte]] = &[
+        &[0x08, lo(DUMP_ADDRESS), hi(DUMP_ADDRESS)], // LD (nn), SP
+        &[0x31, lo(DUMP_ADDRESS + 0x10), hi(DUMP_ADDRESS + 0x10)], // LD SP, nn
+        &[0xE5, 0xD5, 0xC5, 0xF5],                   // PUSH HL; PUSH DE; PUSH BC; PUSH AF
+        &[0x31, 0xFE, 0xFF],                         // LD SP, 0xFFFE
+    ];
+
+    let mut code = save_registers_instructions.concat();
+
+    // LDH A, (n); LD (nn), A
+    //
+    for (i, &register) in DUMPED_IO_REGISTERS.iter().enumerate() {
+        let address = DUMP_ADDRESS + 0x10 + i as Word;
+
+        code.extend_from_slice(&[0xF0, register, 0xEA, lo(address), hi(address)]);
+    }
+
+    let copy_and_draw_instructions: &[&[Byte]] = &[
+        &[0x3E, 0x0A],                               // LD A, 0x0A
+        &[0xEA, 0x00, 0x00],                         // LD (0x0000), A (RAM enable)
+        &[0x21, lo(DUMP_ADDRESS), hi(DUMP_ADDRESS)], // LD HL, nn
+        &[0x11, 0x00, 0xA0],                         // LD DE, 0xA000
+        &[0x06, DUMP_SIZE],                          // LD B, n
+        &[0x2A, 0x12, 0x13, 0x05, 0x20, 0xFA], // LD A, (HL+); LD (DE), A; INC DE; DEC B; JR NZ
+        &[0xF0, 0x44, 0xFE, 0x90, 0x20, 0xFA], // LDH A, (LY); CP 144; JR NZ
+        &[0xAF, 0xE0, 0x40],                   // XOR A; LDH (LCDC), A
+        &[0x21, 0x00, 0x80],                   // LD HL, 0x8000
+        &[0x06, 0x10],                         // LD B, 16
+        &[0x78, 0x22, 0x05, 0x20, 0xFB],       // LD A, B; LD (HL+), A; DEC B; JR NZ
+        &[0x3E, 0x91, 0xE0, 0x40],             // LD A, 0x91; LDH (LCDC), A
+        &[0x18, 0xFE],                         // JR -2
+    ];
+
+    code.extend(copy_and_draw_instructions.concat());
+
+    build_rom(&code, cgb_support)
+}
+
+// Returns the save data and the last frame.
+//
+fn run(game_rom: &[Byte], boot_rom: Option<&[Byte]>) -> (Vec<Byte>, Vec<Pixel>) {
+    let save_storage = CapturingStorage::new();
+    let saved_data = save_storage.saved_data();
+
+    let mut frontend = HeadlessFrontend::new();
+    let mut logger = None;
+
+    let mut game_boy = GameBoy::new(&mut frontend, game_rom, boot_rom, &mut logger).unwrap();
+
+    game_boy.set_save_storage(Box::new(save_storage));
+
+    game_boy.run_frames(FRAMES_COUNT);
+
+    let save_data = saved_data.borrow_mut().take().unwrap();
+
+    (save_data, frontend.frame)
+}
+
+fn assert_same_state(cgb_support: bool, boot_rom_size: usize, registers: [Word; 4]) {
+    let game_rom = test_rom(cgb_support);
+    let boot_rom = stand_in_boot_rom(boot_rom_size, registers);
+
+    let (skipped_save_data, skipped_frame) = run(&game_rom, None);
+    let (booted_save_data, booted_frame) = run(&game_rom, Some(&boot_rom));
+
+    // Sanity check of the dump: SP, then the registers.
+    //
+    let mut expected_registers = vec![0xFE, 0xFF];
+
+    for &register in registers.iter() {
+        expected_registers.extend_from_slice(&[lo(register), hi(register)]);
+    }
+
+    assert_eq!(skipped_save_data[..2], expected_registers[..2]);
+    assert_eq!(skipped_save_data[8..16], expected_registers[2..]);
+
+    assert_eq!(
+        skipped_save_data[..DUMP_SIZE as usize],
+        booted_save_data[..DUMP_SIZE as usize]
+    );
+    assert!(skipped_frame == booted_frame, "The frames differ");
+}
+
+#[test]
+fn dmg_boot_rom_leads_to_the_post_boot_state() {
+    assert_same_state(false, DMG_BOOT_ROM_SIZE, DMG_POST_BOOT_REGISTERS);
+}
+
+#[test]
+fn cgb_boot_rom_leads_to_the_post_boot_state() {
+    assert_same_state(true, CGB_BOOT_ROM_SIZE, CGB_POST_BOOT_REGISTERS);
+}
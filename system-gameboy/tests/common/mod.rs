@@ -0,0 +1,161 @@
+// Helpers shared by the integration tests; each test uses a subset of them.
+
+#![allow(dead_code)]
+
+use interfaces_frontend::events::EventCode;
+use interfaces_frontend::logging::Logger;
+use interfaces_frontend::video::Pixel;
+use interfaces_frontend::IoFrontend;
+use system_gameboy::SaveStorage;
+
+use std::cell::RefCell;
+use std::io;
+use std::rc::Rc;
+
+type Byte = u8;
+
+pub const SCREEN_WIDTH: usize = 160;
+pub const SCREEN_HEIGHT: usize = 144;
+
+const ROM_SIZE: usize = 0x8000;
+const CODE_ADDRESS: usize = 0x150;
+const CARTRIDGE_TYPE_MBC1_RAM_BATTERY: Byte = 0x03;
+const RAM_SIZE_8_KIB: Byte = 0x02;
+const CGB_SUPPORT_FLAG: Byte = 0x80;
+
+// Stores the last frame; the audio is discarded, and there's no input.
+//
+pub struct HeadlessFrontend {
+    pub frame: Vec<Pixel>,
+}
+
+impl HeadlessFrontend {
+    pub fn new() -> HeadlessFrontend {
+        HeadlessFrontend { frame: vec![] }
+    }
+}
+
+impl IoFrontend for HeadlessFrontend {
+    fn init(&mut self, screen_width: u32, screen_height: u32) {
+        assert_eq!(
+            (screen_width, screen_height),
+            (SCREEN_WIDTH as u32, SCREEN_HEIGHT as u32)
+        );
+    }
+
+    fn update_screen(&mut self, pixels: &[Pixel], _force_update: bool) {
+        self.frame = pixels.to_vec();
+    }
+
+    fn read_event(&mut self, _blocking: bool) -> Option<(EventCode, bool)> {
+        None
+    }
+}
+
+// Stores the last data saved, and counts the saves (including the failed ones); there's no stored
+// data. A failing storage fails all the saves.
+//
+pub struct CapturingStorage {
+    saved_data: Rc<RefCell<Option<Vec<Byte>>>>,
+    saves_count: Rc<RefCell<u32>>,
+    failing: bool,
+}
+
+impl CapturingStorage {
+    pub fn new() -> CapturingStorage {
+        CapturingStorage {
+            saved_data: Rc::new(RefCell::new(None)),
+            saves_count: Rc::new(RefCell::new(0)),
+            failing: false,
+        }
+    }
+
+    pub fn failing() -> CapturingStorage {
+        CapturingStorage {
+            failing: true,
+            ..CapturingStorage::new()
+        }
+    }
+
+    pub fn saved_data(&self) -> Rc<RefCell<Option<Vec<Byte>>>> {
+        Rc::clone(&self.saved_data)
+    }
+
+    pub fn saves_count(&self) -> Rc<RefCell<u32>> {
+        Rc::clone(&self.saves_count)
+    }
+}
+
+impl SaveStorage for CapturingStorage {
+    fn load(&mut self) -> Option<Vec<Byte>> {
+        None
+    }
+
+    fn save(&mut self, data: &[Byte]) -> io::Result<()> {
+        *self.saves_count.borrow_mut() += 1;
+
+        if self.failing {
+            return Err(io::Error::other("storage unavailable"));
+        }
+
+        *self.saved_data.borrow_mut() = Some(data.to_vec());
+        Ok(())
+    }
+}
+
+// Stores the messages logged, excluding the instruction traces.
+//
+pub struct CapturingLogger {
+    messages: Rc<RefCell<Vec<String>>>,
+}
+
+impl CapturingLogger {
+    pub fn new() -> CapturingLogger {
+        CapturingLogger {
+            messages: Rc::new(RefCell::new(vec![])),
+        }
+    }
+
+    pub fn messages(&self) -> Rc<RefCell<Vec<String>>> {
+        Rc::clone(&self.messages)
+    }
+}
+
+impl Logger for CapturingLogger {
+    fn log(&mut self, message: String) {
+        if !message.starts_with('[') {
+            self.messages.borrow_mut().push(message);
+        }
+    }
+}
+
+// Builds a 32 KiB MBC1 ROM, with 8 KiB of battery-backed RAM, and valid header; the entry point
+// jumps to the code, which is placed after the header.
+//
+pub fn build_rom(code: &[Byte], cgb_support: bool) -> Vec<Byte> {
+    let mut rom = vec![0x00; ROM_SIZE];
+
+    rom[0x100..0x104].copy_from_slice(&[0x00, 0xC3, 0x50, 0x01]); // NOP; JP 0x0150
+    rom[CODE_ADDRESS..CODE_ADDRESS + code.len()].copy_from_slice(code);
+
+    rom[0x134..0x138].copy_from_slice(b"TEST");
+
+    if cgb_support {
+        rom[0x143] = CGB_SUPPORT_FLAG;
+    }
+
+    rom[0x147] = CARTRIDGE_TYPE_MBC1_RAM_BATTERY;
+    rom[0x149] = RAM_SIZE_8_KIB;
+
+    rom[0x14D] = rom[0x134..0x14D].iter().fold(0_u8, |checksum, &byte| {
+        checksum.wrapping_sub(byte).wrapping_sub(1)
+    });
+
+    let global_checksum = rom
+        .iter()
+        .fold(0_u16, |checksum, &byte| checksum.wrapping_add(byte as u16));
+
+    rom[0x14E..0x150].copy_from_slice(&global_checksum.to_be_bytes());
+
+    rom
+}
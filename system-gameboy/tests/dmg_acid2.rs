@@ -0,0 +1,89 @@
+// dmg-acid2 reference image comparison; the ROM and the reference image are not bundled, so the
+// directory containing them (`dmg-acid2.gb` and `reference-dmg.png`, as distributed) is passed via
+// `DMG_ACID2_DIR`, and the test is ignored by default:
+//
+//     DMG_ACID2_DIR=/path/to/dmg-acid2 cargo test -p system-gameboy --test dmg_acid2 -- --ignored
+//
+// The test ROM completes its drawing in a few frames, then loops forever.
+
+mod common;
+
+use common::{HeadlessFrontend, SCREEN_HEIGHT, SCREEN_WIDTH};
+use interfaces_frontend::video::Pixel;
+use system_gameboy::GameBoy;
+
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+const TEST_DIRECTORY_VARIABLE: &str = "DMG_ACID2_DIR";
+const ROM_FILENAME: &str = "dmg-acid2.gb";
+const REFERENCE_IMAGE_FILENAME: &str = "reference-dmg.png";
+const FRAMES_COUNT: u32 = 60;
+
+fn read_reference_image(path: &Path) -> Vec<Pixel> {
+    let file =
+        File::open(path).unwrap_or_else(|error| panic!("Error reading {:?}: {}", path, error));
+
+    let mut decoder = png::Decoder::new(file);
+    decoder.set_transformations(png::Transformations::EXPAND | png::Transformations::STRIP_16);
+
+    let (info, mut reader) = decoder.read_info().unwrap();
+
+    assert_eq!(
+        (info.width as usize, info.height as usize),
+        (SCREEN_WIDTH, SCREEN_HEIGHT)
+    );
+
+    let mut image_data = vec![0; info.buffer_size()];
+    reader.next_frame(&mut image_data).unwrap();
+
+    let (channels, _) = reader.output_color_type();
+
+    image_data
+        .chunks(channels.samples())
+        .map(|samples| match channels {
+            png::ColorType::Grayscale | png::ColorType::GrayscaleAlpha => {
+                Pixel(samples[0], samples[0], samples[0])
+            }
+            _ => Pixel(samples[0], samples[1], samples[2]),
+        })
+        .collect()
+}
+
+#[test]
+#[ignore]
+fn dmg_acid2() {
+    let directory = std::env::var_os(TEST_DIRECTORY_VARIABLE)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| panic!("`{}` not set", TEST_DIRECTORY_VARIABLE));
+
+    let rom_path = directory.join(ROM_FILENAME);
+    let game_rom = fs::read(&rom_path)
+        .unwrap_or_else(|error| panic!("Error reading {:?}: {}", rom_path, error));
+
+    let reference_image = read_reference_image(&directory.join(REFERENCE_IMAGE_FILENAME));
+
+    let mut frontend = HeadlessFrontend::new();
+    let mut logger = None;
+
+    GameBoy::new(&mut frontend, &game_rom, None, &mut logger)
+        .unwrap_or_else(|error| panic!("Error loading {:?}: {}", rom_path, error))
+        .run_frames(FRAMES_COUNT);
+
+    let mismatches = frontend
+        .frame
+        .iter()
+        .zip(reference_image.iter())
+        .enumerate()
+        .filter(|(_, (actual, expected))| actual != expected)
+        .map(|(i, _)| (i % SCREEN_WIDTH, i / SCREEN_WIDTH))
+        .collect::<Vec<(usize, usize)>>();
+
+    if !mismatches.is_empty() {
+        panic!(
+            "{} pixels differ from the reference image; first (x, y): {:?}",
+            mismatches.len(),
+            mismatches[0]
+        );
+    }
+}
@@ -0,0 +1,111 @@
+// Flushing of the battery-backed RAM: it's saved once per second (60 frames) when modified, and
+// always when the emulation ends; failed saves are logged, and retried on the next flush.
+
+mod common;
+
+use common::{build_rom, CapturingLogger, CapturingStorage, HeadlessFrontend};
+use interfaces_frontend::logging::Logger;
+use system_gameboy::GameBoy;
+
+type Byte = u8;
+
+const ENABLE_RAM: &[Byte] = &[0x3E, 0x0A, 0xEA, 0x00, 0x00]; // LD A, 0x0A; LD (0x0000), A
+
+// Writes the RAM once, then loops.
+//
+const WRITE_RAM_ONCE: &[Byte] = &[
+    0x3E, 0x42, // LD A, 0x42
+    0xEA, 0x00, 0xA0, // LD (0xA000), A
+    0x18, 0xFE, // JR -2
+];
+
+// Writes the RAM continuously.
+//
+const WRITE_RAM_CONTINUOUSLY: &[Byte] = &[
+    0xEA, 0x00, 0xA0, // LD (0xA000), A
+    0x3C, // INC A
+    0x18, 0xFA, // JR -6
+];
+
+const IDLE: &[Byte] = &[0x18, 0xFE]; // JR -2
+
+// Returns the number of saves, the last data saved, and the messages logged.
+//
+fn run(
+    code: &[Byte],
+    frames: u32,
+    save_storage: CapturingStorage,
+) -> (u32, Option<Vec<Byte>>, Vec<String>) {
+    let saves_count = save_storage.saves_count();
+    let saved_data = save_storage.saved_data();
+
+    let logger = CapturingLogger::new();
+    let messages = logger.messages();
+
+    let mut frontend = HeadlessFrontend::new();
+    let mut logger: Option<Box<dyn Logger>> = Some(Box::new(logger));
+
+    let game_rom = build_rom(&[ENABLE_RAM, code].concat(), false);
+
+    let mut game_boy = GameBoy::new(&mut frontend, &game_rom, None, &mut logger).unwrap();
+
+    game_boy.set_save_storage(Box::new(save_storage));
+
+    game_boy.run_frames(frames);
+
+    let saves_count = *saves_count.borrow();
+    let saved_data = saved_data.borrow_mut().take();
+    let messages = messages.borrow().clone();
+
+    (saves_count, saved_data, messages)
+}
+
+#[test]
+fn unmodified_ram_is_saved_only_at_the_end() {
+    let (saves_count, _, _) = run(IDLE, 130, CapturingStorage::new());
+
+    assert_eq!(saves_count, 1);
+}
+
+#[test]
+fn modified_ram_is_saved_once_per_flush_interval() {
+    let (saves_count, saved_data, _) = run(WRITE_RAM_ONCE, 130, CapturingStorage::new());
+
+    // After 60 frames, then at the end.
+    //
+    assert_eq!(saves_count, 2);
+    assert_eq!(saved_data.unwrap()[0], 0x42);
+
+    let (saves_count, _, _) = run(WRITE_RAM_CONTINUOUSLY, 130, CapturingStorage::new());
+
+    // After 60 and 120 frames, then at the end.
+    //
+    assert_eq!(saves_count, 3);
+}
+
+#[test]
+fn ram_is_saved_at_the_end_before_the_flush_interval() {
+    let (saves_count, saved_data, _) = run(WRITE_RAM_ONCE, 10, CapturingStorage::new());
+
+    assert_eq!(saves_count, 1);
+    assert_eq!(saved_data.unwrap()[0], 0x42);
+}
+
+#[test]
+fn failed_saves_are_logged_and_retried() {
+    let (saves_count, _, messages) = run(WRITE_RAM_ONCE, 130, CapturingStorage::failing());
+
+    // The RAM is still considered modified after a failure, so it's saved again at 120 frames.
+    //
+    assert_eq!(saves_count, 3);
+
+    let error_message = "Error saving the cartridge data: storage unavailable".to_string();
+
+    assert_eq!(
+        messages
+            .iter()
+            .filter(|message| **message == error_message)
+            .count(),
+        3
+    );
+}
@@ -0,0 +1,230 @@
+// Serial port transfers, through the capture and the socket links.
+
+mod common;
+
+use common::{build_rom, CapturingLogger, CapturingStorage, HeadlessFrontend};
+use interfaces_frontend::logging::Logger;
+use system_gameboy::{GameBoy, SerialCapture, SocketLink};
+
+use std::fs;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::Path;
+use std::thread;
+
+type Byte = u8;
+
+const MAX_FRAMES_COUNT: u32 = 600;
+
+const SC_INTERNAL_CLOCK_TRANSFER: Byte = 0x81;
+const SC_EXTERNAL_CLOCK_TRANSFER: Byte = 0x80;
+
+// Transfers a byte, waits for the transfer to complete, and leaves the received byte in A.
+//
+fn transfer_instructions(value: Byte, sc_value: Byte) -> Vec<Byte> {
+    let instructions: &[&[Byte]] = &[
+        &[0x3E, value, 0xE0, 0x01],            // LD A, n; LDH (SB), A
+        &[0x3E, sc_value, 0xE0, 0x02],         // LD A, n; LDH (SC), A
+        &[0xF0, 0x02, 0xCB, 0x7F, 0x20, 0xFA], // LDH A, (SC); BIT 7, A; JR NZ
+        &[0xF0, 0x01],                         // LDH A, (SB)
+    ];
+
+    instructions.concat()
+}
+
+// Transfers a byte, and stores the received one at the start of the cartridge RAM.
+//
+fn exchange_rom(value: Byte, sc_value: Byte) -> Vec<Byte> {
+    let mut code = vec![0x3E, 0x0A, 0xEA, 0x00, 0x00]; // LD A, 0x0A; LD (0x0000), A (RAM enable)
+
+    code.extend(transfer_instructions(value, sc_value));
+    code.extend_from_slice(&[0xEA, 0x00, 0xA0]); // LD (0xA000), A
+    code.extend_from_slice(&[0x18, 0xFE]); // JR -2
+
+    build_rom(&code, false)
+}
+
+// Runs until the received byte is stored (the test values are nonzero).
+//
+fn run_exchange(game_rom: &[Byte], link: SocketLink) -> Byte {
+    let save_storage = CapturingStorage::new();
+    let saved_data = save_storage.saved_data();
+
+    let mut frontend = HeadlessFrontend::new();
+    let mut logger = None;
+
+    let mut game_boy = GameBoy::new(&mut frontend, game_rom, None, &mut logger).unwrap();
+
+    game_boy.set_save_storage(Box::new(save_storage));
+    game_boy.set_serial_link(Box::new(link));
+
+    for _ in 0..MAX_FRAMES_COUNT {
+        game_boy.run_frames(1);
+
+        let received = saved_data.borrow().as_ref().unwrap()[0];
+
+        if received != 0 {
+            return received;
+        }
+    }
+
+    panic!("The transfer didn't complete");
+}
+
+#[test]
+fn serial_capture_stores_the_bytes_sent() {
+    let mut code = vec![];
+
+    for &value in b"Passed\n" {
+        code.extend(transfer_instructions(value, SC_INTERNAL_CLOCK_TRANSFER));
+    }
+
+    code.extend_from_slice(&[0x18, 0xFE]); // JR -2
+
+    let game_rom = build_rom(&code, false);
+
+    let capture_path = std::env::temp_dir().join(format!(
+        "system-gameboy-serial-capture-{}.txt",
+        std::process::id()
+    ));
+
+    let serial_capture = SerialCapture::with_file(&capture_path).unwrap();
+    let buffer = serial_capture.buffer();
+
+    let mut frontend = HeadlessFrontend::new();
+    let mut logger = None;
+
+    let mut game_boy = GameBoy::new(&mut frontend, &game_rom, None, &mut logger).unwrap();
+
+    game_boy.set_serial_link(Box::new(serial_capture));
+    game_boy.run_frames(10);
+
+    drop(game_boy);
+
+    let file_content = fs::read(&capture_path).unwrap();
+    fs::remove_file(&capture_path).unwrap();
+
+    assert_eq!(buffer.borrow().as_slice(), b"Passed\n");
+    assert_eq!(file_content, b"Passed\n");
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn serial_capture_logs_the_file_errors() {
+    let mut code = transfer_instructions(b'P', SC_INTERNAL_CLOCK_TRANSFER);
+    code.extend_from_slice(&[0x18, 0xFE]); // JR -2
+
+    let game_rom = build_rom(&code, false);
+
+    // Writes to /dev/full always fail, with ENOSPC.
+    //
+    let serial_capture = SerialCapture::with_file(Path::new("/dev/full")).unwrap();
+    let buffer = serial_capture.buffer();
+
+    let capturing_logger = CapturingLogger::new();
+    let messages = capturing_logger.messages();
+
+    let mut frontend = HeadlessFrontend::new();
+    let mut logger: Option<Box<dyn Logger>> = Some(Box::new(capturing_logger));
+
+    let mut game_boy = GameBoy::new(&mut frontend, &game_rom, None, &mut logger).unwrap();
+
+    game_boy.set_serial_link(Box::new(serial_capture));
+    game_boy.run_frames(10);
+
+    assert_eq!(buffer.borrow().as_slice(), b"P");
+    assert_eq!(messages.borrow().len(), 1);
+    assert!(messages.borrow()[0].starts_with("Error writing the serial output: "));
+}
+
+#[test]
+fn socket_link_logs_the_disconnection() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    drop(TcpStream::connect(address).unwrap());
+
+    let (stream, _) = listener.accept().unwrap();
+    let link = SocketLink::tcp(stream).unwrap();
+
+    let mut code = transfer_instructions(0x42, SC_INTERNAL_CLOCK_TRANSFER);
+    code.extend_from_slice(&[0x18, 0xFE]); // JR -2
+
+    let game_rom = build_rom(&code, false);
+
+    let capturing_logger = CapturingLogger::new();
+    let messages = capturing_logger.messages();
+
+    let mut frontend = HeadlessFrontend::new();
+    let mut logger: Option<Box<dyn Logger>> = Some(Box::new(capturing_logger));
+
+    let mut game_boy = GameBoy::new(&mut frontend, &game_rom, None, &mut logger).unwrap();
+
+    game_boy.set_serial_link(Box::new(link));
+    game_boy.run_frames(10);
+
+    assert_eq!(messages.borrow().len(), 1);
+    assert!(messages.borrow()[0].starts_with("Serial link disconnected: "));
+}
+
+#[test]
+fn socket_link_provider_keeps_running_while_waiting_for_the_answer() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let mut peer = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+
+    let (stream, _) = listener.accept().unwrap();
+    let link = SocketLink::tcp(stream).unwrap();
+
+    let game_rom = exchange_rom(0x42, SC_INTERNAL_CLOCK_TRANSFER);
+
+    let save_storage = CapturingStorage::new();
+    let saved_data = save_storage.saved_data();
+
+    let capturing_logger = CapturingLogger::new();
+    let messages = capturing_logger.messages();
+
+    let mut frontend = HeadlessFrontend::new();
+    let mut logger: Option<Box<dyn Logger>> = Some(Box::new(capturing_logger));
+
+    let mut game_boy = GameBoy::new(&mut frontend, &game_rom, None, &mut logger).unwrap();
+
+    game_boy.set_save_storage(Box::new(save_storage));
+    game_boy.set_serial_link(Box::new(link));
+    game_boy.run_frames(5);
+
+    assert_eq!(saved_data.borrow().as_ref().unwrap()[0], 0);
+    assert!(messages.borrow().is_empty());
+
+    let mut sent = [0];
+    peer.read_exact(&mut sent).unwrap();
+    peer.write_all(&[0x24]).unwrap();
+
+    assert_eq!(sent, [0x42]);
+
+    game_boy.run_frames(1);
+
+    assert_eq!(saved_data.borrow().as_ref().unwrap()[0], 0x24);
+    assert!(messages.borrow().is_empty());
+}
+
+#[test]
+fn socket_link_exchanges_bytes_between_two_instances() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let master = thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let link = SocketLink::tcp(stream).unwrap();
+
+        run_exchange(&exchange_rom(0x42, SC_INTERNAL_CLOCK_TRANSFER), link)
+    });
+
+    let slave = thread::spawn(move || {
+        let link = SocketLink::tcp(TcpStream::connect(address).unwrap()).unwrap();
+
+        run_exchange(&exchange_rom(0x24, SC_EXTERNAL_CLOCK_TRANSFER), link)
+    });
+
+    assert_eq!(master.join().unwrap(), 0x24);
+    assert_eq!(slave.join().unwrap(), 0x42);
+}
@@ -0,0 +1,173 @@
+// Test ROM runner: detection of the outcomes, on locally crafted ROMs, and the run of the actual
+// test ROMs (Blargg, mooneye), which are not bundled; the directory containing them is passed via
+// `GAMEBOY_TEST_ROMS_DIR`, and the test is ignored by default, since it's long running:
+//
+//     GAMEBOY_TEST_ROMS_DIR=/path/to/roms cargo test --release -p system-gameboy -- --ignored
+//
+// The maximum number of frames per ROM can be set via `GAMEBOY_TEST_ROMS_MAX_FRAMES`.
+
+mod common;
+
+use common::build_rom;
+use system_gameboy::{
+    run_test_rom, run_test_rom_directory, summary_table, TestRomOutcome, TestRomResult,
+    DEFAULT_MAX_FRAMES,
+};
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+type Byte = u8;
+
+const TEST_DIRECTORY_VARIABLE: &str = "GAMEBOY_TEST_ROMS_DIR";
+const MAX_FRAMES_VARIABLE: &str = "GAMEBOY_TEST_ROMS_MAX_FRAMES";
+
+const CRAFTED_ROMS_MAX_FRAMES: u32 = 30;
+
+const LOOP_INSTRUCTION: [Byte; 2] = [0x18, 0xFE]; // JR -2
+
+fn serial_print_rom(text: &[Byte]) -> Vec<Byte> {
+    build_rom(&serial_print_code(text), false)
+}
+
+fn serial_print_code(text: &[Byte]) -> Vec<Byte> {
+    let mut code = vec![];
+
+    for &value in text {
+        let instructions: &[&[Byte]] = &[
+            &[0x3E, value, 0xE0, 0x01],            // LD A, n; LDH (SB), A
+            &[0x3E, 0x81, 0xE0, 0x02],             // LD A, 0x81; LDH (SC), A
+            &[0xF0, 0x02, 0xCB, 0x7F, 0x20, 0xFA], // LDH A, (SC); BIT 7, A; JR NZ
+        ];
+
+        code.extend(instructions.concat());
+    }
+
+    code.extend_from_slice(&LOOP_INSTRUCTION);
+
+    code
+}
+
+// Sets B, C, D, E, H, L, then executes the debug breakpoint.
+//
+fn breakpoint_rom(registers: [Byte; 6]) -> Vec<Byte> {
+    let mut code = vec![];
+
+    for (&opcode, &value) in [0x06, 0x0E, 0x16, 0x1E, 0x26, 0x2E]
+        .iter()
+        .zip(registers.iter())
+    {
+        code.extend_from_slice(&[opcode, value]); // LD r, n
+    }
+
+    code.push(0x40); // LD B, B
+    code.extend_from_slice(&LOOP_INSTRUCTION);
+
+    build_rom(&code, false)
+}
+
+fn write_file(directory: &Path, name: &str, content: &[Byte]) {
+    let path = directory.join(name);
+
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+#[test]
+fn test_rom_runner_detects_the_outcomes() {
+    let directory =
+        std::env::temp_dir().join(format!("system-gameboy-test-roms-{}", std::process::id()));
+
+    write_file(
+        &directory,
+        "blargg/failed.gb",
+        &serial_print_rom(b"Failed #1\n"),
+    );
+    write_file(
+        &directory,
+        "blargg/passed.gb",
+        &serial_print_rom(b"Passed\n"),
+    );
+    write_file(&directory, "broken.gb", &[0; 0x10]);
+    write_file(&directory, "loop.gb", &breakpoint_rom([1, 2, 3, 4, 5, 6]));
+    write_file(&directory, "mooneye/failed.gb", &breakpoint_rom([0x42; 6]));
+    write_file(
+        &directory,
+        "mooneye/passed.gbc",
+        &breakpoint_rom([3, 5, 8, 13, 21, 34]),
+    );
+    write_file(&directory, "readme.txt", b"Not a ROM");
+
+    let results = run_test_rom_directory(&directory, CRAFTED_ROMS_MAX_FRAMES);
+
+    fs::remove_dir_all(&directory).unwrap();
+
+    let results = results.unwrap();
+
+    let outcomes = results
+        .iter()
+        .map(|result| (result.name.as_str(), result.outcome.clone()))
+        .collect::<Vec<(&str, TestRomOutcome)>>();
+
+    assert_eq!(outcomes.len(), 6);
+    assert_eq!(outcomes[0], ("blargg/failed.gb", TestRomOutcome::Failed));
+    assert_eq!(outcomes[1], ("blargg/passed.gb", TestRomOutcome::Passed));
+    assert_eq!(outcomes[2].0, "broken.gb");
+    assert!(matches!(outcomes[2].1, TestRomOutcome::Error(_)));
+    assert_eq!(outcomes[3], ("loop.gb", TestRomOutcome::TimedOut));
+    assert_eq!(outcomes[4], ("mooneye/failed.gb", TestRomOutcome::Failed));
+    assert_eq!(outcomes[5], ("mooneye/passed.gbc", TestRomOutcome::Passed));
+
+    assert_eq!(results[3].frames, CRAFTED_ROMS_MAX_FRAMES);
+
+    assert!(summary_table(&results).ends_with("2 passed, 2 failed, 1 timed out, 1 errors\n"));
+}
+
+#[test]
+fn test_rom_runner_resumes_the_frame_after_an_unknown_breakpoint() {
+    let mut code = vec![
+        0xAF, // XOR A
+        0x40, // LD B, B
+        0x3D, // DEC A
+        0x20, 0xFC, // JR NZ, -4
+    ];
+    code.extend(serial_print_code(b"Passed\n"));
+
+    // With a frame per breakpoint, the 256 breakpoints would exhaust the budget.
+    //
+    let (outcome, frames) = run_test_rom(&build_rom(&code, false), CRAFTED_ROMS_MAX_FRAMES);
+
+    assert_eq!(outcome, TestRomOutcome::Passed);
+    assert!(frames <= 2);
+}
+
+#[test]
+#[ignore]
+fn test_roms() {
+    let directory = std::env::var_os(TEST_DIRECTORY_VARIABLE)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| panic!("`{}` not set", TEST_DIRECTORY_VARIABLE));
+
+    let max_frames = std::env::var(MAX_FRAMES_VARIABLE).map_or(DEFAULT_MAX_FRAMES, |max_frames| {
+        max_frames
+            .parse()
+            .unwrap_or_else(|_| panic!("Invalid `{}`", MAX_FRAMES_VARIABLE))
+    });
+
+    let results = run_test_rom_directory(&directory, max_frames)
+        .unwrap_or_else(|error| panic!("Error reading {:?}: {}", directory, error));
+
+    println!("{}", summary_table(&results));
+
+    let unsuccessful = results
+        .iter()
+        .filter(|result| result.outcome != TestRomOutcome::Passed)
+        .collect::<Vec<&TestRomResult>>();
+
+    assert!(
+        unsuccessful.is_empty(),
+        "{} of {} test ROMs didn't pass",
+        unsuccessful.len(),
+        results.len()
+    );
+}